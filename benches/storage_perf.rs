@@ -81,6 +81,8 @@ fn create_test_issue(i: usize) -> Issue {
         labels: vec![format!("label-{}", i % 5)],
         dependencies: vec![],
         comments: vec![],
+        recurrence: None,
+        worklog: vec![],
     }
 }
 
@@ -289,6 +291,8 @@ fn bench_update_issue(c: &mut Criterion) {
                 deleted_at: None,
                 deleted_by: None,
                 delete_reason: None,
+                close_outcome: None,
+                claim: false,
                 skip_cache_rebuild: false,
             };
             let _ = storage.update_issue(black_box(&id), black_box(&update), "benchmark");
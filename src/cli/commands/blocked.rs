@@ -5,12 +5,15 @@
 use crate::cli::{BlockedArgs, OutputFormat, resolve_output_format_basic};
 use crate::config::{
     CliOverrides, discover_beads_dir, external_project_db_paths, load_config,
-    open_storage_with_cli, should_use_color,
+    open_storage_with_cli, open_town_projects, should_use_color,
 };
 use crate::error::Result;
-use crate::format::{BlockedIssue, BlockedIssueOutput};
+use crate::format::{BlockedIssue, BlockedIssueOutput, ProjectScoped, describe_blocker_rule};
 use crate::model::{IssueType, Priority};
 use crate::output::{OutputContext, OutputMode};
+use crate::storage::SqliteStorage;
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::str::FromStr;
 
 /// Execute the blocked command.
@@ -41,6 +44,86 @@ pub fn execute(
     let quiet = overrides.quiet.unwrap_or(false);
     let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
 
+    // With --town, each routed project is queried and the results merged
+    let (mut blocked_issues, projects) = if args.town {
+        let mut merged = Vec::new();
+        let mut projects = HashMap::new();
+        for project in open_town_projects(&beads_dir, overrides)? {
+            for bi in collect_blocked(&project.storage, &project.external_db_paths, args)? {
+                projects.insert(bi.issue.id.clone(), project.name.clone());
+                merged.push(bi);
+            }
+        }
+        (merged, Some(projects))
+    } else {
+        (collect_blocked(storage, &external_db_paths, args)?, None)
+    };
+
+    // Sort by priority (ascending), then by blocker count (descending)
+    sort_blocked_issues(&mut blocked_issues);
+
+    // Apply limit
+    if args.limit > 0 && blocked_issues.len() > args.limit {
+        blocked_issues.truncate(args.limit);
+    }
+
+    for bi in &blocked_issues {
+        tracing::trace!(
+            id = %bi.issue.id,
+            blockers = ?bi.blocked_by,
+            "Blocked issue: {} blocked by {:?}",
+            bi.issue.id,
+            bi.blocked_by
+        );
+    }
+
+    // Output
+    if matches!(ctx.mode(), OutputMode::Quiet) {
+        return Ok(());
+    }
+
+    match output_format {
+        OutputFormat::Json => {
+            if let Some(projects) = &projects {
+                ctx.json_pretty(&project_scoped(&blocked_issues, projects));
+            } else {
+                let output: Vec<BlockedIssueOutput> =
+                    blocked_issues.iter().map(blocked_output).collect();
+                ctx.json_pretty(&output);
+            }
+        }
+        OutputFormat::Toon => {
+            if let Some(projects) = &projects {
+                ctx.toon_with_stats(&project_scoped(&blocked_issues, projects), args.stats);
+            } else {
+                let output: Vec<BlockedIssueOutput> =
+                    blocked_issues.iter().map(blocked_output).collect();
+                ctx.toon_with_stats(&output, args.stats);
+            }
+        }
+        OutputFormat::Text | OutputFormat::Csv => {
+            let max_width = if args.wrap { ctx.width() } else { 0 };
+            if let Some(projects) = &projects {
+                // Blocker details live in other databases, so town output stays compact
+                print_text_output(&blocked_issues, false, storage, max_width, Some(projects));
+            } else if matches!(ctx.mode(), OutputMode::Rich) {
+                render_blocked_rich(&blocked_issues, args.detailed, storage, max_width);
+            } else {
+                print_text_output(&blocked_issues, args.detailed, storage, max_width, None);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Blocked issues from one database, including those held by `external:` blockers,
+/// with the type, priority and label filters applied.
+fn collect_blocked(
+    storage: &SqliteStorage,
+    external_db_paths: &HashMap<String, PathBuf>,
+    args: &BlockedArgs,
+) -> Result<Vec<BlockedIssue>> {
     // Get blocked issues from cache
     let blocked_raw = storage.get_blocked_issues()?;
 
@@ -61,11 +144,11 @@ pub fn execute(
         .collect();
 
     let external_statuses =
-        storage.resolve_external_dependency_statuses(&external_db_paths, true)?;
+        storage.resolve_external_dependency_statuses(external_db_paths, true)?;
     let external_blockers = storage.external_blockers(&external_statuses)?;
 
     if !external_blockers.is_empty() {
-        let mut by_id: std::collections::HashMap<String, usize> = blocked_issues
+        let mut by_id: HashMap<String, usize> = blocked_issues
             .iter()
             .enumerate()
             .map(|(idx, bi)| (bi.issue.id.clone(), idx))
@@ -105,87 +188,7 @@ pub fn execute(
         filter_by_labels(&mut blocked_issues, storage, &args.label)?;
     }
 
-    // Sort by priority (ascending), then by blocker count (descending)
-    sort_blocked_issues(&mut blocked_issues);
-
-    // Apply limit
-    if args.limit > 0 && blocked_issues.len() > args.limit {
-        blocked_issues.truncate(args.limit);
-    }
-
-    for bi in &blocked_issues {
-        tracing::trace!(
-            id = %bi.issue.id,
-            blockers = ?bi.blocked_by,
-            "Blocked issue: {} blocked by {:?}",
-            bi.issue.id,
-            bi.blocked_by
-        );
-    }
-
-    // Output
-    if matches!(ctx.mode(), OutputMode::Quiet) {
-        return Ok(());
-    }
-
-    match output_format {
-        OutputFormat::Json => {
-            let output: Vec<BlockedIssueOutput> = blocked_issues
-                .iter()
-                .map(|bi| BlockedIssueOutput {
-                    blocked_by: bi
-                        .blocked_by
-                        .iter()
-                        .map(|blocker_ref| blocker_id_from_ref(blocker_ref).to_string())
-                        .collect(),
-                    blocked_by_count: bi.blocked_by_count,
-                    created_at: bi.issue.created_at,
-                    created_by: bi.issue.created_by.clone(),
-                    description: bi.issue.description.clone(),
-                    id: bi.issue.id.clone(),
-                    issue_type: bi.issue.issue_type.clone(),
-                    priority: bi.issue.priority,
-                    status: bi.issue.status.clone(),
-                    title: bi.issue.title.clone(),
-                    updated_at: bi.issue.updated_at,
-                })
-                .collect();
-            ctx.json_pretty(&output);
-        }
-        OutputFormat::Toon => {
-            let output: Vec<BlockedIssueOutput> = blocked_issues
-                .iter()
-                .map(|bi| BlockedIssueOutput {
-                    blocked_by: bi
-                        .blocked_by
-                        .iter()
-                        .map(|blocker_ref| blocker_id_from_ref(blocker_ref).to_string())
-                        .collect(),
-                    blocked_by_count: bi.blocked_by_count,
-                    created_at: bi.issue.created_at,
-                    created_by: bi.issue.created_by.clone(),
-                    description: bi.issue.description.clone(),
-                    id: bi.issue.id.clone(),
-                    issue_type: bi.issue.issue_type.clone(),
-                    priority: bi.issue.priority,
-                    status: bi.issue.status.clone(),
-                    title: bi.issue.title.clone(),
-                    updated_at: bi.issue.updated_at,
-                })
-                .collect();
-            ctx.toon_with_stats(&output, args.stats);
-        }
-        OutputFormat::Text | OutputFormat::Csv => {
-            let max_width = if args.wrap { ctx.width() } else { 0 };
-            if matches!(ctx.mode(), OutputMode::Rich) {
-                render_blocked_rich(&blocked_issues, args.detailed, storage, max_width);
-            } else {
-                print_text_output(&blocked_issues, args.detailed, storage, max_width);
-            }
-        }
-    }
-
-    Ok(())
+    Ok(blocked_issues)
 }
 
 /// Sort blocked issues by priority (ascending), then by blocker count (descending).
@@ -230,7 +233,7 @@ fn filter_by_priority(issues: &mut Vec<BlockedIssue>, priorities: &[String]) ->
 
 fn filter_by_labels(
     issues: &mut Vec<BlockedIssue>,
-    storage: &crate::storage::SqliteStorage,
+    storage: &SqliteStorage,
     labels: &[String],
 ) -> Result<()> {
     let mut filtered = Vec::with_capacity(issues.len());
@@ -252,8 +255,9 @@ fn filter_by_labels(
 fn print_text_output(
     blocked_issues: &[BlockedIssue],
     verbose: bool,
-    storage: &crate::storage::SqliteStorage,
+    storage: &SqliteStorage,
     max_width: usize,
+    projects: Option<&HashMap<String, String>>,
 ) {
     use crate::format::truncate_title;
 
@@ -279,7 +283,10 @@ fn print_text_output(
             truncate_title(&bi.issue.title, max_width.saturating_sub(prefix_len))
         };
         // Match bd format: [● P2] ID: Title
-        println!("[● P{}] {}: {}", priority, bi.issue.id, title);
+        match projects.and_then(|p| p.get(&bi.issue.id)) {
+            Some(project) => println!("[{project}] [● P{priority}] {}: {title}", bi.issue.id),
+            None => println!("[● P{}] {}: {}", priority, bi.issue.id, title),
+        }
 
         if verbose {
             println!("  Blocked by:");
@@ -316,9 +323,55 @@ fn print_text_output(
                 ids.join(", ")
             );
         }
+        for rule in blocker_rules(&bi.blocked_by) {
+            println!("  Rule: {rule}");
+        }
     }
 }
 
+fn blocked_output(bi: &BlockedIssue) -> BlockedIssueOutput {
+    BlockedIssueOutput {
+        blocked_by: bi
+            .blocked_by
+            .iter()
+            .map(|blocker_ref| blocker_id_from_ref(blocker_ref).to_string())
+            .collect(),
+        blocked_by_count: bi.blocked_by_count,
+        created_at: bi.issue.created_at,
+        created_by: bi.issue.created_by.clone(),
+        description: bi.issue.description.clone(),
+        id: bi.issue.id.clone(),
+        issue_type: bi.issue.issue_type.clone(),
+        priority: bi.issue.priority,
+        status: bi.issue.status.clone(),
+        title: bi.issue.title.clone(),
+        updated_at: bi.issue.updated_at,
+        rules: blocker_rules(&bi.blocked_by),
+    }
+}
+
+/// Tag each blocked issue with the project it came from (`--town`).
+fn project_scoped(
+    issues: &[BlockedIssue],
+    projects: &HashMap<String, String>,
+) -> Vec<ProjectScoped<BlockedIssueOutput>> {
+    issues
+        .iter()
+        .map(|bi| ProjectScoped {
+            project: projects.get(&bi.issue.id).cloned().unwrap_or_default(),
+            item: blocked_output(bi),
+        })
+        .collect()
+}
+
+/// Explanations for blockers held by conditional-blocks or waits-for rules.
+fn blocker_rules(blocked_by: &[String]) -> Vec<String> {
+    blocked_by
+        .iter()
+        .filter_map(|blocker_ref| describe_blocker_rule(blocker_ref))
+        .collect()
+}
+
 fn blocker_id_from_ref(blocker_ref: &str) -> &str {
     // Split from the right to preserve external IDs containing ':'
     blocker_ref
@@ -329,7 +382,7 @@ fn blocker_id_from_ref(blocker_ref: &str) -> &str {
 fn render_blocked_rich(
     blocked_issues: &[BlockedIssue],
     verbose: bool,
-    storage: &crate::storage::SqliteStorage,
+    storage: &SqliteStorage,
     max_width: usize,
 ) {
     use crate::format::truncate_title;
@@ -435,6 +488,12 @@ fn render_blocked_rich(
             );
             console.print_renderable(&detail);
         }
+        for rule in blocker_rules(&bi.blocked_by) {
+            let mut rule_line = Text::new("");
+            rule_line.append_styled("  Rule: ", Style::new().dim());
+            rule_line.append_styled(&rule, Style::new().color(color("yellow")));
+            console.print_renderable(&rule_line);
+        }
     }
 }
 
@@ -488,6 +547,8 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         }
     }
 
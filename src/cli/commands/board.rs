@@ -0,0 +1,713 @@
+//! Board command implementation.
+//!
+//! `br board` is a full-screen kanban view with one column per status (open,
+//! in progress, blocked, deferred, closed). Issues are loaded with the same
+//! `ListFilters` as `br list`. Status, priority and assignee changes go
+//! through `SqliteStorage::update_issue`, so they record events and mark
+//! issues dirty exactly like `br update`. The detail pane renders the
+//! `IssuePanel` used by `br show`.
+
+use crate::cli::BoardArgs;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::format::{IssueDetails, truncate_title};
+use crate::model::{Issue, IssueType, Priority, Status};
+use crate::output::{IssuePanel, OutputContext, Theme};
+use crate::storage::{IssueUpdate, ListFilters, SqliteStorage};
+use chrono::Utc;
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::{Print, Stylize};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use rich_rust::color::ColorSystem;
+use rich_rust::prelude::{Segment, Style};
+use std::io::{self, IsTerminal, Write};
+use unicode_width::UnicodeWidthStr;
+
+/// Board columns, left to right.
+const COLUMNS: [Status; 5] = [
+    Status::Open,
+    Status::InProgress,
+    Status::Blocked,
+    Status::Deferred,
+    Status::Closed,
+];
+
+/// Index of the closed column (capped by `--closed-limit`).
+const CLOSED_COLUMN: usize = 4;
+
+/// Narrowest column before the board scrolls horizontally.
+const MIN_COLUMN_WIDTH: usize = 16;
+
+const BOARD_HELP: &str = "←→ column  ↑↓ issue  </> status  0-4 priority  a assignee  / filter  enter details  r reload  q quit";
+const DETAIL_HELP: &str = "↑↓ scroll  </> status  0-4 priority  a assignee  enter/esc back  q quit";
+
+/// Execute the board command.
+///
+/// # Errors
+///
+/// Returns an error if not attached to a terminal, or if the database or
+/// terminal operations fail.
+pub fn execute(args: &BoardArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    if ctx.is_json() || !io::stdout().is_terminal() || !io::stdin().is_terminal() {
+        return Err(BeadsError::Config(
+            "br board needs an interactive terminal; use `br list` for scripted output".to_string(),
+        ));
+    }
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let actor = config::resolve_actor(&layer);
+    let filters = build_filters(args)?;
+
+    {
+        let mut board = Board::new(
+            &mut storage_ctx.storage,
+            filters,
+            args.closed_limit,
+            actor,
+            ctx.theme().clone(),
+            ctx.is_rich(),
+        );
+        board.reload()?;
+
+        let _guard = TerminalGuard::enter()?;
+        board.run(&mut io::stdout())?;
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
+
+/// Convert CLI args to the storage filter (all statuses, including closed).
+fn build_filters(args: &BoardArgs) -> Result<ListFilters> {
+    let types = if args.type_.is_empty() {
+        None
+    } else {
+        Some(
+            args.type_
+                .iter()
+                .map(|t| t.parse())
+                .collect::<Result<Vec<IssueType>>>()?,
+        )
+    };
+
+    let priorities = if args.priority.is_empty() {
+        None
+    } else {
+        Some(
+            args.priority
+                .iter()
+                .map(|p| p.parse())
+                .collect::<Result<Vec<Priority>>>()?,
+        )
+    };
+
+    Ok(ListFilters {
+        types,
+        priorities,
+        assignee: args.assignee.clone(),
+        unassigned: args.unassigned,
+        include_closed: true,
+        include_deferred: true,
+        title_contains: args.title_contains.clone(),
+        labels: if args.label.is_empty() {
+            None
+        } else {
+            Some(args.label.clone())
+        },
+        labels_or: if args.label_any.is_empty() {
+            None
+        } else {
+            Some(args.label_any.clone())
+        },
+        ..ListFilters::default()
+    })
+}
+
+/// Bucket issues into board columns.
+///
+/// Issues with other statuses (tombstone, pinned, custom) are left off the
+/// board. The closed column shows the most recently closed issues first,
+/// capped at `closed_limit`.
+fn into_columns(issues: Vec<Issue>, closed_limit: usize) -> Vec<Vec<Issue>> {
+    let mut columns: Vec<Vec<Issue>> = vec![Vec::new(); COLUMNS.len()];
+    for issue in issues {
+        if let Some(idx) = COLUMNS.iter().position(|status| *status == issue.status) {
+            columns[idx].push(issue);
+        }
+    }
+    let closed = &mut columns[CLOSED_COLUMN];
+    closed.sort_by(|a, b| b.closed_at.cmp(&a.closed_at));
+    closed.truncate(closed_limit);
+    columns
+}
+
+/// Restores the terminal when the board exits, including on error.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(Self)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Prompt {
+    Assignee,
+    Filter,
+}
+
+enum Mode {
+    Board,
+    Detail,
+    Prompt {
+        prompt: Prompt,
+        input: String,
+        from_detail: bool,
+    },
+}
+
+struct Board<'a> {
+    storage: &'a mut SqliteStorage,
+    filters: ListFilters,
+    closed_limit: usize,
+    actor: String,
+    theme: Theme,
+    color: bool,
+    columns: Vec<Vec<Issue>>,
+    column: usize,
+    selected: [usize; COLUMNS.len()],
+    mode: Mode,
+    details: Option<IssueDetails>,
+    detail_scroll: usize,
+    message: Option<String>,
+}
+
+impl<'a> Board<'a> {
+    fn new(
+        storage: &'a mut SqliteStorage,
+        filters: ListFilters,
+        closed_limit: usize,
+        actor: String,
+        theme: Theme,
+        color: bool,
+    ) -> Self {
+        Self {
+            storage,
+            filters,
+            closed_limit,
+            actor,
+            theme,
+            color,
+            columns: vec![Vec::new(); COLUMNS.len()],
+            column: 0,
+            selected: [0; COLUMNS.len()],
+            mode: Mode::Board,
+            details: None,
+            detail_scroll: 0,
+            message: None,
+        }
+    }
+
+    fn current(&self) -> Option<&Issue> {
+        self.columns
+            .get(self.column)?
+            .get(self.selected[self.column])
+    }
+
+    /// Reload issues, keeping the selection on the same issue if still visible.
+    fn reload(&mut self) -> Result<()> {
+        let selected_id = self.current().map(|issue| issue.id.clone());
+        self.columns = into_columns(self.storage.list_issues(&self.filters)?, self.closed_limit);
+
+        if let Some(id) = selected_id {
+            for (column, issues) in self.columns.iter().enumerate() {
+                if let Some(row) = issues.iter().position(|issue| issue.id == id) {
+                    self.column = column;
+                    self.selected[column] = row;
+                }
+            }
+        }
+        for (row, issues) in self.selected.iter_mut().zip(&self.columns) {
+            *row = (*row).min(issues.len().saturating_sub(1));
+        }
+
+        if matches!(self.mode, Mode::Detail) {
+            self.load_details()?;
+        }
+        Ok(())
+    }
+
+    fn load_details(&mut self) -> Result<()> {
+        self.details = match self.current().map(|issue| issue.id.clone()) {
+            Some(id) => self.storage.get_issue_details(&id, true, false, 0)?,
+            None => None,
+        };
+        Ok(())
+    }
+
+    fn run(&mut self, out: &mut impl Write) -> Result<()> {
+        loop {
+            self.draw(out)?;
+            // Non-key events (e.g. resize) just trigger a redraw.
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            if !self.handle_key(key)? {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Handle a key press. Returns `false` when the board should close.
+    fn handle_key(&mut self, key: KeyEvent) -> Result<bool> {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return Ok(false);
+        }
+        if matches!(self.mode, Mode::Prompt { .. }) {
+            self.handle_prompt_key(key)?;
+            return Ok(true);
+        }
+
+        self.message = None;
+        let detail = matches!(self.mode, Mode::Detail);
+        match key.code {
+            KeyCode::Char('q') => return Ok(false),
+            KeyCode::Esc if !detail => return Ok(false),
+            KeyCode::Esc | KeyCode::Enter if detail => self.mode = Mode::Board,
+            KeyCode::Enter => {
+                if self.current().is_some() {
+                    self.mode = Mode::Detail;
+                    self.detail_scroll = 0;
+                    self.load_details()?;
+                }
+            }
+            KeyCode::Up | KeyCode::Char('k') if detail => {
+                self.detail_scroll = self.detail_scroll.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') if detail => self.detail_scroll += 1,
+            KeyCode::Up | KeyCode::Char('k') => self.move_row(false),
+            KeyCode::Down | KeyCode::Char('j') => self.move_row(true),
+            KeyCode::Left | KeyCode::Char('h') if !detail => self.move_column(false),
+            KeyCode::Right | KeyCode::Char('l') if !detail => self.move_column(true),
+            KeyCode::Char('<' | 'H') => self.shift_status(false)?,
+            KeyCode::Char('>' | 'L') => self.shift_status(true)?,
+            KeyCode::Char(digit @ '0'..='4') => self.set_priority(digit)?,
+            KeyCode::Char('a') => self.start_prompt(Prompt::Assignee, detail),
+            KeyCode::Char('/') if !detail => self.start_prompt(Prompt::Filter, detail),
+            KeyCode::Char('r') => self.reload()?,
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    fn move_row(&mut self, down: bool) {
+        let len = self.columns[self.column].len();
+        let row = &mut self.selected[self.column];
+        if down {
+            if *row + 1 < len {
+                *row += 1;
+            }
+        } else {
+            *row = row.saturating_sub(1);
+        }
+    }
+
+    fn move_column(&mut self, right: bool) {
+        if right {
+            if self.column + 1 < COLUMNS.len() {
+                self.column += 1;
+            }
+        } else {
+            self.column = self.column.saturating_sub(1);
+        }
+    }
+
+    /// Move the selected issue to the neighbouring status column.
+    fn shift_status(&mut self, right: bool) -> Result<()> {
+        let Some(id) = self.current().map(|issue| issue.id.clone()) else {
+            return Ok(());
+        };
+        let target = if right {
+            self.column + 1
+        } else if let Some(target) = self.column.checked_sub(1) {
+            target
+        } else {
+            return Ok(());
+        };
+        let Some(status) = COLUMNS.get(target).cloned() else {
+            return Ok(());
+        };
+
+        // Same closed_at handling as `br update --status`.
+        let closed_at = match status {
+            Status::Closed => Some(Some(Utc::now())),
+            Status::Open | Status::InProgress => Some(None),
+            _ => None,
+        };
+        let message = format!("{id} → {status}");
+        let update = IssueUpdate {
+            status: Some(status),
+            closed_at,
+            ..IssueUpdate::default()
+        };
+        self.apply(&id, &update, message)
+    }
+
+    fn set_priority(&mut self, digit: char) -> Result<()> {
+        let Some(id) = self.current().map(|issue| issue.id.clone()) else {
+            return Ok(());
+        };
+        let priority: Priority = digit.to_string().parse()?;
+        let update = IssueUpdate {
+            priority: Some(priority),
+            ..IssueUpdate::default()
+        };
+        self.apply(&id, &update, format!("{id} → P{}", priority.0))
+    }
+
+    fn start_prompt(&mut self, prompt: Prompt, from_detail: bool) {
+        let input = match prompt {
+            Prompt::Assignee => match self.current() {
+                Some(issue) => issue.assignee.clone().unwrap_or_default(),
+                None => return,
+            },
+            Prompt::Filter => self.filters.title_contains.clone().unwrap_or_default(),
+        };
+        self.mode = Mode::Prompt {
+            prompt,
+            input,
+            from_detail,
+        };
+    }
+
+    fn handle_prompt_key(&mut self, key: KeyEvent) -> Result<()> {
+        let Mode::Prompt {
+            prompt,
+            input,
+            from_detail,
+        } = &mut self.mode
+        else {
+            return Ok(());
+        };
+        match key.code {
+            KeyCode::Char(ch) => input.push(ch),
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Esc | KeyCode::Enter => {
+                let submit = key.code == KeyCode::Enter;
+                let prompt = *prompt;
+                let value = input.trim().to_string();
+                self.mode = if *from_detail {
+                    Mode::Detail
+                } else {
+                    Mode::Board
+                };
+                if submit {
+                    self.submit_prompt(prompt, value)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn submit_prompt(&mut self, prompt: Prompt, value: String) -> Result<()> {
+        match prompt {
+            Prompt::Assignee => {
+                let Some(id) = self.current().map(|issue| issue.id.clone()) else {
+                    return Ok(());
+                };
+                let message = if value.is_empty() {
+                    format!("{id} unassigned")
+                } else {
+                    format!("{id} → @{value}")
+                };
+                let update = IssueUpdate {
+                    assignee: Some((!value.is_empty()).then_some(value)),
+                    ..IssueUpdate::default()
+                };
+                self.apply(&id, &update, message)
+            }
+            Prompt::Filter => {
+                self.message = Some(if value.is_empty() {
+                    "Filter cleared".to_string()
+                } else {
+                    format!("Filtering titles by \"{value}\"")
+                });
+                self.filters.title_contains = (!value.is_empty()).then_some(value);
+                self.reload()
+            }
+        }
+    }
+
+    /// Apply an update and reload. Storage errors (e.g. validation) are shown
+    /// on the status line rather than closing the board.
+    fn apply(&mut self, id: &str, update: &IssueUpdate, message: String) -> Result<()> {
+        match self.storage.update_issue(id, update, &self.actor) {
+            Ok(_) => {
+                self.message = Some(message);
+                self.reload()
+            }
+            Err(err) => {
+                self.message = Some(format!("Error: {err}"));
+                Ok(())
+            }
+        }
+    }
+
+    fn draw(&self, out: &mut impl Write) -> Result<()> {
+        let (width, height) = terminal::size()?;
+        let (width, height) = (usize::from(width), usize::from(height));
+        let body_height = height.saturating_sub(1);
+
+        let lines = if matches!(
+            self.mode,
+            Mode::Detail
+                | Mode::Prompt {
+                    from_detail: true,
+                    ..
+                }
+        ) {
+            self.detail_lines(width, body_height)
+        } else {
+            self.board_lines(width, body_height)
+        };
+
+        for y in 0..body_height {
+            queue!(out, MoveTo(0, to_u16(y)))?;
+            if let Some(line) = lines.get(y) {
+                queue!(out, Print(line))?;
+            }
+            queue!(out, Clear(ClearType::UntilNewLine))?;
+        }
+        queue!(
+            out,
+            MoveTo(0, to_u16(body_height)),
+            Print(self.status_line(width)),
+            Clear(ClearType::UntilNewLine)
+        )?;
+        out.flush()?;
+        Ok(())
+    }
+
+    fn board_lines(&self, width: usize, height: usize) -> Vec<String> {
+        let column_width = (width / COLUMNS.len()).max(MIN_COLUMN_WIDTH);
+        let visible = (width / column_width).clamp(1, COLUMNS.len());
+        let first = (self.column + 1).saturating_sub(visible);
+        let shown = first..first + visible;
+        let cell_width = column_width.saturating_sub(1);
+
+        let total: usize = self.columns.iter().map(Vec::len).sum();
+        let mut title = format!("br board · {total} issues");
+        if let Some(filter) = &self.filters.title_contains {
+            title.push_str(&format!(" · title contains \"{filter}\""));
+        }
+        let mut lines = vec![self.paint(&self.theme.emphasis, &truncate_title(&title, width))];
+
+        let mut header = String::new();
+        let mut rule = String::new();
+        for idx in shown.clone() {
+            let marker = if idx == self.column { "▸" } else { " " };
+            let label = format!(
+                "{marker}{} ({})",
+                COLUMNS[idx].as_str().replace('_', " "),
+                self.columns[idx].len()
+            );
+            let label = pad(&truncate_title(&label, cell_width), cell_width);
+            header.push_str(&self.paint(&self.theme.status_style(&COLUMNS[idx]), &label));
+            header.push(' ');
+            rule.push_str(&"─".repeat(cell_width));
+            rule.push(' ');
+        }
+        lines.push(header);
+        lines.push(self.paint(&self.theme.dimmed, &rule));
+
+        let rows = height.saturating_sub(lines.len());
+        let offsets: Vec<usize> = shown
+            .clone()
+            .map(|idx| (self.selected[idx] + 1).saturating_sub(rows))
+            .collect();
+        for row in 0..rows {
+            let mut line = String::new();
+            for (idx, offset) in shown.clone().zip(&offsets) {
+                let index = offset + row;
+                match self.columns[idx].get(index) {
+                    Some(issue) => {
+                        let selected = idx == self.column && index == self.selected[idx];
+                        line.push_str(&self.card(issue, cell_width, selected));
+                    }
+                    None => line.push_str(&" ".repeat(cell_width)),
+                }
+                line.push(' ');
+            }
+            lines.push(line);
+        }
+        lines
+    }
+
+    fn card(&self, issue: &Issue, width: usize, selected: bool) -> String {
+        let prefix = format!("{} P{} ", issue.id, issue.priority.0);
+        let text = pad(
+            &truncate_title(&format!("{prefix}{}", issue.title), width),
+            width,
+        );
+        if selected {
+            return text.reverse().to_string();
+        }
+        match text.strip_prefix(&prefix) {
+            Some(rest) if self.color => format!(
+                "{} {} {rest}",
+                self.paint(&self.theme.issue_id, &issue.id),
+                self.paint(
+                    &self.theme.priority_style(issue.priority),
+                    &format!("P{}", issue.priority.0)
+                )
+            ),
+            _ => text,
+        }
+    }
+
+    fn detail_lines(&self, width: usize, height: usize) -> Vec<String> {
+        let Some(details) = &self.details else {
+            return vec!["Issue not found.".to_string()];
+        };
+        let panel = IssuePanel::from_details(details, &self.theme).build(width, true);
+        let lines = segments_to_lines(&panel.render(width), self.color);
+        let max_scroll = lines.len().saturating_sub(height);
+        lines
+            .into_iter()
+            .skip(self.detail_scroll.min(max_scroll))
+            .take(height)
+            .collect()
+    }
+
+    fn status_line(&self, width: usize) -> String {
+        if let Mode::Prompt { prompt, input, .. } = &self.mode {
+            let label = match prompt {
+                Prompt::Assignee => "Assignee (empty to clear): ",
+                Prompt::Filter => "Title filter (empty to clear): ",
+            };
+            return truncate_title(&format!("{label}{input}"), width);
+        }
+        if let Some(message) = &self.message {
+            return self.paint(&self.theme.info, &truncate_title(message, width));
+        }
+        let help = if matches!(self.mode, Mode::Detail) {
+            DETAIL_HELP
+        } else {
+            BOARD_HELP
+        };
+        self.paint(&self.theme.muted, &truncate_title(help, width))
+    }
+
+    fn paint(&self, style: &Style, text: &str) -> String {
+        if self.color {
+            style.render(text, ColorSystem::TrueColor)
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+/// Pad with spaces to `width` visible columns.
+fn pad(text: &str, width: usize) -> String {
+    let fill = width.saturating_sub(text.width());
+    format!("{text}{}", " ".repeat(fill))
+}
+
+fn to_u16(value: usize) -> u16 {
+    u16::try_from(value).unwrap_or(u16::MAX)
+}
+
+/// Split rendered segments into terminal lines.
+fn segments_to_lines(segments: &[Segment<'_>], color: bool) -> Vec<String> {
+    let mut lines = vec![String::new()];
+    for segment in segments {
+        for (idx, part) in segment.text.split('\n').enumerate() {
+            if idx > 0 {
+                lines.push(String::new());
+            }
+            if part.is_empty() {
+                continue;
+            }
+            let line = lines.last_mut().expect("lines is never empty");
+            match &segment.style {
+                Some(style) if color => line.push_str(&style.render(part, ColorSystem::TrueColor)),
+                _ => line.push_str(part),
+            }
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn make_issue(id: &str, status: Status) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: format!("Issue {id}"),
+            status,
+            ..Issue::default()
+        }
+    }
+
+    #[test]
+    fn into_columns_buckets_by_status_and_caps_closed() {
+        let t0 = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let mut issues = vec![
+            make_issue("bd-1", Status::Open),
+            make_issue("bd-2", Status::InProgress),
+            make_issue("bd-3", Status::Tombstone),
+            make_issue("bd-4", Status::Deferred),
+        ];
+        for (day, id) in ["bd-old", "bd-new", "bd-mid"].iter().enumerate() {
+            let mut issue = make_issue(id, Status::Closed);
+            issue.closed_at = Some(t0 + Duration::days(i64::try_from(day).unwrap()));
+            issues.push(issue);
+        }
+        let columns = into_columns(issues, 2);
+
+        let ids = |idx: usize| -> Vec<&str> {
+            columns[idx].iter().map(|issue| issue.id.as_str()).collect()
+        };
+        assert_eq!(ids(0), ["bd-1"]);
+        assert_eq!(ids(1), ["bd-2"]);
+        assert!(ids(2).is_empty());
+        assert_eq!(ids(3), ["bd-4"]);
+        assert_eq!(ids(CLOSED_COLUMN), ["bd-mid", "bd-new"]);
+    }
+
+    #[test]
+    fn build_filters_includes_closed_and_parses_priorities() {
+        let args = BoardArgs {
+            priority: vec!["1".to_string()],
+            label: vec!["backend".to_string()],
+            ..BoardArgs::default()
+        };
+        let filters = build_filters(&args).unwrap();
+        assert!(filters.include_closed);
+        assert!(filters.include_deferred);
+        assert_eq!(filters.priorities, Some(vec![Priority::HIGH]));
+        assert_eq!(filters.labels, Some(vec!["backend".to_string()]));
+    }
+}
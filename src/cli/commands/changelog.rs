@@ -1,65 +1,123 @@
 //! Changelog command implementation.
 //!
-//! Generates release notes from closed issues since a given date or git reference.
-//! Groups issues by type and sorts by priority within each group.
-
-use crate::cli::ChangelogArgs;
-use crate::config;
+//! Generates release notes from issues closed between two dates or git
+//! references. Entries are grouped by type, label or parent epic and sorted
+//! by priority within each group; config (`changelog.exclude_*`) and flags
+//! can leave issues out. `--format`/`--template` render the result through a
+//! Handlebars-style template, and `--output` writes it as a marked section of
+//! a file such as `CHANGELOG.md`, replacing the previous copy of that section.
+
+use crate::cli::commands::orphans::{get_git_commit_refs, is_git_repo};
+use crate::cli::{ChangelogArgs, ChangelogFormat, ChangelogGroupBy};
+use crate::config::{self, ChangelogExclusions};
 use crate::error::{BeadsError, Result};
-use crate::model::{Issue, Status};
+use crate::format::site::escape_html;
+use crate::model::{Issue, IssueType, Status};
 use crate::output::{OutputContext, OutputMode};
-use crate::storage::ListFilters;
+use crate::storage::{ListFilters, SqliteStorage};
+use crate::util::handlebars::Template;
 use crate::util::time::{parse_flexible_timestamp, parse_relative_time};
 use chrono::{DateTime, Utc};
 use rich_rust::prelude::*;
 use serde::Serialize;
-use std::collections::BTreeMap;
+use serde_json::json;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::io::ErrorKind;
 use std::process::Command;
 use tracing::debug;
 
+/// Built-in template for `--format markdown`.
+const MARKDOWN_TEMPLATE: &str = "\
+## {{title}}{{#if date}} - {{date}}{{/if}}
+
+{{#each groups}}
+### {{label}}
+
+{{#each issues}}
+- {{title}} (`{{id}}`){{#if commits}} ({{#each commits}}{{#unless @first}}, {{/unless}}`{{this}}`{{/each}}){{/if}}
+{{/each}}
+
+{{/each}}
+{{#unless groups}}
+_No changes._
+{{/unless}}
+";
+
+/// Built-in template for `--format html`.
+const HTML_TEMPLATE: &str = "\
+<section class=\"changelog\">
+<h2>{{title}}{{#if date}} <small>{{date}}</small>{{/if}}</h2>
+{{#each groups}}
+<h3>{{label}}</h3>
+<ul>
+{{#each issues}}
+<li>{{title}} <code>{{id}}</code>{{#if commits}} <span class=\"commits\">{{#each commits}}{{#unless @first}}, {{/unless}}<code>{{this}}</code>{{/each}}</span>{{/if}}</li>
+{{/each}}
+</ul>
+{{/each}}
+{{#unless groups}}
+<p>No changes.</p>
+{{/unless}}
+</section>
+";
+
 /// Changelog output structure.
 #[derive(Serialize, Debug)]
 pub struct ChangelogOutput {
     /// Start date for the changelog period.
     pub since: String,
-    /// End date for the changelog period (now).
+    /// End date for the changelog period (now unless `--until` is given).
     pub until: String,
     /// Total number of closed issues in the period.
     pub total_closed: usize,
-    /// Issues grouped by type.
+    /// Issues grouped by type, label or epic.
     pub groups: Vec<ChangelogGroup>,
 }
 
-/// A group of issues by type.
+/// A group of issues sharing a type, label or epic.
 #[derive(Serialize, Debug)]
 pub struct ChangelogGroup {
-    /// Issue type (feature, bug, task, etc.).
+    /// Issue type (feature, bug, task, etc.) when grouping by type.
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub issue_type: String,
-    /// Human-readable label for the type.
+    /// Grouping key: the type, label or epic ID (empty for "Other").
+    pub key: String,
+    /// Human-readable heading for the group.
     pub label: String,
     /// Issues in this group, sorted by priority.
     pub issues: Vec<ChangelogEntry>,
 }
 
 /// A single changelog entry.
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Default)]
 pub struct ChangelogEntry {
     pub id: String,
     pub title: String,
     pub priority: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub closed_at: Option<String>,
+    pub issue_type: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_reason: Option<String>,
+    /// Short hashes of commits mentioning the issue, oldest first.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub commits: Vec<String>,
 }
 
 /// Execute changelog generation.
 ///
 /// # Errors
 ///
-/// Returns an error if config loading, git lookup, or storage access fails.
+/// Returns an error if config loading, git lookup, storage access, template
+/// parsing or writing `--output` fails.
 ///
 /// # Panics
 ///
 /// Panics if JSON serialization of the output fails (should never happen with valid data).
+#[allow(clippy::too_many_lines)]
 pub fn execute(
     args: &ChangelogArgs,
     json: bool,
@@ -68,62 +126,96 @@ pub fn execute(
 ) -> Result<()> {
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let config::OpenStorageResult { storage, .. } = config::open_storage_with_cli(&beads_dir, cli)?;
+    let config_layer = config::load_config(&beads_dir, Some(&storage), cli)?;
+    let exclusions = merge_exclusions(args, config::changelog_exclusions_from_layer(&config_layer));
 
     let (since_dt, since_label) = resolve_since(args)?;
-    let until = Utc::now();
+    let (until, until_explicit) = resolve_until(args)?;
 
-    debug!(since = %since_label, "Filtering closed issues for changelog");
+    debug!(since = %since_label, until = %until.to_rfc3339(), "Filtering closed issues for changelog");
 
     let filters = ListFilters {
         statuses: Some(vec![Status::Closed]),
         include_closed: true,
         ..Default::default()
     };
-    let issues = storage.list_issues(&filters)?;
-
-    let mut grouped: BTreeMap<String, Vec<Issue>> = BTreeMap::new();
-    for issue in issues {
-        if let Some(since_dt) = since_dt {
+    let mut labels = storage.get_all_labels()?;
+    let mut issues = Vec::new();
+    for mut issue in storage.list_issues(&filters)? {
+        if since_dt.is_some() || until_explicit {
             let Some(closed_at) = issue.closed_at else {
                 continue;
             };
-            if closed_at < since_dt {
+            if since_dt.is_some_and(|since_dt| closed_at < since_dt)
+                || (until_explicit && closed_at > until)
+            {
                 continue;
             }
         }
-        grouped
-            .entry(issue.issue_type.as_str().to_string())
-            .or_default()
-            .push(issue);
-    }
-
-    let mut groups = Vec::new();
-    for (issue_type, mut items) in grouped {
-        items.sort_by_key(|issue| issue.priority);
-        let label = type_to_header(&issue_type);
-        let issues = items
-            .into_iter()
-            .map(|issue| ChangelogEntry {
-                id: issue.id,
-                title: issue.title,
-                priority: issue.priority.to_string(),
-                closed_at: issue.closed_at.map(|dt| dt.to_rfc3339()),
-            })
-            .collect();
+        issue.labels = labels.remove(&issue.id).unwrap_or_default();
+        if !is_excluded(&issue, &exclusions) {
+            issues.push(issue);
+        }
+    }
+    issues.sort_by_key(|issue| issue.priority);
 
-        groups.push(ChangelogGroup {
+    let commits = if args.commits {
+        commit_hashes_by_issue(&config::id_config_from_layer(&config_layer).prefix)
+    } else {
+        HashMap::new()
+    };
+
+    // "Other" (issues with no label or epic) sorts after every named group
+    let mut grouped: BTreeMap<(bool, String), ChangelogGroup> = BTreeMap::new();
+    for issue in &issues {
+        let issue_type = issue.issue_type.as_str().to_string();
+        let keys: Vec<(String, String)> = match args.group_by {
+            ChangelogGroupBy::Type => vec![(issue_type.clone(), type_to_header(&issue_type))],
+            ChangelogGroupBy::Label => issue
+                .labels
+                .iter()
+                .map(|label| (label.clone(), label.clone()))
+                .collect(),
+            ChangelogGroupBy::Epic => epic_for(&storage, issue)?.into_iter().collect(),
+        };
+        let keys = if keys.is_empty() {
+            vec![(String::new(), "Other".to_string())]
+        } else {
+            keys
+        };
+        let entry = ChangelogEntry {
+            id: issue.id.clone(),
+            title: issue.title.clone(),
+            priority: issue.priority.to_string(),
+            closed_at: issue.closed_at.map(|dt| dt.to_rfc3339()),
             issue_type: issue_type.clone(),
-            label,
-            issues,
-        });
+            labels: issue.labels.clone(),
+            close_reason: issue.close_reason.clone(),
+            commits: commits.get(&issue.id).cloned().unwrap_or_default(),
+        };
+        for (key, label) in keys {
+            grouped
+                .entry((key.is_empty(), key.clone()))
+                .or_insert_with(|| ChangelogGroup {
+                    issue_type: if args.group_by == ChangelogGroupBy::Type {
+                        key.clone()
+                    } else {
+                        String::new()
+                    },
+                    key,
+                    label,
+                    issues: Vec::new(),
+                })
+                .issues
+                .push(entry.clone());
+        }
     }
 
-    let total_closed = groups.iter().map(|g| g.issues.len()).sum();
     let output = ChangelogOutput {
         since: since_label,
         until: until.to_rfc3339(),
-        total_closed,
-        groups,
+        total_closed: issues.len(),
+        groups: grouped.into_values().collect(),
     };
 
     debug!(
@@ -132,6 +224,39 @@ pub fn execute(
         "Generated changelog"
     );
 
+    let title = args
+        .title
+        .clone()
+        .or_else(|| args.until_tag.clone())
+        .unwrap_or_else(|| "Unreleased".to_string());
+    let format = args.format.unwrap_or(ChangelogFormat::Markdown);
+
+    if let Some(path) = &args.output {
+        let rendered = render_template(args, &output, &title, until)?;
+        let existing = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                if format == ChangelogFormat::Markdown {
+                    "# Changelog\n\n".to_string()
+                } else {
+                    String::new()
+                }
+            }
+            Err(err) => return Err(err.into()),
+        };
+        fs::write(path, write_section(&existing, &title, &rendered))?;
+        if json {
+            println!("{}", serde_json::to_string_pretty(&output).unwrap());
+        } else {
+            ctx.success(&format!(
+                "Wrote changelog section '{title}' ({} closed issues) to {}",
+                output.total_closed,
+                path.display()
+            ));
+        }
+        return Ok(());
+    }
+
     if json {
         // Print JSON directly - don't rely on ctx.json_pretty() since the
         // OutputContext may not be in JSON mode when --robot flag is used
@@ -139,6 +264,12 @@ pub fn execute(
         return Ok(());
     }
 
+    if args.format.is_some() || args.template.is_some() {
+        let rendered = render_template(args, &output, &title, until)?;
+        println!("{}", rendered.trim_end());
+        return Ok(());
+    }
+
     if matches!(ctx.mode(), OutputMode::Rich) {
         render_changelog_rich(&output, ctx);
     } else {
@@ -148,6 +279,174 @@ pub fn execute(
     Ok(())
 }
 
+/// Combine config exclusions with the command-line ones.
+fn merge_exclusions(
+    args: &ChangelogArgs,
+    mut exclusions: ChangelogExclusions,
+) -> ChangelogExclusions {
+    exclusions
+        .labels
+        .extend(args.exclude_labels.iter().cloned());
+    exclusions.types.extend(args.exclude_types.iter().cloned());
+    exclusions
+        .reasons
+        .extend(args.exclude_reasons.iter().cloned());
+    exclusions
+}
+
+/// Whether an issue matches any exclusion rule. Labels and types compare
+/// case-insensitively; reasons match as case-insensitive substrings.
+fn is_excluded(issue: &Issue, exclusions: &ChangelogExclusions) -> bool {
+    let issue_type = issue.issue_type.as_str();
+    if exclusions
+        .types
+        .iter()
+        .any(|excluded| excluded.eq_ignore_ascii_case(issue_type))
+    {
+        return true;
+    }
+    if issue.labels.iter().any(|label| {
+        exclusions
+            .labels
+            .iter()
+            .any(|excluded| excluded.eq_ignore_ascii_case(label))
+    }) {
+        return true;
+    }
+    let reason = issue
+        .close_reason
+        .as_deref()
+        .unwrap_or_default()
+        .to_lowercase();
+    !reason.is_empty()
+        && exclusions
+            .reasons
+            .iter()
+            .any(|excluded| reason.contains(&excluded.to_lowercase()))
+}
+
+/// The nearest epic at or above `issue` in the parent chain, as
+/// `(id, "Title (id)")`.
+fn epic_for(storage: &SqliteStorage, issue: &Issue) -> Result<Option<(String, String)>> {
+    if issue.issue_type == IssueType::Epic {
+        return Ok(Some((
+            issue.id.clone(),
+            format!("{} ({})", issue.title, issue.id),
+        )));
+    }
+    let mut seen = HashSet::new();
+    let mut current = storage.get_parent_id(&issue.id)?;
+    while let Some(parent_id) = current {
+        if !seen.insert(parent_id.clone()) {
+            break;
+        }
+        let Some(parent) = storage.get_issue(&parent_id)? else {
+            break;
+        };
+        if parent.issue_type == IssueType::Epic {
+            return Ok(Some((
+                parent.id.clone(),
+                format!("{} ({})", parent.title, parent.id),
+            )));
+        }
+        current = storage.get_parent_id(&parent_id)?;
+    }
+    Ok(None)
+}
+
+/// Map issue IDs to the short hashes of commits (reachable from `HEAD`) that
+/// mention them, oldest first. Empty outside a git repository.
+fn commit_hashes_by_issue(prefix: &str) -> HashMap<String, Vec<String>> {
+    let mut by_issue: HashMap<String, Vec<String>> = HashMap::new();
+    if !is_git_repo() {
+        return by_issue;
+    }
+    let Ok(refs) = get_git_commit_refs(prefix) else {
+        return by_issue;
+    };
+    for (hash, _, issue_id) in refs.into_iter().rev() {
+        let hashes = by_issue.entry(issue_id).or_default();
+        if !hashes.contains(&hash) {
+            hashes.push(hash);
+        }
+    }
+    by_issue
+}
+
+/// Render the changelog through `--template` or the built-in template for
+/// `--format`. Values are HTML-escaped for `--format html`.
+fn render_template(
+    args: &ChangelogArgs,
+    output: &ChangelogOutput,
+    title: &str,
+    until: DateTime<Utc>,
+) -> Result<String> {
+    let html = args.format == Some(ChangelogFormat::Html);
+    let custom = args
+        .template
+        .as_deref()
+        .map(fs::read_to_string)
+        .transpose()?;
+    let source = custom.as_deref().unwrap_or(if html {
+        HTML_TEMPLATE
+    } else {
+        MARKDOWN_TEMPLATE
+    });
+    let template = Template::parse(source)?;
+
+    let mut data = serde_json::to_value(output)?;
+    data["title"] = json!(title);
+    data["date"] = json!(until.format("%Y-%m-%d").to_string());
+    data["group_by"] = json!(args.group_by.as_str());
+    let escape: fn(&str) -> String = if html { escape_html } else { str::to_string };
+    Ok(template.render(&data, escape))
+}
+
+/// Insert or replace the section titled `title` in `existing`.
+///
+/// Sections are wrapped in `<!-- br-changelog: TITLE -->` markers so a rerun
+/// replaces its own section. A new section goes above the newest earlier one,
+/// else above the first `## ` heading, else at the end.
+fn write_section(existing: &str, title: &str, body: &str) -> String {
+    let open = format!("<!-- br-changelog: {title} -->");
+    let close = format!("<!-- /br-changelog: {title} -->");
+    let section = format!("{open}\n{}\n{close}\n", body.trim());
+
+    if let Some(start) = existing.find(&open) {
+        if let Some(offset) = existing[start..].find(&close) {
+            let mut end = start + offset + close.len();
+            if existing[end..].starts_with('\n') {
+                end += 1;
+            }
+            return format!("{}{section}{}", &existing[..start], &existing[end..]);
+        }
+    }
+
+    let insert_at = existing
+        .find("<!-- br-changelog: ")
+        .or_else(|| {
+            if existing.starts_with("## ") {
+                Some(0)
+            } else {
+                existing.find("\n## ").map(|pos| pos + 1)
+            }
+        })
+        .unwrap_or(existing.len());
+    let (head, tail) = existing.split_at(insert_at);
+    let mut out = head.to_string();
+    if !out.is_empty() {
+        while !out.ends_with("\n\n") {
+            out.push('\n');
+        }
+    }
+    out.push_str(&section);
+    if !tail.is_empty() {
+        out.push('\n');
+        out.push_str(tail);
+    }
+    out
+}
+
 /// Convert issue type to human-readable changelog header.
 fn type_to_header(issue_type: &str) -> String {
     match issue_type {
@@ -178,7 +477,17 @@ fn print_text_output(output: &ChangelogOutput) {
         println!();
         println!("{}:", group.label);
         for entry in &group.issues {
-            println!("- [{}] {} {}", entry.priority, entry.id, entry.title);
+            if entry.commits.is_empty() {
+                println!("- [{}] {} {}", entry.priority, entry.id, entry.title);
+            } else {
+                println!(
+                    "- [{}] {} {} ({})",
+                    entry.priority,
+                    entry.id,
+                    entry.title,
+                    entry.commits.join(", ")
+                );
+            }
         }
     }
 }
@@ -209,6 +518,12 @@ fn render_changelog_rich(output: &ChangelogOutput, ctx: &OutputContext) {
                 content.append_styled("  • ", theme.dimmed.clone());
                 content.append(&entry.title);
                 content.append_styled(&format!(" ({})", entry.id), theme.issue_id.clone());
+                if !entry.commits.is_empty() {
+                    content.append_styled(
+                        &format!(" [{}]", entry.commits.join(", ")),
+                        theme.dimmed.clone(),
+                    );
+                }
                 content.append("\n");
             }
             content.append("\n");
@@ -286,6 +601,20 @@ fn resolve_since(args: &ChangelogArgs) -> Result<(Option<DateTime<Utc>>, String)
     Ok((None, "all".to_string()))
 }
 
+/// Resolve the end of the period; the flag is false when it defaults to now.
+fn resolve_until(args: &ChangelogArgs) -> Result<(DateTime<Utc>, bool)> {
+    if let Some(tag) = args.until_tag.as_deref() {
+        return Ok((git_ref_date(tag)?, true));
+    }
+    if let Some(until) = args.until.as_deref() {
+        if let Some(dt) = parse_relative_time(until) {
+            return Ok((dt, true));
+        }
+        return Ok((parse_flexible_timestamp(until, "until")?, true));
+    }
+    Ok((Utc::now(), false))
+}
+
 fn git_ref_date(reference: &str) -> Result<DateTime<Utc>> {
     let output = Command::new("git")
         .args(["show", "-s", "--format=%cI", reference])
@@ -401,16 +730,19 @@ mod tests {
             groups: vec![
                 ChangelogGroup {
                     issue_type: "bug".to_string(),
+                    key: "bug".to_string(),
                     label: "Bug Fixes".to_string(),
                     issues: vec![ChangelogEntry {
                         id: "bd-abc1".to_string(),
                         title: "Fix auth timeout".to_string(),
                         priority: "P1".to_string(),
                         closed_at: Some("2024-01-15T00:00:00Z".to_string()),
+                        ..Default::default()
                     }],
                 },
                 ChangelogGroup {
                     issue_type: "feature".to_string(),
+                    key: "feature".to_string(),
                     label: "Features".to_string(),
                     issues: vec![
                         ChangelogEntry {
@@ -418,12 +750,14 @@ mod tests {
                             title: "Add dark mode".to_string(),
                             priority: "P2".to_string(),
                             closed_at: Some("2024-01-16T00:00:00Z".to_string()),
+                            ..Default::default()
                         },
                         ChangelogEntry {
                             id: "bd-ghi3".to_string(),
                             title: "User preferences".to_string(),
                             priority: "P2".to_string(),
                             closed_at: Some("2024-01-17T00:00:00Z".to_string()),
+                            ..Default::default()
                         },
                     ],
                 },
@@ -444,12 +778,14 @@ mod tests {
             total_closed: 1,
             groups: vec![ChangelogGroup {
                 issue_type: "bug".to_string(),
+                key: "bug".to_string(),
                 label: "Bug Fixes".to_string(),
                 issues: vec![ChangelogEntry {
                     id: "bd-test".to_string(),
                     title: "Test issue".to_string(),
                     priority: "P1".to_string(),
                     closed_at: None,
+                    ..Default::default()
                 }],
             }],
         };
@@ -475,4 +811,94 @@ mod tests {
         assert!(output.groups.is_empty());
         assert_eq!(output.total_closed, 0);
     }
+
+    #[test]
+    fn test_resolve_until_defaults_to_now() {
+        let (until, explicit) = resolve_until(&ChangelogArgs::default()).unwrap();
+        assert!(!explicit);
+        assert!(until <= Utc::now());
+
+        let args = ChangelogArgs {
+            until: Some("2024-02-01T00:00:00Z".to_string()),
+            ..Default::default()
+        };
+        let (until, explicit) = resolve_until(&args).unwrap();
+        assert!(explicit);
+        assert_eq!(until, Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_is_excluded() {
+        let issue = Issue {
+            issue_type: IssueType::Chore,
+            labels: vec!["Internal".to_string()],
+            close_reason: Some("Duplicate of bd-1".to_string()),
+            ..Default::default()
+        };
+        let by_label = ChangelogExclusions {
+            labels: vec!["internal".to_string()],
+            ..Default::default()
+        };
+        let by_reason = ChangelogExclusions {
+            reasons: vec!["duplicate".to_string()],
+            ..Default::default()
+        };
+        let by_type = ChangelogExclusions {
+            types: vec!["bug".to_string()],
+            ..Default::default()
+        };
+        assert!(is_excluded(&issue, &by_label));
+        assert!(is_excluded(&issue, &by_reason));
+        assert!(!is_excluded(&issue, &by_type));
+    }
+
+    #[test]
+    fn test_markdown_template() {
+        let output = ChangelogOutput {
+            since: "all".to_string(),
+            until: "2024-01-22T00:00:00Z".to_string(),
+            total_closed: 1,
+            groups: vec![ChangelogGroup {
+                issue_type: "bug".to_string(),
+                key: "bug".to_string(),
+                label: "Bug Fixes".to_string(),
+                issues: vec![ChangelogEntry {
+                    id: "bd-1".to_string(),
+                    title: "Fix auth".to_string(),
+                    commits: vec!["abc123".to_string()],
+                    ..Default::default()
+                }],
+            }],
+        };
+        let args = ChangelogArgs {
+            format: Some(ChangelogFormat::Markdown),
+            ..Default::default()
+        };
+        let until = Utc.with_ymd_and_hms(2024, 1, 22, 0, 0, 0).unwrap();
+        let rendered = render_template(&args, &output, "v1.0", until).unwrap();
+        assert_eq!(
+            rendered,
+            "## v1.0 - 2024-01-22\n\n### Bug Fixes\n\n- Fix auth (`bd-1`) (`abc123`)\n\n"
+        );
+    }
+
+    #[test]
+    fn test_write_section_is_idempotent() {
+        let file = "# Changelog\n\nNotes.\n\n## 0.9\n\n- Old\n";
+        let first = write_section(file, "v1.0", "## v1.0\n\n- New\n");
+        assert_eq!(
+            first,
+            "# Changelog\n\nNotes.\n\n<!-- br-changelog: v1.0 -->\n## v1.0\n\n- New\n\
+             <!-- /br-changelog: v1.0 -->\n\n## 0.9\n\n- Old\n"
+        );
+        assert_eq!(write_section(&first, "v1.0", "## v1.0\n\n- New\n"), first);
+
+        let replaced = write_section(&first, "v1.0", "## v1.0\n\n- Newer\n");
+        assert!(replaced.contains("- Newer"));
+        assert!(!replaced.contains("- New\n"));
+
+        let second = write_section(&first, "v1.1", "## v1.1\n");
+        assert!(second.find("v1.1").unwrap() < second.find("v1.0").unwrap());
+        assert!(second.starts_with("# Changelog\n\nNotes.\n\n<!-- br-changelog: v1.1 -->"));
+    }
 }
@@ -0,0 +1,141 @@
+//! Claim and Heartbeat command implementations.
+//!
+//! `br claim <id> --ttl 30m` takes a lease on an issue (assigning it to the
+//! actor and moving it to `in_progress`); `br heartbeat` keeps the actor's
+//! leases alive. Leases live in the `leases` table (local DB only, not
+//! exported to JSONL). Leased issues are hidden from `br ready`, and expired
+//! leases are released the next time any command opens the database.
+
+use crate::cli::{ClaimArgs, HeartbeatArgs};
+use crate::config;
+use crate::error::Result;
+use crate::model::Lease;
+use crate::output::OutputContext;
+use crate::storage::SqliteStorage;
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use crate::util::time::parse_duration_minutes;
+use chrono::Duration;
+use tracing::info;
+
+/// Execute the claim command.
+///
+/// # Errors
+///
+/// Returns [`BeadsError::LeaseHeld`](crate::error::BeadsError::LeaseHeld) if another actor holds an unexpired
+/// lease, or an error if the ID cannot be resolved or the update fails.
+pub fn execute_claim(
+    args: &ClaimArgs,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let actor = config::resolve_actor(&layer);
+    let resolver = build_resolver(&layer);
+    let ttl = parse_ttl(&args.ttl)?;
+    let storage = &mut storage_ctx.storage;
+
+    let id = resolve_issue_id(storage, &resolver, &args.id)?;
+    let lease = storage.claim_issue(&id, &actor, ttl)?;
+    info!(id = %id, actor = %actor, expires_at = %lease.expires_at, "Issue claimed");
+    crate::util::set_last_touched_id(&beads_dir, &id);
+
+    if ctx.is_json() {
+        ctx.json_pretty(&lease);
+    } else {
+        ctx.success(&format!(
+            "Claimed {id} until {} (renew with `br heartbeat`)",
+            format_expiry(&lease)
+        ));
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
+
+/// Execute the heartbeat command.
+///
+/// # Errors
+///
+/// Returns an error if a named lease is not held by the actor, an ID cannot
+/// be resolved, or the update fails.
+pub fn execute_heartbeat(
+    args: &HeartbeatArgs,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let actor = config::resolve_actor(&layer);
+    let resolver = build_resolver(&layer);
+    let ttl = parse_ttl(&args.ttl)?;
+    let storage = &mut storage_ctx.storage;
+
+    let ids = args
+        .ids
+        .iter()
+        .map(|input| resolve_issue_id(storage, &resolver, input))
+        .collect::<Result<Vec<_>>>()?;
+    let leases = storage.heartbeat(&actor, &ids, ttl)?;
+    info!(actor = %actor, count = leases.len(), "Leases renewed");
+
+    if ctx.is_json() {
+        ctx.json_pretty(&leases);
+    } else if leases.is_empty() {
+        ctx.info(&format!("{actor} holds no leases"));
+    } else {
+        for lease in &leases {
+            ctx.success(&format!(
+                "Renewed {} until {}",
+                lease.issue_id,
+                format_expiry(lease)
+            ));
+        }
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
+
+/// Parse a lease TTL such as `30m`, `2h` or `1h30m`.
+fn parse_ttl(input: &str) -> Result<Duration> {
+    parse_duration_minutes(input, "ttl").map(Duration::minutes)
+}
+
+fn format_expiry(lease: &Lease) -> String {
+    lease.expires_at.format("%Y-%m-%d %H:%M UTC").to_string()
+}
+
+fn build_resolver(layer: &config::ConfigLayer) -> IdResolver {
+    let id_config = config::id_config_from_layer(layer);
+    IdResolver::new(ResolverConfig::with_prefix(id_config.prefix))
+}
+
+fn resolve_issue_id(storage: &SqliteStorage, resolver: &IdResolver, input: &str) -> Result<String> {
+    let all_ids = storage.get_all_ids()?;
+    resolver
+        .resolve(
+            input,
+            |id| storage.id_exists(id).unwrap_or(false),
+            |hash| find_matching_ids(&all_ids, hash),
+        )
+        .map(|resolved| resolved.id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::BeadsError;
+
+    #[test]
+    fn test_parse_ttl() {
+        assert_eq!(parse_ttl("30m").unwrap(), Duration::minutes(30));
+        assert_eq!(parse_ttl("1h30m").unwrap(), Duration::minutes(90));
+        assert!(matches!(
+            parse_ttl("soon"),
+            Err(BeadsError::Validation { .. })
+        ));
+    }
+}
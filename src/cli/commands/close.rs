@@ -3,7 +3,7 @@
 use crate::cli::CloseArgs as CliCloseArgs;
 use crate::config;
 use crate::error::{BeadsError, Result};
-use crate::model::Status;
+use crate::model::{CloseOutcome, Status};
 use crate::output::OutputContext;
 use crate::storage::IssueUpdate;
 use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
@@ -19,6 +19,8 @@ pub struct CloseArgs {
     pub reason: Option<String>,
     /// Force close even if blocked
     pub force: bool,
+    /// Close outcome (success, failed, wontfix)
+    pub outcome: Option<String>,
     /// Session ID for `closed_by_session` field
     pub session: Option<String>,
     /// Return newly unblocked issues (single ID only)
@@ -31,6 +33,7 @@ impl From<&CliCloseArgs> for CloseArgs {
             ids: cli.ids.clone(),
             reason: cli.reason.clone(),
             force: cli.force,
+            outcome: cli.outcome.clone(),
             session: cli.session.clone(),
             suggest_next: cli.suggest_next,
         }
@@ -85,6 +88,8 @@ pub struct ClosedIssue {
     pub closed_at: String,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub close_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub outcome: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -108,6 +113,7 @@ pub fn execute(
         ids,
         reason: None,
         force: false,
+        outcome: None,
         session: None,
         suggest_next: false,
     };
@@ -160,6 +166,8 @@ pub fn execute_with_args(
         ));
     }
 
+    let outcome: Option<CloseOutcome> = args.outcome.as_deref().map(str::parse).transpose()?;
+
     // Resolve all IDs
     let resolved_ids = resolver.resolve_all(
         &ids,
@@ -235,6 +243,8 @@ pub fn execute_with_args(
             closed_at: Some(Some(now)),
             close_reason: Some(Some(close_reason.clone())),
             closed_by_session: args.session.clone().map(Some),
+            // Recorded in the same transaction, so the cache rebuild on close sees it
+            close_outcome: outcome.map(Some),
             ..Default::default()
         };
 
@@ -251,6 +261,7 @@ pub fn execute_with_args(
             status: "closed".to_string(),
             closed_at: now.to_rfc3339(),
             close_reason: Some(close_reason),
+            outcome: outcome.map(|o| o.as_str().to_string()),
         });
     }
 
@@ -314,6 +325,9 @@ pub fn execute_with_args(
                 if let Some(reason) = &closed.close_reason {
                     msg.push_str(&format!(" ({reason})"));
                 }
+                if let Some(outcome) = &closed.outcome {
+                    msg.push_str(&format!(" [{outcome}]"));
+                }
                 ctx.success(&msg);
             }
             for skipped in &skipped_issues {
@@ -357,6 +371,7 @@ mod tests {
             ids: vec!["bd-abc".to_string(), "bd-xyz".to_string()],
             reason: Some("Fixed in PR #123".to_string()),
             force: true,
+            outcome: None,
             session: Some("session-456".to_string()),
             suggest_next: true,
         };
@@ -381,6 +396,7 @@ mod tests {
                 status: "closed".to_string(),
                 closed_at: "2026-01-01T00:00:00Z".to_string(),
                 close_reason: None,
+                outcome: None,
             }],
             skipped: vec![],
         };
@@ -414,6 +430,7 @@ mod tests {
                     status: "closed".to_string(),
                     closed_at: "2026-01-01T00:00:00Z".to_string(),
                     close_reason: Some("Done".to_string()),
+                    outcome: None,
                 },
                 ClosedIssue {
                     id: "bd-b".to_string(),
@@ -421,6 +438,7 @@ mod tests {
                     status: "closed".to_string(),
                     closed_at: "2026-01-02T00:00:00Z".to_string(),
                     close_reason: None,
+                    outcome: None,
                 },
             ],
             skipped: vec![SkippedIssue {
@@ -450,6 +468,7 @@ mod tests {
                 status: "closed".to_string(),
                 closed_at: "2026-01-15T10:00:00Z".to_string(),
                 close_reason: Some("Completed".to_string()),
+                outcome: None,
             }],
             skipped: vec![],
             unblocked: vec![
@@ -503,6 +522,7 @@ mod tests {
             status: "closed".to_string(),
             closed_at: "2026-01-17T08:00:00Z".to_string(),
             close_reason: Some("Fixed in commit abc123".to_string()),
+            outcome: None,
         };
         let json = serde_json::to_string(&issue).unwrap();
         assert!(json.contains("\"close_reason\":\"Fixed in commit abc123\""));
@@ -516,6 +536,7 @@ mod tests {
             status: "closed".to_string(),
             closed_at: "2026-01-17T08:00:00Z".to_string(),
             close_reason: None,
+            outcome: None,
         };
         let json = serde_json::to_string(&issue).unwrap();
         // close_reason should be omitted due to skip_serializing_if
@@ -530,6 +551,7 @@ mod tests {
             status: "closed".to_string(),
             closed_at: "2026-12-31T23:59:59Z".to_string(),
             close_reason: Some("End of year cleanup".to_string()),
+            outcome: None,
         };
         let json = serde_json::to_string(&issue).unwrap();
         let parsed: ClosedIssue = serde_json::from_str(&json).unwrap();
@@ -612,6 +634,7 @@ mod tests {
                     status: "closed".to_string(),
                     closed_at: "2026-01-01T00:00:00Z".to_string(),
                     close_reason: None,
+                    outcome: None,
                 },
                 ClosedIssue {
                     id: "bd-2".to_string(),
@@ -619,6 +642,7 @@ mod tests {
                     status: "closed".to_string(),
                     closed_at: "2026-01-01T00:00:01Z".to_string(),
                     close_reason: Some("Batch close".to_string()),
+                    outcome: None,
                 },
             ],
             skipped: vec![
@@ -644,6 +668,7 @@ mod tests {
             ids: vec!["bd-clone".to_string()],
             reason: Some("Clone test".to_string()),
             force: true,
+            outcome: None,
             session: Some("sess".to_string()),
             suggest_next: true,
         };
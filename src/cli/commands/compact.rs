@@ -0,0 +1,285 @@
+//! Compact command implementation.
+//!
+//! `br compact` shrinks old closed issues: the description, design, notes and
+//! comments are replaced by a short digest from a summarizer (the built-in
+//! extractive one, or an external command set with `--summarizer` or
+//! `compact.summarizer`). The full original records are first archived to
+//! `.br_history/archive/compacted-<timestamp>.jsonl`, then the whole selection
+//! is compacted in one transaction (one `br undo` step). Each compacted issue
+//! gets its `compaction_level` bumped, `compacted_at`/`compacted_at_commit`
+//! stamped and `original_size` recorded, plus a `compacted` event.
+
+use crate::cli::CompactArgs;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::{Comment, Issue, Status};
+use crate::output::OutputContext;
+use crate::storage::SqliteStorage;
+use crate::sync::history;
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use crate::util::summarize::{CommandSummarizer, ExtractiveSummarizer, Summarizer, SummaryInput};
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::path::Path;
+use std::process::Command;
+use tracing::{debug, info};
+
+/// One issue compacted (or planned, with `--dry-run`) by `br compact`.
+#[derive(Serialize)]
+struct CompactedIssue {
+    id: String,
+    title: String,
+    closed_at: Option<DateTime<Utc>>,
+    original_size: usize,
+    digest_size: usize,
+    digest: String,
+}
+
+/// JSON output for `br compact`.
+#[derive(Serialize)]
+struct CompactReport {
+    dry_run: bool,
+    summarizer: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archive: Option<String>,
+    saved_chars: usize,
+    compacted: Vec<CompactedIssue>,
+}
+
+/// Execute the compact command.
+///
+/// # Errors
+///
+/// Returns an error if database operations fail, the summarizer fails, or an
+/// explicitly named issue is not closed.
+pub fn execute(args: &CompactArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let id_config = config::id_config_from_layer(&layer);
+    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let actor = config::resolve_actor(&layer);
+
+    let command = args
+        .summarizer
+        .clone()
+        .or_else(|| config::compact_summarizer_from_layer(&layer));
+    let summarizer_name = command.clone().unwrap_or_else(|| "extractive".to_string());
+    let summarizer: Box<dyn Summarizer> = match command {
+        Some(command) => Box::new(CommandSummarizer {
+            command,
+            max_chars: args.max_chars,
+        }),
+        None => Box::new(ExtractiveSummarizer {
+            max_chars: args.max_chars,
+        }),
+    };
+
+    let storage = &mut storage_ctx.storage;
+    let candidates = select_candidates(storage, &resolver, args, Utc::now())?;
+
+    let mut planned: Vec<(Issue, Vec<Comment>, CompactedIssue)> = Vec::new();
+    for (issue, comments) in candidates {
+        let input = SummaryInput {
+            id: &issue.id,
+            title: &issue.title,
+            description: issue.description.as_deref(),
+            design: issue.design.as_deref(),
+            notes: issue.notes.as_deref(),
+            comments: &comments,
+        };
+        let original_size = input.size();
+        let digest = summarizer.summarize(&input)?;
+        let digest_size = digest.chars().count();
+        if digest_size >= original_size {
+            debug!(id = %issue.id, original_size, digest_size, "Digest not shorter; skipping");
+            continue;
+        }
+        let item = CompactedIssue {
+            id: issue.id.clone(),
+            title: issue.title.clone(),
+            closed_at: issue.closed_at,
+            original_size,
+            digest_size,
+            digest,
+        };
+        planned.push((issue, comments, item));
+    }
+
+    let mut archive = None;
+    if !args.dry_run && !planned.is_empty() {
+        let mut originals = Vec::with_capacity(planned.len());
+        for (issue, comments, _) in &planned {
+            let mut original = issue.clone();
+            original.labels = storage.get_labels(&issue.id)?;
+            original.dependencies = storage.get_dependencies_full(&issue.id)?;
+            original.comments.clone_from(comments);
+            originals.push(original);
+        }
+        let path = history::archive_issues(&beads_dir, "compacted", &originals)?;
+        archive = Some(path.display().to_string());
+
+        let commit = git_head(&beads_dir);
+        // All or none of the selection is compacted
+        storage.batch("compact", &actor, |storage| {
+            for (_, _, item) in &planned {
+                storage.compact_issue(
+                    &item.id,
+                    &item.digest,
+                    item.original_size,
+                    commit.as_deref(),
+                    &actor,
+                )?;
+            }
+            Ok(())
+        })?;
+        for (_, _, item) in &planned {
+            info!(
+                id = %item.id,
+                original_size = item.original_size,
+                digest_size = item.digest_size,
+                "Compacted issue"
+            );
+        }
+    }
+    storage_ctx.flush_no_db_if_dirty()?;
+
+    let compacted: Vec<CompactedIssue> = planned.into_iter().map(|(_, _, item)| item).collect();
+    let report = CompactReport {
+        dry_run: args.dry_run,
+        summarizer: summarizer_name,
+        archive,
+        saved_chars: compacted
+            .iter()
+            .map(|item| item.original_size - item.digest_size)
+            .sum(),
+        compacted,
+    };
+    print_report(&report, ctx);
+    Ok(())
+}
+
+fn print_report(report: &CompactReport, ctx: &OutputContext) {
+    if ctx.is_json() {
+        ctx.json_pretty(report);
+        return;
+    }
+    if report.compacted.is_empty() {
+        ctx.info("No issues to compact.");
+        return;
+    }
+
+    let verb = if report.dry_run {
+        "Would compact"
+    } else {
+        "Compacted"
+    };
+    for item in &report.compacted {
+        ctx.print(&format!(
+            "{verb} {}: {} -> {} chars  {}",
+            item.id, item.original_size, item.digest_size, item.title
+        ));
+    }
+    let summary = format!(
+        "{verb} {} issue(s), saving {} chars",
+        report.compacted.len(),
+        report.saved_chars
+    );
+    if report.dry_run {
+        ctx.info(&summary);
+    } else {
+        ctx.success(&summary);
+    }
+    if let Some(path) = &report.archive {
+        ctx.info(&format!("Originals archived to {path}"));
+    }
+}
+
+/// Closed issues to compact, with their comments.
+///
+/// Explicit IDs must be closed but skip the age and size thresholds;
+/// otherwise every closed issue older than `--older-than` days whose text
+/// exceeds `--min-size` is eligible.
+fn select_candidates(
+    storage: &SqliteStorage,
+    resolver: &IdResolver,
+    args: &CompactArgs,
+    now: DateTime<Utc>,
+) -> Result<Vec<(Issue, Vec<Comment>)>> {
+    let mut candidates = Vec::new();
+
+    if !args.ids.is_empty() {
+        for input in &args.ids {
+            let id = resolve_issue_id(storage, resolver, input)?;
+            let issue = storage
+                .get_issue(&id)?
+                .ok_or_else(|| BeadsError::IssueNotFound { id: id.clone() })?;
+            if issue.status != Status::Closed {
+                return Err(BeadsError::validation(
+                    "id",
+                    format!(
+                        "{id} is {}; only closed issues can be compacted",
+                        issue.status
+                    ),
+                ));
+            }
+            let comments = storage.get_comments(&id)?;
+            candidates.push((issue, comments));
+        }
+        return Ok(candidates);
+    }
+
+    let cutoff = now - Duration::days(args.older_than);
+    for issue in storage.get_all_issues_for_export()? {
+        if issue.status != Status::Closed || issue.closed_at.is_none_or(|at| at > cutoff) {
+            continue;
+        }
+        let comments = storage.get_comments(&issue.id)?;
+        let size = SummaryInput {
+            id: &issue.id,
+            title: &issue.title,
+            description: issue.description.as_deref(),
+            design: issue.design.as_deref(),
+            notes: issue.notes.as_deref(),
+            comments: &comments,
+        }
+        .size();
+        if size <= args.min_size {
+            continue;
+        }
+        candidates.push((issue, comments));
+        if args.limit.is_some_and(|limit| candidates.len() >= limit) {
+            break;
+        }
+    }
+    Ok(candidates)
+}
+
+/// HEAD commit of the repository holding the `.beads` directory, if any.
+fn git_head(beads_dir: &Path) -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .current_dir(beads_dir)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let commit = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if commit.is_empty() {
+        None
+    } else {
+        Some(commit)
+    }
+}
+
+fn resolve_issue_id(storage: &SqliteStorage, resolver: &IdResolver, input: &str) -> Result<String> {
+    let all_ids = storage.get_all_ids()?;
+    resolver
+        .resolve(
+            input,
+            |id| storage.id_exists(id).unwrap_or(false),
+            |hash| find_matching_ids(&all_ids, hash),
+        )
+        .map(|resolved| resolved.id)
+}
@@ -304,6 +304,8 @@ mod tests {
             dependencies: vec![],
             comments: vec![],
             content_hash: None,
+            recurrence: None,
+            worklog: vec![],
         }
     }
 
@@ -1,10 +1,11 @@
 use crate::cli::CreateArgs;
+use crate::cli::commands::template::instantiate_template;
 use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::model::{Dependency, DependencyType, Issue, IssueType, Priority, Status};
 use crate::output::OutputContext;
 use crate::storage::SqliteStorage;
-use crate::util::id::{IdGenerator, child_id};
+use crate::util::id::{IdGenerator, IdResolver, ResolverConfig, child_id, find_matching_ids};
 use crate::util::markdown_import::{parse_dependency, parse_markdown_file};
 use crate::util::time::parse_flexible_timestamp;
 use crate::validation::{IssueValidator, LabelValidator};
@@ -43,6 +44,16 @@ pub fn execute(args: &CreateArgs, cli: &config::CliOverrides, ctx: &OutputContex
         return execute_import(file_path, args, cli, ctx);
     }
 
+    if let Some(ref template_id) = args.from_template {
+        if args.dry_run {
+            return Err(BeadsError::validation(
+                "dry_run",
+                "--dry-run is not supported with --from-template (use `br template show`)",
+            ));
+        }
+        return execute_from_template(template_id, args, cli, ctx);
+    }
+
     // 1. Open storage (unless dry run without DB)
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
 
@@ -96,6 +107,63 @@ pub fn execute(args: &CreateArgs, cli: &config::CliOverrides, ctx: &OutputContex
     Ok(())
 }
 
+/// Create issues from a template (`--from-template`).
+fn execute_from_template(
+    template_input: &str,
+    args: &CreateArgs,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+
+    let config = CreateConfig {
+        id_config: config::id_config_from_layer(&layer),
+        default_priority: config::default_priority_from_layer(&layer)?,
+        default_issue_type: config::default_issue_type_from_layer(&layer)?,
+        actor: config::resolve_actor(&layer),
+    };
+
+    let storage = &mut storage_ctx.storage;
+    let resolver = IdResolver::new(ResolverConfig::with_prefix(config.id_config.prefix.clone()));
+    let all_ids = storage.get_all_ids()?;
+    let template_id = resolver
+        .resolve(
+            template_input,
+            |id| storage.id_exists(id).unwrap_or(false),
+            |hash| find_matching_ids(&all_ids, hash),
+        )?
+        .id;
+
+    let created = instantiate_template(storage, &template_id, args, &config)?;
+
+    if args.silent {
+        for issue in &created {
+            println!("{}", issue.id);
+        }
+    } else if ctx.is_json() {
+        let mut full_issues = Vec::with_capacity(created.len());
+        for issue in &created {
+            if let Some(full_issue) = storage.get_issue_for_export(&issue.id)? {
+                full_issues.push(full_issue);
+            }
+        }
+        ctx.json_pretty(&full_issues);
+    } else {
+        ctx.success(&format!(
+            "Created {} issue(s) from template {template_id}:",
+            created.len()
+        ));
+        for issue in &created {
+            ctx.print(&format!("  {}: {}", issue.id, issue.title));
+        }
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
+
 /// Core logic for creating an issue.
 ///
 /// Handles ID generation, validation, and storage insertion.
@@ -248,6 +316,8 @@ pub fn create_issue_impl(
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        recurrence: None,
+        worklog: vec![],
     };
 
     // Compute content hash
@@ -514,6 +584,8 @@ fn execute_import(
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         };
 
         issue.content_hash = Some(issue.compute_content_hash());
@@ -644,6 +716,8 @@ mod tests {
             dry_run: false,
             silent: false,
             file: None,
+            from_template: None,
+            vars: Vec::new(),
         }
     }
 
@@ -481,6 +481,8 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         }
     }
 
@@ -517,6 +517,8 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         }
     }
 
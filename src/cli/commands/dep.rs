@@ -7,7 +7,7 @@ use crate::cli::{
 use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::format::truncate_title;
-use crate::model::DependencyType;
+use crate::model::{DependencyType, WaitsForGate};
 use crate::output::{OutputContext, OutputMode};
 use crate::storage::SqliteStorage;
 use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
@@ -171,7 +171,14 @@ fn dep_add(
         });
     }
 
-    let added = storage.add_dependency(&issue_id, &depends_on_id, dep_type.as_str(), actor)?;
+    let metadata = dependency_metadata(&dep_type, args.gate.as_deref(), args.metadata.as_deref())?;
+    let added = storage.add_dependency_with_metadata(
+        &issue_id,
+        &depends_on_id,
+        dep_type.as_str(),
+        metadata.as_deref(),
+        actor,
+    )?;
 
     if ctx.is_json() || ctx.is_toon() {
         let result = DepActionResult {
@@ -221,6 +228,45 @@ fn dep_add(
     Ok(())
 }
 
+/// Combine `--metadata` and `--gate` into the stored metadata JSON.
+///
+/// # Errors
+///
+/// Returns an error if the metadata is not a JSON object, the gate is unknown,
+/// or a gate is given for anything other than a waits-for dependency.
+pub fn dependency_metadata(
+    dep_type: &DependencyType,
+    gate: Option<&str>,
+    metadata: Option<&str>,
+) -> Result<Option<String>> {
+    let mut object = match metadata {
+        Some(raw) => match serde_json::from_str::<serde_json::Value>(raw) {
+            Ok(serde_json::Value::Object(map)) => map,
+            _ => {
+                return Err(BeadsError::validation("metadata", "must be a JSON object"));
+            }
+        },
+        None => serde_json::Map::new(),
+    };
+
+    if let Some(gate) = gate {
+        if *dep_type != DependencyType::WaitsFor {
+            return Err(BeadsError::validation(
+                "gate",
+                "--gate only applies to waits-for dependencies",
+            ));
+        }
+        let gate: WaitsForGate = gate.parse()?;
+        object.insert("gate".to_string(), gate.as_str().into());
+    }
+
+    if object.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(serde_json::Value::Object(object).to_string()))
+    }
+}
+
 fn dep_remove(
     args: &DepRemoveArgs,
     storage: &mut SqliteStorage,
@@ -912,6 +958,8 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         }
     }
 
@@ -1315,4 +1363,20 @@ mod tests {
         assert!(matches!(DepDirection::Both, DepDirection::Both));
         info!("test_dep_direction_variants: assertions passed");
     }
+
+    #[test]
+    fn test_dependency_metadata_gate() {
+        init_test_logging();
+        info!("test_dependency_metadata_gate: starting");
+        let waits_for = DependencyType::WaitsFor;
+        assert_eq!(dependency_metadata(&waits_for, None, None).unwrap(), None);
+        assert_eq!(
+            dependency_metadata(&waits_for, Some("any"), Some(r#"{"note":"x"}"#)).unwrap(),
+            Some(r#"{"gate":"any","note":"x"}"#.to_string())
+        );
+        assert!(dependency_metadata(&waits_for, Some("sometimes"), None).is_err());
+        assert!(dependency_metadata(&DependencyType::Blocks, Some("any"), None).is_err());
+        assert!(dependency_metadata(&waits_for, None, Some("[1]")).is_err());
+        info!("test_dependency_metadata_gate: assertions passed");
+    }
 }
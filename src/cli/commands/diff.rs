@@ -0,0 +1,170 @@
+//! Diff command implementation.
+//!
+//! `br diff --since <t> [--until <t>]` lists every field-level change made
+//! across the workspace in a time window, oldest first, as read from the
+//! audit event log by [`field_changes`]. It is the window counterpart of the
+//! `--as-of` snapshots on `show`, `list`, `ready` and `stats`.
+
+use crate::cli::DiffArgs;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::output::OutputContext;
+use crate::storage::SqliteStorage;
+use crate::storage::history::{FieldChange, field_changes};
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use crate::util::time::parse_flexible_timestamp;
+use chrono::{DateTime, Local, Utc};
+use serde::Serialize;
+use std::collections::HashSet;
+use tracing::debug;
+
+/// JSON output for `br diff`.
+#[derive(Debug, Serialize)]
+pub struct DiffOutput {
+    pub since: DateTime<Utc>,
+    pub until: DateTime<Utc>,
+    pub count: usize,
+    pub changes: Vec<FieldChange>,
+}
+
+/// Execute the diff command.
+///
+/// # Errors
+///
+/// Returns an error if a timestamp or issue ID cannot be resolved, or the
+/// event log cannot be read.
+pub fn execute(args: &DiffArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let since = parse_flexible_timestamp(&args.since, "since")?;
+    let until = args
+        .until
+        .as_deref()
+        .map(|value| parse_flexible_timestamp(value, "until"))
+        .transpose()?
+        .unwrap_or_else(Utc::now);
+    if until < since {
+        return Err(BeadsError::validation(
+            "until",
+            "must not be before --since",
+        ));
+    }
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let storage = &storage_ctx.storage;
+    let layer = config::load_config(&beads_dir, Some(storage), cli)?;
+    let id_config = config::id_config_from_layer(&layer);
+    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let issues = resolve_issue_ids(storage, &resolver, &args.issues)?;
+
+    let mut events: Vec<_> = storage
+        .get_all_events(0)?
+        .into_iter()
+        .filter(|event| event.created_at >= since && event.created_at <= until)
+        .filter(|event| issues.is_empty() || issues.contains(&event.issue_id))
+        .filter(|event| {
+            args.actor
+                .as_deref()
+                .is_none_or(|actor| event.actor == actor)
+        })
+        .collect();
+    // get_all_events is newest first
+    events.reverse();
+    let changes = field_changes(&events);
+    debug!(since = %since, until = %until, changes = changes.len(), "Collected field changes");
+
+    if ctx.is_json() {
+        ctx.json_pretty(&DiffOutput {
+            since,
+            until,
+            count: changes.len(),
+            changes,
+        });
+        return Ok(());
+    }
+    if changes.is_empty() {
+        ctx.info("No changes in this window.");
+        return Ok(());
+    }
+    for change in &changes {
+        println!("{}", format_change(change));
+    }
+    Ok(())
+}
+
+fn resolve_issue_ids(
+    storage: &SqliteStorage,
+    resolver: &IdResolver,
+    inputs: &[String],
+) -> Result<HashSet<String>> {
+    if inputs.is_empty() {
+        return Ok(HashSet::new());
+    }
+    let all_ids = storage.get_all_ids()?;
+    inputs
+        .iter()
+        .map(|input| {
+            resolver
+                .resolve(
+                    input,
+                    |id| storage.id_exists(id).unwrap_or(false),
+                    |hash| find_matching_ids(&all_ids, hash),
+                )
+                .map(|resolved| resolved.id)
+        })
+        .collect()
+}
+
+/// One text line: local time, issue, actor and the change.
+fn format_change(change: &FieldChange) -> String {
+    let when = change
+        .created_at
+        .with_timezone(&Local)
+        .format("%Y-%m-%d %H:%M");
+    let what = match (&change.old_value, &change.new_value) {
+        (None, Some(new)) if change.field == "created" => format!("created: {new}"),
+        (None, Some(new)) => format!("{}: + {new}", change.field),
+        (Some(old), None) => format!("{}: - {old}", change.field),
+        (old, new) => format!(
+            "{}: {} → {}",
+            change.field,
+            old.as_deref().unwrap_or("(none)"),
+            new.as_deref().unwrap_or("(none)")
+        ),
+    };
+    format!("{when}  {}  {}  {what}", change.issue_id, change.actor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(field: &str, old: Option<&str>, new: Option<&str>) -> FieldChange {
+        FieldChange {
+            event_id: 1,
+            issue_id: "bd-1".to_string(),
+            field: field.to_string(),
+            old_value: old.map(str::to_string),
+            new_value: new.map(str::to_string),
+            actor: "alice".to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn format_change_shows_added_removed_and_replaced_values() {
+        let line = format_change(&change("status", Some("open"), Some("closed")));
+        assert!(
+            line.ends_with("bd-1  alice  status: open → closed"),
+            "{line}"
+        );
+
+        let line = format_change(&change("labels", None, Some("backend")));
+        assert!(line.ends_with("labels: + backend"), "{line}");
+
+        let line = format_change(&change("dependencies", Some("bd-2 (blocks)"), None));
+        assert!(line.ends_with("dependencies: - bd-2 (blocks)"), "{line}");
+
+        let line = format_change(&change("created", None, Some("Fix login")));
+        assert!(line.ends_with("created: Fix login"), "{line}");
+    }
+}
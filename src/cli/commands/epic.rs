@@ -478,6 +478,8 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         }
     }
 
@@ -0,0 +1,154 @@
+//! Export command implementation.
+//!
+//! `br export --format html|markdown|jira-csv|github-json` publishes issues
+//! for people outside the terminal. Every format is built from
+//! [`SqliteStorage::get_all_issues_for_export`] (ephemeral issues excluded),
+//! leaving out tombstones and templates. The renderers live in
+//! [`crate::format::publish`] and [`crate::format::site`].
+
+use crate::cli::{ExportArgs, ExportFormat};
+use crate::config;
+use crate::error::Result;
+use crate::format::publish::{to_github_json, to_jira_csv, to_markdown};
+use crate::format::site::render_site;
+use crate::model::{Issue, Status};
+use crate::output::OutputContext;
+use crate::storage::SqliteStorage;
+use chrono::Utc;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::debug;
+
+/// Default output directory for `--format html`.
+const DEFAULT_SITE_DIR: &str = "br-site";
+
+/// Result of an export written to disk (JSON output).
+#[derive(Debug, Serialize)]
+pub struct ExportSummary {
+    pub format: String,
+    pub output: PathBuf,
+    pub issues: usize,
+    pub files: usize,
+}
+
+/// Execute the export command.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be read or the output cannot be
+/// written.
+pub fn execute(args: &ExportArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let issues = load_issues(&storage_ctx.storage)?;
+    let title = beads_dir
+        .canonicalize()
+        .ok()
+        .as_deref()
+        .and_then(Path::parent)
+        .and_then(Path::file_name)
+        .map_or_else(
+            || "Issues".to_string(),
+            |name| format!("{} issues", name.to_string_lossy()),
+        );
+    let now = Utc::now();
+    debug!(
+        format = args.format.as_str(),
+        issues = issues.len(),
+        "Exporting issues"
+    );
+
+    let rendered = match args.format {
+        ExportFormat::Html => None,
+        ExportFormat::Markdown => Some(to_markdown(&issues, &title, now)),
+        ExportFormat::JiraCsv => Some(to_jira_csv(&issues)),
+        ExportFormat::GithubJson => Some(format!(
+            "{}\n",
+            serde_json::to_string_pretty(&to_github_json(&issues))?
+        )),
+    };
+    let (output, files) = match rendered {
+        Some(rendered) => {
+            let Some(path) = &args.output else {
+                print!("{rendered}");
+                return Ok(());
+            };
+            fs::write(path, rendered)?;
+            (path.clone(), 1)
+        }
+        None => {
+            let dir = args
+                .output
+                .clone()
+                .unwrap_or_else(|| PathBuf::from(DEFAULT_SITE_DIR));
+            let files = render_site(&issues, &title, now);
+            clear_issue_pages(&dir)?;
+            for file in &files {
+                let path = dir.join(&file.path);
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&path, &file.contents)?;
+            }
+            (dir, files.len())
+        }
+    };
+
+    if ctx.is_json() {
+        ctx.json_pretty(&ExportSummary {
+            format: args.format.as_str().to_string(),
+            output,
+            issues: issues.len(),
+            files,
+        });
+        return Ok(());
+    }
+    let target = if args.format == ExportFormat::Html {
+        format!("{}/index.html", output.display())
+    } else {
+        output.display().to_string()
+    };
+    ctx.success(&format!(
+        "Exported {} issues as {} to {target}",
+        issues.len(),
+        args.format.as_str()
+    ));
+    Ok(())
+}
+
+/// Load the issues to publish with labels, dependencies and comments.
+///
+/// # Errors
+///
+/// Returns an error if a database query fails.
+pub fn load_issues(storage: &SqliteStorage) -> Result<Vec<Issue>> {
+    let mut issues: Vec<Issue> = storage
+        .get_all_issues_for_export()?
+        .into_iter()
+        .filter(|issue| issue.status != Status::Tombstone && !issue.is_template)
+        .collect();
+    let mut labels = storage.get_all_labels()?;
+    let mut dependencies = storage.get_all_dependency_records()?;
+    let mut comments = storage.get_all_comments()?;
+    for issue in &mut issues {
+        issue.labels = labels.remove(&issue.id).unwrap_or_default();
+        issue.dependencies = dependencies.remove(&issue.id).unwrap_or_default();
+        issue.comments = comments.remove(&issue.id).unwrap_or_default();
+    }
+    Ok(issues)
+}
+
+/// Remove issue pages from an earlier export so deleted issues don't linger.
+fn clear_issue_pages(dir: &Path) -> Result<()> {
+    let Ok(entries) = fs::read_dir(dir.join("issues")) else {
+        return Ok(());
+    };
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "html") {
+            fs::remove_file(path)?;
+        }
+    }
+    Ok(())
+}
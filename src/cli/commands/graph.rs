@@ -4,11 +4,14 @@
 //!
 //! - `br graph <issue-id>`: Show all dependents of an issue (what depends on it)
 //! - `br graph --all`: Show connected components for `open`/`in_progress`/`blocked` issues
+//! - `br graph [<issue-id>] --format dot|mermaid|graphml|json-graph`: Export the
+//!   full dependency graph (every dependency type) for a root or the workspace
 
-use crate::cli::GraphArgs;
+use crate::cli::{GraphArgs, GraphFormat};
 use crate::config;
 use crate::error::{BeadsError, Result};
-use crate::model::{DependencyType, Issue, Status};
+use crate::format::graph::{to_dot, to_graphml, to_json_graph, to_mermaid};
+use crate::model::{Dependency, DependencyType, Issue, IssueType, Status};
 use crate::output::{OutputContext, OutputMode};
 use crate::storage::{ListFilters, SqliteStorage};
 use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
@@ -66,6 +69,15 @@ pub fn execute(args: &GraphArgs, cli: &config::CliOverrides, ctx: &OutputContext
     let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
     let all_ids = storage_ctx.storage.get_all_ids()?;
 
+    if let Some(format) = args.format {
+        let root = args
+            .issue
+            .as_ref()
+            .map(|id| resolve_issue_id(&storage_ctx.storage, &resolver, &all_ids, id))
+            .transpose()?;
+        return export_graph(&storage_ctx.storage, root.as_deref(), args, format);
+    }
+
     if args.all {
         graph_all(&storage_ctx.storage, args.compact, ctx)
     } else {
@@ -396,6 +408,135 @@ fn graph_all(storage: &SqliteStorage, compact: bool, ctx: &OutputContext) -> Res
     Ok(())
 }
 
+/// Export the dependency graph with every dependency type.
+///
+/// With a root, exports the root, what it transitively depends on and what
+/// transitively depends on it (see [`export_scope`]); otherwise the whole
+/// workspace. `--label` keeps only labeled issues (the root
+/// always stays) and closed issues are left out unless `--include-closed`.
+fn export_graph(
+    storage: &SqliteStorage,
+    root: Option<&str>,
+    args: &GraphArgs,
+    format: GraphFormat,
+) -> Result<()> {
+    let filters = ListFilters {
+        include_closed: true,
+        include_deferred: true,
+        include_templates: false,
+        labels: args.label.clone().map(|label| vec![label]),
+        ..Default::default()
+    };
+    let mut issues: Vec<Issue> = storage
+        .list_issues(&filters)?
+        .into_iter()
+        .filter(|issue| issue.status != Status::Tombstone)
+        .filter(|issue| {
+            args.include_closed || issue.status != Status::Closed || Some(issue.id.as_str()) == root
+        })
+        .collect();
+    if let Some(root) = root {
+        if !issues.iter().any(|issue| issue.id == root) {
+            let issue = storage
+                .get_issue(root)?
+                .ok_or_else(|| BeadsError::IssueNotFound {
+                    id: root.to_string(),
+                })?;
+            issues.push(issue);
+        }
+    }
+
+    let ids: HashSet<String> = issues.iter().map(|issue| issue.id.clone()).collect();
+    let mut edges: Vec<Dependency> = storage
+        .get_all_dependency_records()?
+        .into_values()
+        .flatten()
+        .filter(|dep| ids.contains(&dep.issue_id) && ids.contains(&dep.depends_on_id))
+        .collect();
+
+    if let Some(root) = root {
+        let scope = export_scope(root, &issues, &edges);
+        issues.retain(|issue| scope.contains(&issue.id));
+        edges.retain(|dep| scope.contains(&dep.issue_id) && scope.contains(&dep.depends_on_id));
+    }
+
+    issues.sort_by(|a, b| a.id.cmp(&b.id));
+    edges.sort_by(|a, b| {
+        a.issue_id
+            .cmp(&b.issue_id)
+            .then_with(|| a.depends_on_id.cmp(&b.depends_on_id))
+            .then_with(|| a.dep_type.as_str().cmp(b.dep_type.as_str()))
+    });
+    debug!(
+        nodes = issues.len(),
+        edges = edges.len(),
+        ?format,
+        "Exporting graph"
+    );
+
+    let rendered = match format {
+        GraphFormat::Dot => to_dot(&issues, &edges),
+        GraphFormat::Mermaid => to_mermaid(&issues, &edges),
+        GraphFormat::Graphml => to_graphml(&issues, &edges),
+        GraphFormat::JsonGraph => {
+            format!(
+                "{}\n",
+                serde_json::to_string_pretty(&to_json_graph(&issues, &edges))?
+            )
+        }
+    };
+    print!("{rendered}");
+    Ok(())
+}
+
+/// The issues an export rooted at `root` covers: the root, what it depends on
+/// and what depends on it, each followed transitively in its own direction.
+/// `parent-child` links are only followed down from epics to their children,
+/// so a child does not pull in its epic and, through it, every sibling.
+fn export_scope(root: &str, issues: &[Issue], edges: &[Dependency]) -> HashSet<String> {
+    let epics: HashSet<&str> = issues
+        .iter()
+        .filter(|issue| issue.issue_type == IssueType::Epic)
+        .map(|issue| issue.id.as_str())
+        .collect();
+    let mut depends_on: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
+    for dep in edges {
+        if dep.dep_type == DependencyType::ParentChild {
+            if epics.contains(dep.depends_on_id.as_str()) {
+                dependents
+                    .entry(&dep.depends_on_id)
+                    .or_default()
+                    .push(&dep.issue_id);
+            }
+            continue;
+        }
+        depends_on
+            .entry(&dep.issue_id)
+            .or_default()
+            .push(&dep.depends_on_id);
+        dependents
+            .entry(&dep.depends_on_id)
+            .or_default()
+            .push(&dep.issue_id);
+    }
+
+    let mut scope: HashSet<String> = HashSet::from([root.to_string()]);
+    for adjacency in [&depends_on, &dependents] {
+        let mut seen: HashSet<&str> = HashSet::from([root]);
+        let mut queue: VecDeque<&str> = VecDeque::from([root]);
+        while let Some(current) = queue.pop_front() {
+            for &next in adjacency.get(current).into_iter().flatten() {
+                if seen.insert(next) {
+                    scope.insert(next.to_string());
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+    scope
+}
+
 // Calculate depths for nodes using longest path from roots.
 ///
 /// Roots are issues with no dependencies within the component.
@@ -1133,4 +1274,57 @@ mod tests {
         let result = graph_all(&storage, false, &ctx);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_export_scope_follows_directions() {
+        let issue = |id: &str, issue_type: IssueType| Issue {
+            id: id.to_string(),
+            title: id.to_string(),
+            issue_type,
+            ..Issue::default()
+        };
+        let dep = |from: &str, to: &str, dep_type: DependencyType| Dependency {
+            issue_id: from.to_string(),
+            depends_on_id: to.to_string(),
+            dep_type,
+            created_at: chrono::Utc::now(),
+            created_by: None,
+            metadata: None,
+            thread_id: None,
+        };
+        let issues = vec![
+            issue("epic", IssueType::Epic),
+            issue("child", IssueType::Task),
+            issue("sibling", IssueType::Task),
+            issue("blocker", IssueType::Task),
+            issue("waiter", IssueType::Task),
+            issue("aside", IssueType::Task),
+            issue("far", IssueType::Task),
+        ];
+        let edges = vec![
+            dep("child", "epic", DependencyType::ParentChild),
+            dep("sibling", "epic", DependencyType::ParentChild),
+            dep("child", "blocker", DependencyType::Blocks),
+            dep("waiter", "child", DependencyType::Blocks),
+            dep("sibling", "aside", DependencyType::Related),
+            dep("far", "sibling", DependencyType::Blocks),
+        ];
+        let sorted = |scope: HashSet<String>| {
+            let mut ids: Vec<String> = scope.into_iter().collect();
+            ids.sort();
+            ids
+        };
+
+        // A child does not pull in its epic or its siblings
+        assert_eq!(
+            sorted(export_scope("child", &issues, &edges)),
+            ["blocker", "child", "waiter"]
+        );
+        // An epic brings its children and what waits on them, not what
+        // they depend on or merely relate to
+        assert_eq!(
+            sorted(export_scope("epic", &issues, &edges)),
+            ["child", "epic", "far", "sibling", "waiter"]
+        );
+    }
 }
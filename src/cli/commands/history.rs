@@ -6,7 +6,7 @@ use crate::output::OutputContext;
 use crate::sync::history;
 use rich_rust::prelude::*;
 use serde_json::json;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Result type for diff status: (status_string, diff_available, optional_size_tuple).
 type DiffStatusResult = (&'static str, bool, Option<(u64, u64)>);
@@ -127,6 +127,18 @@ fn list_backups(history_dir: &Path, ctx: &OutputContext) -> Result<()> {
     Ok(())
 }
 
+/// Resolve `filename` to one of the export backups in `history_dir`.
+///
+/// Only names that `br history list` shows are accepted, so compaction
+/// archives and other files can never be diffed or restored as a backup.
+fn find_backup(history_dir: &Path, filename: &str) -> Result<PathBuf> {
+    history::list_backups(history_dir, None)?
+        .into_iter()
+        .map(|entry| entry.path)
+        .find(|path| path.file_name().is_some_and(|name| name == filename))
+        .ok_or_else(|| BeadsError::Config(format!("Backup file not found: {filename}")))
+}
+
 /// Show diff between current state and a backup.
 fn diff_backup(
     beads_dir: &Path,
@@ -134,12 +146,7 @@ fn diff_backup(
     filename: &str,
     ctx: &OutputContext,
 ) -> Result<()> {
-    let backup_path = history_dir.join(filename);
-    if !backup_path.exists() {
-        return Err(BeadsError::Config(format!(
-            "Backup file not found: {filename}"
-        )));
-    }
+    let backup_path = find_backup(history_dir, filename)?;
 
     let current_path = beads_dir.join("issues.jsonl");
     if !current_path.exists() {
@@ -236,12 +243,7 @@ fn restore_backup(
     force: bool,
     ctx: &OutputContext,
 ) -> Result<()> {
-    let backup_path = history_dir.join(filename);
-    if !backup_path.exists() {
-        return Err(BeadsError::Config(format!(
-            "Backup file not found: {filename}"
-        )));
-    }
+    let backup_path = find_backup(history_dir, filename)?;
 
     let target_path = beads_dir.join("issues.jsonl");
 
@@ -0,0 +1,439 @@
+//! Hooks command implementation.
+//!
+//! `br hooks list` shows the lifecycle hooks configured under `hooks.*`;
+//! `br hooks test` replays a stored audit event through them and reports each
+//! result, so a hook can be tried without changing an issue. Replays ignore
+//! `on_failure` and never roll anything back.
+//!
+//! `br hooks install` writes the git hooks described in
+//! [`crate::hooks::git`]; the hidden `commit-msg`, `pre-commit` and
+//! `post-commit` subcommands are what those hooks run.
+
+use crate::cli::commands::close::{self, CloseArgs};
+use crate::cli::{HooksCommands, HooksCommitMsgArgs, HooksInstallArgs, HooksTestArgs, SyncArgs};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::hooks::git::{self, GIT_HOOKS};
+use crate::hooks::{HookAction, HookPayload, HookRun, Hooks};
+use crate::output::OutputContext;
+use crate::storage::SqliteStorage;
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tracing::{debug, info};
+
+/// JSON output for `br hooks install` and `br hooks uninstall`.
+#[derive(Serialize)]
+struct GitHooksResult {
+    hooks_dir: PathBuf,
+    hooks: Vec<String>,
+    /// Foreign hooks moved aside (install) or restored (uninstall).
+    backups: Vec<String>,
+}
+
+/// Execute the hooks command.
+///
+/// # Errors
+///
+/// Returns an error if the hook config is invalid, the event or issue cannot
+/// be found, git fails, a commit message references unknown issues, or a
+/// database query fails.
+pub fn execute(
+    command: &HooksCommands,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    match command {
+        HooksCommands::List | HooksCommands::Test(_) => {
+            let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+            let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+            let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+            let hooks = config::hooks_from_layer(&layer)?;
+            if let HooksCommands::Test(args) = command {
+                hooks_test(args, &storage_ctx.storage, &layer, &hooks, ctx)
+            } else {
+                hooks_list(&hooks, ctx);
+                Ok(())
+            }
+        }
+        HooksCommands::Install(args) => hooks_install(args, ctx),
+        HooksCommands::Uninstall => hooks_uninstall(ctx),
+        HooksCommands::CommitMsg(args) => commit_msg(args, cli),
+        HooksCommands::PreCommit => pre_commit(cli),
+        HooksCommands::PostCommit => post_commit(cli, ctx),
+    }
+}
+
+fn hooks_list(hooks: &Hooks, ctx: &OutputContext) {
+    if ctx.is_json() {
+        ctx.json_pretty(&hooks.hooks());
+        return;
+    }
+    if hooks.is_empty() {
+        println!("No hooks configured. Add them under `hooks:` in .beads/config.yaml.");
+        return;
+    }
+    for hook in hooks.hooks() {
+        let action = match &hook.action {
+            HookAction::Command(command) => format!("run {command}"),
+            HookAction::Url(url) => format!("POST {url}"),
+        };
+        let mut filters = Vec::new();
+        if !hook.labels.is_empty() {
+            filters.push(format!("labels: {}", hook.labels.join(", ")));
+        }
+        if !hook.types.is_empty() {
+            filters.push(format!("types: {}", hook.types.join(", ")));
+        }
+        filters.push(format!("timeout: {}s", hook.timeout_secs));
+        println!("{}  {action}", hook.name);
+        println!(
+            "    on failure: {}  {}",
+            hook.on_failure.as_str(),
+            filters.join("  ")
+        );
+    }
+}
+
+fn hooks_test(
+    args: &HooksTestArgs,
+    storage: &SqliteStorage,
+    layer: &config::ConfigLayer,
+    hooks: &Hooks,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let event = if let Some(input) = &args.issue {
+        let id = resolve_issue_id(storage, layer, input)?;
+        storage
+            .get_events(&id, 1)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| BeadsError::validation("issue", format!("{id} has no events")))?
+    } else if let Some(event_id) = args.event {
+        storage.get_event(event_id)?.ok_or_else(|| {
+            BeadsError::validation("event", format!("no event with ID {event_id}"))
+        })?
+    } else {
+        storage
+            .get_all_events(1)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| BeadsError::validation("event", "no events recorded yet"))?
+    };
+
+    let issue = match storage.get_issue(&event.issue_id)? {
+        Some(mut issue) => {
+            issue.labels = storage.get_labels(&issue.id)?;
+            Some(issue)
+        }
+        None => None,
+    };
+    let payload = HookPayload { event, issue };
+
+    let selected = match &args.hook {
+        Some(name) => vec![hooks.get(name).ok_or_else(|| {
+            BeadsError::validation(
+                "hook",
+                format!("no hook named '{name}' (see `br hooks list`)"),
+            )
+        })?],
+        None => hooks
+            .hooks()
+            .iter()
+            .filter(|hook| hook.matches(&payload))
+            .collect(),
+    };
+    let runs: Vec<HookRun> = selected.iter().map(|hook| hook.run(&payload)).collect();
+
+    if ctx.is_json() {
+        ctx.json_pretty(&runs);
+        return Ok(());
+    }
+    let event = &payload.event;
+    println!(
+        "Replaying event {} ({} on {} by {})",
+        event.id,
+        event.event_type.as_str(),
+        event.issue_id,
+        event.actor
+    );
+    if runs.is_empty() {
+        println!("No hooks fire on this event.");
+    }
+    for run in &runs {
+        match run.failure() {
+            None => ctx.success(&format!("{} ({} ms)", run.hook, run.elapsed_ms)),
+            Some(reason) => ctx.warning(&format!(
+                "{} failed after {} ms: {reason}",
+                run.hook, run.elapsed_ms
+            )),
+        }
+        if run.ok && !run.output.is_empty() {
+            for line in run.output.lines() {
+                println!("    {line}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn resolve_issue_id(
+    storage: &SqliteStorage,
+    layer: &config::ConfigLayer,
+    input: &str,
+) -> Result<String> {
+    let id_config = config::id_config_from_layer(layer);
+    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let all_ids = storage.get_all_ids()?;
+    resolver
+        .resolve(
+            input,
+            |id| storage.id_exists(id).unwrap_or(false),
+            |hash| find_matching_ids(&all_ids, hash),
+        )
+        .map(|resolved| resolved.id)
+}
+
+fn hooks_install(args: &HooksInstallArgs, ctx: &OutputContext) -> Result<()> {
+    let hooks_dir = git_hooks_dir()?;
+    fs::create_dir_all(&hooks_dir)?;
+
+    // Check every hook first so a conflict leaves all of them untouched
+    let mut backups = Vec::new();
+    for name in GIT_HOOKS {
+        let path = hooks_dir.join(name);
+        if path.exists() && !git::is_br_hook(&fs::read_to_string(&path).unwrap_or_default()) {
+            if !args.force {
+                return Err(BeadsError::validation(
+                    "hooks",
+                    format!(
+                        "{} already exists; use --force to replace it (it is kept as {name}.bak)",
+                        path.display()
+                    ),
+                ));
+            }
+            backups.push((*name).to_string());
+        }
+    }
+
+    for name in GIT_HOOKS {
+        let path = hooks_dir.join(name);
+        if backups.iter().any(|backup| backup.as_str() == *name) {
+            fs::rename(&path, hooks_dir.join(format!("{name}.bak")))?;
+        }
+        fs::write(&path, git::hook_script(name, args.require_issue))?;
+        make_executable(&path)?;
+    }
+    info!(dir = %hooks_dir.display(), require_issue = args.require_issue, "Installed git hooks");
+
+    let result = GitHooksResult {
+        hooks_dir,
+        hooks: GIT_HOOKS.iter().map(ToString::to_string).collect(),
+        backups,
+    };
+    if ctx.is_json() {
+        ctx.json_pretty(&result);
+    } else {
+        ctx.success(&format!(
+            "Installed {} hooks in {}",
+            GIT_HOOKS.join(", "),
+            result.hooks_dir.display()
+        ));
+        for name in &result.backups {
+            ctx.info(&format!("Kept the previous {name} hook as {name}.bak"));
+        }
+    }
+    Ok(())
+}
+
+fn hooks_uninstall(ctx: &OutputContext) -> Result<()> {
+    let hooks_dir = git_hooks_dir()?;
+    let mut removed = Vec::new();
+    let mut restored = Vec::new();
+    for name in GIT_HOOKS {
+        let path = hooks_dir.join(name);
+        if !git::is_br_hook(&fs::read_to_string(&path).unwrap_or_default()) {
+            continue;
+        }
+        fs::remove_file(&path)?;
+        removed.push((*name).to_string());
+        let backup = hooks_dir.join(format!("{name}.bak"));
+        if backup.is_file() {
+            fs::rename(&backup, &path)?;
+            restored.push((*name).to_string());
+        }
+    }
+
+    let result = GitHooksResult {
+        hooks_dir,
+        hooks: removed,
+        backups: restored,
+    };
+    if ctx.is_json() {
+        ctx.json_pretty(&result);
+    } else if result.hooks.is_empty() {
+        ctx.info("No hooks installed by br");
+    } else {
+        ctx.success(&format!("Removed {} hooks", result.hooks.join(", ")));
+        for name in &result.backups {
+            ctx.info(&format!("Restored the previous {name} hook"));
+        }
+    }
+    Ok(())
+}
+
+/// Validate the issue IDs in a commit message.
+fn commit_msg(args: &HooksCommitMsgArgs, cli: &config::CliOverrides) -> Result<()> {
+    let message = git::strip_comments(&fs::read_to_string(&args.file)?);
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let prefix = config::id_config_from_layer(&layer).prefix;
+
+    let refs = git::find_issue_refs(&message, &prefix);
+    if refs.is_empty() {
+        if args.require_issue && !git::is_exempt(&message) {
+            return Err(BeadsError::validation(
+                "commit message",
+                format!("reference an issue (e.g. {prefix}-abc1), or commit with --no-verify"),
+            ));
+        }
+        return Ok(());
+    }
+
+    let storage = &storage_ctx.storage;
+    let resolver = IdResolver::new(ResolverConfig::with_prefix(prefix));
+    let unknown: Vec<String> = refs
+        .into_iter()
+        .filter(|id| {
+            resolver
+                .resolve(
+                    id,
+                    |id| storage.id_exists(id).unwrap_or(false),
+                    |hash| storage.find_ids_by_hash(hash).unwrap_or_default(),
+                )
+                .is_err()
+        })
+        .collect();
+    if !unknown.is_empty() {
+        return Err(BeadsError::validation(
+            "commit message",
+            format!(
+                "unknown or ambiguous issue {}: {}",
+                if unknown.len() == 1 { "ID" } else { "IDs" },
+                unknown.join(", ")
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Export the database and stage the JSONL if the repository tracks it.
+fn pre_commit(cli: &config::CliOverrides) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let sync_args = SyncArgs {
+        flush_only: true,
+        ..SyncArgs::default()
+    };
+    let quiet = OutputContext::from_flags(false, true, true);
+    crate::cli::commands::sync::execute(&sync_args, false, cli, &quiet)?;
+
+    let paths = config::resolve_paths(&beads_dir, cli.db.as_ref())?;
+    let jsonl = paths.jsonl_path.to_string_lossy();
+    if git_output(&["ls-files", "--error-unmatch", "--", &jsonl]).is_ok() {
+        git_output(&["add", "--", &jsonl])?;
+        debug!(path = %jsonl, "Staged JSONL");
+    }
+    Ok(())
+}
+
+/// Close the issues named in `Closes <id>` lines of the last commit.
+fn post_commit(cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let log = git_output(&["log", "-1", "--format=%H%n%B"])?;
+    let (hash, body) = log.split_once('\n').unwrap_or((log.as_str(), ""));
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let ids = {
+        let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+        let storage = &storage_ctx.storage;
+        let layer = config::load_config(&beads_dir, Some(storage), cli)?;
+        let prefix = config::id_config_from_layer(&layer).prefix;
+        let resolver = IdResolver::new(ResolverConfig::with_prefix(prefix.clone()));
+
+        let mut ids = Vec::new();
+        for reference in git::find_closing_refs(body, &prefix) {
+            let resolved = resolver.resolve(
+                &reference,
+                |id| storage.id_exists(id).unwrap_or(false),
+                |hash| storage.find_ids_by_hash(hash).unwrap_or_default(),
+            );
+            match resolved {
+                Ok(resolved) => {
+                    let open = storage
+                        .get_issue(&resolved.id)?
+                        .is_some_and(|issue| !issue.status.is_terminal());
+                    if open {
+                        ids.push(resolved.id);
+                    }
+                }
+                Err(err) => ctx.warning(&format!("Not closing {reference}: {err}")),
+            }
+        }
+        ids
+    };
+    if ids.is_empty() {
+        return Ok(());
+    }
+
+    let close_args = CloseArgs {
+        ids,
+        reason: Some(format!("Closed in commit {hash}")),
+        force: false,
+        outcome: None,
+        session: None,
+        suggest_next: false,
+    };
+    close::execute_with_args(&close_args, ctx.is_json(), cli, ctx)
+}
+
+/// The repository's hooks directory (honors `core.hooksPath` and worktrees).
+fn git_hooks_dir() -> Result<PathBuf> {
+    let dir = PathBuf::from(git_output(&["rev-parse", "--git-path", "hooks"])?);
+    if dir.is_absolute() {
+        Ok(dir)
+    } else {
+        Ok(std::env::current_dir()?.join(dir))
+    }
+}
+
+/// Run git in the current directory and return its trimmed stdout.
+fn git_output(args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|e| BeadsError::Config(format!("Failed to run git: {e}")))?;
+    if !output.status.success() {
+        return Err(BeadsError::Config(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755))?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,550 @@
+//! Import command implementation.
+//!
+//! `br import --from github|gitlab|jira <file>` maps another tracker's export
+//! (parsed by [`crate::util::tracker_import`]) onto br issues. Issues are
+//! matched by `external_ref`, so re-running an import updates the issues an
+//! earlier run created instead of duplicating them: changed fields are
+//! updated, missing labels, comments and links are added, and nothing is
+//! removed. A run is written in one transaction, so it applies completely or
+//! not at all.
+
+use crate::cli::commands::create::CreateConfig;
+use crate::cli::{ImportArgs, ImportSource};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::{Comment, Issue, Status};
+use crate::output::OutputContext;
+use crate::storage::{IssueUpdate, SqliteStorage};
+use crate::util::id::IdGenerator;
+use crate::util::tracker_import::{ExternalIssue, github, gitlab, jira};
+use crate::validation::{IssueValidator, LabelValidator};
+use chrono::Utc;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use tracing::{debug, info};
+
+/// Result of an import run (JSON output).
+#[derive(Debug, Default, Serialize)]
+pub struct ImportReport {
+    pub source: String,
+    pub dry_run: bool,
+    pub created: Vec<ImportedIssue>,
+    pub updated: Vec<ImportedIssue>,
+    pub unchanged: usize,
+    pub dependencies_added: usize,
+    pub skipped: Vec<SkippedItem>,
+}
+
+/// An issue created or updated by the import.
+#[derive(Debug, Serialize)]
+pub struct ImportedIssue {
+    /// br ID (the external ref for issues a dry run would create).
+    pub id: String,
+    pub external_ref: String,
+    pub title: String,
+}
+
+/// An issue or link the import could not apply.
+#[derive(Debug, Serialize)]
+pub struct SkippedItem {
+    pub external_ref: String,
+    pub reason: String,
+}
+
+/// Execute the import command.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or parsed, or a database
+/// operation fails.
+pub fn execute(args: &ImportArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let text = fs::read_to_string(&args.file)?;
+    let external = match args.from {
+        ImportSource::Github => github::parse(&text)?,
+        ImportSource::Gitlab => gitlab::parse(&text)?,
+        ImportSource::Jira => jira::parse(&text)?,
+    };
+    info!(
+        source = args.from.as_str(),
+        count = external.len(),
+        file = %args.file.display(),
+        "Parsed tracker export"
+    );
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let create_config = CreateConfig {
+        id_config: config::id_config_from_layer(&layer),
+        default_priority: config::default_priority_from_layer(&layer)?,
+        default_issue_type: config::default_issue_type_from_layer(&layer)?,
+        actor: config::resolve_actor(&layer),
+    };
+
+    let report = import_issues(
+        &mut storage_ctx.storage,
+        &external,
+        args.from,
+        &create_config,
+        args.dry_run,
+    )?;
+    if !args.dry_run {
+        storage_ctx.flush_no_db_if_dirty()?;
+    }
+
+    if ctx.is_json() {
+        ctx.json_pretty(&report);
+        return Ok(());
+    }
+    let verb = if args.dry_run {
+        "Would import"
+    } else {
+        "Imported"
+    };
+    ctx.success(&format!(
+        "{verb} {} issues from {}: {} created, {} updated, {} unchanged, {} dependencies added",
+        report.created.len() + report.updated.len() + report.unchanged,
+        args.file.display(),
+        report.created.len(),
+        report.updated.len(),
+        report.unchanged,
+        report.dependencies_added
+    ));
+    for issue in &report.created {
+        ctx.print(&format!(
+            "  + {}: {} ({})",
+            issue.id, issue.title, issue.external_ref
+        ));
+    }
+    for issue in &report.updated {
+        ctx.print(&format!(
+            "  ~ {}: {} ({})",
+            issue.id, issue.title, issue.external_ref
+        ));
+    }
+    for skipped in &report.skipped {
+        ctx.warning(&format!(
+            "Skipped {}: {}",
+            skipped.external_ref, skipped.reason
+        ));
+    }
+    Ok(())
+}
+
+/// Create or update br issues for `external`, then link them, in one
+/// transaction (one `br undo` step).
+///
+/// # Errors
+///
+/// Returns an error if a database operation fails, after rolling back the
+/// whole run. Issues and links that cannot be applied are reported in
+/// [`ImportReport::skipped`].
+pub fn import_issues(
+    storage: &mut SqliteStorage,
+    external: &[ExternalIssue],
+    source: ImportSource,
+    create_config: &CreateConfig,
+    dry_run: bool,
+) -> Result<ImportReport> {
+    if dry_run {
+        return apply_import(storage, external, source, create_config, true);
+    }
+    storage.batch("import", &create_config.actor, |storage| {
+        apply_import(storage, external, source, create_config, false)
+    })
+}
+
+#[allow(clippy::too_many_lines)]
+fn apply_import(
+    storage: &mut SqliteStorage,
+    external: &[ExternalIssue],
+    source: ImportSource,
+    create_config: &CreateConfig,
+    dry_run: bool,
+) -> Result<ImportReport> {
+    let mut report = ImportReport {
+        source: source.as_str().to_string(),
+        dry_run,
+        ..ImportReport::default()
+    };
+    // External ref -> br ID; issues a dry run would create map to their ref
+    let mut ids: HashMap<String, String> = HashMap::new();
+    let mut pending: HashSet<String> = HashSet::new();
+    let id_gen = IdGenerator::new(create_config.id_config.clone());
+    let actor = create_config.actor.as_str();
+
+    for ext in external {
+        if ext.title.trim().is_empty() {
+            report.skipped.push(SkippedItem {
+                external_ref: ext.external_ref.clone(),
+                reason: "missing title".to_string(),
+            });
+            continue;
+        }
+        if let Some(existing) = storage.find_by_external_ref(&ext.external_ref)? {
+            let changed = update_existing(storage, &existing, ext, actor, dry_run)?;
+            let imported = ImportedIssue {
+                id: existing.id.clone(),
+                external_ref: ext.external_ref.clone(),
+                title: ext.title.clone(),
+            };
+            if changed {
+                report.updated.push(imported);
+            } else {
+                report.unchanged += 1;
+            }
+            ids.insert(ext.external_ref.clone(), existing.id);
+            continue;
+        }
+
+        let issue = new_issue(storage, &id_gen, ext, source, create_config);
+        if let Err(err) =
+            IssueValidator::validate(&issue).map_err(BeadsError::from_validation_errors)
+        {
+            report.skipped.push(SkippedItem {
+                external_ref: ext.external_ref.clone(),
+                reason: err.to_string(),
+            });
+            continue;
+        }
+        let id = if dry_run {
+            pending.insert(ext.external_ref.clone());
+            ext.external_ref.clone()
+        } else {
+            storage.create_issue(&issue, actor)?;
+            debug!(id = %issue.id, external_ref = %ext.external_ref, "Imported issue");
+            issue.id.clone()
+        };
+        report.created.push(ImportedIssue {
+            id: id.clone(),
+            external_ref: ext.external_ref.clone(),
+            title: issue.title.clone(),
+        });
+        ids.insert(ext.external_ref.clone(), id);
+    }
+
+    // Links go in after every issue exists, since they may point forward
+    let mut seen: HashSet<(String, String)> = HashSet::new();
+    for ext in external {
+        if !ids.contains_key(&ext.external_ref) {
+            continue;
+        }
+        // (dependent, dependency, type) as external refs
+        let mut links: Vec<(&str, &str, &str)> = Vec::new();
+        if let Some(parent) = &ext.parent {
+            links.push((ext.external_ref.as_str(), parent.as_str(), "parent-child"));
+        }
+        for blocked in &ext.blocks {
+            links.push((blocked.as_str(), ext.external_ref.as_str(), "blocks"));
+        }
+        for blocker in &ext.blocked_by {
+            links.push((ext.external_ref.as_str(), blocker.as_str(), "blocks"));
+        }
+
+        for (from_ref, to_ref, dep_type) in links {
+            let from = resolve_ref(storage, &ids, from_ref)?;
+            let to = resolve_ref(storage, &ids, to_ref)?;
+            let (from, to) = match (from, to) {
+                (Some(from), Some(to)) => (from, to),
+                (from, _) => {
+                    let missing = if from.is_none() { from_ref } else { to_ref };
+                    report.skipped.push(SkippedItem {
+                        external_ref: ext.external_ref.clone(),
+                        reason: format!("{dep_type} link to {missing}, which is not imported"),
+                    });
+                    continue;
+                }
+            };
+            if !seen.insert((from.clone(), to.clone())) {
+                continue;
+            }
+            if pending.contains(&from) || pending.contains(&to) {
+                report.dependencies_added += 1;
+                continue;
+            }
+            if dry_run {
+                if !storage.dependency_exists_between(&from, &to)? {
+                    report.dependencies_added += 1;
+                }
+                continue;
+            }
+            match storage.add_dependency(&from, &to, dep_type, actor) {
+                Ok(true) => report.dependencies_added += 1,
+                Ok(false) => {}
+                Err(err @ BeadsError::DependencyCycle { .. }) => {
+                    report.skipped.push(SkippedItem {
+                        external_ref: ext.external_ref.clone(),
+                        reason: err.to_string(),
+                    });
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+    Ok(report)
+}
+
+fn new_issue(
+    storage: &SqliteStorage,
+    id_gen: &IdGenerator,
+    ext: &ExternalIssue,
+    source: ImportSource,
+    create_config: &CreateConfig,
+) -> Issue {
+    let now = Utc::now();
+    let title = ext.title.trim().to_string();
+    let created_at = ext.created_at.unwrap_or(now);
+    let id = id_gen.generate(
+        &title,
+        ext.description.as_deref(),
+        ext.created_by.as_deref(),
+        created_at,
+        storage.count_issues().unwrap_or(0),
+        |id| storage.id_exists(id).unwrap_or(false),
+    );
+    let closed = ext.status == Status::Closed;
+    let comments = ext
+        .comments
+        .iter()
+        .map(|comment| Comment {
+            id: 0,
+            issue_id: id.clone(),
+            author: comment.author.clone(),
+            body: comment.body.clone(),
+            created_at: comment.created_at.unwrap_or(now),
+        })
+        .collect();
+
+    let mut issue = Issue {
+        id,
+        title,
+        description: ext.description.clone(),
+        status: ext.status.clone(),
+        priority: ext.priority.unwrap_or(create_config.default_priority),
+        issue_type: ext
+            .issue_type
+            .clone()
+            .unwrap_or_else(|| create_config.default_issue_type.clone()),
+        assignee: ext.assignee.clone(),
+        created_at,
+        created_by: ext.created_by.clone(),
+        updated_at: now,
+        closed_at: if closed {
+            Some(ext.closed_at.unwrap_or(now))
+        } else {
+            None
+        },
+        close_reason: if closed {
+            ext.close_reason.clone()
+        } else {
+            None
+        },
+        due_at: ext.due_at,
+        external_ref: Some(ext.external_ref.clone()),
+        source_system: Some(source.as_str().to_string()),
+        labels: valid_labels(&ext.labels),
+        comments,
+        ..Issue::default()
+    };
+    issue.content_hash = Some(issue.compute_content_hash());
+    issue
+}
+
+/// Apply the tracker's current state to an issue imported earlier. Only
+/// fields the export sets are compared; labels and comments are added, never
+/// removed. Returns whether anything changed (or would change).
+fn update_existing(
+    storage: &mut SqliteStorage,
+    existing: &Issue,
+    ext: &ExternalIssue,
+    actor: &str,
+    dry_run: bool,
+) -> Result<bool> {
+    let mut updates = IssueUpdate::default();
+    let title = ext.title.trim();
+    if existing.title != title {
+        updates.title = Some(title.to_string());
+    }
+    if ext.description.is_some() && existing.description != ext.description {
+        updates.description = Some(ext.description.clone());
+    }
+    if existing.status != ext.status {
+        updates.status = Some(ext.status.clone());
+        if ext.status == Status::Closed {
+            updates.closed_at = Some(Some(ext.closed_at.unwrap_or_else(Utc::now)));
+            updates.close_reason = Some(ext.close_reason.clone());
+        }
+    }
+    if ext
+        .priority
+        .is_some_and(|priority| priority != existing.priority)
+    {
+        updates.priority = ext.priority;
+    }
+    if ext
+        .issue_type
+        .as_ref()
+        .is_some_and(|issue_type| *issue_type != existing.issue_type)
+    {
+        updates.issue_type.clone_from(&ext.issue_type);
+    }
+    if ext.assignee.is_some() && existing.assignee != ext.assignee {
+        updates.assignee = Some(ext.assignee.clone());
+    }
+    if ext.due_at.is_some() && existing.due_at != ext.due_at {
+        updates.due_at = Some(ext.due_at);
+    }
+
+    let current_labels = storage.get_labels(&existing.id)?;
+    let new_labels: Vec<String> = valid_labels(&ext.labels)
+        .into_iter()
+        .filter(|label| !current_labels.contains(label))
+        .collect();
+    let current_comments = storage.get_comments(&existing.id)?;
+    let new_comments: Vec<_> = ext
+        .comments
+        .iter()
+        .filter(|comment| {
+            !current_comments
+                .iter()
+                .any(|c| c.author == comment.author && c.body.trim() == comment.body.trim())
+        })
+        .collect();
+
+    let changed = !updates.is_empty() || !new_labels.is_empty() || !new_comments.is_empty();
+    if dry_run || !changed {
+        return Ok(changed);
+    }
+    if !updates.is_empty() {
+        storage.update_issue(&existing.id, &updates, actor)?;
+    }
+    for label in &new_labels {
+        storage.add_label(&existing.id, label, actor)?;
+    }
+    for comment in new_comments {
+        storage.add_comment(&existing.id, &comment.author, &comment.body)?;
+    }
+    debug!(id = %existing.id, external_ref = %ext.external_ref, "Updated imported issue");
+    Ok(true)
+}
+
+/// br ID for an external ref: imported in this run or by an earlier one.
+fn resolve_ref(
+    storage: &SqliteStorage,
+    ids: &HashMap<String, String>,
+    external_ref: &str,
+) -> Result<Option<String>> {
+    if let Some(id) = ids.get(external_ref) {
+        return Ok(Some(id.clone()));
+    }
+    Ok(storage
+        .find_by_external_ref(external_ref)?
+        .map(|issue| issue.id))
+}
+
+fn valid_labels(labels: &[String]) -> Vec<String> {
+    labels
+        .iter()
+        .filter(|label| LabelValidator::validate(label).is_ok())
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{IssueType, Priority};
+    use crate::util::IdConfig;
+
+    fn test_config() -> CreateConfig {
+        CreateConfig {
+            id_config: IdConfig::with_prefix("bd"),
+            default_priority: Priority(2),
+            default_issue_type: IssueType::Task,
+            actor: "importer".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_import_is_idempotent_and_links_issues() {
+        let export = r#"[
+            {"number": 1, "title": "Epic", "state": "OPEN", "subIssues": [{"number": 2}]},
+            {"number": 2, "title": "Child", "state": "OPEN", "labels": [{"name": "bug"}],
+             "comments": [{"author": {"login": "octocat"}, "body": "Seen it"}],
+             "blockedBy": [{"number": 3}]},
+            {"number": 3, "title": "Blocker", "state": "CLOSED"}
+        ]"#;
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let issues = github::parse(export).unwrap();
+        let config = test_config();
+
+        let dry =
+            import_issues(&mut storage, &issues, ImportSource::Github, &config, true).unwrap();
+        assert_eq!(dry.created.len(), 3);
+        assert_eq!(dry.dependencies_added, 2);
+        assert_eq!(storage.count_issues().unwrap(), 0);
+
+        let first =
+            import_issues(&mut storage, &issues, ImportSource::Github, &config, false).unwrap();
+        assert_eq!(first.created.len(), 3);
+        assert_eq!(first.dependencies_added, 2);
+        let child = storage.find_by_external_ref("gh-2").unwrap().unwrap();
+        assert_eq!(child.source_system.as_deref(), Some("github"));
+        assert_eq!(child.issue_type, IssueType::Bug);
+        assert_eq!(storage.get_comments(&child.id).unwrap().len(), 1);
+        assert_eq!(storage.get_dependencies(&child.id).unwrap().len(), 2);
+        let blocker = storage.find_by_external_ref("gh-3").unwrap().unwrap();
+        assert_eq!(blocker.status, Status::Closed);
+
+        let second =
+            import_issues(&mut storage, &issues, ImportSource::Github, &config, false).unwrap();
+        assert!(second.created.is_empty());
+        assert!(second.updated.is_empty());
+        assert_eq!(second.unchanged, 3);
+        assert_eq!(second.dependencies_added, 0);
+        assert_eq!(storage.count_issues().unwrap(), 3);
+
+        let reopened = export.replace(r#""state": "CLOSED""#, r#""state": "OPEN""#);
+        let issues = github::parse(&reopened).unwrap();
+        let third =
+            import_issues(&mut storage, &issues, ImportSource::Github, &config, false).unwrap();
+        assert_eq!(third.updated.len(), 1);
+        let blocker = storage.get_issue(&blocker.id).unwrap().unwrap();
+        assert_eq!(blocker.status, Status::Open);
+    }
+
+    #[test]
+    fn test_import_keeps_repositories_apart() {
+        let export = |repo: &str, title: &str| {
+            format!(
+                r#"[{{"number": 1, "title": "{title}", "state": "open",
+                     "html_url": "https://github.com/octo/{repo}/issues/1"}}]"#
+            )
+        };
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let config = test_config();
+
+        for (repo, title) in [("app", "App bug"), ("lib", "Lib bug")] {
+            let issues = github::parse(&export(repo, title)).unwrap();
+            let report =
+                import_issues(&mut storage, &issues, ImportSource::Github, &config, false).unwrap();
+            assert_eq!(report.created.len(), 1);
+        }
+
+        let app = storage
+            .find_by_external_ref("gh-octo/app#1")
+            .unwrap()
+            .unwrap();
+        let lib = storage
+            .find_by_external_ref("gh-octo/lib#1")
+            .unwrap()
+            .unwrap();
+        assert_eq!(app.title, "App bug");
+        assert_eq!(lib.title, "Lib bug");
+        // Each run is one undo step
+        let batches = storage.undo_stack(usize::MAX, None).unwrap();
+        assert_eq!(batches.len(), 2);
+        assert!(batches.iter().all(|batch| batch.op == "import"));
+    }
+}
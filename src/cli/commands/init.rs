@@ -1,6 +1,7 @@
 use crate::error::{BeadsError, Result};
 use crate::output::{OutputContext, OutputMode};
 use crate::storage::SqliteStorage;
+use crate::sync::merge_driver::{GITATTRIBUTES_CONTENT, MERGE_DRIVER_SETUP_COMMAND};
 use crate::util::db_path;
 use rich_rust::prelude::*;
 use std::fs;
@@ -94,6 +95,13 @@ last-touched
         fs::write(gitignore_path, gitignore)?;
     }
 
+    // Write .gitattributes so git merges issues.jsonl with `br merge-driver`
+    let gitattributes_path = beads_dir.join(".gitattributes");
+    let gitattributes_existed = gitattributes_path.exists();
+    if !gitattributes_existed {
+        fs::write(gitattributes_path, GITATTRIBUTES_CONTENT)?;
+    }
+
     // Write empty issues.jsonl for compatibility with bv (beads_viewer)
     // bv expects this file to exist even if there are no issues yet
     let jsonl_path = beads_dir.join("issues.jsonl");
@@ -114,6 +122,7 @@ last-touched
             force,
             config_existed,
             gitignore_existed,
+            gitattributes_existed,
             jsonl_existed,
             prefix_set.as_deref(),
         );
@@ -123,6 +132,10 @@ last-touched
             println!("Prefix set to: {p}");
         }
         println!("Initialized beads workspace in .beads/");
+        if !gitattributes_existed {
+            println!("To merge issues.jsonl by issue, register the git merge driver:");
+            println!("  {MERGE_DRIVER_SETUP_COMMAND}");
+        }
     }
 
     Ok(())
@@ -148,6 +161,7 @@ fn build_init_steps(
     force: bool,
     config_existed: bool,
     gitignore_existed: bool,
+    gitattributes_existed: bool,
     jsonl_existed: bool,
     prefix: Option<&str>,
 ) -> Vec<InitStep> {
@@ -201,6 +215,15 @@ fn build_init_steps(
         },
     });
 
+    steps.push(InitStep {
+        label: ".gitattributes (issues.jsonl merge driver)".to_string(),
+        status: if gitattributes_existed {
+            InitStepStatus::Existing
+        } else {
+            InitStepStatus::Created
+        },
+    });
+
     steps.push(InitStep {
         label: "issues.jsonl (for bv compatibility)".to_string(),
         status: if jsonl_existed {
@@ -248,12 +271,14 @@ fn render_init_rich(
     content.append("    |-- metadata.json\n");
     content.append("    |-- config.yaml\n");
     content.append("    |-- .gitignore\n");
+    content.append("    |-- .gitattributes\n");
     content.append("    `-- issues.jsonl\n");
 
     content.append("\n");
     content.append_styled("Next steps:\n", theme.emphasis.clone());
     content.append("  br create \"My first issue\"\n");
     content.append("  br list\n");
+    content.append(&format!("  {MERGE_DRIVER_SETUP_COMMAND}\n"));
 
     if prefix.is_none() {
         content.append("\n");
@@ -418,4 +443,23 @@ mod tests {
         assert!(content.contains("*.lock"));
         info!("test_gitignore_excludes_db_files: assertions passed");
     }
+
+    #[test]
+    fn test_gitattributes_registers_merge_driver() {
+        init_logging();
+        info!("test_gitattributes_registers_merge_driver: starting");
+        let temp_dir = TempDir::new().unwrap();
+        let ctx = OutputContext::from_flags(false, false, true);
+        execute(None, false, Some(temp_dir.path()), &ctx).unwrap();
+
+        let gitattributes_path = temp_dir.path().join(".beads/.gitattributes");
+        let content = fs::read_to_string(gitattributes_path).unwrap();
+
+        assert!(
+            content
+                .lines()
+                .any(|line| line == "issues.jsonl merge=beads")
+        );
+        info!("test_gitattributes_registers_merge_driver: assertions passed");
+    }
 }
@@ -75,7 +75,11 @@ struct RenameResult {
 /// Validate a label name.
 ///
 /// Labels must be alphanumeric with dashes and underscores allowed.
-fn validate_label(label: &str) -> Result<()> {
+///
+/// # Errors
+///
+/// Returns a validation error if the label is empty or has other characters.
+pub fn validate_label(label: &str) -> Result<()> {
     if label.is_empty() {
         return Err(BeadsError::validation("label", "label cannot be empty"));
     }
@@ -7,13 +7,17 @@ use crate::cli::{ListArgs, OutputFormat, resolve_output_format};
 use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::format::csv;
-use crate::format::{IssueWithCounts, TextFormatOptions, format_issue_line_with, terminal_width};
-use crate::model::{IssueType, Priority, Status};
+use crate::format::{
+    IssueWithCounts, ProjectScoped, TextFormatOptions, format_issue_line_with, terminal_width,
+};
+use crate::model::{Issue, IssueType, Priority, Status};
 use crate::output::{IssueTable, IssueTableColumns, OutputContext, OutputMode};
 use crate::storage::{ListFilters, SqliteStorage};
 use chrono::Utc;
-use std::collections::HashSet;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 use std::io::IsTerminal;
+use std::path::Path;
 
 /// Execute the list command.
 ///
@@ -29,7 +33,7 @@ pub fn execute(
 ) -> Result<()> {
     // Open storage (--db flag allows working from any directory)
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
-    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let storage_ctx = config::open_storage_as_of(&beads_dir, cli, args.as_of.as_deref())?;
     let storage = &storage_ctx.storage;
     let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
     let use_color = config::should_use_color(&config_layer);
@@ -56,6 +60,16 @@ pub fn execute(
     // Validate sort key before query
     validate_sort_key(args.sort.as_deref())?;
 
+    // Determine output format: --json flag overrides --format
+    let output_format = resolve_output_format(args.format, outer_ctx.is_json(), false);
+    let quiet = cli.quiet.unwrap_or(false);
+    let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
+
+    if args.town {
+        filters.limit = limit.or(filters.limit);
+        return execute_town(args, filters, &beads_dir, cli, &ctx, format_options);
+    }
+
     // Query issues
     let issues = storage.list_issues(&filters)?;
     let mut issues = if client_filters {
@@ -70,10 +84,6 @@ pub fn execute(
         }
     }
 
-    // Determine output format: --json flag overrides --format
-    let output_format = resolve_output_format(args.format, outer_ctx.is_json(), false);
-    let quiet = cli.quiet.unwrap_or(false);
-    let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
     if matches!(ctx.mode(), OutputMode::Quiet) {
         return Ok(());
     }
@@ -81,32 +91,7 @@ pub fn execute(
     // Output
     match output_format {
         OutputFormat::Json | OutputFormat::Toon => {
-            // Fetch relations for all issues
-            let issue_ids: Vec<String> = issues.iter().map(|i| i.id.clone()).collect();
-            let mut labels_map = storage.get_labels_for_issues(&issue_ids)?;
-
-            // Use batch counting
-            let dependency_counts = storage.count_dependencies_for_issues(&issue_ids)?;
-            let dependent_counts = storage.count_dependents_for_issues(&issue_ids)?;
-
-            // Convert to IssueWithCounts
-            let issues_with_counts: Vec<IssueWithCounts> = issues
-                .into_iter()
-                .map(|mut issue| {
-                    if let Some(labels) = labels_map.remove(&issue.id) {
-                        issue.labels = labels;
-                    }
-
-                    let dependency_count = *dependency_counts.get(&issue.id).unwrap_or(&0);
-                    let dependent_count = *dependent_counts.get(&issue.id).unwrap_or(&0);
-
-                    IssueWithCounts {
-                        issue,
-                        dependency_count,
-                        dependent_count,
-                    }
-                })
-                .collect();
+            let issues_with_counts = with_counts(storage, issues)?;
 
             if matches!(output_format, OutputFormat::Toon) {
                 ctx.toon_with_stats(&issues_with_counts, args.stats);
@@ -116,42 +101,17 @@ pub fn execute(
         }
         OutputFormat::Csv => {
             let fields = csv::parse_fields(args.fields.as_deref());
-            let csv_output = csv::format_csv(&issues, &fields);
+            let time_spent = if csv::needs_time(&fields) {
+                storage.get_time_rollup()?
+            } else {
+                HashMap::new()
+            };
+            let csv_output = csv::format_csv_with_time(&issues, &fields, &time_spent);
             print!("{csv_output}");
         }
         OutputFormat::Text => {
             if matches!(ctx.mode(), OutputMode::Rich) {
-                let columns = if args.long {
-                    IssueTableColumns {
-                        id: true,
-                        priority: true,
-                        status: true,
-                        issue_type: true,
-                        title: true,
-                        assignee: true,
-                        created: true,
-                        updated: true,
-                        ..Default::default()
-                    }
-                } else {
-                    IssueTableColumns {
-                        id: true,
-                        priority: true,
-                        status: true,
-                        issue_type: true,
-                        title: true,
-                        ..Default::default()
-                    }
-                };
-                let mut table = IssueTable::new(&issues, ctx.theme())
-                    .columns(columns)
-                    .title(format!("Issues ({})", issues.len()))
-                    .wrap(args.wrap);
-                if args.wrap {
-                    table = table.width(Some(ctx.width()));
-                }
-                let table = table.build();
-                ctx.render(&table);
+                render_table(&issues, None, args, &ctx);
             } else {
                 // Note: bd outputs nothing when no issues found, matching that for conformance
                 for issue in &issues {
@@ -165,6 +125,169 @@ pub fn execute(
     Ok(())
 }
 
+/// `--town`: list issues from every routed project, merged in sort order.
+fn execute_town(
+    args: &ListArgs,
+    mut filters: ListFilters,
+    beads_dir: &Path,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+    format_options: TextFormatOptions,
+) -> Result<()> {
+    if matches!(ctx.mode(), OutputMode::Quiet) {
+        return Ok(());
+    }
+    if args.format == Some(OutputFormat::Csv) {
+        return Err(BeadsError::validation(
+            "format",
+            "CSV output is not supported with --town",
+        ));
+    }
+
+    // The limit applies to the merged list, not to each project
+    let limit = filters.limit.take();
+    let mut rows: Vec<ProjectScoped<IssueWithCounts>> = Vec::new();
+    for project in config::open_town_projects(beads_dir, cli)? {
+        let storage = &project.storage;
+        let mut issues = storage.list_issues(&filters)?;
+        if needs_client_filters(args) {
+            issues = apply_client_filters(storage, issues, args)?;
+        }
+        rows.extend(
+            with_counts(storage, issues)?
+                .into_iter()
+                .map(|item| ProjectScoped {
+                    project: project.name.clone(),
+                    item,
+                }),
+        );
+    }
+    rows.sort_by(|a, b| {
+        compare_issues(
+            args.sort.as_deref(),
+            args.reverse,
+            &a.item.issue,
+            &b.item.issue,
+        )
+    });
+    if let Some(limit) = limit {
+        if limit > 0 && rows.len() > limit {
+            rows.truncate(limit);
+        }
+    }
+
+    if ctx.is_toon() {
+        ctx.toon_with_stats(&rows, args.stats);
+    } else if ctx.is_json() {
+        ctx.json_pretty(&rows);
+    } else if matches!(ctx.mode(), OutputMode::Rich) {
+        let projects: HashMap<String, String> = rows
+            .iter()
+            .map(|row| (row.item.issue.id.clone(), row.project.clone()))
+            .collect();
+        let issues: Vec<Issue> = rows.into_iter().map(|row| row.item.issue).collect();
+        render_table(&issues, Some(projects), args, ctx);
+    } else {
+        for row in &rows {
+            let line = format_issue_line_with(&row.item.issue, format_options);
+            println!("[{}] {line}", row.project);
+        }
+    }
+    Ok(())
+}
+
+/// Attach labels and dependency counts for JSON output.
+fn with_counts(storage: &SqliteStorage, issues: Vec<Issue>) -> Result<Vec<IssueWithCounts>> {
+    // Fetch relations for all issues
+    let issue_ids: Vec<String> = issues.iter().map(|i| i.id.clone()).collect();
+    let mut labels_map = storage.get_labels_for_issues(&issue_ids)?;
+
+    // Use batch counting
+    let dependency_counts = storage.count_dependencies_for_issues(&issue_ids)?;
+    let dependent_counts = storage.count_dependents_for_issues(&issue_ids)?;
+
+    // Convert to IssueWithCounts
+    Ok(issues
+        .into_iter()
+        .map(|mut issue| {
+            if let Some(labels) = labels_map.remove(&issue.id) {
+                issue.labels = labels;
+            }
+
+            let dependency_count = *dependency_counts.get(&issue.id).unwrap_or(&0);
+            let dependent_count = *dependent_counts.get(&issue.id).unwrap_or(&0);
+
+            IssueWithCounts {
+                issue,
+                dependency_count,
+                dependent_count,
+            }
+        })
+        .collect())
+}
+
+fn render_table(
+    issues: &[Issue],
+    projects: Option<HashMap<String, String>>,
+    args: &ListArgs,
+    ctx: &OutputContext,
+) {
+    let columns = if args.long {
+        IssueTableColumns {
+            id: true,
+            priority: true,
+            status: true,
+            issue_type: true,
+            title: true,
+            assignee: true,
+            created: true,
+            updated: true,
+            ..Default::default()
+        }
+    } else {
+        IssueTableColumns {
+            id: true,
+            priority: true,
+            status: true,
+            issue_type: true,
+            title: true,
+            ..Default::default()
+        }
+    };
+    let mut table = IssueTable::new(issues, ctx.theme())
+        .columns(columns)
+        .title(format!("Issues ({})", issues.len()))
+        .wrap(args.wrap);
+    if let Some(projects) = projects {
+        table = table.projects(projects);
+    }
+    if args.wrap {
+        table = table.width(Some(ctx.width()));
+    }
+    let table = table.build();
+    ctx.render(&table);
+}
+
+/// In-memory equivalent of the `ORDER BY` that `list_issues` builds for a sort key.
+fn compare_issues(sort: Option<&str>, reverse: bool, a: &Issue, b: &Issue) -> Ordering {
+    let by_priority = || {
+        a.priority
+            .cmp(&b.priority)
+            .then_with(|| b.created_at.cmp(&a.created_at))
+    };
+    let ordering = match sort {
+        Some("created_at" | "created") => b.created_at.cmp(&a.created_at),
+        Some("updated_at" | "updated") => b.updated_at.cmp(&a.updated_at),
+        Some("title") => a.title.to_lowercase().cmp(&b.title.to_lowercase()),
+        _ => by_priority(),
+    };
+    if reverse {
+        ordering.reverse()
+    } else {
+        ordering
+    }
+}
+
 /// Convert CLI args to storage filter.
 fn build_filters(args: &ListArgs) -> Result<ListFilters> {
     // Parse status strings to Status enums
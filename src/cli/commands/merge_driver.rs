@@ -0,0 +1,70 @@
+//! Merge-driver command implementation.
+//!
+//! Invoked by git as `br merge-driver %O %A %B` for `.beads/issues.jsonl`.
+//! Merges by issue and writes the result to `%A`; any error leaves `%A`
+//! untouched and exits non-zero so git reports the file as conflicted.
+
+use crate::cli::MergeDriverArgs;
+use crate::error::{BeadsError, Result};
+use crate::output::OutputContext;
+use crate::sync::ConflictResolution;
+use crate::sync::merge_driver::{merge_jsonl_files, merged_issues, write_merged_jsonl};
+use std::fmt::Write;
+use tracing::{debug, info};
+
+/// Execute the merge-driver command.
+///
+/// # Errors
+///
+/// Returns an error if any version cannot be parsed, the result cannot be
+/// written, or conflicts remain under `--strategy manual`.
+pub fn execute(args: &MergeDriverArgs, ctx: &OutputContext) -> Result<()> {
+    let strategy: ConflictResolution = args.strategy.into();
+    debug!(
+        base = %args.base.display(),
+        local = %args.local.display(),
+        external = %args.external.display(),
+        ?strategy,
+        "Running JSONL merge driver"
+    );
+
+    let report = merge_jsonl_files(&args.base, &args.local, &args.external, strategy)?;
+
+    if report.has_conflicts() {
+        let mut msg = String::from("Unresolved issue conflicts in issues.jsonl:\n");
+        for (id, kind) in &report.conflicts {
+            let _ = writeln!(msg, "  - {id}: {kind:?}");
+        }
+        msg.push_str("Resolve the file by hand, or rerun the merge with a non-manual --strategy.");
+        return Err(BeadsError::Config(msg));
+    }
+
+    let issues = merged_issues(&report);
+    write_merged_jsonl(&args.local, &issues)?;
+    info!(
+        merged = issues.len(),
+        deleted = report.deleted.len(),
+        field_conflicts = report.field_conflicts.len(),
+        "JSONL merge driver completed"
+    );
+
+    if ctx.is_json() {
+        ctx.json_pretty(&serde_json::json!({
+            "status": "success",
+            "merged_issues": issues.len(),
+            "deleted_issues": report.deleted.len(),
+            "field_conflicts": report.field_conflicts.len(),
+            "notes": report.notes,
+        }));
+    } else {
+        // Git prints driver output inline with the merge, so keep it to one line.
+        ctx.info(&format!(
+            "br merge-driver: merged {} issue(s), {} deleted, {} field conflict(s) auto-resolved",
+            issues.len(),
+            report.deleted.len(),
+            report.field_conflicts.len()
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,472 @@
+//! Move command implementation.
+//!
+//! Moves an issue into another routed project. The target gets a fresh ID
+//! under its own prefix along with the labels, comments, audit events and
+//! dependencies. Dependencies in the source that pointed at the issue become
+//! `external:<project>:<new-id>` references (the moved issue gains the matching
+//! `provides:` label), and the source keeps a tombstone whose `moved-to:` label
+//! lets `br show` follow the issue to its new home.
+//!
+//! Each database is written in one transaction, target first. A failure in
+//! the target leaves both projects untouched; a failure in the source leaves
+//! the original issue in place next to its complete copy in the target.
+
+use crate::cli::MoveArgs;
+use crate::config::{self, CliOverrides, routing};
+use crate::error::{BeadsError, Result};
+use crate::model::{Dependency, Event, EventType, Issue, MOVED_TO_LABEL_PREFIX, Status, moved_to};
+use crate::output::OutputContext;
+use crate::storage::SqliteStorage;
+use crate::util::id::{IdGenerator, IdResolver, ResolverConfig};
+use chrono::Utc;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use tracing::debug;
+
+/// Result of a move for JSON output.
+#[derive(Debug, Serialize)]
+pub struct MoveResult {
+    pub from: String,
+    pub to: String,
+    pub project: String,
+    pub dry_run: bool,
+    /// Dependencies carried over to the new issue.
+    pub dependencies: Vec<String>,
+    /// Source issues whose dependency now points at `external:<project>:<new-id>`.
+    pub rewritten_dependents: Vec<String>,
+    pub labels_copied: usize,
+    pub comments_copied: usize,
+    pub events_copied: usize,
+}
+
+/// Where the issue is going and what the two projects are called.
+struct MoveTarget<'a> {
+    new_id: &'a str,
+    source_project: &'a str,
+    target_project: &'a str,
+}
+
+/// Execute the move command.
+///
+/// # Errors
+///
+/// Returns an error if the issue or the target project cannot be found, the
+/// target is the current project, or a database operation fails.
+pub fn execute(args: &MoveArgs, cli: &CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut source_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let source_layer = config::load_config(&beads_dir, Some(&source_ctx.storage), cli)?;
+    let actor = config::resolve_actor(&source_layer);
+    let id_config = config::id_config_from_layer(&source_layer);
+
+    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let source = &mut source_ctx.storage;
+    let id = resolver
+        .resolve(
+            &args.id,
+            |id| source.id_exists(id).unwrap_or(false),
+            |hash| source.find_ids_by_hash(hash).unwrap_or_default(),
+        )?
+        .id;
+    let issue = source
+        .get_issue(&id)?
+        .ok_or_else(|| BeadsError::IssueNotFound { id: id.clone() })?;
+    if issue.status == Status::Tombstone {
+        let labels = source.get_labels(&id)?;
+        let reason = moved_to(&labels).map_or_else(
+            || "issue is deleted".to_string(),
+            |new_id| format!("issue was already moved to {new_id}"),
+        );
+        return Err(BeadsError::validation("id", reason));
+    }
+
+    let target_dir = resolve_target(&beads_dir, &args.to)?;
+    let target_cli = CliOverrides {
+        actor: cli.actor.clone(),
+        lock_timeout: cli.lock_timeout,
+        ..CliOverrides::default()
+    };
+    let mut target_ctx = config::open_storage_with_cli(&target_dir, &target_cli)?;
+    let target_layer = config::load_config(&target_dir, Some(&target_ctx.storage), &target_cli)?;
+    let target = &mut target_ctx.storage;
+
+    let id_gen = IdGenerator::new(config::id_config_from_layer(&target_layer));
+    let new_id = id_gen.generate(
+        &issue.title,
+        issue.description.as_deref(),
+        issue.created_by.as_deref(),
+        Utc::now(),
+        target.count_issues()?,
+        |candidate| target.id_exists(candidate).unwrap_or(false),
+    );
+    let source_project = routing::project_name(&beads_dir);
+    let target_project = routing::project_name(&target_dir);
+    debug!(from = %id, to = %new_id, project = %target_project, "Moving issue");
+
+    let plan = MoveTarget {
+        new_id: &new_id,
+        source_project: &source_project,
+        target_project: &target_project,
+    };
+    let result = move_issue(source, target, &issue, &plan, &actor, args.dry_run)?;
+
+    if !args.dry_run {
+        if !cli.no_auto_flush.unwrap_or(false) && !target_ctx.no_db {
+            crate::sync::auto_flush(&mut target_ctx.storage, &target_dir)?;
+        }
+        target_ctx.flush_no_db_if_dirty()?;
+        source_ctx.flush_no_db_if_dirty()?;
+    }
+
+    if ctx.is_json() {
+        ctx.json_pretty(&result);
+        return Ok(());
+    }
+    if ctx.is_quiet() {
+        return Ok(());
+    }
+    print_result(&result);
+    Ok(())
+}
+
+/// Find the beads directory for `--to`: a route prefix first, then a routed
+/// project's directory name.
+fn resolve_target(beads_dir: &Path, to: &str) -> Result<PathBuf> {
+    let prefix = to.trim_end_matches('-');
+    if prefix.is_empty() {
+        return Err(BeadsError::validation(
+            "to",
+            "target project cannot be empty",
+        ));
+    }
+
+    // Routes are keyed on ID prefixes, so resolve a placeholder ID under it
+    let routed = routing::resolve_route(&format!("{prefix}-0"), beads_dir)?;
+    let target = if routed.is_external {
+        Some(routed.beads_dir)
+    } else {
+        routing::discover_routed_projects(beads_dir)?
+            .into_iter()
+            .find(|project| !project.is_local && project.name == to)
+            .map(|project| project.beads_dir)
+    };
+
+    let target = target.ok_or_else(|| {
+        BeadsError::validation(
+            "to",
+            format!("no route to project '{to}' (check .beads/routes.jsonl)"),
+        )
+    })?;
+    let same = |a: &Path, b: &Path| {
+        a.canonicalize()
+            .ok()
+            .zip(b.canonicalize().ok())
+            .is_some_and(|(a, b)| a == b)
+    };
+    if same(&target, beads_dir) {
+        return Err(BeadsError::validation(
+            "to",
+            format!("'{to}' is the current project"),
+        ));
+    }
+    Ok(target)
+}
+
+/// Copy `issue` into `target` under `plan.new_id`, rewrite inbound
+/// dependencies in `source`, and leave a forwarding tombstone.
+///
+/// With `dry_run`, nothing is written and the result describes the move.
+#[allow(clippy::too_many_lines)]
+fn move_issue(
+    source: &mut SqliteStorage,
+    target: &mut SqliteStorage,
+    issue: &Issue,
+    plan: &MoveTarget<'_>,
+    actor: &str,
+    dry_run: bool,
+) -> Result<MoveResult> {
+    let id = issue.id.as_str();
+    let new_id = plan.new_id;
+
+    let dependents = source.get_dependents_full(id)?;
+    let mut labels = source.get_labels(id)?;
+    labels.retain(|label| !label.starts_with(MOVED_TO_LABEL_PREFIX));
+    if !dependents.is_empty() {
+        // Lets the rewritten `external:` references resolve once it closes
+        labels.push(format!("provides:{new_id}"));
+    }
+    let comments = source.get_comments(id)?;
+    let mut events = source.get_events(id, 0)?;
+    events.reverse();
+
+    // Outbound dependencies on source issues become external references
+    // served by a `provides:` label on the source issue
+    let mut dependencies = Vec::new();
+    let mut providers = Vec::new();
+    for dep in source.get_dependencies_full(id)? {
+        let depends_on_id = if dep.depends_on_id.starts_with("external:")
+            || target.id_exists(&dep.depends_on_id)?
+        {
+            dep.depends_on_id.clone()
+        } else {
+            providers.push(dep.depends_on_id.clone());
+            format!("external:{}:{}", plan.source_project, dep.depends_on_id)
+        };
+        dependencies.push(Dependency {
+            issue_id: new_id.to_string(),
+            depends_on_id,
+            ..dep
+        });
+    }
+
+    let result = MoveResult {
+        from: id.to_string(),
+        to: new_id.to_string(),
+        project: plan.target_project.to_string(),
+        dry_run,
+        dependencies: dependencies
+            .iter()
+            .map(|dep| dep.depends_on_id.clone())
+            .collect(),
+        rewritten_dependents: dependents.iter().map(|dep| dep.issue_id.clone()).collect(),
+        labels_copied: labels.len(),
+        comments_copied: comments.len(),
+        events_copied: events.len(),
+    };
+    if dry_run {
+        return Ok(result);
+    }
+
+    // 1-3. Every target-side write lands in one target transaction
+    target.batch("move", actor, |target| {
+        // 1. Create the issue in the target with its labels and comments
+        let mut moved = Issue {
+            id: new_id.to_string(),
+            labels,
+            comments: comments
+                .into_iter()
+                .map(|comment| crate::model::Comment {
+                    issue_id: new_id.to_string(),
+                    ..comment
+                })
+                .collect(),
+            dependencies: Vec::new(),
+            updated_at: Utc::now(),
+            ..issue.clone()
+        };
+        moved.content_hash = Some(moved.compute_content_hash());
+        target.create_issue(&moved, actor)?;
+
+        // 2. Dependencies go in one at a time so metadata (gates) survives
+        for dep in &dependencies {
+            target.add_dependency_with_metadata(
+                new_id,
+                &dep.depends_on_id,
+                dep.dep_type.as_str(),
+                dep.metadata.as_deref(),
+                actor,
+            )?;
+        }
+
+        // 3. Carry the audit trail over, plus a record of the move
+        events.push(Event {
+            id: 0,
+            issue_id: new_id.to_string(),
+            event_type: EventType::Custom("moved".to_string()),
+            actor: actor.to_string(),
+            old_value: Some(id.to_string()),
+            new_value: Some(new_id.to_string()),
+            comment: Some(format!("Moved from {id} ({})", plan.source_project)),
+            created_at: Utc::now(),
+        });
+        target.append_history(new_id, &events, actor)?;
+        Ok(())
+    })?;
+
+    // 4-6. Every source-side write lands in one source transaction
+    source.batch("move", actor, |source| {
+        // 4. Source issues the moved issue depended on now advertise themselves
+        for provider in &providers {
+            if source.id_exists(provider)? {
+                source.add_label(provider, &format!("provides:{provider}"), actor)?;
+            }
+        }
+
+        // 5. Point inbound dependencies at the new home
+        let external_ref = format!("external:{}:{new_id}", plan.target_project);
+        for dep in &dependents {
+            source.remove_dependency(&dep.issue_id, id, actor)?;
+            source.add_dependency_with_metadata(
+                &dep.issue_id,
+                &external_ref,
+                dep.dep_type.as_str(),
+                dep.metadata.as_deref(),
+                actor,
+            )?;
+        }
+
+        // 6. Leave a forwarding tombstone
+        source.remove_all_dependencies(id, actor)?;
+        source.add_label(id, &format!("{MOVED_TO_LABEL_PREFIX}{new_id}"), actor)?;
+        source.delete_issue(
+            id,
+            actor,
+            &format!("moved to {new_id} ({})", plan.target_project),
+            None,
+        )?;
+        Ok(())
+    })?;
+
+    Ok(result)
+}
+
+fn print_result(result: &MoveResult) {
+    let verb = if result.dry_run {
+        "Would move"
+    } else {
+        "Moved"
+    };
+    println!(
+        "{verb} {} → {} ({})",
+        result.from, result.to, result.project
+    );
+    println!(
+        "  {} label(s), {} comment(s), {} event(s)",
+        result.labels_copied, result.comments_copied, result.events_copied
+    );
+    if !result.dependencies.is_empty() {
+        println!("  Depends on: {}", result.dependencies.join(", "));
+    }
+    if !result.rewritten_dependents.is_empty() {
+        println!(
+            "  Rewrote {} dependent(s) to external:{}:{}: {}",
+            result.rewritten_dependents.len(),
+            result.project,
+            result.to,
+            result.rewritten_dependents.join(", ")
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logging::init_test_logging;
+    use crate::model::{Comment, IssueType, Priority};
+    use tracing::info;
+
+    fn make_issue(id: &str, title: &str) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: title.to_string(),
+            status: Status::Open,
+            priority: Priority::MEDIUM,
+            issue_type: IssueType::Task,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            ..Issue::default()
+        }
+    }
+
+    fn plan() -> MoveTarget<'static> {
+        MoveTarget {
+            new_id: "api-1",
+            source_project: "web",
+            target_project: "api",
+        }
+    }
+
+    #[test]
+    fn test_move_copies_issue_and_leaves_tombstone() {
+        init_test_logging();
+        info!("test_move_copies_issue_and_leaves_tombstone: starting");
+        let mut source = SqliteStorage::open_memory().unwrap();
+        let mut target = SqliteStorage::open_memory().unwrap();
+
+        let issue = make_issue("web-1", "Rate limiter");
+        source.create_issue(&issue, "tester").unwrap();
+        source
+            .create_issue(&make_issue("web-2", "Schema"), "tester")
+            .unwrap();
+        source
+            .create_issue(&make_issue("web-3", "Rollout"), "tester")
+            .unwrap();
+        source.add_label("web-1", "backend", "tester").unwrap();
+        source.add_comment("web-1", "alice", "needs redis").unwrap();
+        source
+            .add_dependency("web-1", "web-2", "blocks", "tester")
+            .unwrap();
+        source
+            .add_dependency("web-3", "web-1", "blocks", "tester")
+            .unwrap();
+
+        let issue = source.get_issue("web-1").unwrap().unwrap();
+        let before = source.undo_stack(usize::MAX, None).unwrap().len();
+        let result =
+            move_issue(&mut source, &mut target, &issue, &plan(), "tester", false).unwrap();
+        assert_eq!(result.rewritten_dependents, vec!["web-3".to_string()]);
+        // Each side's writes are one transaction (one undo batch)
+        let batches = source.undo_stack(usize::MAX, None).unwrap();
+        assert_eq!(batches.len(), before + 1);
+        assert_eq!(batches[0].op, "move");
+        let batches = target.undo_stack(usize::MAX, None).unwrap();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].op, "move");
+
+        let moved = target.get_issue("api-1").unwrap().unwrap();
+        assert_eq!(moved.title, "Rate limiter");
+        let labels = target.get_labels("api-1").unwrap();
+        assert!(labels.contains(&"backend".to_string()));
+        assert!(labels.contains(&"provides:api-1".to_string()));
+        let comments: Vec<Comment> = target.get_comments("api-1").unwrap();
+        assert_eq!(comments[0].body, "needs redis");
+        assert_eq!(comments[0].author, "alice");
+        assert_eq!(
+            target.get_dependencies("api-1").unwrap(),
+            vec!["external:web:web-2".to_string()]
+        );
+        let events = target.get_events("api-1", 0).unwrap();
+        assert!(
+            events
+                .iter()
+                .any(|event| event.event_type == EventType::Custom("moved".to_string()))
+        );
+
+        // Inbound dependency now points across projects
+        assert_eq!(
+            source.get_dependencies("web-3").unwrap(),
+            vec!["external:api:api-1".to_string()]
+        );
+        assert!(
+            source
+                .get_labels("web-2")
+                .unwrap()
+                .contains(&"provides:web-2".to_string())
+        );
+
+        let tombstone = source.get_issue("web-1").unwrap().unwrap();
+        assert_eq!(tombstone.status, Status::Tombstone);
+        assert_eq!(
+            moved_to(&source.get_labels("web-1").unwrap()),
+            Some("api-1")
+        );
+        info!("test_move_copies_issue_and_leaves_tombstone: assertions passed");
+    }
+
+    #[test]
+    fn test_move_dry_run_writes_nothing() {
+        init_test_logging();
+        let mut source = SqliteStorage::open_memory().unwrap();
+        let mut target = SqliteStorage::open_memory().unwrap();
+        let issue = make_issue("web-1", "Rate limiter");
+        source.create_issue(&issue, "tester").unwrap();
+
+        let result = move_issue(&mut source, &mut target, &issue, &plan(), "tester", true).unwrap();
+        assert!(result.dry_run);
+        assert!(target.get_issue("api-1").unwrap().is_none());
+        assert_eq!(
+            source.get_issue("web-1").unwrap().unwrap().status,
+            Status::Open
+        );
+    }
+}
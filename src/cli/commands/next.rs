@@ -0,0 +1,109 @@
+//! Next command implementation.
+//!
+//! `br next` picks the top ready issue and claims it for the actor under a
+//! lease (see `br claim`) in a single transaction, then prints the issue in
+//! full. Agents racing on the same database never receive the same issue.
+
+use crate::cli::commands::ready::{parse_priorities, parse_types, ready_sort_policy};
+use crate::cli::commands::show::print_issue_details;
+use crate::cli::{NextArgs, OutputFormat, resolve_output_format_basic};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::format::IssueDetails;
+use crate::model::Lease;
+use crate::output::{IssuePanel, OutputContext, OutputMode};
+use crate::storage::ReadyFilters;
+use crate::util::time::parse_duration_minutes;
+use chrono::Duration;
+use serde::Serialize;
+use std::collections::HashSet;
+use tracing::info;
+
+/// JSON/TOON output: the claimed issue with its lease.
+#[derive(Serialize)]
+struct ClaimedIssue {
+    #[serde(flatten)]
+    details: IssueDetails,
+    lease: Lease,
+}
+
+/// Execute the next command.
+///
+/// # Errors
+///
+/// Returns an error if filters are invalid or the database query or claim fails.
+pub fn execute(
+    args: &NextArgs,
+    cli: &config::CliOverrides,
+    outer_ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let actor = config::resolve_actor(&layer);
+    let use_color = config::should_use_color(&layer);
+    let output_format = resolve_output_format_basic(args.format, outer_ctx.is_json(), args.robot);
+    let quiet = cli.quiet.unwrap_or(false);
+    let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
+
+    let filters = ReadyFilters {
+        labels_and: args.label.clone(),
+        labels_or: args.label_any.clone(),
+        types: parse_types(&args.type_)?,
+        priorities: parse_priorities(&args.priority)?,
+        ..ReadyFilters::default()
+    };
+    let sort_policy = ready_sort_policy(args.sort);
+    let weights = config::score_weights_from_layer(&layer)?;
+    let ttl = parse_duration_minutes(&args.ttl, "ttl").map(Duration::minutes)?;
+
+    // External blockers live in other databases, so they are resolved up front
+    let external_db_paths = config::external_project_db_paths(&layer, &beads_dir);
+    let storage = &mut storage_ctx.storage;
+    let external_statuses =
+        storage.resolve_external_dependency_statuses(&external_db_paths, true)?;
+    let excluded: HashSet<String> = storage
+        .external_blockers(&external_statuses)?
+        .into_keys()
+        .collect();
+
+    let claimed = storage.claim_next(&filters, sort_policy, &weights, &excluded, &actor, ttl)?;
+    let claimed = match claimed {
+        Some(lease) => {
+            info!(id = %lease.issue_id, actor = %actor, "Claimed next issue");
+            crate::util::set_last_touched_id(&beads_dir, &lease.issue_id);
+            let details = storage
+                .get_issue_details(&lease.issue_id, true, false, 10)?
+                .ok_or_else(|| BeadsError::IssueNotFound {
+                    id: lease.issue_id.clone(),
+                })?;
+            Some(ClaimedIssue { details, lease })
+        }
+        None => None,
+    };
+    storage_ctx.flush_no_db_if_dirty()?;
+
+    if matches!(ctx.mode(), OutputMode::Quiet) {
+        return Ok(());
+    }
+    match output_format {
+        OutputFormat::Json => ctx.json_pretty(&claimed),
+        OutputFormat::Toon => ctx.toon_with_stats(&claimed, args.stats),
+        OutputFormat::Text | OutputFormat::Csv => match &claimed {
+            Some(claimed) => {
+                if matches!(ctx.mode(), OutputMode::Rich) {
+                    IssuePanel::from_details(&claimed.details, ctx.theme()).print(&ctx, args.wrap);
+                } else {
+                    print_issue_details(&claimed.details, use_color);
+                }
+                println!(
+                    "\nClaimed by {} until {} (renew with `br heartbeat`)",
+                    claimed.lease.holder,
+                    claimed.lease.expires_at.format("%Y-%m-%d %H:%M UTC")
+                );
+            }
+            None => println!("No ready issues to claim."),
+        },
+    }
+    Ok(())
+}
@@ -212,6 +212,7 @@ pub fn execute(
                         ids: vec![orphan.issue_id.clone()],
                         reason: Some("Implemented (detected by orphans scan)".to_string()),
                         force: false,
+                        outcome: None,
                         session: None,
                         suggest_next: false,
                     };
@@ -230,7 +231,8 @@ pub fn execute(
 }
 
 /// Check if the current directory is inside a git repository.
-fn is_git_repo() -> bool {
+#[must_use]
+pub fn is_git_repo() -> bool {
     Command::new("git")
         .args(["rev-parse", "--git-dir"])
         .output()
@@ -241,7 +243,11 @@ fn is_git_repo() -> bool {
 ///
 /// Returns Vec of (`commit_hash`, `commit_message`, `issue_id`) tuples.
 /// The list is ordered from most recent to oldest commit.
-fn get_git_commit_refs(prefix: &str) -> Result<Vec<(String, String, String)>> {
+///
+/// # Errors
+///
+/// Returns an error if `git` cannot be spawned or its output cannot be read.
+pub fn get_git_commit_refs(prefix: &str) -> Result<Vec<(String, String, String)>> {
     let mut child = Command::new("git")
         .args(["log", "--oneline", "HEAD"])
         .stdout(Stdio::piped())
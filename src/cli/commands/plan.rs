@@ -0,0 +1,808 @@
+//! Plan command implementation.
+//!
+//! Forecasts when open work lands. Issues are scheduled in dependency order
+//! over `blocks` edges and `parent-child` edges (children finish before their
+//! parent). Each assignee works one issue at a time at their configured hours
+//! per day; unassigned work runs in parallel. Working days run Monday to
+//! Friday from 09:00 UTC for the configured hours per day, so durations and
+//! due dates are measured on the same calendar (no holidays).
+//!
+//! The forward pass gives each issue's earliest start/finish and the critical
+//! path (the chain of dependencies and assignee hand-offs that sets the
+//! projected finish). A backward pass from the projected finish gives the
+//! latest finish and slack, and due dates are pushed back through their
+//! predecessors to flag issues that put a deadline at risk.
+
+use crate::cli::PlanArgs;
+use crate::config::{self, PlanConfig};
+use crate::error::{BeadsError, Result};
+use crate::model::{DependencyType, Issue};
+use crate::output::{OutputContext, OutputMode};
+use crate::storage::{ListFilters, SqliteStorage};
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use crate::util::time::{format_minutes, parse_duration_minutes, parse_flexible_timestamp};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Utc};
+use rich_rust::prelude::*;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use tracing::debug;
+
+/// Slack below this many days counts as zero.
+const EPSILON_DAYS: f64 = 1e-6;
+
+/// Hour (UTC) at which each working day starts.
+const WORKDAY_START_HOUR: u32 = 9;
+
+/// One issue in the schedule.
+#[derive(Debug, Clone, Serialize)]
+struct PlannedIssue {
+    id: String,
+    title: String,
+    status: String,
+    priority: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    assignee: Option<String>,
+    estimate_minutes: i64,
+    /// Where the estimate came from: issue, type, default, or children.
+    estimate_source: &'static str,
+    remaining_minutes: i64,
+    earliest_start: DateTime<Utc>,
+    earliest_finish: DateTime<Utc>,
+    latest_finish: DateTime<Utc>,
+    slack_days: f64,
+    critical: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    due_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    depends_on: Vec<String>,
+}
+
+/// An issue whose projected finish misses a due date.
+#[derive(Debug, Clone, Serialize)]
+struct DueRisk {
+    id: String,
+    title: String,
+    projected_finish: DateTime<Utc>,
+    /// Latest finish that still meets the due date of `deadline_from`.
+    deadline: DateTime<Utc>,
+    deadline_from: String,
+    late_days: f64,
+}
+
+/// JSON output for the plan command.
+#[derive(Debug, Serialize)]
+struct PlanOutput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    root: Option<String>,
+    start: DateTime<Utc>,
+    finish: DateTime<Utc>,
+    working_days: f64,
+    critical_path: Vec<String>,
+    issues: Vec<PlannedIssue>,
+    at_risk: Vec<DueRisk>,
+}
+
+/// Scheduling input for one issue.
+#[derive(Debug, Clone)]
+struct Task {
+    id: String,
+    title: String,
+    status: String,
+    priority: i32,
+    assignee: Option<String>,
+    estimate_minutes: i64,
+    estimate_source: &'static str,
+    remaining_minutes: i64,
+    hours_per_day: f64,
+    due_at: Option<DateTime<Utc>>,
+    preds: Vec<String>,
+}
+
+/// Execute the plan command.
+///
+/// # Errors
+///
+/// Returns an error if database operations fail, inputs are invalid, or the
+/// dependency graph has a cycle.
+pub fn execute(args: &PlanArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let storage = &storage_ctx.storage;
+
+    let layer = config::load_config(&beads_dir, Some(storage), cli)?;
+    let mut plan_config = config::plan_config_from_layer(&layer)?;
+    if let Some(estimate) = &args.default_estimate {
+        plan_config.default_estimate = parse_duration_minutes(estimate, "default-estimate")?;
+    }
+    if let Some(hours) = &args.hours_per_day {
+        plan_config.hours_per_day = config::parse_hours_per_day(hours, "hours-per-day")?;
+    }
+    let start = match &args.start {
+        Some(value) => parse_flexible_timestamp(value, "start")?,
+        None => Utc::now(),
+    };
+
+    let root = match &args.id {
+        Some(input) => {
+            let id_config = config::id_config_from_layer(&layer);
+            let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+            let all_ids = storage.get_all_ids()?;
+            let id = resolver
+                .resolve(
+                    input,
+                    |id| storage.id_exists(id).unwrap_or(false),
+                    |hash| find_matching_ids(&all_ids, hash),
+                )?
+                .id;
+            Some(id)
+        }
+        None => None,
+    };
+
+    let tasks = load_tasks(storage, &plan_config, root.as_deref())?;
+    debug!(count = tasks.len(), root = ?root, "Planning issues");
+    let calendar = Calendar {
+        hours_per_day: plan_config.hours_per_day,
+    };
+    let output = schedule(&tasks, start, calendar, root)?;
+
+    if ctx.is_json() {
+        ctx.json_pretty(&output);
+    } else if matches!(ctx.mode(), OutputMode::Rich) {
+        render_plan_rich(&output, ctx);
+    } else {
+        print_plan_text(&output);
+    }
+    Ok(())
+}
+
+/// Load open issues and their predecessor edges, limited to `root` and
+/// everything it transitively waits on when a root is given.
+fn load_tasks(
+    storage: &SqliteStorage,
+    plan_config: &PlanConfig,
+    root: Option<&str>,
+) -> Result<Vec<Task>> {
+    let filters = ListFilters {
+        include_closed: false,
+        include_deferred: true,
+        include_templates: false,
+        ..Default::default()
+    };
+    let issues: Vec<Issue> = storage
+        .list_issues(&filters)?
+        .into_iter()
+        .filter(|issue| !issue.status.is_terminal())
+        .collect();
+    let open: HashSet<&str> = issues.iter().map(|issue| issue.id.as_str()).collect();
+
+    if let Some(root) = root {
+        if !open.contains(root) {
+            return Err(BeadsError::validation(
+                "id",
+                format!("{root} is not open; nothing to plan"),
+            ));
+        }
+    }
+
+    // Predecessors: blockers of an issue, and the children of a parent.
+    let mut preds: HashMap<String, Vec<String>> = HashMap::new();
+    let mut parents: HashSet<String> = HashSet::new();
+    for issue in &issues {
+        for dep in storage.get_dependencies_full(&issue.id)? {
+            if !open.contains(dep.depends_on_id.as_str()) {
+                continue;
+            }
+            match dep.dep_type {
+                DependencyType::Blocks => preds
+                    .entry(issue.id.clone())
+                    .or_default()
+                    .push(dep.depends_on_id),
+                DependencyType::ParentChild => {
+                    parents.insert(dep.depends_on_id.clone());
+                    preds
+                        .entry(dep.depends_on_id)
+                        .or_default()
+                        .push(issue.id.clone());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let scope: Option<HashSet<String>> = root.map(|root| {
+        let mut seen = HashSet::from([root.to_string()]);
+        let mut stack = vec![root.to_string()];
+        while let Some(id) = stack.pop() {
+            for pred in preds.get(&id).into_iter().flatten() {
+                if seen.insert(pred.clone()) {
+                    stack.push(pred.clone());
+                }
+            }
+        }
+        seen
+    });
+
+    let logged = storage.get_time_rollup()?;
+
+    let tasks = issues
+        .iter()
+        .filter(|issue| scope.as_ref().is_none_or(|scope| scope.contains(&issue.id)))
+        .map(|issue| {
+            let (estimate_minutes, estimate_source) = match issue.estimated_minutes {
+                Some(minutes) => (i64::from(minutes.max(0)), "issue"),
+                // A parent's work is its children unless it has its own estimate
+                None if parents.contains(&issue.id) => (0, "children"),
+                None if plan_config
+                    .type_estimates
+                    .contains_key(issue.issue_type.as_str()) =>
+                {
+                    (plan_config.estimate_for(&issue.issue_type), "type")
+                }
+                None => (plan_config.default_estimate, "default"),
+            };
+            let logged_minutes = logged.get(&issue.id).map_or(0, |t| t.logged_minutes);
+            let mut task_preds = preds.get(&issue.id).cloned().unwrap_or_default();
+            task_preds.sort();
+            task_preds.dedup();
+            Task {
+                id: issue.id.clone(),
+                title: issue.title.clone(),
+                status: issue.status.as_str().to_string(),
+                priority: issue.priority.0,
+                assignee: issue.assignee.clone(),
+                estimate_minutes,
+                estimate_source,
+                remaining_minutes: (estimate_minutes - logged_minutes).max(0),
+                hours_per_day: plan_config.hours_for(issue.assignee.as_deref()),
+                due_at: issue.due_at,
+                preds: task_preds,
+            }
+        })
+        .collect();
+    Ok(tasks)
+}
+
+/// Schedule `tasks` from `start`: forward pass, backward pass, due-date risk.
+#[allow(clippy::too_many_lines)]
+fn schedule(
+    tasks: &[Task],
+    start: DateTime<Utc>,
+    calendar: Calendar,
+    root: Option<String>,
+) -> Result<PlanOutput> {
+    // Schedule offsets are working days from `start` on `calendar`.
+    let origin = calendar.position(start);
+    let date_at = |days: f64| calendar.at(origin + days);
+    let n = tasks.len();
+    let index: HashMap<&str, usize> = tasks
+        .iter()
+        .enumerate()
+        .map(|(i, task)| (task.id.as_str(), i))
+        .collect();
+    let preds: Vec<Vec<usize>> = tasks
+        .iter()
+        .map(|task| {
+            task.preds
+                .iter()
+                .filter_map(|id| index.get(id.as_str()).copied())
+                .collect()
+        })
+        .collect();
+    let mut succs: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for (i, task_preds) in preds.iter().enumerate() {
+        for &p in task_preds {
+            succs[p].push(i);
+        }
+    }
+    let dur: Vec<f64> = tasks
+        .iter()
+        .map(|task| working_days(task.remaining_minutes, task.hours_per_day))
+        .collect();
+
+    // Forward pass: list scheduling in dependency order, highest priority first.
+    let mut indegree: Vec<usize> = preds.iter().map(Vec::len).collect();
+    let mut ready: BTreeSet<(i32, &str, usize)> = (0..n)
+        .filter(|&i| indegree[i] == 0)
+        .map(|i| (tasks[i].priority, tasks[i].id.as_str(), i))
+        .collect();
+    let mut order = Vec::with_capacity(n);
+    let mut es = vec![0.0_f64; n];
+    let mut ef = vec![0.0_f64; n];
+    // What set each issue's start: a predecessor or the assignee's previous issue.
+    let mut driver: Vec<Option<usize>> = vec![None; n];
+    let mut assignee_free: HashMap<&str, (f64, usize)> = HashMap::new();
+    // Successors for the backward pass: dependents plus the assignee's next issue.
+    let mut next: Vec<Vec<usize>> = succs.clone();
+
+    while let Some((_, _, i)) = ready.pop_first() {
+        let mut begin = 0.0_f64;
+        for &p in &preds[i] {
+            if ef[p] > begin {
+                begin = ef[p];
+                driver[i] = Some(p);
+            }
+        }
+        let assignee = tasks[i].assignee.as_deref();
+        if let Some(&(free_at, previous)) = assignee.and_then(|a| assignee_free.get(a)) {
+            next[previous].push(i);
+            if free_at > begin {
+                begin = free_at;
+                driver[i] = Some(previous);
+            }
+        }
+        es[i] = begin;
+        ef[i] = begin + dur[i];
+        if let Some(assignee) = assignee {
+            assignee_free.insert(assignee, (ef[i], i));
+        }
+        order.push(i);
+        for &s in &succs[i] {
+            indegree[s] -= 1;
+            if indegree[s] == 0 {
+                ready.insert((tasks[s].priority, tasks[s].id.as_str(), s));
+            }
+        }
+    }
+
+    if order.len() < n {
+        let scheduled: HashSet<usize> = order.iter().copied().collect();
+        let mut stuck: Vec<&str> = (0..n)
+            .filter(|i| !scheduled.contains(i))
+            .map(|i| tasks[i].id.as_str())
+            .collect();
+        stuck.sort_unstable();
+        return Err(BeadsError::DependencyCycle {
+            path: stuck.join(", "),
+        });
+    }
+
+    // Backward pass: latest finish from the projected finish, and the latest
+    // finish that still meets each downstream due date, following both
+    // dependencies and assignee hand-offs.
+    let finish = ef.iter().copied().fold(0.0_f64, f64::max);
+    let mut lf = vec![finish; n];
+    let mut deadline: Vec<Option<(f64, usize)>> = tasks
+        .iter()
+        .enumerate()
+        .map(|(i, task)| task.due_at.map(|due| (calendar.position(due) - origin, i)))
+        .collect();
+    for &i in order.iter().rev() {
+        for &s in &next[i] {
+            lf[i] = lf[i].min(lf[s] - dur[s]);
+            if let Some((due, from)) = deadline[s] {
+                let needed = due - dur[s];
+                if deadline[i].is_none_or(|(current, _)| needed < current) {
+                    deadline[i] = Some((needed, from));
+                }
+            }
+        }
+    }
+
+    // Critical path: walk the drivers back from the last issue to finish.
+    let mut critical_path = Vec::new();
+    let mut current = order
+        .iter()
+        .copied()
+        .filter(|&i| ef[i] >= finish - EPSILON_DAYS)
+        .last();
+    while let Some(i) = current {
+        critical_path.push(i);
+        current = driver[i];
+    }
+    critical_path.reverse();
+    let critical: HashSet<usize> = critical_path.iter().copied().collect();
+
+    let issues = order
+        .iter()
+        .map(|&i| {
+            let task = &tasks[i];
+            PlannedIssue {
+                id: task.id.clone(),
+                title: task.title.clone(),
+                status: task.status.clone(),
+                priority: task.priority,
+                assignee: task.assignee.clone(),
+                estimate_minutes: task.estimate_minutes,
+                estimate_source: task.estimate_source,
+                remaining_minutes: task.remaining_minutes,
+                earliest_start: date_at(es[i]),
+                earliest_finish: date_at(ef[i]),
+                latest_finish: date_at(lf[i]),
+                slack_days: round_days((lf[i] - ef[i]).max(0.0)),
+                critical: critical.contains(&i),
+                due_at: task.due_at,
+                depends_on: preds[i].iter().map(|&p| tasks[p].id.clone()).collect(),
+            }
+        })
+        .collect();
+
+    let mut at_risk: Vec<DueRisk> = order
+        .iter()
+        .filter_map(|&i| {
+            let (due, from) = deadline[i]?;
+            (ef[i] > due + EPSILON_DAYS).then(|| DueRisk {
+                id: tasks[i].id.clone(),
+                title: tasks[i].title.clone(),
+                projected_finish: date_at(ef[i]),
+                deadline: date_at(due),
+                deadline_from: tasks[from].id.clone(),
+                late_days: round_days(ef[i] - due),
+            })
+        })
+        .collect();
+    at_risk.sort_by(|a, b| {
+        b.late_days
+            .total_cmp(&a.late_days)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+
+    Ok(PlanOutput {
+        root,
+        start,
+        finish: date_at(finish),
+        working_days: round_days(finish),
+        critical_path: critical_path.iter().map(|&i| tasks[i].id.clone()).collect(),
+        issues,
+        at_risk,
+    })
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn working_days(minutes: i64, hours_per_day: f64) -> f64 {
+    minutes as f64 / 60.0 / hours_per_day
+}
+
+/// Working calendar: Monday to Friday, each day starting at
+/// [`WORKDAY_START_HOUR`] UTC and lasting `hours_per_day` hours. Time
+/// outside working hours maps to the nearest working-day boundary.
+#[derive(Debug, Clone, Copy)]
+struct Calendar {
+    hours_per_day: f64,
+}
+
+impl Calendar {
+    /// A Monday all positions are counted from.
+    fn epoch() -> NaiveDate {
+        NaiveDate::from_ymd_opt(1970, 1, 5).unwrap_or_default()
+    }
+
+    /// When the working day on `date` starts.
+    fn day_start(date: NaiveDate) -> DateTime<Utc> {
+        date.and_time(NaiveTime::MIN).and_utc() + Duration::hours(i64::from(WORKDAY_START_HOUR))
+    }
+
+    /// Working days from [`Self::epoch`] to `at`.
+    fn position(self, at: DateTime<Utc>) -> f64 {
+        let date = at.date_naive();
+        let days = (date - Self::epoch()).num_days();
+        // Weekdays before `date`; a weekend counts as the end of Friday.
+        let whole = days.div_euclid(7) * 5 + days.rem_euclid(7).min(5);
+        let fraction = if date.weekday().num_days_from_monday() < 5 {
+            let hours = (at - Self::day_start(date)).num_seconds() as f64 / 3_600.0;
+            (hours / self.hours_per_day).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        whole as f64 + fraction
+    }
+
+    /// The instant `position` working days after [`Self::epoch`].
+    #[allow(clippy::cast_possible_truncation)]
+    fn at(self, position: f64) -> DateTime<Utc> {
+        let whole = position.floor();
+        let fraction = position - whole;
+        let whole = whole as i64;
+        let date = Self::epoch() + Duration::days(whole.div_euclid(5) * 7 + whole.rem_euclid(5));
+        Self::day_start(date)
+            + Duration::seconds((fraction * self.hours_per_day * 3_600.0).round() as i64)
+    }
+}
+
+fn round_days(days: f64) -> f64 {
+    (days * 100.0).round() / 100.0
+}
+
+fn format_day(at: DateTime<Utc>) -> String {
+    at.format("%Y-%m-%d").to_string()
+}
+
+fn plan_heading(output: &PlanOutput) -> String {
+    let count = output.issues.len();
+    let scope = output
+        .root
+        .clone()
+        .unwrap_or_else(|| "all open work".to_string());
+    format!(
+        "Plan for {scope}: {count} issue{}, starting {}",
+        if count == 1 { "" } else { "s" },
+        format_day(output.start)
+    )
+}
+
+fn print_plan_text(output: &PlanOutput) {
+    println!("{}", plan_heading(output));
+    if output.issues.is_empty() {
+        println!("Nothing to plan.");
+        return;
+    }
+    println!(
+        "Projected finish: {} ({:.1} working days)",
+        format_day(output.finish),
+        output.working_days
+    );
+
+    println!("\nCritical path:");
+    for id in &output.critical_path {
+        if let Some(issue) = output.issues.iter().find(|issue| &issue.id == id) {
+            println!(
+                "  {}  {} ({}, done {})",
+                issue.id,
+                issue.title,
+                format_minutes(issue.remaining_minutes),
+                format_day(issue.earliest_finish)
+            );
+        }
+    }
+
+    println!("\nSchedule:");
+    for issue in &output.issues {
+        let marker = if issue.critical { "*" } else { " " };
+        let assignee = issue
+            .assignee
+            .as_ref()
+            .map_or_else(String::new, |a| format!(" @{a}"));
+        let due = issue
+            .due_at
+            .map_or_else(String::new, |due| format!(" due {}", format_day(due)));
+        println!(
+            "{marker} {}  {} -> {}  slack {:.1}d{assignee}{due}  {}",
+            issue.id,
+            format_day(issue.earliest_start),
+            format_day(issue.earliest_finish),
+            issue.slack_days,
+            issue.title
+        );
+    }
+
+    if !output.at_risk.is_empty() {
+        println!("\nDue dates at risk:");
+        for risk in &output.at_risk {
+            println!("  {}  {}", risk.id, risk_summary(risk));
+        }
+    }
+}
+
+fn risk_summary(risk: &DueRisk) -> String {
+    let target = if risk.deadline_from == risk.id {
+        "its due date".to_string()
+    } else {
+        format!("{}'s due date", risk.deadline_from)
+    };
+    format!(
+        "finishes {}, needs {} for {target} ({:.1}d late)",
+        format_day(risk.projected_finish),
+        format_day(risk.deadline),
+        risk.late_days
+    )
+}
+
+// ─────────────────────────────────────────────────────────────
+// Rich Output Rendering
+// ─────────────────────────────────────────────────────────────
+
+fn render_plan_rich(output: &PlanOutput, ctx: &OutputContext) {
+    let theme = ctx.theme();
+    if output.issues.is_empty() {
+        let panel = Panel::from_text("Nothing to plan.")
+            .title(Text::styled(
+                &plan_heading(output),
+                theme.panel_title.clone(),
+            ))
+            .box_style(theme.box_style)
+            .border_style(theme.panel_border.clone());
+        ctx.render(&panel);
+        return;
+    }
+
+    let mut summary = Text::new("");
+    summary.append_styled("Projected finish: ", theme.dimmed.clone());
+    summary.append_styled(&format_day(output.finish), theme.emphasis.clone());
+    summary.append_styled(
+        &format!(" ({:.1} working days)", output.working_days),
+        theme.dimmed.clone(),
+    );
+    ctx.render(&summary);
+
+    let mut table = Table::new()
+        .box_style(theme.box_style)
+        .border_style(theme.panel_border.clone())
+        .title(Text::styled(
+            &plan_heading(output),
+            theme.panel_title.clone(),
+        ))
+        .with_column(Column::new("ID").min_width(8).max_width(20))
+        .with_column(Column::new("Title").min_width(12).max_width(40))
+        .with_column(Column::new("Who").min_width(4).max_width(16))
+        .with_column(Column::new("Left").min_width(4).max_width(8))
+        .with_column(Column::new("Start").min_width(10).max_width(10))
+        .with_column(Column::new("Finish").min_width(10).max_width(10))
+        .with_column(Column::new("Slack").min_width(5).max_width(8))
+        .with_column(Column::new("Due").min_width(10).max_width(10));
+
+    for issue in &output.issues {
+        let id_style = if issue.critical {
+            theme.error.clone()
+        } else {
+            theme.issue_id.clone()
+        };
+        let late = output.at_risk.iter().any(|risk| risk.id == issue.id);
+        let due = issue.due_at.map(format_day).unwrap_or_default();
+        table.add_row(Row::new(vec![
+            Cell::new(Text::styled(&issue.id, id_style)),
+            Cell::new(Text::new(issue.title.clone())),
+            Cell::new(Text::styled(
+                issue.assignee.as_deref().unwrap_or("-"),
+                theme.username.clone(),
+            )),
+            Cell::new(Text::new(format_minutes(issue.remaining_minutes))),
+            Cell::new(Text::styled(
+                &format_day(issue.earliest_start),
+                theme.timestamp.clone(),
+            )),
+            Cell::new(Text::styled(
+                &format_day(issue.earliest_finish),
+                theme.timestamp.clone(),
+            )),
+            Cell::new(Text::new(format!("{:.1}d", issue.slack_days))),
+            Cell::new(Text::styled(
+                &due,
+                if late {
+                    theme.warning.clone()
+                } else {
+                    theme.dimmed.clone()
+                },
+            )),
+        ]));
+    }
+    ctx.render(&table);
+
+    let mut path = Text::new("");
+    path.append_styled("Critical path: ", theme.section.clone());
+    path.append_styled(&output.critical_path.join(" → "), theme.error.clone());
+    ctx.render(&path);
+
+    if !output.at_risk.is_empty() {
+        let mut risks = Text::new("");
+        risks.append_styled("Due dates at risk:", theme.warning.clone());
+        for risk in &output.at_risk {
+            risks.append_styled(&format!("\n  {}  ", risk.id), theme.issue_id.clone());
+            risks.append_styled(&risk_summary(risk), theme.warning.clone());
+        }
+        ctx.render(&risks);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn task(id: &str, minutes: i64, assignee: Option<&str>, preds: &[&str]) -> Task {
+        Task {
+            id: id.to_string(),
+            title: id.to_string(),
+            status: "open".to_string(),
+            priority: 2,
+            assignee: assignee.map(str::to_string),
+            estimate_minutes: minutes,
+            estimate_source: "issue",
+            remaining_minutes: minutes,
+            hours_per_day: 8.0,
+            due_at: None,
+            preds: preds.iter().map(|p| (*p).to_string()).collect(),
+        }
+    }
+
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 5, 9, 0, 0).unwrap()
+    }
+
+    const CALENDAR: Calendar = Calendar { hours_per_day: 8.0 };
+
+    fn planned<'a>(output: &'a PlanOutput, id: &str) -> &'a PlannedIssue {
+        output.issues.iter().find(|issue| issue.id == id).unwrap()
+    }
+
+    #[test]
+    fn test_schedule_critical_path_and_slack() {
+        // a (1d) -> c (1d); b (0.5d) -> c; epic waits on c
+        let tasks = vec![
+            task("a", 480, None, &[]),
+            task("b", 240, None, &[]),
+            task("c", 480, None, &["a", "b"]),
+            task("epic", 0, None, &["c"]),
+        ];
+        let output = schedule(&tasks, start(), CALENDAR, Some("epic".to_string())).unwrap();
+
+        assert!((output.working_days - 2.0).abs() < f64::EPSILON);
+        assert_eq!(output.critical_path, vec!["a", "c", "epic"]);
+        assert!((planned(&output, "b").slack_days - 0.5).abs() < f64::EPSILON);
+        assert!(planned(&output, "a").critical);
+        assert!(!planned(&output, "b").critical);
+        assert_eq!(
+            planned(&output, "c").earliest_start,
+            start() + Duration::days(1)
+        );
+    }
+
+    #[test]
+    fn test_schedule_serializes_assignee_work() {
+        let tasks = vec![
+            task("a", 480, Some("alice"), &[]),
+            task("b", 480, Some("alice"), &[]),
+        ];
+        let output = schedule(&tasks, start(), CALENDAR, None).unwrap();
+        assert!((output.working_days - 2.0).abs() < f64::EPSILON);
+        assert_eq!(output.critical_path, vec!["a", "b"]);
+        assert!(output.issues.iter().all(|issue| issue.critical));
+        assert!(
+            output
+                .issues
+                .iter()
+                .all(|issue| issue.slack_days.abs() < f64::EPSILON)
+        );
+
+        // A due date on b puts a at risk too: alice cannot start b before a
+        let mut due = tasks;
+        due[1].due_at = Some(start() + Duration::days(1));
+        let output = schedule(&due, start(), CALENDAR, None).unwrap();
+        let ids: Vec<&str> = output.at_risk.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "b"]);
+        assert!(output.at_risk.iter().all(|r| r.deadline_from == "b"));
+    }
+
+    #[test]
+    fn test_schedule_flags_due_date_risk_upstream() {
+        let mut late = task("ship", 480, None, &["build"]);
+        late.due_at = Some(start() + Duration::days(1));
+        let tasks = vec![task("build", 480, None, &[]), late];
+        let output = schedule(&tasks, start(), CALENDAR, None).unwrap();
+
+        let ids: Vec<&str> = output.at_risk.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["build", "ship"]);
+        assert!(output.at_risk.iter().all(|r| r.deadline_from == "ship"));
+        assert!((output.at_risk[0].late_days - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_schedule_reports_cycles() {
+        let tasks = vec![task("a", 60, None, &["b"]), task("b", 60, None, &["a"])];
+        let err = schedule(&tasks, start(), CALENDAR, None).unwrap_err();
+        assert!(matches!(err, BeadsError::DependencyCycle { .. }));
+    }
+
+    #[test]
+    fn test_schedule_skips_weekends_and_uses_working_hours() {
+        // Friday 09:00 plus 1.5 working days of 6 hours ends Monday 12:00
+        let friday = Utc.with_ymd_and_hms(2026, 1, 9, 9, 0, 0).unwrap();
+        let calendar = Calendar { hours_per_day: 6.0 };
+        let mut ship = task("ship", 540, None, &[]);
+        ship.hours_per_day = 6.0;
+        // Due Saturday counts as the end of Friday, i.e. Monday 09:00
+        ship.due_at = Some(Utc.with_ymd_and_hms(2026, 1, 10, 12, 0, 0).unwrap());
+        let output = schedule(&[ship], friday, calendar, None).unwrap();
+
+        assert_eq!(
+            output.finish,
+            Utc.with_ymd_and_hms(2026, 1, 12, 12, 0, 0).unwrap()
+        );
+        assert!((output.working_days - 1.5).abs() < f64::EPSILON);
+        assert_eq!(output.at_risk.len(), 1);
+        assert!((output.at_risk[0].late_days - 0.5).abs() < f64::EPSILON);
+        assert_eq!(
+            output.at_risk[0].deadline,
+            Utc.with_ymd_and_hms(2026, 1, 12, 9, 0, 0).unwrap()
+        );
+    }
+}
@@ -101,6 +101,8 @@ pub fn execute(args: QuickArgs, cli: &config::CliOverrides, ctx: &OutputContext)
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        recurrence: None,
+        worklog: vec![],
     };
 
     // Resolve actor and set created_by
@@ -133,6 +133,8 @@ impl SavedFilters {
             // Output-related fields use defaults
             long: false,
             pretty: false,
+            town: false,
+            as_of: None,
             wrap: false,
             format: None,
             stats: false,
@@ -194,6 +196,8 @@ impl SavedFilters {
             // Output fields from CLI only
             long: cli.long,
             pretty: cli.pretty,
+            town: cli.town,
+            as_of: cli.as_of.clone(),
             wrap: cli.wrap,
             format: cli.format,
             stats: cli.stats,
@@ -5,11 +5,19 @@
 use crate::cli::{OutputFormat, ReadyArgs, SortPolicy, resolve_output_format_basic};
 use crate::config;
 use crate::error::Result;
-use crate::format::{ReadyIssue, format_priority_badge, terminal_width, truncate_title};
-use crate::model::{IssueType, Priority};
+use crate::format::{
+    ProjectScoped, ReadyIssue, describe_blocker_rule, format_priority_badge, terminal_width,
+    truncate_title,
+};
+use crate::model::{Issue, IssueType, Priority};
 use crate::output::{IssueTable, IssueTableColumns, OutputContext, OutputMode};
-use crate::storage::{ReadyFilters, ReadySortPolicy};
+use crate::storage::score::sort_by_score;
+use crate::storage::{ReadyFilters, ReadySortPolicy, ScoreBreakdown, SqliteStorage};
+use chrono::Utc;
+use serde::Serialize;
+use std::collections::HashMap;
 use std::io::IsTerminal;
+use std::path::PathBuf;
 use std::str::FromStr;
 use tracing::{debug, info, trace};
 use unicode_width::UnicodeWidthStr;
@@ -27,7 +35,7 @@ pub fn execute(
 ) -> Result<()> {
     // Open storage
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
-    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let storage_ctx = config::open_storage_as_of(&beads_dir, cli, args.as_of.as_deref())?;
     let storage = &storage_ctx.storage;
 
     let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
@@ -54,23 +62,49 @@ pub fn execute(
         limit: None,
     };
 
-    let sort_policy = match args.sort {
-        SortPolicy::Hybrid => ReadySortPolicy::Hybrid,
-        SortPolicy::Priority => ReadySortPolicy::Priority,
-        SortPolicy::Oldest => ReadySortPolicy::Oldest,
+    let sort_policy = ready_sort_policy(args.sort);
+    // Scores rank issues under --sort score and are reported by --explain
+    let weights = if sort_policy == ReadySortPolicy::Score || args.explain {
+        Some(config::score_weights_from_layer(&config_layer)?)
+    } else {
+        None
     };
+    let now = Utc::now();
+    let mut scores = HashMap::new();
 
     info!("Fetching ready issues");
     debug!(filters = ?filters, sort = ?sort_policy, "Applied ready filters");
 
-    // Get ready issues from storage (blocked cache only)
-    let mut ready_issues = storage.get_ready_issues(&filters, sort_policy)?;
-
-    let external_statuses =
-        storage.resolve_external_dependency_statuses(&external_db_paths, true)?;
-    let external_blockers = storage.external_blockers(&external_statuses)?;
-    if !external_blockers.is_empty() {
-        ready_issues.retain(|issue| !external_blockers.contains_key(&issue.id));
+    // With --town, each routed project is queried and the rows merged in policy order
+    let (mut ready_issues, projects) = if args.town {
+        let mut rows = Vec::new();
+        let mut projects = HashMap::new();
+        for project in config::open_town_projects(&beads_dir, cli)? {
+            let issues = collect_ready(
+                &project.storage,
+                &filters,
+                sort_policy,
+                &project.external_db_paths,
+            )?;
+            if let Some(weights) = &weights {
+                scores.extend(project.storage.score_issues(&issues, weights, now)?);
+            }
+            for issue in issues {
+                projects.insert(issue.id.clone(), project.name.clone());
+                rows.push(issue);
+            }
+        }
+        rows.sort_by(|a, b| sort_policy.compare(a, b));
+        (rows, Some(projects))
+    } else {
+        let issues = collect_ready(storage, &filters, sort_policy, &external_db_paths)?;
+        if let Some(weights) = &weights {
+            scores = storage.score_issues(&issues, weights, now)?;
+        }
+        (issues, None)
+    };
+    if sort_policy == ReadySortPolicy::Score {
+        sort_by_score(&mut ready_issues, &scores);
     }
 
     // Apply limit after external filtering
@@ -83,18 +117,42 @@ pub fn execute(
         trace!(id = %issue.id, priority = issue.priority.0, "Ready issue");
     }
 
+    let held = if args.explain {
+        held_issues(storage)?
+    } else {
+        Vec::new()
+    };
+
     // Output
     if matches!(ctx.mode(), OutputMode::Quiet) {
         return Ok(());
     }
     match output_format {
         OutputFormat::Json => {
-            let ready_output: Vec<ReadyIssue> = ready_issues.iter().map(ReadyIssue::from).collect();
-            ctx.json_pretty(&ready_output);
+            if let Some(projects) = &projects {
+                ctx.json_pretty(&project_scoped(&ready_issues, projects));
+                return Ok(());
+            }
+            if args.explain {
+                ctx.json_pretty(&explained(&ready_issues, &scores, held));
+            } else {
+                let ready_output: Vec<ReadyIssue> =
+                    ready_issues.iter().map(ReadyIssue::from).collect();
+                ctx.json_pretty(&ready_output);
+            }
         }
         OutputFormat::Toon => {
-            let ready_output: Vec<ReadyIssue> = ready_issues.iter().map(ReadyIssue::from).collect();
-            ctx.toon_with_stats(&ready_output, args.stats);
+            if let Some(projects) = &projects {
+                ctx.toon_with_stats(&project_scoped(&ready_issues, projects), args.stats);
+                return Ok(());
+            }
+            if args.explain {
+                ctx.toon_with_stats(&explained(&ready_issues, &scores, held), args.stats);
+            } else {
+                let ready_output: Vec<ReadyIssue> =
+                    ready_issues.iter().map(ReadyIssue::from).collect();
+                ctx.toon_with_stats(&ready_output, args.stats);
+            }
         }
         OutputFormat::Text | OutputFormat::Csv => {
             if ready_issues.is_empty() {
@@ -117,6 +175,9 @@ pub fn execute(
                         if ready_issues.len() == 1 { "" } else { "s" }
                     ))
                     .wrap(args.wrap);
+                if let Some(projects) = &projects {
+                    table = table.projects(projects.clone());
+                }
                 if args.wrap {
                     table = table.width(Some(ctx.width()));
                 }
@@ -131,15 +192,154 @@ pub fn execute(
                 );
                 for (i, issue) in ready_issues.iter().enumerate() {
                     let line = format_ready_line(i + 1, issue, use_color, max_width, args.wrap);
-                    println!("{line}");
+                    match projects.as_ref().and_then(|p| p.get(&issue.id)) {
+                        Some(project) => println!("[{project}] {line}"),
+                        None => println!("{line}"),
+                    }
+                    if args.explain {
+                        if let Some(score) = scores.get(&issue.id) {
+                            println!("   {}", format_score(score));
+                        }
+                    }
                 }
             }
+            if !matches!(output_format, OutputFormat::Csv) {
+                print_held(&held);
+            }
         }
     }
 
     Ok(())
 }
 
+/// Ready issues from one database, minus those held by unresolved `external:` blockers.
+fn collect_ready(
+    storage: &SqliteStorage,
+    filters: &ReadyFilters,
+    sort_policy: ReadySortPolicy,
+    external_db_paths: &HashMap<String, PathBuf>,
+) -> Result<Vec<Issue>> {
+    // Get ready issues from storage (blocked cache only)
+    let mut ready_issues = storage.get_ready_issues(filters, sort_policy)?;
+
+    let external_statuses =
+        storage.resolve_external_dependency_statuses(external_db_paths, true)?;
+    let external_blockers = storage.external_blockers(&external_statuses)?;
+    if !external_blockers.is_empty() {
+        ready_issues.retain(|issue| !external_blockers.contains_key(&issue.id));
+    }
+    Ok(ready_issues)
+}
+
+/// Tag each ready issue with the project it came from (`--town`).
+fn project_scoped(
+    issues: &[Issue],
+    projects: &HashMap<String, String>,
+) -> Vec<ProjectScoped<ReadyIssue>> {
+    issues
+        .iter()
+        .map(|issue| ProjectScoped {
+            project: projects.get(&issue.id).cloned().unwrap_or_default(),
+            item: ReadyIssue::from(issue),
+        })
+        .collect()
+}
+
+/// Ready output with score breakdowns and the issues held back by
+/// dependency rules (`--explain`).
+#[derive(Serialize)]
+struct ExplainedReady {
+    ready: Vec<ScoredReadyIssue>,
+    held: Vec<HeldIssue>,
+}
+
+#[derive(Serialize)]
+struct ScoredReadyIssue {
+    #[serde(flatten)]
+    issue: ReadyIssue,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    score: Option<ScoreBreakdown>,
+}
+
+fn explained(
+    issues: &[Issue],
+    scores: &HashMap<String, ScoreBreakdown>,
+    held: Vec<HeldIssue>,
+) -> ExplainedReady {
+    ExplainedReady {
+        ready: issues
+            .iter()
+            .map(|issue| ScoredReadyIssue {
+                issue: ReadyIssue::from(issue),
+                score: scores.get(&issue.id).cloned(),
+            })
+            .collect(),
+        held,
+    }
+}
+
+/// One-line score breakdown, listing only the factors that contributed.
+fn format_score(score: &ScoreBreakdown) -> String {
+    let factors = [
+        ("priority", score.priority),
+        ("age", score.age),
+        ("due", score.due),
+        ("unblocks", score.unblocks),
+        ("labels", score.labels),
+        ("staleness", score.staleness),
+    ];
+    let parts: Vec<String> = factors
+        .iter()
+        .filter(|(_, value)| value.abs() >= 0.001)
+        .map(|(name, value)| format!("{name} {value:.2}"))
+        .collect();
+    format!("score {:.2}: {}", score.total, parts.join(", "))
+}
+
+/// An open issue held back by a conditional-blocks or waits-for rule.
+#[derive(Serialize)]
+struct HeldIssue {
+    id: String,
+    title: String,
+    rules: Vec<String>,
+}
+
+fn held_issues(storage: &SqliteStorage) -> Result<Vec<HeldIssue>> {
+    let held = storage
+        .get_blocked_issues()?
+        .into_iter()
+        .filter_map(|(issue, blocked_by)| {
+            let rules: Vec<String> = blocked_by
+                .iter()
+                .filter_map(|blocker_ref| describe_blocker_rule(blocker_ref))
+                .collect();
+            if rules.is_empty() {
+                None
+            } else {
+                Some(HeldIssue {
+                    id: issue.id,
+                    title: issue.title,
+                    rules,
+                })
+            }
+        })
+        .collect();
+    Ok(held)
+}
+
+fn print_held(held: &[HeldIssue]) {
+    if held.is_empty() {
+        return;
+    }
+    println!("\nHeld by dependency rules ({}):", held.len());
+    for issue in held {
+        println!("  {}: {}", issue.id, issue.title);
+        for rule in &issue.rules {
+            println!("    - {rule}");
+        }
+    }
+}
+
 fn format_ready_line(
     index: usize,
     issue: &crate::model::Issue,
@@ -175,7 +375,7 @@ fn format_ready_line(
 }
 
 /// Parse type filter strings to `IssueType` enums.
-fn parse_types(types: &[String]) -> Result<Option<Vec<IssueType>>> {
+pub(crate) fn parse_types(types: &[String]) -> Result<Option<Vec<IssueType>>> {
     if types.is_empty() {
         return Ok(None);
     }
@@ -188,8 +388,18 @@ fn parse_types(types: &[String]) -> Result<Option<Vec<IssueType>>> {
     Ok(Some(parsed))
 }
 
+/// Storage sort policy for a `--sort` value.
+pub(crate) const fn ready_sort_policy(policy: SortPolicy) -> ReadySortPolicy {
+    match policy {
+        SortPolicy::Hybrid => ReadySortPolicy::Hybrid,
+        SortPolicy::Priority => ReadySortPolicy::Priority,
+        SortPolicy::Oldest => ReadySortPolicy::Oldest,
+        SortPolicy::Score => ReadySortPolicy::Score,
+    }
+}
+
 /// Parse priority filter strings to Priority values.
-fn parse_priorities(priorities: &[String]) -> Result<Option<Vec<Priority>>> {
+pub(crate) fn parse_priorities(priorities: &[String]) -> Result<Option<Vec<Priority>>> {
     if priorities.is_empty() {
         return Ok(None);
     }
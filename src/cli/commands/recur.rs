@@ -0,0 +1,463 @@
+//! Recur command implementation.
+//!
+//! A recurrence rule is attached to the latest instance of a series (stored in
+//! the `recurrence_rules` table and exported as that issue's `recurrence`
+//! field in JSONL). `br recur run` creates the next
+//! instance when the current one is closed or its scheduled time has passed:
+//! the copy gets a fresh ID, keeps title, description, type, priority, labels,
+//! assignee and owner, is due at the scheduled occurrence, and depends on its
+//! predecessor via a `recurs-from` link. The rule then moves to the new
+//! instance, in the same transaction that creates it.
+
+use super::create::{CreateConfig, create_issue_impl};
+use crate::cli::{CreateArgs, RecurClearArgs, RecurCommands, RecurRunArgs, RecurSetArgs};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::Status;
+use crate::output::OutputContext;
+use crate::storage::SqliteStorage;
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use crate::util::recurrence::{RecurrenceRule, latest_occurrence, truncate_to_minute};
+use crate::util::time::parse_flexible_timestamp;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tracing::{debug, info};
+
+/// Dependency type linking a generated instance to the one it recurs from.
+pub const RECURS_FROM_DEP: &str = "recurs-from";
+
+/// JSON output for `br recur list`.
+#[derive(Serialize)]
+struct RecurrenceItem {
+    issue_id: String,
+    title: String,
+    status: String,
+    rule: String,
+    next_at: Option<DateTime<Utc>>,
+}
+
+/// One instance created (or planned, with `--dry-run`) by `br recur run`.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecurrenceRun {
+    /// The instance the new one recurs from.
+    pub from: String,
+    /// ID of the new instance (empty for dry runs).
+    pub id: String,
+    pub title: String,
+    /// Scheduled occurrence; becomes the new instance's due date.
+    pub due_at: DateTime<Utc>,
+    /// When the instance after this one is due.
+    pub next_at: Option<DateTime<Utc>>,
+}
+
+/// Execute the recur command.
+///
+/// # Errors
+///
+/// Returns an error if database operations fail or if inputs are invalid.
+pub fn execute(
+    command: &RecurCommands,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let id_config = config::id_config_from_layer(&layer);
+    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix.clone()));
+    let actor = config::resolve_actor(&layer);
+
+    match command {
+        RecurCommands::Set(args) => {
+            recur_set(args, &mut storage_ctx.storage, &resolver, &actor, ctx)?;
+        }
+        RecurCommands::List => recur_list(&storage_ctx.storage, ctx)?,
+        RecurCommands::Clear(args) => {
+            recur_clear(args, &mut storage_ctx.storage, &resolver, &actor, ctx)?;
+        }
+        RecurCommands::Run(args) => {
+            let config = CreateConfig {
+                id_config,
+                default_priority: config::default_priority_from_layer(&layer)?,
+                default_issue_type: config::default_issue_type_from_layer(&layer)?,
+                actor,
+            };
+            recur_run(args, &mut storage_ctx.storage, &config, ctx)?;
+        }
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
+
+fn recur_set(
+    args: &RecurSetArgs,
+    storage: &mut SqliteStorage,
+    resolver: &IdResolver,
+    actor: &str,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let id = resolve_issue_id(storage, resolver, &args.id)?;
+    let issue = storage
+        .get_issue(&id)?
+        .ok_or_else(|| BeadsError::IssueNotFound { id: id.clone() })?;
+    let rule: RecurrenceRule = args.rule.parse()?;
+
+    // Monthly and yearly rules keep the day of the series' first date
+    let (rule, next_at) = match &args.start {
+        Some(start) => {
+            let start = parse_flexible_timestamp(start, "start")?;
+            (rule.anchored(start), Some(start))
+        }
+        None => {
+            let base = issue
+                .due_at
+                .unwrap_or_else(|| truncate_to_minute(Utc::now()));
+            let rule = rule.anchored(base);
+            let next_at = rule.next_after(base);
+            (rule, next_at)
+        }
+    };
+    let canonical = rule.to_string();
+    storage.set_recurrence(&id, &canonical, next_at, actor)?;
+    info!(id = %id, rule = %canonical, "Recurrence rule set");
+
+    if ctx.is_json() {
+        ctx.json_pretty(&serde_json::json!({
+            "issue_id": id,
+            "rule": canonical,
+            "next_at": next_at,
+        }));
+    } else {
+        ctx.success(&format!("{id} recurs {canonical}"));
+        match next_at {
+            Some(next) => ctx.print(&format!("Next instance due {}", next.to_rfc3339())),
+            None => ctx.print("The rule never matches; no instance will be created."),
+        }
+    }
+
+    Ok(())
+}
+
+fn recur_list(storage: &SqliteStorage, ctx: &OutputContext) -> Result<()> {
+    let mut items = Vec::new();
+    for record in storage.list_recurrences()? {
+        let Some(issue) = storage.get_issue(&record.issue_id)? else {
+            continue;
+        };
+        items.push(RecurrenceItem {
+            issue_id: record.issue_id,
+            title: issue.title,
+            status: issue.status.as_str().to_string(),
+            rule: record.rule,
+            next_at: record.next_at,
+        });
+    }
+
+    if ctx.is_json() {
+        ctx.json_pretty(&items);
+    } else if items.is_empty() {
+        println!("No recurring issues. Add a rule with `br recur set <id> <rule>`.");
+    } else {
+        for item in &items {
+            let next = item
+                .next_at
+                .map_or_else(|| "never".to_string(), |next| next.to_rfc3339());
+            println!("{}  [{}] {}", item.issue_id, item.status, item.title);
+            println!("    {}  next: {next}", item.rule);
+        }
+        println!("\n{} recurring issue(s)", items.len());
+    }
+
+    Ok(())
+}
+
+fn recur_clear(
+    args: &RecurClearArgs,
+    storage: &mut SqliteStorage,
+    resolver: &IdResolver,
+    actor: &str,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let id = resolve_issue_id(storage, resolver, &args.id)?;
+    if !storage.remove_recurrence(&id, actor)? {
+        return Err(BeadsError::validation(
+            "id",
+            format!("{id} has no recurrence rule"),
+        ));
+    }
+    info!(id = %id, "Recurrence rule cleared");
+
+    if ctx.is_json() {
+        ctx.json_pretty(&serde_json::json!({ "issue_id": id, "cleared": true }));
+    } else {
+        ctx.success(&format!("{id} no longer recurs"));
+    }
+
+    Ok(())
+}
+
+fn recur_run(
+    args: &RecurRunArgs,
+    storage: &mut SqliteStorage,
+    config: &CreateConfig,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let runs = run_recurrences(storage, config, Utc::now(), args.dry_run)?;
+
+    if ctx.is_json() {
+        ctx.json_pretty(&runs);
+    } else if runs.is_empty() {
+        println!("No recurring issues are due.");
+    } else {
+        for run in &runs {
+            let due = run.due_at.to_rfc3339();
+            if args.dry_run {
+                println!("Would create next instance of {} (due {due})", run.from);
+            } else {
+                println!(
+                    "Created {} from {} (due {due}): {}",
+                    run.id, run.from, run.title
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Create the next instance of every series whose current instance is closed
+/// or whose scheduled time is at or before `now`.
+///
+/// Missed occurrences are skipped: the new instance is due at the latest
+/// occurrence that has already passed (or the upcoming one, when the current
+/// instance was closed early). With `dry_run`, nothing is written.
+///
+/// # Errors
+///
+/// Returns an error if a stored rule is invalid or an issue or dependency
+/// cannot be created. Each instance is written in its own transaction, so
+/// instances created before the failure are kept.
+pub fn run_recurrences(
+    storage: &mut SqliteStorage,
+    config: &CreateConfig,
+    now: DateTime<Utc>,
+    dry_run: bool,
+) -> Result<Vec<RecurrenceRun>> {
+    let mut runs = Vec::new();
+
+    for record in storage.list_recurrences()? {
+        let Some(scheduled) = record.next_at else {
+            continue;
+        };
+        let Some(current) = storage.get_issue(&record.issue_id)? else {
+            continue;
+        };
+        if current.status == Status::Tombstone {
+            debug!(id = %current.id, "Skipping recurrence of deleted issue");
+            continue;
+        }
+        if current.status != Status::Closed && scheduled > now {
+            continue;
+        }
+
+        let rule: RecurrenceRule = record.rule.parse()?;
+        let due_at = latest_occurrence(&rule, scheduled, now);
+        let next_at = rule.next_after(due_at);
+
+        if dry_run {
+            runs.push(RecurrenceRun {
+                from: current.id.clone(),
+                id: String::new(),
+                title: current.title.clone(),
+                due_at,
+                next_at,
+            });
+            continue;
+        }
+
+        let create_args = CreateArgs {
+            title: Some(current.title.clone()),
+            type_: Some(current.issue_type.as_str().to_string()),
+            priority: Some(current.priority.0.to_string()),
+            description: current.description.clone(),
+            assignee: current.assignee.clone(),
+            owner: current.owner.clone(),
+            labels: storage.get_labels(&current.id)?,
+            estimate: current.estimated_minutes,
+            // Build the issue without writing it, then set the fields
+            // `CreateArgs` has no flag for.
+            dry_run: true,
+            ..CreateArgs::default()
+        };
+        let mut issue = create_issue_impl(storage, &create_args, config)?;
+        issue.due_at = Some(due_at);
+        issue.design.clone_from(&current.design);
+        issue
+            .acceptance_criteria
+            .clone_from(&current.acceptance_criteria);
+        issue.content_hash = Some(issue.compute_content_hash());
+        // One transaction: the rule only moves once the instance and its
+        // link exist, so a failure cannot fork or lose the series
+        storage.batch("recur_run", &config.actor, |storage| {
+            storage.create_issue(&issue, &config.actor)?;
+            storage.add_dependency(&issue.id, &current.id, RECURS_FROM_DEP, &config.actor)?;
+            storage.advance_recurrence(&current.id, &issue.id, next_at, &config.actor)
+        })?;
+
+        info!(from = %current.id, id = %issue.id, due = %due_at, "Recurring instance created");
+        runs.push(RecurrenceRun {
+            from: current.id,
+            id: issue.id,
+            title: issue.title,
+            due_at,
+            next_at,
+        });
+    }
+
+    Ok(runs)
+}
+
+fn resolve_issue_id(storage: &SqliteStorage, resolver: &IdResolver, input: &str) -> Result<String> {
+    let all_ids = storage.get_all_ids()?;
+    resolver
+        .resolve(
+            input,
+            |id| storage.id_exists(id).unwrap_or(false),
+            |hash| find_matching_ids(&all_ids, hash),
+        )
+        .map(|resolved| resolved.id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{IssueType, Priority};
+    use crate::storage::IssueUpdate;
+    use crate::util::id::IdConfig;
+    use chrono::{Duration, TimeZone};
+
+    fn config() -> CreateConfig {
+        CreateConfig {
+            id_config: IdConfig {
+                prefix: "bd".to_string(),
+                min_hash_length: 3,
+                max_hash_length: 8,
+                max_collision_prob: 0.25,
+            },
+            default_priority: Priority::MEDIUM,
+            default_issue_type: IssueType::Task,
+            actor: "tester".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_run_creates_next_instance_when_due_or_closed() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let args = CreateArgs {
+            title: Some("Dependency audit".to_string()),
+            assignee: Some("alice".to_string()),
+            labels: vec!["chore".to_string()],
+            ..CreateArgs::default()
+        };
+        let first = create_issue_impl(&mut storage, &args, &config()).unwrap();
+        let start = Utc.with_ymd_and_hms(2025, 1, 6, 9, 0, 0).unwrap();
+        storage
+            .set_recurrence(&first.id, "FREQ=WEEKLY", Some(start), "tester")
+            .unwrap();
+
+        // Not due yet and still open: nothing happens.
+        let runs =
+            run_recurrences(&mut storage, &config(), start - Duration::days(1), false).unwrap();
+        assert!(runs.is_empty());
+
+        // Two weeks late: catch up to the latest elapsed occurrence.
+        let late = start + Duration::days(15);
+        let planned = run_recurrences(&mut storage, &config(), late, true).unwrap();
+        assert_eq!(planned.len(), 1);
+        assert!(storage.get_recurrence(&first.id).unwrap().is_some());
+
+        let runs = run_recurrences(&mut storage, &config(), late, false).unwrap();
+        assert_eq!(runs.len(), 1);
+        let second = storage.get_issue(&runs[0].id).unwrap().unwrap();
+        assert_ne!(second.id, first.id);
+        assert_eq!(second.title, "Dependency audit");
+        assert_eq!(second.assignee.as_deref(), Some("alice"));
+        assert_eq!(second.due_at, Some(start + Duration::weeks(2)));
+        assert_eq!(storage.get_labels(&second.id).unwrap(), vec!["chore"]);
+        assert_eq!(
+            storage.get_dependencies(&second.id).unwrap(),
+            vec![first.id.clone()]
+        );
+        assert!(storage.get_recurrence(&first.id).unwrap().is_none());
+        let record = storage.get_recurrence(&second.id).unwrap().unwrap();
+        assert_eq!(record.next_at, Some(start + Duration::weeks(3)));
+
+        // Closing the instance early creates the upcoming one right away.
+        storage
+            .update_issue(
+                &second.id,
+                &IssueUpdate {
+                    status: Some(Status::Closed),
+                    ..IssueUpdate::default()
+                },
+                "tester",
+            )
+            .unwrap();
+        let runs = run_recurrences(&mut storage, &config(), late, false).unwrap();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].from, second.id);
+        assert_eq!(runs[0].due_at, start + Duration::weeks(3));
+    }
+
+    #[test]
+    fn test_run_is_one_batch_and_rule_round_trips_through_jsonl() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let args = CreateArgs {
+            title: Some("Rotate keys".to_string()),
+            ..CreateArgs::default()
+        };
+        let first = create_issue_impl(&mut storage, &args, &config()).unwrap();
+        let start = Utc.with_ymd_and_hms(2025, 1, 6, 9, 0, 0).unwrap();
+        storage
+            .set_recurrence(&first.id, "FREQ=DAILY", Some(start), "tester")
+            .unwrap();
+        storage.clear_all_dirty_flags().unwrap();
+
+        let runs = run_recurrences(&mut storage, &config(), start, false).unwrap();
+        let second = &runs[0].id;
+        let batches = storage.undo_stack(1, None).unwrap();
+        assert_eq!(batches[0].op, "recur_run");
+        let mut dirty = storage.get_dirty_issue_ids().unwrap();
+        dirty.sort();
+        let mut expected = vec![first.id.clone(), second.clone()];
+        expected.sort();
+        assert_eq!(dirty, expected);
+
+        let exported = storage.get_all_issues_for_export().unwrap();
+        let carrier = exported.iter().find(|issue| &issue.id == second).unwrap();
+        let rule = carrier.recurrence.clone().unwrap();
+        assert_eq!(rule.rule, "FREQ=DAILY");
+        assert_eq!(rule.next_at, Some(start + Duration::days(1)));
+        assert!(
+            exported
+                .iter()
+                .find(|issue| issue.id == first.id)
+                .unwrap()
+                .recurrence
+                .is_none()
+        );
+
+        let mut rebuilt = SqliteStorage::open_memory().unwrap();
+        for issue in &exported {
+            rebuilt.upsert_issue_for_import(issue).unwrap();
+            rebuilt
+                .sync_recurrence_for_import(&issue.id, issue.recurrence.as_ref())
+                .unwrap();
+        }
+        let record = rebuilt.get_recurrence(second).unwrap().unwrap();
+        assert_eq!(record.rule, "FREQ=DAILY");
+        assert_eq!(record.next_at, Some(start + Duration::days(1)));
+        assert!(rebuilt.get_recurrence(&first.id).unwrap().is_none());
+    }
+}
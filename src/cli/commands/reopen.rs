@@ -121,7 +121,7 @@ pub fn execute(
             ..Default::default()
         };
 
-        // Apply update
+        // Apply update (this also clears the close outcome)
         storage.update_issue(id, &update, &actor)?;
         tracing::info!(id = %id, reason = ?args.reason, "Issue reopened");
 
@@ -7,7 +7,8 @@
 use crate::cli::{OutputFormat, SchemaArgs, SchemaTarget, resolve_output_format_basic};
 use crate::error::Result;
 use crate::format::{
-    BlockedIssue, IssueDetails, IssueWithCounts, ReadyIssue, StaleIssue, Statistics, TreeNode,
+    BlockedIssue, IssueDetails, IssueWithCounts, ReadyIssue, SearchResult, StaleIssue, Statistics,
+    TreeNode,
 };
 use crate::model::Issue;
 use crate::output::{OutputContext, OutputMode};
@@ -95,6 +96,7 @@ fn build_schemas(target: SchemaTarget) -> BTreeMap<&'static str, RootSchema> {
         SchemaTarget::All => {
             schemas.insert("Issue", schema_for!(Issue));
             schemas.insert("IssueWithCounts", schema_for!(IssueWithCounts));
+            schemas.insert("SearchResult", schema_for!(SearchResult));
             schemas.insert("IssueDetails", schema_for!(IssueDetails));
             schemas.insert("ReadyIssue", schema_for!(ReadyIssue));
             schemas.insert("StaleIssue", schema_for!(StaleIssue));
@@ -109,6 +111,9 @@ fn build_schemas(target: SchemaTarget) -> BTreeMap<&'static str, RootSchema> {
         SchemaTarget::IssueWithCounts => {
             schemas.insert("IssueWithCounts", schema_for!(IssueWithCounts));
         }
+        SchemaTarget::SearchResult => {
+            schemas.insert("SearchResult", schema_for!(SearchResult));
+        }
         SchemaTarget::IssueDetails => {
             schemas.insert("IssueDetails", schema_for!(IssueDetails));
         }
@@ -1,16 +1,18 @@
 //! Search command implementation.
 //!
-//! Classic bd-style LIKE search across title/description/id with list-like filters.
+//! Full-text search (FTS5, BM25-ranked) across title, description, design,
+//! acceptance criteria, notes and comments, with bd-style substring matching on
+//! title/description/id as a fallback and list-like filters.
 
 use crate::cli::{ListArgs, OutputFormat, SearchArgs, resolve_output_format};
 use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::format::{
-    IssueWithCounts, TextFormatOptions, csv, format_issue_line_with, terminal_width,
+    IssueWithCounts, SearchResult, TextFormatOptions, csv, format_issue_line_with, terminal_width,
 };
 use crate::model::{IssueType, Priority, Status};
 use crate::output::{IssueTable, IssueTableColumns, OutputContext, OutputMode};
-use crate::storage::{ListFilters, SqliteStorage};
+use crate::storage::{ListFilters, SEARCH_SNIPPET_CLOSE, SEARCH_SNIPPET_OPEN, SqliteStorage};
 use chrono::Utc;
 use regex::{Regex, RegexBuilder};
 use std::collections::{HashMap, HashSet};
@@ -39,7 +41,7 @@ pub fn execute(
     }
 
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
-    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let storage_ctx = config::open_storage_as_of(&beads_dir, cli, args.filters.as_of.as_deref())?;
     let storage = &storage_ctx.storage;
     let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
     let use_color = config::should_use_color(&config_layer);
@@ -62,7 +64,16 @@ pub fn execute(
         None
     };
 
-    let issues = storage.search_issues(query, &filters)?;
+    let hits = storage.search_issues_ranked(query, &filters)?;
+    let mut relevance: HashMap<String, (Option<f64>, Option<String>)> =
+        HashMap::with_capacity(hits.len());
+    let issues: Vec<_> = hits
+        .into_iter()
+        .map(|hit| {
+            relevance.insert(hit.issue.id.clone(), (hit.score, hit.snippet));
+            hit.issue
+        })
+        .collect();
     let issues = if client_filters {
         apply_client_filters(storage, issues, &args.filters)?
     } else {
@@ -115,11 +126,14 @@ pub fn execute(
 
     match output_format {
         OutputFormat::Json => {
-            ctx.json_pretty(&issues_with_counts);
+            ctx.json_pretty(&to_search_results(issues_with_counts, &relevance));
             return Ok(());
         }
         OutputFormat::Toon => {
-            ctx.toon_with_stats(&issues_with_counts, args.filters.stats);
+            ctx.toon_with_stats(
+                &to_search_results(issues_with_counts, &relevance),
+                args.filters.stats,
+            );
             return Ok(());
         }
         OutputFormat::Csv => {
@@ -128,7 +142,12 @@ pub fn execute(
                 .map(|iwc| iwc.issue.clone())
                 .collect();
             let fields = csv::parse_fields(args.filters.fields.as_deref());
-            let csv_output = csv::format_csv(&issues, &fields);
+            let time_spent = if csv::needs_time(&fields) {
+                storage.get_time_rollup()?
+            } else {
+                HashMap::new()
+            };
+            let csv_output = csv::format_csv_with_time(&issues, &fields, &time_spent);
             print!("{csv_output}");
             return Ok(());
         }
@@ -140,7 +159,12 @@ pub fn execute(
             .iter()
             .map(|iwc| iwc.issue.clone())
             .collect();
-        let context_snippets = build_context_snippets(&issues, query);
+        let mut context_snippets = build_context_snippets(&issues, query);
+        for issue in &issues {
+            if let Some((_, Some(snippet))) = relevance.get(&issue.id) {
+                context_snippets.insert(issue.id.clone(), strip_snippet_markers(snippet));
+            }
+        }
         let show_context = !context_snippets.is_empty();
         let columns = IssueTableColumns {
             id: true,
@@ -180,11 +204,43 @@ pub fn execute(
     for iwc in &issues_with_counts {
         let line = format_issue_line_with(&iwc.issue, format_options);
         ctx.print(&line);
+        if let Some((_, Some(snippet))) = relevance.get(&iwc.issue.id) {
+            ctx.print(&format!("    {}", normalize_whitespace(snippet)));
+        }
     }
 
     Ok(())
 }
 
+/// Attach relevance metadata to the (filtered, sorted) search rows.
+fn to_search_results(
+    issues: Vec<IssueWithCounts>,
+    relevance: &HashMap<String, (Option<f64>, Option<String>)>,
+) -> Vec<SearchResult> {
+    issues
+        .into_iter()
+        .map(|iwc| {
+            let (score, snippet) = relevance.get(&iwc.issue.id).cloned().unwrap_or_default();
+            SearchResult {
+                issue: iwc.issue,
+                dependency_count: iwc.dependency_count,
+                dependent_count: iwc.dependent_count,
+                score,
+                snippet: snippet.map(|s| normalize_whitespace(&s)),
+            }
+        })
+        .collect()
+}
+
+/// Remove FTS highlight markers; the rich table applies its own highlighting.
+fn strip_snippet_markers(snippet: &str) -> String {
+    normalize_whitespace(
+        &snippet
+            .replace(SEARCH_SNIPPET_OPEN, "")
+            .replace(SEARCH_SNIPPET_CLOSE, ""),
+    )
+}
+
 fn build_context_snippets(issues: &[crate::model::Issue], query: &str) -> HashMap<String, String> {
     let Some(regex) = build_highlight_regex(query) else {
         return HashMap::new();
@@ -522,6 +578,8 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         }
     }
 
@@ -552,6 +610,62 @@ mod tests {
         assert_eq!(results[0].id, "bd-xyz");
     }
 
+    #[test]
+    fn test_search_ranks_title_matches_and_searches_all_fields() {
+        let mut storage = SqliteStorage::open_memory().expect("db");
+        let t1 = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+
+        let mut in_notes = make_issue("bd-001", "Unrelated", None, t1);
+        in_notes.notes = Some("remember the migration plan".to_string());
+        let in_title = make_issue("bd-002", "Migration tooling", None, t1);
+        let in_comment = make_issue("bd-003", "Something else", None, t1);
+
+        storage.create_issue(&in_notes, "tester").expect("create");
+        storage.create_issue(&in_title, "tester").expect("create");
+        storage.create_issue(&in_comment, "tester").expect("create");
+        storage
+            .add_comment("bd-003", "tester", "blocked on the migrations")
+            .expect("comment");
+
+        let filters = ListFilters::default();
+        let hits = storage
+            .search_issues_ranked("migra", &filters)
+            .expect("search");
+        let ids: Vec<&str> = hits.iter().map(|hit| hit.issue.id.as_str()).collect();
+        assert_eq!(ids.len(), 3);
+        assert_eq!(ids[0], "bd-002", "title match should rank first");
+        assert!(hits.iter().all(|hit| hit.score.is_some()));
+
+        let snippet = hits[0].snippet.as_deref().expect("snippet");
+        assert!(snippet.contains("[[Migration]]"), "snippet: {snippet}");
+    }
+
+    #[test]
+    fn test_search_fts_syntax_phrase_and_boolean() {
+        let mut storage = SqliteStorage::open_memory().expect("db");
+        let t1 = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+
+        let a = make_issue("bd-001", "Login page broken", None, t1);
+        let b = make_issue("bd-002", "Broken page on login", None, t1);
+        storage.create_issue(&a, "tester").expect("create");
+        storage.create_issue(&b, "tester").expect("create");
+
+        let filters = ListFilters::default();
+        let results = storage
+            .search_issues("\"login page\"", &filters)
+            .expect("phrase");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, "bd-001");
+
+        let results = storage
+            .search_issues("broken NOT login", &filters)
+            .expect("boolean");
+        assert!(results.is_empty());
+
+        let err = storage.search_issues("login AND", &filters);
+        assert!(err.is_err(), "dangling operator should be rejected");
+    }
+
     #[test]
     fn test_sort_by_title_and_reverse() {
         let t1 = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
@@ -0,0 +1,1190 @@
+//! Serve command implementation.
+//!
+//! `br serve` runs a small HTTP/1.1 JSON API on a local address, backed by one
+//! `SqliteStorage` for the life of the process. Each connection is read on its
+//! own thread (and closed after its response), so a slow or idle client cannot
+//! stall the others; the parsed requests are then applied one at a time under
+//! a lock on the storage, so writes never race each other. Successful responses use the same JSON shapes as the CLI
+//! (`IssueWithCounts` for lists, `IssueDetails` for a single issue); failures
+//! return the `StructuredError` body with a matching HTTP status. Writes are
+//! flushed to JSONL once no further write has arrived for `--flush-debounce`
+//! milliseconds.
+//!
+//! There is no authentication, so the server only listens on loopback and
+//! rejects what a browser could send it from another site: a `Host` or
+//! `Origin` that is not a loopback name (DNS rebinding, cross-site pages),
+//! and writes without `Content-Type: application/json` (which, unlike
+//! `text/plain` or form posts, cannot be sent cross-site without a CORS
+//! preflight).
+//!
+//! Routes:
+//!
+//! | Method   | Path                          | Result                    |
+//! |----------|-------------------------------|---------------------------|
+//! | `GET`    | `/health`                     | server status             |
+//! | `GET`    | `/issues`                     | `[IssueWithCounts]`       |
+//! | `POST`   | `/issues`                     | `IssueDetails` (201)      |
+//! | `GET`    | `/issues/{id}`                | `IssueDetails`            |
+//! | `PATCH`  | `/issues/{id}`                | `IssueDetails`            |
+//! | `POST`   | `/issues/{id}/close`          | `IssueDetails`            |
+//! | `GET`    | `/ready`                      | `[IssueWithCounts]`       |
+//! | `GET`    | `/blocked`                    | `[BlockedIssue]`          |
+//! | `GET`    | `/issues/{id}/deps`           | dependency list           |
+//! | `POST`   | `/issues/{id}/deps`           | `IssueDetails`            |
+//! | `DELETE` | `/issues/{id}/deps/{dep}`     | `IssueDetails`            |
+//! | `GET`    | `/issues/{id}/labels`         | `[String]`                |
+//! | `POST`   | `/issues/{id}/labels`         | `IssueDetails`            |
+//! | `DELETE` | `/issues/{id}/labels/{label}` | `IssueDetails`            |
+//! | `GET`    | `/issues/{id}/comments`       | `[Comment]`               |
+//! | `POST`   | `/issues/{id}/comments`       | `Comment` (201)           |
+
+use crate::cli::commands::create::{CreateConfig, create_issue_impl};
+use crate::cli::commands::dep::dependency_metadata;
+use crate::cli::commands::label::validate_label;
+use crate::cli::commands::update::build_update;
+use crate::cli::{CreateArgs, ServeArgs, UpdateArgs};
+use crate::config::{self, OpenStorageResult};
+use crate::error::{BeadsError, ErrorCode, Result, StructuredError};
+use crate::format::{BlockedIssue, IssueWithCounts};
+use crate::model::{CloseOutcome, DependencyType, Issue, IssueType, Priority, Status};
+use crate::output::OutputContext;
+use crate::storage::score::sort_by_score;
+use crate::storage::{
+    IssueUpdate, ListFilters, ReadyFilters, ReadySortPolicy, ScoreWeights, SqliteStorage,
+};
+use crate::sync::auto_flush;
+use crate::util::id::{IdResolver, ResolverConfig};
+use chrono::Utc;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::json;
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{IpAddr, TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread;
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+
+/// Largest request body accepted.
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+/// How long the accept loop sleeps when no client is waiting.
+const POLL_INTERVAL: Duration = Duration::from_millis(25);
+/// Read and write timeout for a single client connection.
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Execute the serve command.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be opened or the address cannot be
+/// bound. Errors from individual requests are returned to the client instead.
+pub fn execute(args: &ServeArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let id_config = config::id_config_from_layer(&layer);
+
+    let api = Api {
+        resolver: IdResolver::new(ResolverConfig::with_prefix(id_config.prefix.clone())),
+        create_config: CreateConfig {
+            id_config,
+            default_priority: config::default_priority_from_layer(&layer)?,
+            default_issue_type: config::default_issue_type_from_layer(&layer)?,
+            actor: config::resolve_actor(&layer),
+        },
+        external_db_paths: config::external_project_db_paths(&layer, &beads_dir),
+        score_weights: config::score_weights_from_layer(&layer)?,
+        beads_dir: beads_dir.clone(),
+    };
+
+    let listener = TcpListener::bind(&args.bind)
+        .map_err(|e| BeadsError::Config(format!("cannot listen on {}: {e}", args.bind)))?;
+    listener.set_nonblocking(true)?;
+    let addr = listener.local_addr()?;
+    if !addr.ip().is_loopback() {
+        return Err(BeadsError::Config(format!(
+            "refusing to serve on {addr}: br serve has no authentication, bind a loopback address"
+        )));
+    }
+
+    if ctx.is_json() {
+        ctx.json_pretty(&json!({ "listening": format!("http://{addr}") }));
+    } else {
+        ctx.success(&format!("Serving {} on http://{addr}", beads_dir.display()));
+        ctx.info("Press Ctrl+C to stop.");
+    }
+    info!(%addr, "br serve listening");
+
+    let server = Server {
+        api,
+        storage_ctx,
+        beads_dir,
+        auto_flush: !cli.no_auto_flush.unwrap_or(false),
+        debounce: Duration::from_millis(args.flush_debounce),
+        dirty_since: None,
+    };
+    run(&Arc::new(Mutex::new(server)), &listener)
+}
+
+/// Accept connections until the listener fails, reading each on its own
+/// thread.
+fn run(server: &Arc<Mutex<Server>>, listener: &TcpListener) -> Result<()> {
+    loop {
+        match listener.accept() {
+            Ok((stream, peer)) => {
+                debug!(%peer, "Accepted connection");
+                let server = Arc::clone(server);
+                thread::spawn(move || {
+                    if let Err(e) = handle_connection(&server, stream) {
+                        debug!(%peer, ?e, "Connection failed");
+                    }
+                });
+            }
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => thread::sleep(POLL_INTERVAL),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e.into()),
+        }
+        lock(server).flush_if_due();
+    }
+}
+
+/// Read one request, apply it under the storage lock, and write the response.
+///
+/// The lock is held only while the request is applied, never while the
+/// client is being read from or written to.
+fn handle_connection(server: &Mutex<Server>, stream: TcpStream) -> io::Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(CLIENT_TIMEOUT))?;
+    stream.set_write_timeout(Some(CLIENT_TIMEOUT))?;
+    let mut reader = BufReader::new(&stream);
+    let response = match read_request(&mut reader) {
+        Ok(request) => check_request(&request).unwrap_or_else(|| lock(server).handle(&request)),
+        Err(err) => Response::error(&err),
+    };
+    let mut stream = &stream;
+    stream.write_all(&response.to_bytes())?;
+    stream.flush()
+}
+
+/// Lock the server state, carrying on after a request that panicked rather
+/// than failing every request after it.
+fn lock(server: &Mutex<Server>) -> MutexGuard<'_, Server> {
+    server.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Accept loop state: the storage handle and the pending flush.
+struct Server {
+    api: Api,
+    storage_ctx: OpenStorageResult,
+    beads_dir: PathBuf,
+    auto_flush: bool,
+    debounce: Duration,
+    dirty_since: Option<Instant>,
+}
+
+impl Server {
+    fn handle(&mut self, request: &Request) -> Response {
+        let response = self.api.handle(&mut self.storage_ctx.storage, request);
+        info!(
+            method = %request.method,
+            path = %request.path,
+            status = response.status,
+            "Handled request"
+        );
+        if request.method != "GET" && response.status < 400 {
+            self.dirty_since = Some(Instant::now());
+        }
+        response
+    }
+
+    /// Flush to JSONL once writes have settled for the debounce interval.
+    fn flush_if_due(&mut self) {
+        let Some(since) = self.dirty_since else {
+            return;
+        };
+        if since.elapsed() < self.debounce {
+            return;
+        }
+        self.dirty_since = None;
+
+        let result = if self.storage_ctx.no_db {
+            self.storage_ctx.flush_no_db_if_dirty()
+        } else if self.auto_flush {
+            auto_flush(&mut self.storage_ctx.storage, &self.beads_dir).map(|result| {
+                if result.flushed {
+                    debug!(exported = result.exported_count, "Flushed to JSONL");
+                }
+            })
+        } else {
+            Ok(())
+        };
+        if let Err(e) = result {
+            warn!(?e, "Flush to JSONL failed");
+        }
+    }
+}
+
+/// A parsed HTTP request.
+#[derive(Debug)]
+struct Request {
+    method: String,
+    path: String,
+    query: Vec<(String, String)>,
+    /// Header names (lowercased) and values.
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl Request {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// All values of a query parameter, splitting comma-separated lists.
+    fn query_values(&self, key: &str) -> Vec<String> {
+        self.query
+            .iter()
+            .filter(|(k, _)| k == key)
+            .flat_map(|(_, v)| v.split(','))
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn query_value(&self, key: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .rev()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn query_flag(&self, key: &str) -> bool {
+        self.query_value(key)
+            .is_some_and(|v| matches!(v, "" | "1" | "true" | "yes"))
+    }
+
+    fn json_body<T: DeserializeOwned>(&self) -> Result<T> {
+        let body: &[u8] = if self.body.is_empty() {
+            b"{}"
+        } else {
+            &self.body
+        };
+        serde_json::from_slice(body)
+            .map_err(|e| BeadsError::validation("body", format!("invalid JSON: {e}")))
+    }
+}
+
+/// A JSON response ready to be written.
+#[derive(Debug)]
+struct Response {
+    status: u16,
+    body: String,
+}
+
+impl Response {
+    fn json<T: serde::Serialize>(status: u16, value: &T) -> Result<Self> {
+        Ok(Self {
+            status,
+            body: serde_json::to_string(value)?,
+        })
+    }
+
+    fn error(err: &BeadsError) -> Self {
+        let structured = StructuredError::from_error(err);
+        Self {
+            status: http_status(structured.code),
+            body: structured.to_json().to_string(),
+        }
+    }
+
+    /// An error body sent with `status` instead of the one for its code.
+    fn rejected(status: u16, err: &BeadsError) -> Self {
+        Self {
+            status,
+            ..Self::error(err)
+        }
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let head = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            self.status,
+            reason_phrase(self.status),
+            self.body.len()
+        );
+        let mut bytes = head.into_bytes();
+        bytes.extend_from_slice(self.body.as_bytes());
+        bytes
+    }
+}
+
+/// The rejection of a request a browser may have sent on behalf of another
+/// site, or `None` if it may be handled.
+fn check_request(request: &Request) -> Option<Response> {
+    let forbidden = |header: &str, value: &str| {
+        Response::rejected(
+            403,
+            &BeadsError::validation(
+                header,
+                format!("'{value}' is not a loopback address; br serve only accepts local clients"),
+            ),
+        )
+    };
+    if let Some(host) = request.header("host") {
+        if !is_loopback_authority(host) {
+            return Some(forbidden("Host", host));
+        }
+    }
+    if let Some(origin) = request.header("origin") {
+        let authority = origin
+            .split_once("://")
+            .map_or("", |(_, rest)| rest.split('/').next().unwrap_or(""));
+        if !is_loopback_authority(authority) {
+            return Some(forbidden("Origin", origin));
+        }
+    }
+    if request.method != "GET" {
+        let json = request.header("content-type").is_some_and(|value| {
+            value
+                .split(';')
+                .next()
+                .unwrap_or("")
+                .trim()
+                .eq_ignore_ascii_case("application/json")
+        });
+        if !json {
+            return Some(Response::rejected(
+                415,
+                &BeadsError::validation(
+                    "Content-Type",
+                    format!(
+                        "{} requests must be sent as application/json",
+                        request.method
+                    ),
+                ),
+            ));
+        }
+    }
+    None
+}
+
+/// Whether a `host[:port]` names the local machine.
+fn is_loopback_authority(authority: &str) -> bool {
+    let host = match authority.strip_prefix('[') {
+        Some(rest) => rest.split(']').next().unwrap_or(""),
+        None => authority
+            .rsplit_once(':')
+            .map_or(authority, |(host, _)| host),
+    };
+    host.eq_ignore_ascii_case("localhost")
+        || host.parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback())
+}
+
+/// HTTP status for a structured error code.
+const fn http_status(code: ErrorCode) -> u16 {
+    match code {
+        ErrorCode::IssueNotFound | ErrorCode::DependencyNotFound => 404,
+        ErrorCode::AmbiguousId
+        | ErrorCode::InvalidId
+        | ErrorCode::ValidationFailed
+        | ErrorCode::InvalidStatus
+        | ErrorCode::InvalidType
+        | ErrorCode::InvalidPriority
+        | ErrorCode::RequiredField
+        | ErrorCode::SelfDependency => 400,
+        ErrorCode::IdCollision
+        | ErrorCode::CycleDetected
+        | ErrorCode::HasDependents
+        | ErrorCode::DuplicateDependency
+        | ErrorCode::LeaseHeld
+        | ErrorCode::HookRejected => 409,
+        ErrorCode::DatabaseLocked => 503,
+        _ => 500,
+    }
+}
+
+const fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        400 => "Bad Request",
+        403 => "Forbidden",
+        404 => "Not Found",
+        409 => "Conflict",
+        415 => "Unsupported Media Type",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    }
+}
+
+/// Read one request (request line, headers and `Content-Length` body).
+fn read_request<R: BufRead>(reader: &mut R) -> Result<Request> {
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err(BeadsError::validation("request", "malformed request line"));
+    };
+    let method = method.to_ascii_uppercase();
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let path = path.to_string();
+    let query = parse_query(query);
+
+    let mut content_length = 0usize;
+    let mut headers = Vec::new();
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 {
+            break;
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            let name = name.trim().to_ascii_lowercase();
+            if name == "content-length" {
+                content_length = value.trim().parse().map_err(|_| {
+                    BeadsError::validation("request", "invalid Content-Length header")
+                })?;
+            }
+            headers.push((name, value.trim().to_string()));
+        }
+    }
+    if content_length > MAX_BODY_BYTES {
+        return Err(BeadsError::validation(
+            "body",
+            format!("request body exceeds {MAX_BODY_BYTES} bytes"),
+        ));
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+    Ok(Request {
+        method,
+        path,
+        query,
+        headers,
+        body,
+    })
+}
+
+fn parse_query(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (percent_decode(key), percent_decode(value))
+        })
+        .collect()
+}
+
+/// Decode `%XX` escapes and `+` (as space) in a URL component.
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => out.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..=i + 2]).unwrap_or("");
+                if let Ok(byte) = u8::from_str_radix(hex, 16) {
+                    out.push(byte);
+                    i += 3;
+                    continue;
+                }
+                out.push(b'%');
+            }
+            byte => out.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn parse_all<T: FromStr<Err = BeadsError>>(values: &[String]) -> Result<Option<Vec<T>>> {
+    if values.is_empty() {
+        return Ok(None);
+    }
+    values
+        .iter()
+        .map(|v| v.parse())
+        .collect::<Result<Vec<T>>>()
+        .map(Some)
+}
+
+/// Body of `POST /issues`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct CreateBody {
+    title: Option<String>,
+    description: Option<String>,
+    #[serde(rename = "type", alias = "issue_type")]
+    issue_type: Option<String>,
+    priority: Option<String>,
+    status: Option<String>,
+    assignee: Option<String>,
+    owner: Option<String>,
+    labels: Vec<String>,
+    parent: Option<String>,
+    deps: Vec<String>,
+    estimate: Option<i32>,
+    due: Option<String>,
+    defer: Option<String>,
+    external_ref: Option<String>,
+}
+
+/// Body of `PATCH /issues/{id}`; an empty string clears a field, as on the CLI.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct UpdateBody {
+    title: Option<String>,
+    description: Option<String>,
+    design: Option<String>,
+    acceptance_criteria: Option<String>,
+    notes: Option<String>,
+    status: Option<String>,
+    priority: Option<String>,
+    #[serde(rename = "type", alias = "issue_type")]
+    issue_type: Option<String>,
+    assignee: Option<String>,
+    owner: Option<String>,
+    claim: bool,
+    due: Option<String>,
+    defer: Option<String>,
+    estimate: Option<i32>,
+    external_ref: Option<String>,
+}
+
+/// Body of `POST /issues/{id}/close`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct CloseBody {
+    reason: Option<String>,
+    outcome: Option<String>,
+    force: bool,
+    session: Option<String>,
+}
+
+/// Body of `POST /issues/{id}/deps`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DepBody {
+    depends_on: String,
+    #[serde(rename = "type", default = "default_dep_type")]
+    dep_type: String,
+    #[serde(default)]
+    gate: Option<String>,
+}
+
+fn default_dep_type() -> String {
+    "blocks".to_string()
+}
+
+/// Body of `POST /issues/{id}/labels`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LabelBody {
+    label: String,
+}
+
+/// Body of `POST /issues/{id}/comments`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CommentBody {
+    text: String,
+    author: Option<String>,
+}
+
+/// Request routing and handlers; storage is passed in so tests can use an
+/// in-memory database.
+struct Api {
+    resolver: IdResolver,
+    create_config: CreateConfig,
+    external_db_paths: HashMap<String, PathBuf>,
+    score_weights: ScoreWeights,
+    beads_dir: PathBuf,
+}
+
+impl Api {
+    fn handle(&self, storage: &mut SqliteStorage, request: &Request) -> Response {
+        self.route(storage, request)
+            .unwrap_or_else(|err| Response::error(&err))
+    }
+
+    fn route(&self, storage: &mut SqliteStorage, request: &Request) -> Result<Response> {
+        let segments: Vec<String> = request
+            .path
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .map(percent_decode)
+            .collect();
+        let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+
+        match (request.method.as_str(), segments.as_slice()) {
+            ("GET", ["health"]) => Response::json(
+                200,
+                &json!({
+                    "status": "ok",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "beads_dir": self.beads_dir.display().to_string(),
+                }),
+            ),
+            ("GET", ["issues"]) => Self::list(storage, request),
+            ("POST", ["issues"]) => self.create(storage, request),
+            ("GET", ["issues", id]) => Self::details(storage, &self.resolve(storage, id)?, 200),
+            ("PATCH", ["issues", id]) => self.update(storage, id, request),
+            ("POST", ["issues", id, "close"]) => self.close(storage, id, request),
+            ("GET", ["ready"]) => self.ready(storage, request),
+            ("GET", ["blocked"]) => self.blocked(storage),
+            ("GET", ["issues", id, "deps"]) => {
+                let id = self.resolve(storage, id)?;
+                Response::json(200, &storage.get_dependencies_with_metadata(&id)?)
+            }
+            ("POST", ["issues", id, "deps"]) => self.add_dep(storage, id, request),
+            ("DELETE", ["issues", id, "deps", dep]) => {
+                let id = self.resolve(storage, id)?;
+                let dep = self.resolve_dep_target(storage, dep)?;
+                if !storage.remove_dependency(&id, &dep, &self.create_config.actor)? {
+                    return Err(BeadsError::DependencyNotFound { id: dep });
+                }
+                Self::details(storage, &id, 200)
+            }
+            ("GET", ["issues", id, "labels"]) => {
+                let id = self.resolve(storage, id)?;
+                Response::json(200, &storage.get_labels(&id)?)
+            }
+            ("POST", ["issues", id, "labels"]) => {
+                let id = self.resolve(storage, id)?;
+                let body: LabelBody = request.json_body()?;
+                validate_label(&body.label)?;
+                storage.add_label(&id, &body.label, &self.create_config.actor)?;
+                Self::details(storage, &id, 200)
+            }
+            ("DELETE", ["issues", id, "labels", label]) => {
+                let id = self.resolve(storage, id)?;
+                storage.remove_label(&id, label, &self.create_config.actor)?;
+                Self::details(storage, &id, 200)
+            }
+            ("GET", ["issues", id, "comments"]) => {
+                let id = self.resolve(storage, id)?;
+                Response::json(200, &storage.get_comments(&id)?)
+            }
+            ("POST", ["issues", id, "comments"]) => {
+                let id = self.resolve(storage, id)?;
+                let body: CommentBody = request.json_body()?;
+                if body.text.trim().is_empty() {
+                    return Err(BeadsError::validation(
+                        "text",
+                        "comment text cannot be empty",
+                    ));
+                }
+                let author = body
+                    .author
+                    .filter(|a| !a.trim().is_empty())
+                    .unwrap_or_else(|| self.create_config.actor.clone());
+                Response::json(201, &storage.add_comment(&id, &author, &body.text)?)
+            }
+            (method, _) => {
+                let err = BeadsError::validation(
+                    "path",
+                    format!("no route for {method} {}", request.path),
+                );
+                let mut response = Response::error(&err);
+                response.status = 404;
+                Ok(response)
+            }
+        }
+    }
+
+    fn resolve(&self, storage: &SqliteStorage, input: &str) -> Result<String> {
+        self.resolver
+            .resolve(
+                input,
+                |id| storage.id_exists(id).unwrap_or(false),
+                |hash| storage.find_ids_by_hash(hash).unwrap_or_default(),
+            )
+            .map(|resolved| resolved.id)
+    }
+
+    /// External dependency targets are stored verbatim.
+    fn resolve_dep_target(&self, storage: &SqliteStorage, input: &str) -> Result<String> {
+        if input.starts_with("external:") {
+            Ok(input.to_string())
+        } else {
+            self.resolve(storage, input)
+        }
+    }
+
+    fn details(storage: &SqliteStorage, id: &str, status: u16) -> Result<Response> {
+        let details = storage
+            .get_issue_details(id, true, false, 0)?
+            .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })?;
+        Response::json(status, &details)
+    }
+
+    fn list(storage: &SqliteStorage, request: &Request) -> Result<Response> {
+        let statuses: Option<Vec<Status>> = parse_all(&request.query_values("status"))?;
+        let all = request.query_flag("all");
+        let filters = ListFilters {
+            include_closed: all
+                || statuses
+                    .as_ref()
+                    .is_some_and(|s| s.iter().any(Status::is_terminal)),
+            include_deferred: all
+                || statuses
+                    .as_ref()
+                    .is_some_and(|s| s.contains(&Status::Deferred)),
+            statuses,
+            types: parse_all::<IssueType>(&request.query_values("type"))?,
+            priorities: parse_all::<Priority>(&request.query_values("priority"))?,
+            assignee: request.query_value("assignee").map(ToString::to_string),
+            unassigned: request.query_flag("unassigned"),
+            title_contains: request.query_value("title").map(ToString::to_string),
+            labels: Some(request.query_values("label")).filter(|l| !l.is_empty()),
+            labels_or: Some(request.query_values("label_any")).filter(|l| !l.is_empty()),
+            limit: parse_limit(request)?,
+            sort: request.query_value("sort").map(ToString::to_string),
+            reverse: request.query_flag("reverse"),
+            ..Default::default()
+        };
+        let issues = storage.list_issues(&filters)?;
+        Response::json(200, &with_counts(storage, issues)?)
+    }
+
+    fn ready(&self, storage: &SqliteStorage, request: &Request) -> Result<Response> {
+        let filters = ReadyFilters {
+            assignee: request.query_value("assignee").map(ToString::to_string),
+            unassigned: request.query_flag("unassigned"),
+            labels_and: request.query_values("label"),
+            labels_or: request.query_values("label_any"),
+            types: parse_all(&request.query_values("type"))?,
+            priorities: parse_all(&request.query_values("priority"))?,
+            include_deferred: request.query_flag("include_deferred"),
+            limit: None,
+        };
+        let sort = match request.query_value("sort") {
+            None | Some("hybrid") => ReadySortPolicy::Hybrid,
+            Some("priority") => ReadySortPolicy::Priority,
+            Some("oldest") => ReadySortPolicy::Oldest,
+            Some("score") => ReadySortPolicy::Score,
+            Some(other) => {
+                return Err(BeadsError::validation(
+                    "sort",
+                    format!("unknown sort '{other}' (expected hybrid, priority, oldest or score)"),
+                ));
+            }
+        };
+
+        let mut issues = storage.get_ready_issues(&filters, sort)?;
+        let external_statuses =
+            storage.resolve_external_dependency_statuses(&self.external_db_paths, true)?;
+        let external_blockers = storage.external_blockers(&external_statuses)?;
+        issues.retain(|issue| !external_blockers.contains_key(&issue.id));
+        if sort == ReadySortPolicy::Score {
+            let scores = storage.score_issues(&issues, &self.score_weights, Utc::now())?;
+            sort_by_score(&mut issues, &scores);
+        }
+        if let Some(limit) = parse_limit(request)? {
+            issues.truncate(limit);
+        }
+        Response::json(200, &with_counts(storage, issues)?)
+    }
+
+    fn blocked(&self, storage: &SqliteStorage) -> Result<Response> {
+        let mut blocked: Vec<BlockedIssue> = storage
+            .get_blocked_issues()?
+            .into_iter()
+            .map(|(issue, blockers)| BlockedIssue {
+                blocked_by_count: blockers.len(),
+                blocked_by: blockers,
+                issue,
+            })
+            .collect();
+
+        let external_statuses =
+            storage.resolve_external_dependency_statuses(&self.external_db_paths, true)?;
+        for (id, blockers) in storage.external_blockers(&external_statuses)? {
+            if let Some(existing) = blocked.iter_mut().find(|b| b.issue.id == id) {
+                existing.blocked_by.extend(blockers);
+                existing.blocked_by_count = existing.blocked_by.len();
+            } else if let Some(issue) = storage.get_issue(&id)? {
+                blocked.push(BlockedIssue {
+                    blocked_by_count: blockers.len(),
+                    blocked_by: blockers,
+                    issue,
+                });
+            }
+        }
+        blocked.sort_by(|a, b| {
+            a.issue
+                .priority
+                .cmp(&b.issue.priority)
+                .then_with(|| a.issue.created_at.cmp(&b.issue.created_at))
+        });
+        Response::json(200, &blocked)
+    }
+
+    fn create(&self, storage: &mut SqliteStorage, request: &Request) -> Result<Response> {
+        let body: CreateBody = request.json_body()?;
+        let parent = body
+            .parent
+            .as_deref()
+            .map(|p| self.resolve(storage, p))
+            .transpose()?;
+        let args = CreateArgs {
+            title: body.title,
+            type_: body.issue_type,
+            priority: body.priority,
+            description: body.description,
+            assignee: body.assignee,
+            owner: body.owner,
+            labels: body.labels,
+            parent,
+            deps: body.deps,
+            estimate: body.estimate,
+            due: body.due,
+            defer: body.defer,
+            external_ref: body.external_ref,
+            status: body.status,
+            ..Default::default()
+        };
+        let issue = create_issue_impl(storage, &args, &self.create_config)?;
+        Self::details(storage, &issue.id, 201)
+    }
+
+    fn update(
+        &self,
+        storage: &mut SqliteStorage,
+        input: &str,
+        request: &Request,
+    ) -> Result<Response> {
+        let id = self.resolve(storage, input)?;
+        let body: UpdateBody = request.json_body()?;
+        let args = UpdateArgs {
+            title: body.title,
+            description: body.description,
+            design: body.design,
+            acceptance_criteria: body.acceptance_criteria,
+            notes: body.notes,
+            status: body.status,
+            priority: body.priority,
+            type_: body.issue_type,
+            assignee: body.assignee,
+            owner: body.owner,
+            claim: body.claim,
+            due: body.due,
+            defer: body.defer,
+            estimate: body.estimate,
+            external_ref: body.external_ref,
+            ..Default::default()
+        };
+        let update = build_update(&args, &self.create_config.actor)?;
+        if update.is_empty() {
+            return Err(BeadsError::validation("body", "no fields to update"));
+        }
+        storage.update_issue(&id, &update, &self.create_config.actor)?;
+        Self::details(storage, &id, 200)
+    }
+
+    fn close(
+        &self,
+        storage: &mut SqliteStorage,
+        input: &str,
+        request: &Request,
+    ) -> Result<Response> {
+        let id = self.resolve(storage, input)?;
+        let body: CloseBody = request.json_body()?;
+        let outcome = body
+            .outcome
+            .as_deref()
+            .map(CloseOutcome::from_str)
+            .transpose()?;
+        let issue = storage
+            .get_issue(&id)?
+            .ok_or_else(|| BeadsError::IssueNotFound { id: id.clone() })?;
+        if issue.status.is_terminal() {
+            return Err(BeadsError::validation(
+                "status",
+                format!("{id} is already {}", issue.status.as_str()),
+            ));
+        }
+        if !body.force && storage.is_blocked(&id)? {
+            return Err(BeadsError::validation(
+                "status",
+                format!("{id} is blocked by open dependencies (use \"force\": true)"),
+            ));
+        }
+
+        let update = IssueUpdate {
+            status: Some(Status::Closed),
+            closed_at: Some(Some(Utc::now())),
+            close_reason: Some(Some(body.reason.unwrap_or_else(|| "done".to_string()))),
+            closed_by_session: body.session.map(Some),
+            close_outcome: outcome.map(Some),
+            ..Default::default()
+        };
+        storage.update_issue(&id, &update, &self.create_config.actor)?;
+        Self::details(storage, &id, 200)
+    }
+
+    fn add_dep(
+        &self,
+        storage: &mut SqliteStorage,
+        input: &str,
+        request: &Request,
+    ) -> Result<Response> {
+        let id = self.resolve(storage, input)?;
+        let body: DepBody = request.json_body()?;
+        let depends_on = self.resolve_dep_target(storage, &body.depends_on)?;
+        let dep_type: DependencyType = body.dep_type.parse()?;
+        if let DependencyType::Custom(_) = dep_type {
+            return Err(BeadsError::validation(
+                "type",
+                format!("unknown dependency type '{}'", body.dep_type),
+            ));
+        }
+        if id == depends_on {
+            return Err(BeadsError::SelfDependency { id });
+        }
+        let metadata = dependency_metadata(&dep_type, body.gate.as_deref(), None)?;
+        storage.add_dependency_with_metadata(
+            &id,
+            &depends_on,
+            dep_type.as_str(),
+            metadata.as_deref(),
+            &self.create_config.actor,
+        )?;
+        Self::details(storage, &id, 200)
+    }
+}
+
+fn parse_limit(request: &Request) -> Result<Option<usize>> {
+    request
+        .query_value("limit")
+        .map(|v| {
+            v.parse::<usize>()
+                .map_err(|_| BeadsError::validation("limit", format!("invalid limit '{v}'")))
+        })
+        .transpose()
+        .map(|limit| limit.filter(|&l| l > 0))
+}
+
+/// Attach labels and dependency counts, as `br list --json` does.
+fn with_counts(storage: &SqliteStorage, issues: Vec<Issue>) -> Result<Vec<IssueWithCounts>> {
+    let ids: Vec<String> = issues.iter().map(|i| i.id.clone()).collect();
+    let mut labels = storage.get_labels_for_issues(&ids)?;
+    let dependency_counts = storage.count_dependencies_for_issues(&ids)?;
+    let dependent_counts = storage.count_dependents_for_issues(&ids)?;
+    Ok(issues
+        .into_iter()
+        .map(|mut issue| {
+            if let Some(l) = labels.remove(&issue.id) {
+                issue.labels = l;
+            }
+            IssueWithCounts {
+                dependency_count: *dependency_counts.get(&issue.id).unwrap_or(&0),
+                dependent_count: *dependent_counts.get(&issue.id).unwrap_or(&0),
+                issue,
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::id::IdConfig;
+
+    fn api() -> Api {
+        Api {
+            resolver: IdResolver::new(ResolverConfig::with_prefix("bd".to_string())),
+            create_config: CreateConfig {
+                id_config: IdConfig::with_prefix("bd"),
+                default_priority: Priority::MEDIUM,
+                default_issue_type: IssueType::Task,
+                actor: "tester".to_string(),
+            },
+            external_db_paths: HashMap::new(),
+            score_weights: ScoreWeights::default(),
+            beads_dir: PathBuf::from(".beads"),
+        }
+    }
+
+    fn request(method: &str, target: &str, body: &str) -> Request {
+        let raw = format!(
+            "{method} {target} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        );
+        read_request(&mut raw.as_bytes()).unwrap()
+    }
+
+    fn body(response: &Response) -> serde_json::Value {
+        serde_json::from_str(&response.body).unwrap()
+    }
+
+    #[test]
+    fn test_read_request_parses_query_and_body() {
+        let req = request(
+            "post",
+            "/issues?status=open,in_progress&label=a%20b",
+            "{\"x\":1}",
+        );
+        assert_eq!(req.method, "POST");
+        assert_eq!(req.path, "/issues");
+        assert_eq!(req.query_values("status"), vec!["open", "in_progress"]);
+        assert_eq!(req.query_value("label"), Some("a b"));
+        assert_eq!(req.body, b"{\"x\":1}");
+    }
+
+    #[test]
+    fn test_check_request_rejects_cross_site_requests() {
+        let raw = |head: &str| {
+            let text = format!("{head}\r\nContent-Length: 2\r\n\r\n{{}}");
+            let request = read_request(&mut text.as_bytes()).unwrap();
+            check_request(&request).map(|response| response.status)
+        };
+
+        assert_eq!(raw("GET /ready HTTP/1.1\r\nHost: localhost:7777"), None);
+        assert_eq!(raw("GET /ready HTTP/1.1\r\nHost: [::1]:7777"), None);
+        assert_eq!(
+            raw("GET /ready HTTP/1.1\r\nHost: evil.example:7777"),
+            Some(403)
+        );
+        assert_eq!(
+            raw(
+                "POST /issues HTTP/1.1\r\nHost: 127.0.0.1:7777\r\nContent-Type: application/json; charset=utf-8"
+            ),
+            None
+        );
+        assert_eq!(
+            raw("POST /issues HTTP/1.1\r\nHost: 127.0.0.1:7777\r\nContent-Type: text/plain"),
+            Some(415)
+        );
+        assert_eq!(
+            raw("POST /issues HTTP/1.1\r\nHost: 127.0.0.1:7777"),
+            Some(415)
+        );
+        assert_eq!(
+            raw(
+                "POST /issues HTTP/1.1\r\nHost: localhost\r\nOrigin: https://evil.example\r\nContent-Type: application/json"
+            ),
+            Some(403)
+        );
+        assert_eq!(
+            raw(
+                "POST /issues HTTP/1.1\r\nHost: localhost\r\nOrigin: http://localhost:3000\r\nContent-Type: application/json"
+            ),
+            None
+        );
+        assert_eq!(raw("GET /ready HTTP/1.1\r\nOrigin: null"), Some(403));
+    }
+
+    #[test]
+    fn test_create_show_and_errors() {
+        let api = api();
+        let mut storage = SqliteStorage::open_memory().unwrap();
+
+        let created = api.handle(
+            &mut storage,
+            &request(
+                "POST",
+                "/issues",
+                r#"{"title":"Served","priority":"1","labels":["api"]}"#,
+            ),
+        );
+        assert_eq!(created.status, 201);
+        let id = body(&created)["id"].as_str().unwrap().to_string();
+        assert_eq!(body(&created)["labels"][0], "api");
+
+        let listed = api.handle(&mut storage, &request("GET", "/issues?priority=1", ""));
+        assert_eq!(listed.status, 200);
+        assert_eq!(body(&listed)[0]["id"], id.as_str());
+        assert_eq!(body(&listed)[0]["dependency_count"], 0);
+
+        let missing = api.handle(&mut storage, &request("GET", "/issues/bd-zzzzzz", ""));
+        assert_eq!(missing.status, 404);
+        assert_eq!(body(&missing)["error"]["code"], "ISSUE_NOT_FOUND");
+
+        let invalid = api.handle(
+            &mut storage,
+            &request("PATCH", &format!("/issues/{id}"), "{}"),
+        );
+        assert_eq!(invalid.status, 400);
+
+        let closed = api.handle(
+            &mut storage,
+            &request(
+                "POST",
+                &format!("/issues/{id}/close"),
+                r#"{"reason":"shipped"}"#,
+            ),
+        );
+        assert_eq!(closed.status, 200);
+        assert_eq!(body(&closed)["status"], "closed");
+        assert_eq!(body(&closed)["close_reason"], "shipped");
+    }
+
+    #[test]
+    fn test_patch_claim_respects_leases_and_assignees() {
+        let api = api();
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let mut create = |title: &str| {
+            let created = api.handle(
+                &mut storage,
+                &request("POST", "/issues", &format!(r#"{{"title":"{title}"}}"#)),
+            );
+            body(&created)["id"].as_str().unwrap().to_string()
+        };
+        let leased = create("Leased");
+        let assigned = create("Assigned");
+        let free = create("Free");
+        storage
+            .claim_issue(&leased, "alice", chrono::Duration::minutes(30))
+            .unwrap();
+        storage
+            .update_issue(
+                &assigned,
+                &IssueUpdate {
+                    assignee: Some(Some("bob".to_string())),
+                    ..IssueUpdate::default()
+                },
+                "bob",
+            )
+            .unwrap();
+
+        let claim = |storage: &mut SqliteStorage, id: &str| {
+            api.handle(
+                storage,
+                &request("PATCH", &format!("/issues/{id}"), r#"{"claim":true}"#),
+            )
+        };
+        let held = claim(&mut storage, &leased);
+        assert_eq!(held.status, 409);
+        assert_eq!(body(&held)["error"]["code"], "LEASE_HELD");
+        assert_eq!(claim(&mut storage, &assigned).status, 400);
+        // Closing someone else's leased issue is refused the same way
+        let close = api.handle(
+            &mut storage,
+            &request("POST", &format!("/issues/{leased}/close"), "{}"),
+        );
+        assert_eq!(close.status, 409);
+        let issue = storage.get_issue(&assigned).unwrap().unwrap();
+        assert_eq!(issue.assignee.as_deref(), Some("bob"));
+
+        let claimed = claim(&mut storage, &free);
+        assert_eq!(claimed.status, 200);
+        assert_eq!(body(&claimed)["assignee"], "tester");
+        assert_eq!(body(&claimed)["status"], "in_progress");
+    }
+}
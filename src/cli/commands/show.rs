@@ -1,12 +1,16 @@
 //! Show command implementation.
 
 use crate::cli::{ShowArgs, resolve_output_format_basic};
-use crate::config;
+use crate::config::{self, routing};
 use crate::error::{BeadsError, Result};
-use crate::format::{format_priority_label, format_status_icon_colored};
+use crate::format::{
+    IssueDetails, format_priority_label, format_status_icon_colored, format_time_tracking,
+};
+use crate::model::{Status, moved_to};
 use crate::output::{IssuePanel, OutputContext, OutputMode};
 use crate::util::id::{IdResolver, ResolverConfig};
 use std::fmt::Write as FmtWrite;
+use std::path::Path;
 
 /// Execute the show command.
 ///
@@ -20,7 +24,7 @@ pub fn execute(
     outer_ctx: &OutputContext,
 ) -> Result<()> {
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
-    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let storage_ctx = config::open_storage_as_of(&beads_dir, cli, args.as_of.as_deref())?;
     let storage = &storage_ctx.storage;
 
     let mut target_ids = args.ids.clone();
@@ -52,10 +56,18 @@ pub fn execute(
         )?;
 
         // Fetch full details including comments and events
-        if let Some(details) = storage.get_issue_details(&resolution.id, true, false, 10)? {
+        let Some(details) = storage.get_issue_details(&resolution.id, true, false, 10)? else {
+            return Err(BeadsError::IssueNotFound { id: resolution.id });
+        };
+        // Forwarding tombstones point at the current state of other projects
+        if args.no_follow || args.as_of.is_some() {
             details_list.push(details);
         } else {
-            return Err(BeadsError::IssueNotFound { id: resolution.id });
+            details_list.push(follow_moves(
+                details,
+                &beads_dir,
+                ctx.is_rich() || ctx.is_plain(),
+            )?);
         }
     }
 
@@ -87,12 +99,52 @@ pub fn execute(
     Ok(())
 }
 
-fn print_issue_details(details: &crate::format::IssueDetails, use_color: bool) {
+/// Follow `moved-to:` forwarding pointers left by `br move` into the projects
+/// the issue was moved to. Stops at the last issue that can be found.
+fn follow_moves(
+    mut details: IssueDetails,
+    beads_dir: &Path,
+    announce: bool,
+) -> Result<IssueDetails> {
+    const MAX_HOPS: usize = 8;
+
+    let mut current_dir = beads_dir.to_path_buf();
+    for _ in 0..MAX_HOPS {
+        if details.issue.status != Status::Tombstone {
+            break;
+        }
+        let Some(new_id) = moved_to(&details.labels).map(str::to_string) else {
+            break;
+        };
+        let route = routing::resolve_route(&new_id, &current_dir)?;
+        let storage =
+            config::open_storage_with_cli(&route.beads_dir, &config::CliOverrides::default())?
+                .storage;
+        let Some(next) = storage.get_issue_details(&new_id, true, false, 10)? else {
+            tracing::warn!(from = %details.issue.id, to = %new_id, "Moved issue not found");
+            break;
+        };
+        if announce {
+            eprintln!(
+                "{} moved to {} ({})",
+                details.issue.id,
+                new_id,
+                routing::project_name(&route.beads_dir)
+            );
+        }
+        current_dir = route.beads_dir;
+        details = next;
+    }
+    Ok(details)
+}
+
+/// Print issue details in the plain `br show` layout.
+pub fn print_issue_details(details: &IssueDetails, use_color: bool) {
     let output = format_issue_details(details, use_color);
     print!("{output}");
 }
 
-fn format_issue_details(details: &crate::format::IssueDetails, use_color: bool) -> String {
+fn format_issue_details(details: &IssueDetails, use_color: bool) -> String {
     let mut output = String::new();
     let issue = &details.issue;
     let status_icon = format_status_icon_colored(&issue.status, use_color);
@@ -134,6 +186,22 @@ fn format_issue_details(details: &crate::format::IssueDetails, use_color: bool)
         let _ = writeln!(output, "Labels: {}", details.labels.join(", "));
     }
 
+    if let Some(time) =
+        format_time_tracking(issue.estimated_minutes.map(i64::from), details.time_spent)
+    {
+        let _ = writeln!(output, "Time: {time}");
+    }
+
+    if let Some(compacted_at) = issue.compacted_at {
+        let _ = writeln!(
+            output,
+            "Compacted: level {} on {} (originally {} chars)",
+            issue.compaction_level.unwrap_or(0),
+            compacted_at.format("%Y-%m-%d"),
+            issue.original_size.unwrap_or(0)
+        );
+    }
+
     if let Some(desc) = &issue.description {
         output.push('\n');
         let _ = writeln!(output, "{desc}");
@@ -227,6 +295,8 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         }
     }
 
@@ -383,6 +453,7 @@ mod tests {
             comments: Vec::new(),
             events: Vec::new(),
             parent: None,
+            time_spent: None,
         };
         let json = serde_json::to_string_pretty(&vec![details]).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
@@ -419,6 +490,7 @@ mod tests {
             }],
             events: Vec::new(),
             parent: None,
+            time_spent: None,
         };
         let output = format_issue_details(&details, false);
         assert!(output.contains("Dependencies:"));
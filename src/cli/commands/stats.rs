@@ -12,6 +12,7 @@ use crate::format::{
 use crate::model::{IssueType, Status};
 use crate::output::{OutputContext, OutputMode};
 use crate::storage::{ListFilters, SqliteStorage};
+use crate::util::time::format_minutes;
 use chrono::Utc;
 use rich_rust::prelude::*;
 use std::collections::BTreeMap;
@@ -32,7 +33,7 @@ pub fn execute(
     outer_ctx: &OutputContext,
 ) -> Result<()> {
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
-    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let storage_ctx = config::open_storage_as_of(&beads_dir, cli, args.as_of.as_deref())?;
     let storage = &storage_ctx.storage;
     let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
     let use_color = config::should_use_color(&config_layer);
@@ -72,8 +73,9 @@ pub fn execute(
     }
 
     // Compute recent activity by default (matches bd behavior).
-    // Use --no-activity to skip this (for performance).
-    let recent_activity = if args.no_activity {
+    // Use --no-activity to skip this (for performance). Git history is not
+    // rewound, so --as-of skips it too.
+    let recent_activity = if args.no_activity || args.as_of.is_some() {
         None
     } else {
         compute_recent_activity(&beads_dir, args.activity_hours)
@@ -197,6 +199,19 @@ fn compute_summary(
     // blocked_by_status is unused but kept for potential future use
     let _ = blocked_by_status;
 
+    // Estimate vs. logged time (own entries only, so epics aren't double-counted)
+    let rollup = storage.get_time_rollup()?;
+    let (estimated_minutes, logged_minutes) = issues
+        .iter()
+        .filter(|i| i.status != Status::Tombstone)
+        .fold((0_i64, 0_i64), |(estimated, logged), issue| {
+            (
+                estimated + i64::from(issue.estimated_minutes.unwrap_or(0)),
+                logged + rollup.get(&issue.id).map_or(0, |t| t.logged_minutes),
+            )
+        });
+    let tracked = estimated_minutes > 0 || logged_minutes > 0;
+
     Ok(StatsSummary {
         total_issues: total,
         open_issues: open,
@@ -209,6 +224,8 @@ fn compute_summary(
         pinned_issues: pinned,
         epics_eligible_for_closure: epics_eligible,
         average_lead_time_hours: avg_lead_time,
+        estimated_minutes: tracked.then_some(estimated_minutes),
+        logged_minutes: tracked.then_some(logged_minutes),
     })
 }
 
@@ -440,7 +457,7 @@ fn print_text_output(output: &Statistics) {
     }
 
     // Extended section (matches bd format)
-    if s.average_lead_time_hours.is_some() || s.tombstone_issues > 0 {
+    if s.average_lead_time_hours.is_some() || s.tombstone_issues > 0 || s.logged_minutes.is_some() {
         println!("\nExtended:");
         if let Some(avg_hours) = s.average_lead_time_hours {
             // Format like bd: "N.N hours" or "N days" for large values
@@ -458,6 +475,13 @@ fn print_text_output(output: &Statistics) {
                 s.tombstone_issues
             );
         }
+        if let (Some(estimated), Some(logged)) = (s.estimated_minutes, s.logged_minutes) {
+            println!(
+                "  Time Logged:            {} of {} estimated",
+                format_minutes(logged),
+                format_minutes(estimated)
+            );
+        }
     }
 
     for breakdown in &output.breakdowns {
@@ -544,6 +568,16 @@ fn render_stats_rich(output: &Statistics, ctx: &OutputContext) {
         content.append("\n\n");
     }
 
+    // === Time Tracking ===
+    if let (Some(estimated), Some(logged)) = (s.estimated_minutes, s.logged_minutes) {
+        content.append_styled("\u{23f1} Time\n", theme.section.clone());
+        content.append_styled("   Logged: ", theme.dimmed.clone());
+        content.append(&format_minutes(logged));
+        content.append_styled("    Estimated: ", theme.dimmed.clone());
+        content.append(&format_minutes(estimated));
+        content.append("\n\n");
+    }
+
     // === Health Warnings ===
     let mut warnings = Vec::new();
     if s.blocked_issues > 5 {
@@ -733,6 +767,8 @@ mod tests {
             dependencies: vec![],
             comments: vec![],
             content_hash: None,
+            recurrence: None,
+            worklog: vec![],
         }
     }
 
@@ -1,20 +1,26 @@
 //! Sync command implementation.
 //!
 //! Provides explicit JSONL sync actions without git operations.
-//! Supports `--flush-only` (export) and `--import-only` (import).
+//! Supports `--flush-only` (export), `--import-only` (import), `--merge`
+//! (3-way merge), and `br sync resolve` for recorded merge conflicts.
 
-use crate::cli::SyncArgs;
+use crate::cli::{SyncArgs, SyncCommands, SyncResolveArgs};
 use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::output::OutputContext;
+use crate::storage::undo::SYSTEM_ACTOR;
 use crate::sync::history::HistoryConfig;
+use crate::sync::resolve::{
+    ConflictChoice, FieldChoice, PendingConflict, PendingMerge, ResolvedOutcome, Side,
+    WHOLE_ISSUE_KEY, conflict_kind_label,
+};
 use crate::sync::{
-    ConflictResolution, ExportConfig, ExportEntityType, ExportError, ExportErrorPolicy,
-    ImportConfig, METADATA_JSONL_CONTENT_HASH, METADATA_LAST_EXPORT_TIME,
-    METADATA_LAST_IMPORT_TIME, MergeContext, OrphanMode, compute_jsonl_hash, count_issues_in_jsonl,
-    export_to_jsonl_with_policy, finalize_export, get_issue_ids_from_jsonl, import_from_jsonl,
-    load_base_snapshot, read_issues_from_jsonl, require_safe_sync_overwrite_path,
-    save_base_snapshot, three_way_merge,
+    ConflictResolution, ConflictType, ExportConfig, ExportEntityType, ExportError,
+    ExportErrorPolicy, ImportConfig, METADATA_JSONL_CONTENT_HASH, METADATA_LAST_EXPORT_TIME,
+    METADATA_LAST_IMPORT_TIME, MergeContext, MergeReport, OrphanMode, compute_jsonl_hash,
+    count_issues_in_jsonl, export_to_jsonl_with_policy, finalize_export, get_issue_ids_from_jsonl,
+    import_from_jsonl, issue_changed_since, load_base_snapshot, read_issues_from_jsonl,
+    require_safe_sync_overwrite_path, require_valid_sync_path, save_base_snapshot, three_way_merge,
 };
 use rich_rust::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -105,6 +111,19 @@ pub fn execute(
         "Resolved sync path policy"
     );
 
+    if let Some(SyncCommands::Resolve(resolve_args)) = &args.command {
+        return execute_resolve(
+            &mut storage,
+            &path_policy,
+            args,
+            resolve_args,
+            use_json,
+            show_progress,
+            retention_days,
+            ctx,
+        );
+    }
+
     // Handle --status flag
     if args.status {
         return execute_status(&storage, &path_policy, use_json, ctx);
@@ -139,7 +158,6 @@ pub fn execute(
             json,
             show_progress,
             retention_days,
-            cli,
             ctx,
         )
     } else {
@@ -1101,11 +1119,101 @@ fn execute_merge(
     use_json: bool,
     show_progress: bool,
     retention_days: Option<u64>,
-    cli: &config::CliOverrides,
     ctx: &OutputContext,
 ) -> Result<()> {
     info!("Starting 3-way merge");
     let beads_dir = &path_policy.beads_dir;
+
+    // 1-3. Load base snapshot, local DB state, and external JSONL state
+    let context = load_merge_context(storage, path_policy)?;
+
+    // 4. Perform Merge
+    let strategy: ConflictResolution = args.strategy.into();
+    let tombstones = None;
+
+    let report = three_way_merge(&context, strategy, tombstones)?;
+
+    // 5. Apply Changes to DB
+    info!(
+        kept = report.kept.len(),
+        deleted = report.deleted.len(),
+        conflicts = report.conflicts.len(),
+        "Merge calculated"
+    );
+
+    if report.has_conflicts() {
+        // Nothing is applied; record the conflicts so `br sync resolve` can finish the merge.
+        let jsonl_hash = if path_policy.jsonl_path.exists() {
+            compute_jsonl_hash(&path_policy.jsonl_path)?
+        } else {
+            String::new()
+        };
+        let pending = PendingMerge {
+            created_at: chrono::Utc::now(),
+            jsonl_hash,
+            conflicts: report
+                .conflicts
+                .iter()
+                .map(|(id, kind)| PendingConflict {
+                    id: id.clone(),
+                    kind: *kind,
+                    base: context.base.get(id).cloned(),
+                    local: context.left.get(id).cloned(),
+                    external: context.right.get(id).cloned(),
+                    fields: report
+                        .field_conflicts
+                        .iter()
+                        .filter(|(cid, _)| cid == id)
+                        .map(|(_, conflict)| conflict.clone())
+                        .collect(),
+                    choices: std::collections::BTreeMap::new(),
+                })
+                .collect(),
+        };
+        require_valid_sync_path(&PendingMerge::path(beads_dir), beads_dir)?;
+        pending.save(beads_dir)?;
+
+        if ctx.is_rich() {
+            render_merge_conflicts_rich(&report.conflicts, ctx);
+        }
+        let mut msg = String::from("Merge conflicts detected:\n");
+        for (id, kind) in &report.conflicts {
+            use std::fmt::Write;
+            let _ = writeln!(msg, "  - {id}: {kind:?}");
+            for (_, conflict) in report.field_conflicts.iter().filter(|(cid, _)| cid == id) {
+                let _ = writeln!(
+                    msg,
+                    "      {}: local={} external={}",
+                    conflict.field, conflict.local, conflict.external
+                );
+            }
+        }
+        msg.push_str("Conflicts recorded in .beads/merge-conflicts.json; run `br sync resolve`.");
+        return Err(BeadsError::Config(msg));
+    }
+
+    apply_merge_report(
+        storage,
+        &report,
+        path_policy,
+        args,
+        show_progress,
+        retention_days,
+    )?;
+    // A fresh merge supersedes any conflicts recorded earlier.
+    PendingMerge::clear(beads_dir)?;
+
+    print_merge_result(&report, use_json, ctx);
+
+    Ok(())
+}
+
+/// Load base (snapshot), left (local DB), and right (external JSONL) merge states.
+fn load_merge_context(
+    storage: &crate::storage::SqliteStorage,
+    path_policy: &SyncPathPolicy,
+) -> Result<MergeContext> {
+    let beads_dir = &path_policy.beads_dir;
     let jsonl_path = &path_policy.jsonl_path;
 
     // 1. Load Base State (ancestor)
@@ -1145,41 +1253,24 @@ fn execute_merge(
     }
     debug!(right_count = right.len(), "Loaded external state (JSONL)");
 
-    // 4. Perform Merge
-    let context = MergeContext::new(base, left, right);
-    // Currently hardcoded to PreferNewer (Last Write Wins).
-    // Future work: support configurable conflict strategy via CLI args if needed.
-    let strategy = ConflictResolution::PreferNewer;
-    let tombstones = None;
-
-    let report = three_way_merge(&context, strategy, tombstones);
-
-    // 5. Apply Changes to DB
-    info!(
-        kept = report.kept.len(),
-        deleted = report.deleted.len(),
-        conflicts = report.conflicts.len(),
-        "Merge calculated"
-    );
-
-    if report.has_conflicts() {
-        // For now, fail on conflicts. Future: interactive resolution or force flags.
-        if ctx.is_rich() {
-            render_merge_conflicts_rich(&report.conflicts, ctx);
-        }
-        let mut msg = String::from("Merge conflicts detected:\n");
-        for (id, kind) in &report.conflicts {
-            use std::fmt::Write;
-            let _ = writeln!(msg, "  - {id}: {kind:?}");
-        }
-        return Err(BeadsError::Config(msg));
-    }
+    Ok(MergeContext::new(base, left, right))
+}
 
-    let _actor = cli.actor.as_deref().unwrap_or("br");
+/// Apply a conflict-free merge report: DB writes, base snapshot, and JSONL export.
+fn apply_merge_report(
+    storage: &mut crate::storage::SqliteStorage,
+    report: &MergeReport,
+    path_policy: &SyncPathPolicy,
+    args: &SyncArgs,
+    show_progress: bool,
+    retention_days: Option<u64>,
+) -> Result<()> {
+    let beads_dir = &path_policy.beads_dir;
+    let jsonl_path = &path_policy.jsonl_path;
 
     // Apply deletions
     for id in &report.deleted {
-        storage.delete_issue(id, "system", "merge deletion", Some(chrono::Utc::now()))?;
+        storage.delete_issue(id, SYSTEM_ACTOR, "merge deletion", Some(chrono::Utc::now()))?;
     }
 
     // Apply updates/creates (upsert)
@@ -1189,6 +1280,8 @@ fn execute_merge(
         storage.sync_labels_for_import(&issue.id, &issue.labels)?;
         storage.sync_dependencies_for_import(&issue.id, &issue.dependencies)?;
         storage.sync_comments_for_import(&issue.id, &issue.comments)?;
+        storage.sync_recurrence_for_import(&issue.id, issue.recurrence.as_ref())?;
+        storage.sync_worklog_for_import(&issue.id, &issue.worklog)?;
     }
 
     // Rebuild cache
@@ -1218,18 +1311,34 @@ fn execute_merge(
     let (export_result, _) = export_to_jsonl_with_policy(storage, jsonl_path, &export_config)?;
     finalize_export(storage, &export_result, Some(&export_result.issue_hashes))?;
 
-    // Output success message
+    Ok(())
+}
+
+fn print_merge_result(report: &MergeReport, use_json: bool, ctx: &OutputContext) {
     if use_json {
         let output = serde_json::json!({
             "status": "success",
             "merged_issues": report.kept.len(),
             "deleted_issues": report.deleted.len(),
             "conflicts": report.conflicts.len(),
+            "field_conflicts": report
+                .field_conflicts
+                .iter()
+                .map(|(id, conflict)| {
+                    serde_json::json!({
+                        "id": id,
+                        "field": conflict.field,
+                        "base": conflict.base,
+                        "local": conflict.local,
+                        "external": conflict.external,
+                    })
+                })
+                .collect::<Vec<_>>(),
             "notes": report.notes,
         });
         ctx.json_pretty(&output);
     } else if ctx.is_rich() {
-        render_merge_result_rich(&report, ctx);
+        render_merge_result_rich(report, ctx);
     } else {
         println!("Merge complete:");
         println!("  Kept/Updated: {} issues", report.kept.len());
@@ -1240,9 +1349,383 @@ fn execute_merge(
                 println!("    - {id}: {note}");
             }
         }
+        if !report.field_conflicts.is_empty() {
+            println!("  Field conflicts (auto-resolved):");
+            for (id, conflict) in &report.field_conflicts {
+                println!(
+                    "    - {id}.{}: local={} external={}",
+                    conflict.field, conflict.local, conflict.external
+                );
+            }
+        }
         println!("  Base snapshot updated.");
         println!("  JSONL exported.");
     }
+}
+
+/// Execute `br sync resolve`.
+#[allow(clippy::too_many_arguments)]
+fn execute_resolve(
+    storage: &mut crate::storage::SqliteStorage,
+    path_policy: &SyncPathPolicy,
+    args: &SyncArgs,
+    resolve_args: &SyncResolveArgs,
+    use_json: bool,
+    show_progress: bool,
+    retention_days: Option<u64>,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = &path_policy.beads_dir;
+    let Some(mut pending) = PendingMerge::load(beads_dir)? else {
+        return Err(BeadsError::validation(
+            "resolve",
+            "no pending merge conflicts (run `br sync --merge --strategy manual` first)",
+        ));
+    };
+
+    if resolve_args.abort {
+        PendingMerge::clear(beads_dir)?;
+        if use_json {
+            ctx.json_pretty(&serde_json::json!({
+                "status": "aborted",
+                "discarded": pending.conflicts.len(),
+            }));
+        } else {
+            println!(
+                "Discarded {} pending merge conflict(s); nothing was merged.",
+                pending.conflicts.len()
+            );
+        }
+        return Ok(());
+    }
+
+    if let Some(path) = &resolve_args.file {
+        let contents = fs::read_to_string(path)?;
+        let resolutions: std::collections::BTreeMap<String, ConflictChoice> =
+            serde_json::from_str(&contents).map_err(|e| {
+                BeadsError::validation("file", format!("invalid resolution file: {e}"))
+            })?;
+        pending.apply_resolutions(&resolutions)?;
+    }
+    for spec in &resolve_args.pick {
+        let (target, side) = split_resolution_spec(spec)?;
+        let side: Side = side.parse()?;
+        apply_resolution_target(&mut pending, target, FieldChoice::Side(side))?;
+    }
+    for spec in &resolve_args.set {
+        let (target, raw) = split_resolution_spec(spec)?;
+        let value = serde_json::from_str(raw)
+            .unwrap_or_else(|_| serde_json::Value::String(raw.to_string()));
+        apply_resolution_target(&mut pending, target, FieldChoice::Value { value })?;
+    }
+
+    let scripted = resolve_args.file.is_some()
+        || !resolve_args.pick.is_empty()
+        || !resolve_args.set.is_empty();
+    if !resolve_args.list && !scripted && !use_json && std::io::stdin().is_terminal() {
+        prompt_resolutions(&mut pending, ctx.width())?;
+    }
+
+    if !resolve_args.list {
+        pending.save(beads_dir)?;
+    }
+
+    let unresolved = pending.unresolved_count();
+    if resolve_args.list || unresolved > 0 {
+        if use_json {
+            ctx.json_pretty(&serde_json::json!({
+                "status": "pending",
+                "unresolved": unresolved,
+                "conflicts": pending.conflicts,
+            }));
+        } else {
+            for conflict in &pending.conflicts {
+                print_conflict_diff(conflict, ctx.width());
+            }
+            println!(
+                "{unresolved} of {} conflict(s) unresolved. Resolve with --pick, --set, or --file.",
+                pending.conflicts.len()
+            );
+        }
+        return Ok(());
+    }
+
+    let report = finish_resolved_merge(storage, path_policy, &pending)?;
+    apply_merge_report(
+        storage,
+        &report,
+        path_policy,
+        args,
+        show_progress,
+        retention_days,
+    )?;
+    PendingMerge::clear(beads_dir)?;
+
+    print_merge_result(&report, use_json, ctx);
+    Ok(())
+}
+
+/// Re-run the merge and substitute the recorded resolutions for its conflicts.
+///
+/// Refuses when the JSONL file, or the database copy of a conflicted issue,
+/// changed since the conflicts were recorded: the resolutions were made
+/// against those versions and would silently overwrite the newer edits.
+fn finish_resolved_merge(
+    storage: &crate::storage::SqliteStorage,
+    path_policy: &SyncPathPolicy,
+    pending: &PendingMerge,
+) -> Result<MergeReport> {
+    let jsonl_hash = if path_policy.jsonl_path.exists() {
+        compute_jsonl_hash(&path_policy.jsonl_path)?
+    } else {
+        String::new()
+    };
+    if jsonl_hash != pending.jsonl_hash {
+        return Err(BeadsError::Config(
+            "JSONL changed since the conflicts were recorded; run `br sync --merge --strategy manual` again"
+                .to_string(),
+        ));
+    }
+
+    let context = load_merge_context(storage, path_policy)?;
+    for conflict in &pending.conflicts {
+        let unchanged = match (conflict.local.as_ref(), context.left.get(&conflict.id)) {
+            (Some(recorded), Some(current)) => {
+                recorded.compute_content_hash() == current.compute_content_hash()
+                    && !issue_changed_since(recorded, current)
+            }
+            (None, None) => true,
+            _ => false,
+        };
+        if !unchanged {
+            return Err(BeadsError::Config(format!(
+                "{} changed in the database since the conflicts were recorded; run `br sync --merge --strategy manual` again",
+                conflict.id
+            )));
+        }
+    }
+
+    let mut report = three_way_merge(&context, ConflictResolution::Manual, None)?;
+    let outcomes = pending.outcomes()?;
+
+    for (id, _) in std::mem::take(&mut report.conflicts) {
+        match outcomes.get(&id) {
+            Some(ResolvedOutcome::Keep(issue)) => {
+                report
+                    .notes
+                    .push((id.clone(), "Resolved manually".to_string()));
+                report.kept.push(issue.as_ref().clone());
+            }
+            Some(ResolvedOutcome::Delete) => {
+                if context.left.contains_key(&id) {
+                    report.deleted.push(id);
+                }
+            }
+            None => {
+                return Err(BeadsError::Config(format!(
+                    "New merge conflict on {id}; run `br sync --merge --strategy manual` again"
+                )));
+            }
+        }
+    }
+    report.field_conflicts.clear();
+
+    Ok(report)
+}
+
+/// Split `<target>=<value>` at the first `=`.
+fn split_resolution_spec(spec: &str) -> Result<(&str, &str)> {
+    spec.split_once('=')
+        .filter(|(target, _)| !target.is_empty())
+        .ok_or_else(|| {
+            BeadsError::validation(
+                "resolution",
+                format!("expected <id>[.<field>]=<value>, got '{spec}'"),
+            )
+        })
+}
+
+/// Apply a choice to `<id>` (every conflicting field) or `<id>.<field>`.
+///
+/// IDs may themselves contain dots (`bd-abc.1`), so an exact ID match wins.
+fn apply_resolution_target(
+    pending: &mut PendingMerge,
+    target: &str,
+    choice: FieldChoice,
+) -> Result<()> {
+    if pending.conflicts.iter().any(|c| c.id == target) {
+        let conflict = pending.conflict_mut(target)?;
+        for key in conflict.required_keys() {
+            conflict.choose(&key, choice.clone())?;
+        }
+        return Ok(());
+    }
+    let Some((id, field)) = target.rsplit_once('.') else {
+        return Err(BeadsError::validation(
+            "resolution",
+            format!("no pending merge conflict for {target}"),
+        ));
+    };
+    pending.conflict_mut(id)?.choose(field, choice)
+}
+
+/// Render a value for the side-by-side diff.
+fn diff_cell(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => "(none)".to_string(),
+        serde_json::Value::String(s) => s.split_whitespace().collect::<Vec<_>>().join(" "),
+        serde_json::Value::Object(map) if map.is_empty() => "(none)".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn fit_cell(text: &str, width: usize) -> String {
+    let len = text.chars().count();
+    if len <= width {
+        format!("{text:<width$}")
+    } else {
+        let truncated: String = text.chars().take(width.saturating_sub(1)).collect();
+        format!("{truncated}…")
+    }
+}
+
+/// Print a side-by-side (base / local / external) diff of one conflict.
+fn print_conflict_diff(conflict: &PendingConflict, width: usize) {
+    let field_width = 14;
+    let column = (width.saturating_sub(field_width + 8) / 3).max(12);
+    let title = conflict
+        .local
+        .as_ref()
+        .or(conflict.external.as_ref())
+        .or(conflict.base.as_ref())
+        .map_or("", |issue| issue.title.as_str());
+
+    println!(
+        "{} ({} conflict): {title}",
+        conflict.id,
+        conflict_kind_label(conflict.kind)
+    );
+    println!(
+        "  {} {} {} {}",
+        fit_cell("FIELD", field_width),
+        fit_cell("BASE", column),
+        fit_cell("LOCAL", column),
+        fit_cell("EXTERNAL", column)
+    );
+
+    let rows: Vec<(String, String, String, String)> =
+        if conflict.kind == ConflictType::FieldConflict {
+            conflict
+                .fields
+                .iter()
+                .map(|f| {
+                    (
+                        f.field.clone(),
+                        diff_cell(&f.base),
+                        diff_cell(&f.local),
+                        diff_cell(&f.external),
+                    )
+                })
+                .collect()
+        } else {
+            let describe = |issue: Option<&crate::model::Issue>| {
+                issue.map_or_else(
+                    || "(deleted)".to_string(),
+                    |i| format!("{} [{}]", i.title, i.status.as_str()),
+                )
+            };
+            vec![(
+                WHOLE_ISSUE_KEY.to_string(),
+                describe(conflict.base.as_ref()),
+                describe(conflict.local.as_ref()),
+                describe(conflict.external.as_ref()),
+            )]
+        };
+
+    for (field, base, local, external) in rows {
+        let status = match conflict.choices.get(&field) {
+            Some(FieldChoice::Side(side)) => format!("  -> {side:?}").to_lowercase(),
+            Some(FieldChoice::Value { value }) => format!("  -> edited: {}", diff_cell(value)),
+            None => String::new(),
+        };
+        println!(
+            "  {} {} {} {}{status}",
+            fit_cell(&field, field_width),
+            fit_cell(&base, column),
+            fit_cell(&local, column),
+            fit_cell(&external, column)
+        );
+    }
+    println!();
+}
+
+/// Prompt for each unresolved field on the terminal. EOF stops prompting.
+fn prompt_resolutions(pending: &mut PendingMerge, width: usize) -> Result<()> {
+    use std::io::Write as _;
+
+    let stdin = std::io::stdin();
+    let mut line = String::new();
+
+    for conflict in &mut pending.conflicts {
+        if conflict.is_resolved() {
+            continue;
+        }
+        print_conflict_diff(conflict, width);
+        // Convergent creations have no common ancestor to pick
+        let base = if conflict.base.is_some() {
+            " / [b]ase"
+        } else {
+            ""
+        };
+
+        for key in conflict.unresolved_keys() {
+            let editable = key != WHOLE_ISSUE_KEY;
+            loop {
+                print!(
+                    "{} {key}: [l]ocal / [e]xternal{base}{} / [s]kip > ",
+                    conflict.id,
+                    if editable { " / [m]anual edit" } else { "" }
+                );
+                std::io::stdout().flush()?;
+
+                line.clear();
+                if stdin.lock().read_line(&mut line)? == 0 {
+                    println!();
+                    return Ok(());
+                }
+                let answer = line.trim();
+                if answer.is_empty() || answer.eq_ignore_ascii_case("s") {
+                    break;
+                }
+                if editable && (answer.eq_ignore_ascii_case("m") || answer == "edit") {
+                    print!("{} {key} value (JSON or text) > ", conflict.id);
+                    std::io::stdout().flush()?;
+                    line.clear();
+                    if stdin.lock().read_line(&mut line)? == 0 {
+                        println!();
+                        return Ok(());
+                    }
+                    let raw = line.trim_end_matches(['\n', '\r']);
+                    let value = serde_json::from_str(raw)
+                        .unwrap_or_else(|_| serde_json::Value::String(raw.to_string()));
+                    // A rejected choice asks again rather than dropping the
+                    // choices already made
+                    match conflict.choose(&key, FieldChoice::Value { value }) {
+                        Ok(()) => break,
+                        Err(e) => eprintln!("{e}"),
+                    }
+                    continue;
+                }
+                match answer
+                    .parse::<Side>()
+                    .and_then(|side| conflict.choose(&key, FieldChoice::Side(side)))
+                {
+                    Ok(()) => break,
+                    Err(e) => eprintln!("{e}"),
+                }
+            }
+        }
+    }
 
     Ok(())
 }
@@ -1394,6 +1877,8 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         }
     }
 
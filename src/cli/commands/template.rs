@@ -0,0 +1,691 @@
+//! Template command implementation.
+//!
+//! Templates are ordinary issues with `is_template` set. They are hidden from
+//! list/ready/search, and their child issues (via `parent-child` dependencies)
+//! are templates too. `br create --from-template <id> --var key=value` copies
+//! the whole tree, substituting `{{key}}` placeholders, and recreates the
+//! dependencies between the copied issues.
+
+use super::create::{CreateConfig, create_issue_impl};
+use crate::cli::{CreateArgs, TemplateCommands, TemplateCreateArgs, TemplateShowArgs};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::{DependencyType, Issue};
+use crate::output::OutputContext;
+use crate::storage::{ListFilters, SqliteStorage};
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use tracing::{debug, info};
+
+/// A template and its child templates, parents before children.
+#[derive(Debug, Clone, Serialize)]
+pub struct TemplateTree {
+    /// Template issues; the first entry is the root.
+    pub issues: Vec<Issue>,
+    /// Variables referenced as `{{name}}` anywhere in the tree.
+    pub variables: Vec<String>,
+}
+
+impl TemplateTree {
+    fn root(&self) -> &Issue {
+        &self.issues[0]
+    }
+}
+
+/// JSON output for `br template list`.
+#[derive(Serialize)]
+struct TemplateListItem {
+    id: String,
+    title: String,
+    issue_type: String,
+    child_count: usize,
+    variables: Vec<String>,
+}
+
+/// Execute the template command.
+///
+/// # Errors
+///
+/// Returns an error if database operations fail or if inputs are invalid.
+pub fn execute(
+    command: &TemplateCommands,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+
+    match command {
+        TemplateCommands::Create(args) => {
+            let config = CreateConfig {
+                id_config: config::id_config_from_layer(&layer),
+                default_priority: config::default_priority_from_layer(&layer)?,
+                default_issue_type: config::default_issue_type_from_layer(&layer)?,
+                actor: config::resolve_actor(&layer),
+            };
+            let resolver =
+                IdResolver::new(ResolverConfig::with_prefix(config.id_config.prefix.clone()));
+            template_create(args, &mut storage_ctx.storage, &resolver, &config, ctx)?;
+        }
+        TemplateCommands::List => template_list(&storage_ctx.storage, ctx)?,
+        TemplateCommands::Show(args) => {
+            let id_config = config::id_config_from_layer(&layer);
+            let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+            template_show(args, &storage_ctx.storage, &resolver, ctx)?;
+        }
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
+
+fn template_create(
+    args: &TemplateCreateArgs,
+    storage: &mut SqliteStorage,
+    resolver: &IdResolver,
+    config: &CreateConfig,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let parent = match &args.parent {
+        Some(input) => {
+            let parent_id = resolve_issue_id(storage, resolver, input)?;
+            if !is_template(storage, &parent_id)? {
+                return Err(BeadsError::validation(
+                    "parent",
+                    format!("{parent_id} is not a template"),
+                ));
+            }
+            Some(parent_id)
+        }
+        None => None,
+    };
+
+    let create_args = CreateArgs {
+        title: Some(args.title.clone()),
+        type_: args.type_.clone(),
+        priority: args.priority.clone(),
+        description: args.description.clone(),
+        labels: args.labels.clone(),
+        parent,
+        deps: args.deps.clone(),
+        estimate: args.estimate,
+        // Build the issue without writing it, then store it as a template.
+        dry_run: true,
+        ..CreateArgs::default()
+    };
+    let mut issue = create_issue_impl(storage, &create_args, config)?;
+    issue.is_template = true;
+    issue.design.clone_from(&args.design);
+    issue
+        .acceptance_criteria
+        .clone_from(&args.acceptance_criteria);
+    issue.content_hash = Some(issue.compute_content_hash());
+    storage.create_issue(&issue, &config.actor)?;
+
+    info!(id = %issue.id, "Template created");
+
+    if ctx.is_json() {
+        let full_issue =
+            storage
+                .get_issue_for_export(&issue.id)?
+                .ok_or_else(|| BeadsError::IssueNotFound {
+                    id: issue.id.clone(),
+                })?;
+        ctx.json_pretty(&full_issue);
+    } else {
+        ctx.success(&format!("Created template {}: {}", issue.id, issue.title));
+        let variables = template_variables(&issue);
+        if !variables.is_empty() {
+            ctx.print(&format!("Variables: {}", variables.join(", ")));
+        }
+    }
+
+    Ok(())
+}
+
+fn template_list(storage: &SqliteStorage, ctx: &OutputContext) -> Result<()> {
+    let filters = ListFilters {
+        include_closed: true,
+        include_deferred: true,
+        include_templates: true,
+        ..ListFilters::default()
+    };
+    let templates: Vec<Issue> = storage
+        .list_issues(&filters)?
+        .into_iter()
+        .filter(|issue| issue.is_template)
+        .collect();
+
+    let mut items = Vec::new();
+    for issue in &templates {
+        if storage
+            .get_parent_id(&issue.id)?
+            .is_some_and(|parent| templates.iter().any(|t| t.id == parent))
+        {
+            // Child templates are listed under their root by `br template show`.
+            continue;
+        }
+        let tree = load_template_tree(storage, &issue.id)?;
+        items.push(TemplateListItem {
+            id: issue.id.clone(),
+            title: issue.title.clone(),
+            issue_type: issue.issue_type.as_str().to_string(),
+            child_count: tree.issues.len() - 1,
+            variables: tree.variables,
+        });
+    }
+    items.sort_by(|a, b| a.id.cmp(&b.id));
+
+    if ctx.is_json() {
+        ctx.json_pretty(&items);
+    } else if items.is_empty() {
+        println!("No templates. Create one with `br template create <title>`.");
+    } else {
+        for item in &items {
+            let children = match item.child_count {
+                0 => String::new(),
+                1 => " (1 child)".to_string(),
+                n => format!(" ({n} children)"),
+            };
+            println!(
+                "{}  [{}] {}{children}",
+                item.id, item.issue_type, item.title
+            );
+            if !item.variables.is_empty() {
+                println!("    vars: {}", item.variables.join(", "));
+            }
+        }
+        println!("\n{} template(s)", items.len());
+    }
+
+    Ok(())
+}
+
+fn template_show(
+    args: &TemplateShowArgs,
+    storage: &SqliteStorage,
+    resolver: &IdResolver,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let id = resolve_issue_id(storage, resolver, &args.id)?;
+    if !is_template(storage, &id)? {
+        return Err(BeadsError::validation(
+            "id",
+            format!("{id} is not a template"),
+        ));
+    }
+    let tree = load_template_tree(storage, &id)?;
+
+    if ctx.is_json() {
+        ctx.json_pretty(&tree);
+        return Ok(());
+    }
+
+    let root = tree.root();
+    println!("{}: {}", root.id, root.title);
+    println!(
+        "Type: {}  Priority: {}",
+        root.issue_type.as_str(),
+        root.priority
+    );
+    if !tree.variables.is_empty() {
+        println!("Variables: {}", tree.variables.join(", "));
+    }
+    if !root.labels.is_empty() {
+        println!("Labels: {}", root.labels.join(", "));
+    }
+    if let Some(description) = root.description.as_deref().filter(|d| !d.is_empty()) {
+        println!("\n{description}");
+    }
+
+    if tree.issues.len() > 1 {
+        println!("\nChildren:");
+        let depth_of = template_depths(&tree);
+        for issue in &tree.issues[1..] {
+            let indent = "  ".repeat(depth_of.get(issue.id.as_str()).copied().unwrap_or(1));
+            let blockers: Vec<&str> = issue
+                .dependencies
+                .iter()
+                .filter(|dep| dep.dep_type != DependencyType::ParentChild)
+                .map(|dep| dep.depends_on_id.as_str())
+                .collect();
+            let after = if blockers.is_empty() {
+                String::new()
+            } else {
+                format!("  (after {})", blockers.join(", "))
+            };
+            println!("{indent}{}: {}{after}", issue.id, issue.title);
+        }
+    }
+
+    Ok(())
+}
+
+/// Instantiate a template for `br create --from-template`.
+///
+/// The root copy takes CLI overrides from `args` (title, type, priority,
+/// description, assignee, extra labels, parent, deps); child copies keep the
+/// template's values. Returns the created issues, root first.
+///
+/// # Errors
+///
+/// Returns an error if the template does not exist, a `{{variable}}` has no
+/// `--var`, or an issue or dependency cannot be created.
+pub fn instantiate_template(
+    storage: &mut SqliteStorage,
+    template_id: &str,
+    args: &CreateArgs,
+    config: &CreateConfig,
+) -> Result<Vec<Issue>> {
+    if !is_template(storage, template_id)? {
+        return Err(BeadsError::validation(
+            "from_template",
+            format!("{template_id} is not a template"),
+        ));
+    }
+    let tree = load_template_tree(storage, template_id)?;
+    let vars = parse_vars(&args.vars)?;
+
+    let missing: Vec<&str> = tree
+        .variables
+        .iter()
+        .filter(|name| !vars.contains_key(name.as_str()))
+        .map(String::as_str)
+        .collect();
+    if !missing.is_empty() {
+        return Err(BeadsError::validation(
+            "var",
+            format!("missing value for {}", missing.join(", ")),
+        ));
+    }
+
+    // One transaction: a failure on any issue or dependency leaves no
+    // half-instantiated tree behind
+    let created = storage.batch("instantiate_template", &config.actor, |storage| {
+        let mut id_map: HashMap<String, String> = HashMap::new();
+        let mut created = Vec::new();
+
+        for (index, template) in tree.issues.iter().enumerate() {
+            let mut labels: Vec<String> = template
+                .labels
+                .iter()
+                .map(|label| substitute(label, &vars))
+                .collect();
+
+            let create_args = if index == 0 {
+                labels.extend(args.labels.iter().cloned());
+                CreateArgs {
+                    title: Some(
+                        args.title
+                            .clone()
+                            .or_else(|| args.title_flag.clone())
+                            .unwrap_or_else(|| substitute(&template.title, &vars)),
+                    ),
+                    type_: args
+                        .type_
+                        .clone()
+                        .or_else(|| Some(template.issue_type.as_str().to_string())),
+                    priority: args
+                        .priority
+                        .clone()
+                        .or_else(|| Some(template.priority.0.to_string())),
+                    description: args.description.clone().or_else(|| {
+                        template
+                            .description
+                            .as_deref()
+                            .map(|text| substitute(text, &vars))
+                    }),
+                    labels,
+                    estimate: args.estimate.or(template.estimated_minutes),
+                    dry_run: true,
+                    ..args.clone()
+                }
+            } else {
+                let parent = storage
+                    .get_parent_id(&template.id)?
+                    .and_then(|parent| id_map.get(&parent).cloned());
+                CreateArgs {
+                    title: Some(substitute(&template.title, &vars)),
+                    type_: Some(template.issue_type.as_str().to_string()),
+                    priority: Some(template.priority.0.to_string()),
+                    description: template
+                        .description
+                        .as_deref()
+                        .map(|text| substitute(text, &vars)),
+                    labels,
+                    parent,
+                    estimate: template.estimated_minutes,
+                    dry_run: true,
+                    ..CreateArgs::default()
+                }
+            };
+
+            let mut issue = create_issue_impl(storage, &create_args, config)?;
+            issue.design = template
+                .design
+                .as_deref()
+                .map(|text| substitute(text, &vars));
+            issue.acceptance_criteria = template
+                .acceptance_criteria
+                .as_deref()
+                .map(|text| substitute(text, &vars));
+            issue.content_hash = Some(issue.compute_content_hash());
+            storage.create_issue(&issue, &config.actor)?;
+
+            debug!(template = %template.id, id = %issue.id, "Instantiated template issue");
+            id_map.insert(template.id.clone(), issue.id.clone());
+            created.push(issue);
+        }
+
+        // Recreate dependencies between template issues (parent-child links were
+        // made above). Dependencies on regular issues are kept as-is.
+        for template in &tree.issues {
+            let issue_id = &id_map[&template.id];
+            for dep in &template.dependencies {
+                if dep.dep_type == DependencyType::ParentChild {
+                    continue;
+                }
+                let target = match id_map.get(&dep.depends_on_id) {
+                    Some(mapped) => mapped.clone(),
+                    None if is_template(storage, &dep.depends_on_id)? => {
+                        debug!(
+                            template = %template.id,
+                            target = %dep.depends_on_id,
+                            "Skipping dependency on a template outside the tree"
+                        );
+                        continue;
+                    }
+                    None => dep.depends_on_id.clone(),
+                };
+                storage.add_dependency(issue_id, &target, dep.dep_type.as_str(), &config.actor)?;
+            }
+        }
+
+        Ok(created)
+    })?;
+
+    info!(
+        template = %template_id,
+        created = created.len(),
+        "Template instantiated"
+    );
+
+    Ok(created)
+}
+
+/// Load a template and its child templates, depth-first in ID order.
+///
+/// # Errors
+///
+/// Returns an error if the database query fails or the template is missing.
+pub fn load_template_tree(storage: &SqliteStorage, root_id: &str) -> Result<TemplateTree> {
+    let mut issues = Vec::new();
+    let mut stack = vec![root_id.to_string()];
+
+    while let Some(id) = stack.pop() {
+        let issue = storage
+            .get_issue_for_export(&id)?
+            .ok_or_else(|| BeadsError::IssueNotFound { id: id.clone() })?;
+
+        let mut children = Vec::new();
+        for dep in storage.get_dependents_with_metadata(&id)? {
+            if dep.dep_type == DependencyType::ParentChild.as_str()
+                && is_template(storage, &dep.id)?
+            {
+                children.push(dep.id);
+            }
+        }
+        children.sort_by(|a, b| natural_id_cmp(b, a));
+        stack.extend(children);
+
+        issues.push(issue);
+    }
+
+    let variables = issues
+        .iter()
+        .flat_map(template_variables)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+
+    Ok(TemplateTree { issues, variables })
+}
+
+/// Variables referenced in an issue's copied text fields and labels.
+fn template_variables(issue: &Issue) -> Vec<String> {
+    let texts = [
+        Some(issue.title.as_str()),
+        issue.description.as_deref(),
+        issue.design.as_deref(),
+        issue.acceptance_criteria.as_deref(),
+    ];
+    let mut names = BTreeSet::new();
+    for text in texts
+        .into_iter()
+        .flatten()
+        .chain(issue.labels.iter().map(String::as_str))
+    {
+        names.extend(
+            placeholders(text)
+                .into_iter()
+                .map(|(_, name)| name.to_string()),
+        );
+    }
+    names.into_iter().collect()
+}
+
+/// `{{name}}` placeholders in `text` as (byte range, trimmed name).
+fn placeholders(text: &str) -> Vec<(std::ops::Range<usize>, &str)> {
+    let mut found = Vec::new();
+    let mut offset = 0;
+    while let Some(start) = text[offset..].find("{{") {
+        let open = offset + start;
+        let Some(len) = text[open + 2..].find("}}") else {
+            break;
+        };
+        let close = open + 2 + len;
+        let name = text[open + 2..close].trim();
+        if !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.')
+        {
+            found.push((open..close + 2, name));
+        }
+        offset = close + 2;
+    }
+    found
+}
+
+/// Replace `{{name}}` placeholders with their values.
+fn substitute(text: &str, vars: &BTreeMap<String, String>) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+    for (range, name) in placeholders(text) {
+        if let Some(value) = vars.get(name) {
+            out.push_str(&text[last..range.start]);
+            out.push_str(value);
+            last = range.end;
+        }
+    }
+    out.push_str(&text[last..]);
+    out
+}
+
+/// Parse `--var key=value` arguments.
+fn parse_vars(raw: &[String]) -> Result<BTreeMap<String, String>> {
+    raw.iter()
+        .map(|entry| {
+            let (key, value) = entry.split_once('=').ok_or_else(|| {
+                BeadsError::validation("var", format!("expected key=value, got '{entry}'"))
+            })?;
+            let key = key.trim();
+            if key.is_empty() {
+                return Err(BeadsError::validation(
+                    "var",
+                    "variable name cannot be empty",
+                ));
+            }
+            Ok((key.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+/// Depth of each issue below the root, following parent-child links.
+fn template_depths(tree: &TemplateTree) -> HashMap<&str, usize> {
+    let mut depths = HashMap::new();
+    depths.insert(tree.root().id.as_str(), 0);
+    for issue in &tree.issues[1..] {
+        let parent_depth = issue
+            .dependencies
+            .iter()
+            .find(|dep| dep.dep_type == DependencyType::ParentChild)
+            .and_then(|dep| depths.get(dep.depends_on_id.as_str()).copied())
+            .unwrap_or(0);
+        depths.insert(issue.id.as_str(), parent_depth + 1);
+    }
+    depths
+}
+
+/// Order child IDs numerically (`bd-a.2` before `bd-a.10`).
+fn natural_id_cmp(a: &str, b: &str) -> std::cmp::Ordering {
+    let key = |id: &str| {
+        id.rsplit_once('.')
+            .and_then(|(_, n)| n.parse::<u64>().ok())
+            .unwrap_or(u64::MAX)
+    };
+    key(a).cmp(&key(b)).then_with(|| a.cmp(b))
+}
+
+fn is_template(storage: &SqliteStorage, id: &str) -> Result<bool> {
+    Ok(storage
+        .get_issue(id)?
+        .is_some_and(|issue| issue.is_template))
+}
+
+fn resolve_issue_id(storage: &SqliteStorage, resolver: &IdResolver, input: &str) -> Result<String> {
+    let all_ids = storage.get_all_ids()?;
+    resolver
+        .resolve(
+            input,
+            |id| storage.id_exists(id).unwrap_or(false),
+            |hash| find_matching_ids(&all_ids, hash),
+        )
+        .map(|resolved| resolved.id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{IssueType, Priority};
+    use crate::util::id::IdConfig;
+
+    fn config() -> CreateConfig {
+        CreateConfig {
+            id_config: IdConfig {
+                prefix: "bd".to_string(),
+                min_hash_length: 3,
+                max_hash_length: 8,
+                max_collision_prob: 0.25,
+            },
+            default_priority: Priority::MEDIUM,
+            default_issue_type: IssueType::Task,
+            actor: "tester".to_string(),
+        }
+    }
+
+    fn create_template(
+        storage: &mut SqliteStorage,
+        title: &str,
+        parent: Option<&str>,
+        deps: &[&str],
+    ) -> String {
+        let args = TemplateCreateArgs {
+            title: title.to_string(),
+            type_: None,
+            priority: None,
+            description: Some(format!("{title} for {{{{version}}}}")),
+            design: None,
+            acceptance_criteria: None,
+            labels: vec!["release".to_string()],
+            parent: parent.map(str::to_string),
+            deps: deps.iter().map(|d| (*d).to_string()).collect(),
+            estimate: None,
+        };
+        let resolver = IdResolver::new(ResolverConfig::with_prefix("bd"));
+        let ctx = OutputContext::from_flags(false, true, false);
+        template_create(&args, storage, &resolver, &config(), &ctx).unwrap();
+        storage
+            .list_issues(&ListFilters {
+                include_templates: true,
+                ..ListFilters::default()
+            })
+            .unwrap()
+            .into_iter()
+            .find(|issue| issue.title == title)
+            .unwrap()
+            .id
+    }
+
+    #[test]
+    fn test_substitute_and_placeholders() {
+        let vars = parse_vars(&["version=1.2".to_string(), "owner = ops".to_string()]).unwrap();
+        assert_eq!(
+            substitute("Release {{version}} ({{ owner }}) {{unknown}} {{}}", &vars),
+            "Release 1.2 (ops) {{unknown}} {{}}"
+        );
+        let names: Vec<&str> = placeholders("{{a}} {{ b.c }} {{bad name}}")
+            .into_iter()
+            .map(|(_, name)| name)
+            .collect();
+        assert_eq!(names, vec!["a", "b.c"]);
+        assert!(parse_vars(&["novalue".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_instantiate_copies_tree_and_dependencies() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let root = create_template(&mut storage, "Release {{version}}", None, &[]);
+        let build = create_template(&mut storage, "Build {{version}}", Some(&root), &[]);
+        let tag = create_template(&mut storage, "Tag {{version}}", Some(&root), &[&build]);
+
+        let tree = load_template_tree(&storage, &root).unwrap();
+        let ids: Vec<&str> = tree.issues.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec![root.as_str(), build.as_str(), tag.as_str()]);
+        assert_eq!(tree.variables, vec!["version"]);
+
+        let missing = instantiate_template(&mut storage, &root, &CreateArgs::default(), &config());
+        assert!(missing.is_err());
+
+        let args = CreateArgs {
+            vars: vec!["version=2.0".to_string()],
+            ..CreateArgs::default()
+        };
+        let created = instantiate_template(&mut storage, &root, &args, &config()).unwrap();
+        assert_eq!(created.len(), 3);
+        assert_eq!(created[0].title, "Release 2.0");
+        assert!(created.iter().all(|issue| !issue.is_template));
+
+        let new_root = &created[0].id;
+        let new_build = &created[1];
+        let new_tag = &created[2];
+        assert_eq!(new_build.title, "Build 2.0");
+        assert_eq!(new_build.description.as_deref(), Some("Build 2.0 for 2.0"));
+        assert_eq!(
+            storage.get_parent_id(&new_tag.id).unwrap().as_ref(),
+            Some(new_root)
+        );
+        assert!(
+            storage
+                .dependency_exists_between(&new_tag.id, &new_build.id)
+                .unwrap()
+        );
+        assert_eq!(storage.get_labels(new_root).unwrap(), vec!["release"]);
+
+        // Templates stay hidden from regular listings.
+        let visible = storage.list_issues(&ListFilters::default()).unwrap();
+        assert_eq!(visible.len(), 3);
+    }
+}
@@ -0,0 +1,141 @@
+//! Time command implementation.
+//!
+//! Work is logged to the `worklog` table, either directly with
+//! `br time log <id> 30m` or with a per-actor timer (`br time start` /
+//! `br time stop`). Each entry records a `work_logged` event. Finished
+//! entries are exported as the issue's `worklog` field in JSONL; running
+//! timers stay in the local database. Logged time rolls up to parents through parent-child
+//! dependencies and is shown against `estimated_minutes` in `br show`,
+//! `br stats`, and CSV output.
+
+use crate::cli::{TimeCommands, TimeLogArgs, TimeStartArgs, TimeStopArgs};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::format::{TimeSpent, format_time_tracking};
+use crate::model::WorkLogEntry;
+use crate::output::OutputContext;
+use crate::storage::SqliteStorage;
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use crate::util::time::{format_minutes, parse_duration_minutes};
+use serde::Serialize;
+use tracing::info;
+
+/// JSON output for `br time stop` and `br time log`.
+#[derive(Serialize)]
+struct WorkLogged {
+    entry: WorkLogEntry,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    estimated_minutes: Option<i32>,
+    time_spent: TimeSpent,
+}
+
+/// Execute the time command.
+///
+/// # Errors
+///
+/// Returns an error if database operations fail or if inputs are invalid.
+pub fn execute(
+    command: &TimeCommands,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let id_config = config::id_config_from_layer(&layer);
+    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let actor = config::resolve_actor(&layer);
+    let storage = &mut storage_ctx.storage;
+
+    match command {
+        TimeCommands::Start(args) => time_start(args, storage, &resolver, &actor, ctx)?,
+        TimeCommands::Stop(args) => {
+            let id = resolve_issue_id(storage, &resolver, &args.id)?;
+            let entry = storage.stop_timer(&id, &actor, args.note.as_deref())?;
+            report_logged(storage, entry, ctx)?;
+        }
+        TimeCommands::Log(args) => time_log(args, storage, &resolver, &actor, ctx)?,
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
+
+fn time_start(
+    args: &TimeStartArgs,
+    storage: &mut SqliteStorage,
+    resolver: &IdResolver,
+    actor: &str,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let id = resolve_issue_id(storage, resolver, &args.id)?;
+    let entry = storage.start_timer(&id, actor)?;
+    info!(id = %id, actor = %actor, "Timer started");
+
+    if ctx.is_json() {
+        ctx.json_pretty(&entry);
+    } else {
+        ctx.success(&format!(
+            "Started timer on {id} (stop with `br time stop {id}`)"
+        ));
+    }
+    Ok(())
+}
+
+fn time_log(
+    args: &TimeLogArgs,
+    storage: &mut SqliteStorage,
+    resolver: &IdResolver,
+    actor: &str,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let id = resolve_issue_id(storage, resolver, &args.id)?;
+    let minutes = parse_duration_minutes(&args.duration, "duration")?;
+    let entry = storage.log_work(&id, actor, minutes, args.note.as_deref())?;
+    report_logged(storage, entry, ctx)
+}
+
+fn report_logged(storage: &SqliteStorage, entry: WorkLogEntry, ctx: &OutputContext) -> Result<()> {
+    let issue = storage
+        .get_issue(&entry.issue_id)?
+        .ok_or_else(|| BeadsError::IssueNotFound {
+            id: entry.issue_id.clone(),
+        })?;
+    let time_spent = storage
+        .get_time_rollup()?
+        .remove(&entry.issue_id)
+        .unwrap_or_default();
+    let minutes = entry.minutes.unwrap_or(0);
+    info!(id = %entry.issue_id, minutes, "Work logged");
+
+    if ctx.is_json() {
+        ctx.json_pretty(&WorkLogged {
+            entry,
+            estimated_minutes: issue.estimated_minutes,
+            time_spent,
+        });
+    } else {
+        ctx.success(&format!(
+            "Logged {} on {}",
+            format_minutes(minutes),
+            entry.issue_id
+        ));
+        if let Some(summary) =
+            format_time_tracking(issue.estimated_minutes.map(i64::from), Some(time_spent))
+        {
+            ctx.print(&format!("Total: {summary}"));
+        }
+    }
+    Ok(())
+}
+
+fn resolve_issue_id(storage: &SqliteStorage, resolver: &IdResolver, input: &str) -> Result<String> {
+    let all_ids = storage.get_all_ids()?;
+    resolver
+        .resolve(
+            input,
+            |id| storage.id_exists(id).unwrap_or(false),
+            |hash| find_matching_ids(&all_ids, hash),
+        )
+        .map(|resolved| resolved.id)
+}
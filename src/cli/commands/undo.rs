@@ -0,0 +1,121 @@
+//! Undo command implementation.
+//!
+//! `br undo` reverts the most recent mutation batches recorded by
+//! [`SqliteStorage::mutate`](crate::storage::SqliteStorage::mutate),
+//! newest first, by restoring the issue images saved before each change.
+//! `--redo` reverts the most recent undos instead, until a new change
+//! clears the redo stack.
+
+use crate::cli::UndoArgs;
+use crate::config;
+use crate::error::Result;
+use crate::output::OutputContext;
+use crate::storage::history::FieldChange;
+use crate::storage::undo::{REDO_OP, Reversal, UNDO_OP};
+use chrono::Local;
+use serde::Serialize;
+use tracing::debug;
+
+/// JSON output for `br undo`.
+#[derive(Debug, Serialize)]
+pub struct UndoOutput {
+    pub redo: bool,
+    pub dry_run: bool,
+    pub count: usize,
+    pub reverted: Vec<Reversal>,
+}
+
+/// Execute the undo command.
+///
+/// # Errors
+///
+/// Returns an error if an issue changed after the batch being undone, or
+/// the database cannot be read or written.
+pub fn execute(args: &UndoArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let actor = config::resolve_actor(&layer);
+    let storage = &mut storage_ctx.storage;
+
+    let (op, batches) = if args.redo {
+        (
+            REDO_OP,
+            storage.redo_stack(args.steps, args.actor.as_deref())?,
+        )
+    } else {
+        (
+            UNDO_OP,
+            storage.undo_stack(args.steps, args.actor.as_deref())?,
+        )
+    };
+    debug!(
+        op,
+        batches = batches.len(),
+        dry_run = args.dry_run,
+        "Reverting batches"
+    );
+    let reverted = storage.revert_batches(&batches, op, &actor, args.dry_run)?;
+    if !args.dry_run {
+        storage_ctx.flush_no_db_if_dirty()?;
+    }
+
+    if ctx.is_json() {
+        ctx.json_pretty(&UndoOutput {
+            redo: args.redo,
+            dry_run: args.dry_run,
+            count: reverted.len(),
+            reverted,
+        });
+        return Ok(());
+    }
+    if reverted.is_empty() {
+        ctx.info(if args.redo {
+            "Nothing to redo."
+        } else {
+            "Nothing to undo."
+        });
+        return Ok(());
+    }
+    let verb = match (args.redo, args.dry_run) {
+        (false, false) => "Undid",
+        (false, true) => "Would undo",
+        (true, false) => "Redid",
+        (true, true) => "Would redo",
+    };
+    for reversal in &reverted {
+        println!("{}", format_reversal(verb, reversal));
+        for change in &reversal.changes {
+            println!("  {}", format_change(change));
+        }
+    }
+    Ok(())
+}
+
+/// Header line of one reverted batch.
+fn format_reversal(verb: &str, reversal: &Reversal) -> String {
+    let batch = &reversal.batch;
+    let when = batch
+        .created_at
+        .with_timezone(&Local)
+        .format("%Y-%m-%d %H:%M");
+    format!(
+        "{verb} #{} ({} by {}, {when})",
+        batch.id, batch.op, batch.actor
+    )
+}
+
+/// One restored field of an issue.
+fn format_change(change: &FieldChange) -> String {
+    let what = match (&change.old_value, &change.new_value) {
+        (None, Some(new)) => format!("{}: + {new}", change.field),
+        (Some(old), None) => format!("{}: - {old}", change.field),
+        (old, new) => format!(
+            "{}: {} → {}",
+            change.field,
+            old.as_deref().unwrap_or("(none)"),
+            new.as_deref().unwrap_or("(none)")
+        ),
+    };
+    format!("{}  {what}", change.issue_id)
+}
@@ -64,20 +64,8 @@ pub fn execute(args: &UpdateArgs, cli: &config::CliOverrides, ctx: &OutputContex
         // Get issue before update for change tracking
         let issue_before = storage.get_issue(id)?;
 
-        if args.claim {
-            if let Some(ref issue) = issue_before {
-                if let Some(ref current_assignee) = issue.assignee {
-                    if current_assignee != &actor {
-                        return Err(BeadsError::validation(
-                            "claim",
-                            format!("issue already assigned to {current_assignee}"),
-                        ));
-                    }
-                }
-            }
-        }
-
-        // Apply basic field updates
+        // Apply basic field updates (a claim is checked against leases and
+        // the current assignee inside the same transaction)
         if !update.is_empty() {
             storage.update_issue(id, &update, &actor)?;
         }
@@ -206,7 +194,12 @@ fn resolve_target_ids(
     Ok(resolved_ids.into_iter().map(|r| r.id).collect())
 }
 
-fn build_update(args: &UpdateArgs, actor: &str) -> Result<IssueUpdate> {
+/// Build the field update described by `args` (labels and parent are applied separately).
+///
+/// # Errors
+///
+/// Returns an error if a status, priority, type or date cannot be parsed.
+pub fn build_update(args: &UpdateArgs, actor: &str) -> Result<IssueUpdate> {
     let status = if args.claim {
         Some(Status::InProgress)
     } else {
@@ -255,6 +248,8 @@ fn build_update(args: &UpdateArgs, actor: &str) -> Result<IssueUpdate> {
         deleted_at: None,
         deleted_by: None,
         delete_reason: None,
+        close_outcome: None,
+        claim: args.claim,
         skip_cache_rebuild: false,
     })
 }
@@ -162,6 +162,8 @@ const CSV_FIELD_CANDIDATES: &[(&str, &str)] = &[
     ("defer_until", "Defer until"),
     ("notes", "Notes"),
     ("external_ref", "External ref"),
+    ("estimated_minutes", "Estimated minutes"),
+    ("actual_minutes", "Logged minutes (including children)"),
 ];
 
 const EXPORT_ERROR_POLICY_CANDIDATES: &[(&str, &str)] = &[
@@ -736,6 +738,12 @@ pub enum Commands {
     /// Update an issue
     Update(UpdateArgs),
 
+    /// Claim an issue under a time-limited lease
+    Claim(ClaimArgs),
+
+    /// Renew your claim leases
+    Heartbeat(HeartbeatArgs),
+
     /// Close an issue
     Close(CloseArgs),
 
@@ -745,12 +753,21 @@ pub enum Commands {
     /// Delete an issue (creates tombstone)
     Delete(DeleteArgs),
 
+    /// Move an issue to another routed project
+    Move(MoveArgs),
+
     /// List ready issues (unblocked, not deferred)
     Ready(ReadyArgs),
 
+    /// Claim the best ready issue and show it
+    Next(NextArgs),
+
     /// List blocked issues
     Blocked(BlockedArgs),
 
+    /// Interactive kanban board (full screen)
+    Board(BoardArgs),
+
     /// Search issues
     Search(SearchArgs),
 
@@ -838,9 +855,17 @@ EXAMPLES:
   br sync --flush-only           Export database to .beads/issues.jsonl
   br sync --flush-only -v        Export with safety logging
   br sync --import-only          Import from JSONL (validates first)
-  br sync --status               Show current sync status")]
+  br sync --status               Show current sync status
+  br sync --merge --strategy manual   3-way merge, record conflicts
+  br sync resolve                Resolve recorded merge conflicts")]
     Sync(SyncArgs),
 
+    /// Import issues from a GitHub, GitLab or Jira export file
+    Import(ImportArgs),
+
+    /// Export issues as a static HTML site, Markdown, Jira CSV or GitHub JSON
+    Export(ExportArgs),
+
     /// Run read-only diagnostics
     Doctor,
 
@@ -876,6 +901,10 @@ EXAMPLES:
     Orphans(OrphansArgs),
     /// Generate changelog from closed issues
     Changelog(ChangelogArgs),
+    /// List field-level changes across the workspace in a time window
+    Diff(DiffArgs),
+    /// Undo the last change(s), or redo them with --redo
+    Undo(UndoArgs),
 
     /// Manage saved queries
     Query {
@@ -883,11 +912,61 @@ EXAMPLES:
         command: QueryCommands,
     },
 
+    /// Manage issue templates (instantiate with `br create --from-template`)
+    Template {
+        #[command(subcommand)]
+        command: TemplateCommands,
+    },
+
+    /// Manage recurring issues (generate due instances with `br recur run`)
+    Recur {
+        #[command(subcommand)]
+        command: RecurCommands,
+    },
+
+    /// Track time spent on issues against their estimates
+    Time {
+        #[command(subcommand)]
+        command: TimeCommands,
+    },
+
+    /// List and replay lifecycle hooks (configured under `hooks:` in config.yaml)
+    Hooks {
+        #[command(subcommand)]
+        command: HooksCommands,
+    },
+
+    /// Summarize old closed issues to shrink the JSONL
+    Compact(CompactArgs),
+
+    /// Serve a local HTTP JSON API over the issue database
+    Serve(ServeArgs),
+
     /// Visualize dependency graph
     Graph(GraphArgs),
 
+    /// Forecast the critical path, finish dates and due date risk
+    Plan(PlanArgs),
+
     /// Manage AGENTS.md workflow instructions
     Agents(AgentsArgs),
+
+    /// Git merge driver for issues.jsonl (invoked by git, not by hand)
+    #[command(
+        name = "merge-driver",
+        long_about = "Git merge driver for .beads/issues.jsonl.
+
+Parses the ancestor, current, and other versions as issues, runs the same
+3-way merge as `br sync --merge`, and writes the merged issues (sorted by ID)
+back to the current version. Exits non-zero only when the merge cannot be
+completed: unparseable input, or conflicts with --strategy manual.
+
+SETUP:
+  `br init` writes .beads/.gitattributes (issues.jsonl merge=beads).
+  Register the driver once per clone:
+    git config merge.beads.driver \"br merge-driver %O %A %B\""
+    )]
+    MergeDriver(MergeDriverArgs),
 }
 
 /// Arguments for the completions command.
@@ -919,7 +998,7 @@ pub enum ShellType {
     Elvish,
 }
 
-#[derive(Args, Debug, Default)]
+#[derive(Args, Debug, Clone, Default)]
 pub struct CreateArgs {
     /// Issue title
     pub title: Option<String>,
@@ -995,6 +1074,14 @@ pub struct CreateArgs {
     /// Create issues from a markdown file (bulk import)
     #[arg(long, short = 'f')]
     pub file: Option<std::path::PathBuf>,
+
+    /// Instantiate a template (and its child templates); the title is optional
+    #[arg(long, value_name = "TEMPLATE_ID", conflicts_with = "file", add = ArgValueCompleter::new(issue_id_completer))]
+    pub from_template: Option<String>,
+
+    /// Template variable substituted for {{KEY}} (repeatable)
+    #[arg(long = "var", value_name = "KEY=VALUE", requires = "from_template")]
+    pub vars: Vec<String>,
 }
 
 #[derive(Args, Debug)]
@@ -1135,6 +1222,46 @@ pub struct DeleteArgs {
     pub dry_run: bool,
 }
 
+/// Arguments for the claim command.
+#[derive(Args, Debug, Clone)]
+pub struct ClaimArgs {
+    /// Issue ID to claim
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: String,
+
+    /// Lease length: minutes (45) or units (30m, 2h, 1h30m)
+    #[arg(long, default_value = "30m")]
+    pub ttl: String,
+}
+
+/// Arguments for the heartbeat command.
+#[derive(Args, Debug, Clone)]
+pub struct HeartbeatArgs {
+    /// Issue IDs whose leases to renew (default: all of yours)
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub ids: Vec<String>,
+
+    /// New lease length from now: minutes (45) or units (30m, 2h, 1h30m)
+    #[arg(long, default_value = "30m")]
+    pub ttl: String,
+}
+
+/// Arguments for the move command.
+#[derive(Args, Debug, Default, Clone)]
+pub struct MoveArgs {
+    /// Issue ID to move
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: String,
+
+    /// Target project: a route prefix (e.g. `api` or `api-`) or project directory name
+    #[arg(long)]
+    pub to: String,
+
+    /// Preview only, no changes
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
 /// Arguments for the info command.
 #[derive(Args, Debug, Default, Clone)]
 pub struct InfoArgs {
@@ -1175,8 +1302,10 @@ pub enum SchemaTarget {
     All,
     /// Core Issue object (used by many commands)
     Issue,
-    /// List/search row: Issue + dependency/dependent counts
+    /// List row: Issue + dependency/dependent counts
     IssueWithCounts,
+    /// Search row: Issue + counts, relevance score and snippet
+    SearchResult,
     /// Show view: Issue + relations/comments/events
     IssueDetails,
     /// Ready list row
@@ -1377,6 +1506,14 @@ pub struct ListArgs {
     #[arg(long)]
     pub pretty: bool,
 
+    /// Aggregate every project reachable through routes (routes.jsonl, mayor/town.json)
+    #[arg(long, visible_alias = "all-projects")]
+    pub town: bool,
+
+    /// Show the state at this time (RFC3339, YYYY-MM-DD, or relative like -7d)
+    #[arg(long, value_name = "TIME", conflicts_with = "town")]
+    pub as_of: Option<String>,
+
     /// Wrap long lines instead of truncating in text output
     #[arg(long)]
     pub wrap: bool,
@@ -1393,7 +1530,7 @@ pub struct ListArgs {
     ///
     /// Available: id, title, description, status, priority, `issue_type`,
     /// assignee, owner, `created_at`, `updated_at`, `closed_at`, `due_at`,
-    /// `defer_until`, notes, `external_ref`
+    /// `defer_until`, notes, `external_ref`, `estimated_minutes`, `actual_minutes`
     ///
     /// Default: id, title, status, priority, `issue_type`, assignee, `created_at`, `updated_at`
     #[arg(long, value_name = "FIELDS", add = ArgValueCompleter::new(csv_fields_completer))]
@@ -1428,6 +1565,14 @@ pub struct ShowArgs {
     /// Show token savings stats when using TOON output
     #[arg(long)]
     pub stats: bool,
+
+    /// Show a moved issue's tombstone instead of following it to its new project
+    #[arg(long)]
+    pub no_follow: bool,
+
+    /// Show the state at this time (RFC3339, YYYY-MM-DD, or relative like -7d)
+    #[arg(long, value_name = "TIME")]
+    pub as_of: Option<String>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -1488,6 +1633,11 @@ pub struct DepAddArgs {
     /// Optional JSON metadata
     #[arg(long)]
     pub metadata: Option<String>,
+
+    /// Release rule for waits-for: all (target closed), all-children,
+    /// any-children, or any (first of the issue's any targets to close)
+    #[arg(long)]
+    pub gate: Option<String>,
 }
 
 #[derive(Args, Debug)]
@@ -1899,7 +2049,7 @@ pub struct ReadyArgs {
     #[arg(long, short = 'p', add = ArgValueCompleter::new(priority_completer))]
     pub priority: Vec<String>,
 
-    /// Sort policy: hybrid (default), priority, oldest
+    /// Sort policy: hybrid (default), priority, oldest, score
     #[arg(long, default_value = "hybrid", value_enum)]
     pub sort: SortPolicy,
 
@@ -1907,6 +2057,62 @@ pub struct ReadyArgs {
     #[arg(long)]
     pub include_deferred: bool,
 
+    /// Also list open issues held back by conditional-blocks or waits-for rules
+    #[arg(long)]
+    pub explain: bool,
+
+    /// Aggregate every project reachable through routes (routes.jsonl, mayor/town.json)
+    #[arg(long, visible_alias = "all-projects")]
+    pub town: bool,
+
+    /// Show the state at this time (RFC3339, YYYY-MM-DD, or relative like -7d)
+    #[arg(long, value_name = "TIME", conflicts_with = "town")]
+    pub as_of: Option<String>,
+
+    /// Wrap long lines instead of truncating in text output
+    #[arg(long)]
+    pub wrap: bool,
+
+    /// Output format (text, json, toon). Env: BR_OUTPUT_FORMAT, TOON_DEFAULT_FORMAT.
+    #[arg(long, value_enum)]
+    pub format: Option<OutputFormatBasic>,
+
+    /// Show token savings stats when using TOON output
+    #[arg(long)]
+    pub stats: bool,
+
+    /// Machine-readable output (alias for --json)
+    #[arg(long)]
+    pub robot: bool,
+}
+
+/// Arguments for the next command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct NextArgs {
+    /// Filter by label (AND logic, can be repeated)
+    #[arg(long, short = 'l', add = ArgValueCompleter::new(label_completer))]
+    pub label: Vec<String>,
+
+    /// Filter by label (OR logic, can be repeated)
+    #[arg(long, add = ArgValueCompleter::new(label_completer))]
+    pub label_any: Vec<String>,
+
+    /// Filter by issue type (can be repeated)
+    #[arg(long = "type", short = 't', add = ArgValueCompleter::new(issue_type_completer))]
+    pub type_: Vec<String>,
+
+    /// Filter by priority (can be repeated, 0-4 or P0-P4)
+    #[arg(long, short = 'p', add = ArgValueCompleter::new(priority_completer))]
+    pub priority: Vec<String>,
+
+    /// Sort policy: hybrid (default), priority, oldest, score
+    #[arg(long, default_value = "hybrid", value_enum)]
+    pub sort: SortPolicy,
+
+    /// Lease length: minutes (45) or units (30m, 2h, 1h30m)
+    #[arg(long, default_value = "30m")]
+    pub ttl: String,
+
     /// Wrap long lines instead of truncating in text output
     #[arg(long)]
     pub wrap: bool,
@@ -1952,6 +2158,10 @@ pub struct BlockedArgs {
     #[arg(long, short = 'l', add = ArgValueCompleter::new(label_completer))]
     pub label: Vec<String>,
 
+    /// Aggregate every project reachable through routes (routes.jsonl, mayor/town.json)
+    #[arg(long, visible_alias = "all-projects")]
+    pub town: bool,
+
     /// Output format (text, json, toon). Env: BR_OUTPUT_FORMAT, TOON_DEFAULT_FORMAT.
     #[arg(long, value_enum)]
     pub format: Option<OutputFormatBasic>,
@@ -1965,6 +2175,42 @@ pub struct BlockedArgs {
     pub robot: bool,
 }
 
+/// Arguments for the board command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct BoardArgs {
+    /// Filter by issue type (can be repeated)
+    #[arg(long = "type", short = 't', add = ArgValueCompleter::new(issue_type_completer))]
+    pub type_: Vec<String>,
+
+    /// Filter by priority (can be repeated, 0-4)
+    #[arg(long, short = 'p', add = ArgValueCompleter::new(priority_completer))]
+    pub priority: Vec<String>,
+
+    /// Filter by assignee
+    #[arg(long, add = ArgValueCompleter::new(assignee_completer))]
+    pub assignee: Option<String>,
+
+    /// Only show unassigned issues
+    #[arg(long)]
+    pub unassigned: bool,
+
+    /// Filter by label (AND logic, can be repeated)
+    #[arg(long, short = 'l', add = ArgValueCompleter::new(label_completer))]
+    pub label: Vec<String>,
+
+    /// Filter by label (OR logic, can be repeated)
+    #[arg(long, add = ArgValueCompleter::new(label_completer))]
+    pub label_any: Vec<String>,
+
+    /// Filter by title substring (also editable on the board with `/`)
+    #[arg(long)]
+    pub title_contains: Option<String>,
+
+    /// Maximum number of recently closed issues to show
+    #[arg(long, default_value_t = 50)]
+    pub closed_limit: usize,
+}
+
 /// Arguments for the close command.
 #[derive(Args, Debug, Clone, Default)]
 pub struct CloseArgs {
@@ -1980,6 +2226,11 @@ pub struct CloseArgs {
     #[arg(long, short = 'f')]
     pub force: bool,
 
+    /// How the work turned out: success, failed or wontfix (failed and
+    /// wontfix release conditional-blocks dependents)
+    #[arg(long)]
+    pub outcome: Option<String>,
+
     /// After closing, return newly unblocked issues (single ID only)
     #[arg(long)]
     pub suggest_next: bool,
@@ -2019,12 +2270,42 @@ pub enum SortPolicy {
     Priority,
     /// Sort by `created_at` ASC only
     Oldest,
+    /// Sort by weighted score (`ready.score.*` in config)
+    Score,
+}
+
+/// Conflict strategy for `br sync --merge`.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum MergeStrategy {
+    /// Same-field conflicts take the side with the later `updated_at`
+    #[default]
+    Newer,
+    /// Same-field conflicts keep the local (database) value
+    Local,
+    /// Same-field conflicts keep the external (JSONL) value
+    External,
+    /// Stop on conflicts and record them for `br sync resolve`
+    Manual,
+}
+
+impl From<MergeStrategy> for crate::sync::ConflictResolution {
+    fn from(strategy: MergeStrategy) -> Self {
+        match strategy {
+            MergeStrategy::Newer => Self::PreferNewer,
+            MergeStrategy::Local => Self::PreferLocal,
+            MergeStrategy::External => Self::PreferExternal,
+            MergeStrategy::Manual => Self::Manual,
+        }
+    }
 }
 
 /// Arguments for the sync command.
 #[derive(Args, Debug, Clone, Default)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct SyncArgs {
+    #[command(subcommand)]
+    pub command: Option<SyncCommands>,
+
     /// Export database to JSONL (DB → .beads/issues.jsonl)
     ///
     /// Writes all issues from `SQLite` database to JSONL format.
@@ -2047,6 +2328,14 @@ pub struct SyncArgs {
     #[arg(long)]
     pub merge: bool,
 
+    /// Conflict strategy for --merge: newer (default), local, external, manual
+    ///
+    /// Non-overlapping field changes always merge cleanly; the strategy only
+    /// decides fields both sides changed. `manual` applies nothing and records
+    /// the conflicts for `br sync resolve`.
+    #[arg(long, value_enum, default_value = "newer")]
+    pub strategy: MergeStrategy,
+
     /// Show sync status (read-only)
     ///
     /// Displays hash comparison and freshness info without modifications.
@@ -2092,6 +2381,132 @@ pub struct SyncArgs {
     pub robot: bool,
 }
 
+/// Arguments for the import command.
+#[derive(Args, Debug, Clone)]
+pub struct ImportArgs {
+    /// Tracker the export comes from
+    #[arg(long, value_enum)]
+    pub from: ImportSource,
+
+    /// Export file (JSON; Jira also accepts CSV or XML)
+    pub file: PathBuf,
+
+    /// Show what would be created and updated without writing
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+/// Issue tracker for `br import --from`.
+#[derive(ValueEnum, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ImportSource {
+    /// GitHub Issues (`gh issue list --json` or REST API JSON)
+    Github,
+    /// GitLab issues (REST API JSON or project export NDJSON)
+    Gitlab,
+    /// Jira (CSV, XML or REST API JSON export)
+    Jira,
+}
+
+impl ImportSource {
+    /// Value stored in `source_system` for imported issues.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Github => "github",
+            Self::Gitlab => "gitlab",
+            Self::Jira => "jira",
+        }
+    }
+}
+
+/// Arguments for the export command.
+#[derive(Args, Debug, Clone)]
+pub struct ExportArgs {
+    /// Output format
+    #[arg(long, value_enum)]
+    pub format: ExportFormat,
+
+    /// Output directory for html (default: br-site), output file otherwise (default: stdout)
+    #[arg(long, short = 'o')]
+    pub output: Option<PathBuf>,
+}
+
+/// Output format for `br export`.
+#[derive(ValueEnum, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ExportFormat {
+    /// Static site: filterable index, issue pages, dependency graph, epic progress
+    Html,
+    /// Markdown document grouped by status
+    Markdown,
+    /// CSV for Jira's CSV importer
+    JiraCsv,
+    /// JSON records for GitHub's issue import API
+    GithubJson,
+}
+
+impl ExportFormat {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Html => "html",
+            Self::Markdown => "markdown",
+            Self::JiraCsv => "jira-csv",
+            Self::GithubJson => "github-json",
+        }
+    }
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum SyncCommands {
+    /// Resolve conflicts recorded by `br sync --merge --strategy manual`
+    ///
+    /// Shows a side-by-side diff (base / local / external) for each conflicting
+    /// field. Choices come from --file, --pick, --set, or interactive prompts;
+    /// once every conflict is resolved the merge is completed.
+    Resolve(SyncResolveArgs),
+}
+
+/// Arguments for `br sync resolve`.
+#[derive(Args, Debug, Clone, Default)]
+pub struct SyncResolveArgs {
+    /// JSON resolution file: {"<id>": "local|external|base" | {"<field>": "local" | {"value": ...}}}
+    #[arg(long, value_name = "PATH")]
+    pub file: Option<PathBuf>,
+
+    /// Pick a side for an issue or one field: <id>=<side> or <id>.<field>=<side> (repeatable)
+    #[arg(long, value_name = "ID[.FIELD]=SIDE")]
+    pub pick: Vec<String>,
+
+    /// Hand-edit a field with a JSON value: <id>.<field>=<json> (repeatable)
+    #[arg(long, value_name = "ID.FIELD=JSON")]
+    pub set: Vec<String>,
+
+    /// Show pending conflicts without resolving anything
+    #[arg(long, conflicts_with_all = ["file", "pick", "set", "abort"])]
+    pub list: bool,
+
+    /// Discard pending conflicts without merging
+    #[arg(long, conflicts_with_all = ["file", "pick", "set"])]
+    pub abort: bool,
+}
+
+/// Arguments for the merge-driver command.
+#[derive(Args, Debug, Clone)]
+pub struct MergeDriverArgs {
+    /// Common ancestor version (git's %O)
+    pub base: PathBuf,
+
+    /// Current branch version; receives the merged result (git's %A)
+    pub local: PathBuf,
+
+    /// Other branch version (git's %B)
+    pub external: PathBuf,
+
+    /// How to settle same-field conflicts (local = current branch, external = other branch)
+    #[arg(long, value_enum, default_value = "newer")]
+    pub strategy: MergeStrategy,
+}
+
 #[derive(Subcommand, Debug, Clone)]
 pub enum ConfigCommands {
     /// List all available config options
@@ -2170,6 +2585,10 @@ pub struct StatsArgs {
     #[arg(long, default_value_t = 24)]
     pub activity_hours: u32,
 
+    /// Show the state at this time (RFC3339, YYYY-MM-DD, or relative like -7d)
+    #[arg(long, value_name = "TIME")]
+    pub as_of: Option<String>,
+
     /// Output format (text, json, toon). Env: BR_OUTPUT_FORMAT, TOON_DEFAULT_FORMAT.
     #[arg(long, value_enum)]
     pub format: Option<OutputFormatBasic>,
@@ -2281,11 +2700,127 @@ pub struct ChangelogArgs {
     #[arg(long, conflicts_with_all = ["since", "since_tag"])]
     pub since_commit: Option<String>,
 
+    /// End date (RFC3339, YYYY-MM-DD, or relative like -7d; default: now)
+    #[arg(long)]
+    pub until: Option<String>,
+
+    /// End at git tag date (also the default section title)
+    #[arg(long, conflicts_with = "until")]
+    pub until_tag: Option<String>,
+
+    /// Group entries by issue type, label or parent epic
+    #[arg(long, value_enum, default_value_t)]
+    pub group_by: ChangelogGroupBy,
+
+    /// Leave out issues with this label (repeatable)
+    #[arg(long = "exclude-label", value_name = "LABEL")]
+    pub exclude_labels: Vec<String>,
+
+    /// Leave out issues of this type (repeatable)
+    #[arg(long = "exclude-type", value_name = "TYPE")]
+    pub exclude_types: Vec<String>,
+
+    /// Leave out issues whose close reason contains this text (repeatable)
+    #[arg(long = "exclude-reason", value_name = "TEXT")]
+    pub exclude_reasons: Vec<String>,
+
+    /// List the git commits that reference each issue
+    #[arg(long)]
+    pub commits: bool,
+
+    /// Render with the built-in markdown or HTML template
+    #[arg(long, value_enum)]
+    pub format: Option<ChangelogFormat>,
+
+    /// Render with a Handlebars-style template file
+    #[arg(long)]
+    pub template: Option<PathBuf>,
+
+    /// Section title (default: --until-tag, else "Unreleased")
+    #[arg(long)]
+    pub title: Option<String>,
+
+    /// Write the section into this file, replacing an earlier copy
+    #[arg(long, short = 'o')]
+    pub output: Option<PathBuf>,
+
     /// Machine-readable output (alias for --json)
     #[arg(long)]
     pub robot: bool,
 }
 
+/// Grouping for `br changelog`.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum ChangelogGroupBy {
+    /// One section per issue type
+    #[default]
+    Type,
+    /// One section per label (issues appear under each of their labels)
+    Label,
+    /// One section per parent epic
+    Epic,
+}
+
+impl ChangelogGroupBy {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Type => "type",
+            Self::Label => "label",
+            Self::Epic => "epic",
+        }
+    }
+}
+
+/// Built-in templates for `br changelog`.
+#[derive(ValueEnum, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ChangelogFormat {
+    /// Markdown section (the default for `--output` and `--template`)
+    Markdown,
+    /// HTML fragment
+    Html,
+}
+
+/// Arguments for the diff command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct DiffArgs {
+    /// Start of the window (RFC3339, YYYY-MM-DD, or relative like -7d)
+    #[arg(long, value_name = "TIME")]
+    pub since: String,
+
+    /// End of the window (same formats; default: now)
+    #[arg(long, value_name = "TIME")]
+    pub until: Option<String>,
+
+    /// Only changes to these issues (repeatable)
+    #[arg(long = "issue", value_name = "ID", add = ArgValueCompleter::new(issue_id_completer))]
+    pub issues: Vec<String>,
+
+    /// Only changes made by this actor
+    #[arg(long)]
+    pub actor: Option<String>,
+}
+
+/// Arguments for the undo command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct UndoArgs {
+    /// Number of changes to undo (or redo)
+    #[arg(long, default_value_t = 1, value_name = "N")]
+    pub steps: usize,
+
+    /// Only undo changes made by this actor (`system` for automatic ones)
+    #[arg(long)]
+    pub actor: Option<String>,
+
+    /// Redo the last undone changes instead
+    #[arg(long)]
+    pub redo: bool,
+
+    /// Preview the changes without applying them
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
 /// Subcommands for the query command.
 #[derive(Subcommand, Debug)]
 pub enum QueryCommands {
@@ -2334,6 +2869,264 @@ pub struct QueryDeleteArgs {
     pub name: String,
 }
 
+#[derive(Subcommand, Debug, Clone)]
+pub enum TemplateCommands {
+    /// Create a template issue (use --parent for child templates)
+    Create(TemplateCreateArgs),
+    /// List root templates
+    List,
+    /// Show a template with its child templates and variables
+    Show(TemplateShowArgs),
+}
+
+/// Arguments for the template create command.
+#[derive(Args, Debug, Clone)]
+pub struct TemplateCreateArgs {
+    /// Template title; {{name}} placeholders are filled by --var on instantiation
+    pub title: String,
+
+    /// Issue type (task, bug, feature, etc.)
+    #[arg(long = "type", short = 't', add = ArgValueCompleter::new(issue_type_completer))]
+    pub type_: Option<String>,
+
+    /// Priority (0-4 or P0-P4)
+    #[arg(long, short = 'p', add = ArgValueCompleter::new(priority_completer))]
+    pub priority: Option<String>,
+
+    /// Description
+    #[arg(long, short = 'd')]
+    pub description: Option<String>,
+
+    /// Design notes
+    #[arg(long)]
+    pub design: Option<String>,
+
+    /// Acceptance criteria
+    #[arg(long, visible_alias = "acceptance")]
+    pub acceptance_criteria: Option<String>,
+
+    /// Labels (comma-separated)
+    #[arg(long, short = 'l', value_delimiter = ',', add = ArgValueCompleter::new(label_completer_delimited))]
+    pub labels: Vec<String>,
+
+    /// Parent template ID (makes this a child template)
+    #[arg(long, add = ArgValueCompleter::new(issue_id_completer))]
+    pub parent: Option<String>,
+
+    /// Dependencies on other templates (format: type:id,type:id)
+    #[arg(long, value_delimiter = ',', add = ArgValueCompleter::new(deps_completer))]
+    pub deps: Vec<String>,
+
+    /// Time estimate in minutes
+    #[arg(long, short = 'e')]
+    pub estimate: Option<i32>,
+}
+
+/// Arguments for the template show command.
+#[derive(Args, Debug, Clone)]
+pub struct TemplateShowArgs {
+    /// Template ID
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: String,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum RecurCommands {
+    /// Attach a recurrence rule to an issue (replaces any existing rule)
+    Set(RecurSetArgs),
+    /// List recurring issues and when their next instance is due
+    List,
+    /// Stop an issue from recurring
+    Clear(RecurClearArgs),
+    /// Create the next instance of every series that is closed or due
+    Run(RecurRunArgs),
+}
+
+/// Arguments for the recur set command.
+#[derive(Args, Debug, Clone)]
+pub struct RecurSetArgs {
+    /// Issue ID
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: String,
+
+    /// Rule: daily, weekly, monthly, yearly, "every 2 weeks", weekly:mon,thu,
+    /// monthly:15, FREQ=WEEKLY;INTERVAL=2;BYDAY=MO, or "cron:0 9 * * 1"
+    pub rule: String,
+
+    /// When the next instance is due (default: one period after the issue's
+    /// due date, or after now)
+    #[arg(long)]
+    pub start: Option<String>,
+}
+
+/// Arguments for the recur clear command.
+#[derive(Args, Debug, Clone)]
+pub struct RecurClearArgs {
+    /// Issue ID
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: String,
+}
+
+/// Arguments for the recur run command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct RecurRunArgs {
+    /// Show which instances would be created without creating them
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum TimeCommands {
+    /// Start a timer on an issue
+    Start(TimeStartArgs),
+    /// Stop your running timer on an issue and log the elapsed time
+    Stop(TimeStopArgs),
+    /// Log time spent on an issue (e.g. 30m, 1h30m, 2h)
+    Log(TimeLogArgs),
+}
+
+/// Arguments for the time start command.
+#[derive(Args, Debug, Clone)]
+pub struct TimeStartArgs {
+    /// Issue ID
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: String,
+}
+
+/// Arguments for the time stop command.
+#[derive(Args, Debug, Clone)]
+pub struct TimeStopArgs {
+    /// Issue ID
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: String,
+
+    /// Note describing the work
+    #[arg(long, short = 'm')]
+    pub note: Option<String>,
+}
+
+/// Arguments for the time log command.
+#[derive(Args, Debug, Clone)]
+pub struct TimeLogArgs {
+    /// Issue ID
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: String,
+
+    /// Time spent: minutes (45) or units (30m, 2h, 1h30m, 1d = 8h)
+    pub duration: String,
+
+    /// Note describing the work
+    #[arg(long, short = 'm')]
+    pub note: Option<String>,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum HooksCommands {
+    /// List the configured hooks
+    List,
+    /// Replay a stored event through the hooks and report the results
+    Test(HooksTestArgs),
+    /// Install git commit-msg, pre-commit and post-commit hooks
+    Install(HooksInstallArgs),
+    /// Remove the git hooks written by `br hooks install`
+    Uninstall,
+    /// Check the issue IDs in a commit message (run by the commit-msg hook)
+    #[command(hide = true)]
+    CommitMsg(HooksCommitMsgArgs),
+    /// Export and stage the JSONL (run by the pre-commit hook)
+    #[command(hide = true)]
+    PreCommit,
+    /// Close issues named in `Closes <id>` lines (run by the post-commit hook)
+    #[command(hide = true)]
+    PostCommit,
+}
+
+/// Arguments for the hooks install command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct HooksInstallArgs {
+    /// Reject commit messages that reference no issue (merges, reverts and
+    /// fixups are exempt)
+    #[arg(long)]
+    pub require_issue: bool,
+
+    /// Replace existing hooks not written by br (kept as `<hook>.bak`)
+    #[arg(long, short = 'f')]
+    pub force: bool,
+}
+
+/// Arguments for the hooks commit-msg command.
+#[derive(Args, Debug, Clone)]
+pub struct HooksCommitMsgArgs {
+    /// File holding the commit message (passed by git)
+    pub file: PathBuf,
+
+    /// Reject messages that reference no issue
+    #[arg(long)]
+    pub require_issue: bool,
+}
+
+/// Arguments for the hooks test command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct HooksTestArgs {
+    /// Event ID to replay (default: the most recent event)
+    pub event: Option<i64>,
+
+    /// Replay the most recent event of this issue instead
+    #[arg(long, conflicts_with = "event", add = ArgValueCompleter::new(issue_id_completer))]
+    pub issue: Option<String>,
+
+    /// Run only this hook (by name, see `br hooks list`), even if its trigger
+    /// or filters don't match the event
+    #[arg(long)]
+    pub hook: Option<String>,
+}
+
+/// Arguments for the compact command.
+#[derive(Args, Debug, Clone)]
+pub struct CompactArgs {
+    /// Issue IDs to compact (default: all eligible closed issues)
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub ids: Vec<String>,
+
+    /// Only compact issues closed at least this many days ago
+    #[arg(long, default_value_t = 30)]
+    pub older_than: i64,
+
+    /// Only compact issues whose description, design, notes and comments
+    /// together exceed this many characters
+    #[arg(long, default_value_t = 1000)]
+    pub min_size: usize,
+
+    /// Maximum digest length in characters
+    #[arg(long, default_value_t = crate::util::summarize::DEFAULT_DIGEST_CHARS)]
+    pub max_chars: usize,
+
+    /// External summarizer command (reads the issue as Markdown on stdin,
+    /// prints the digest); overrides `compact.summarizer` in config
+    #[arg(long)]
+    pub summarizer: Option<String>,
+
+    /// Compact at most this many issues
+    #[arg(long)]
+    pub limit: Option<usize>,
+
+    /// Show what would be compacted without changing anything
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+/// Arguments for the serve command.
+#[derive(Args, Debug, Clone)]
+pub struct ServeArgs {
+    /// Loopback address to listen on (there is no authentication)
+    #[arg(long, default_value = "127.0.0.1:7777")]
+    pub bind: String,
+
+    /// Milliseconds to wait after the last write before flushing to JSONL
+    #[arg(long, default_value_t = 1000)]
+    pub flush_debounce: u64,
+}
+
 /// Arguments for the graph command.
 #[derive(Args, Debug, Clone, Default)]
 pub struct GraphArgs {
@@ -2348,6 +3141,51 @@ pub struct GraphArgs {
     /// One line per issue (compact output)
     #[arg(long)]
     pub compact: bool,
+
+    /// Export every dependency type instead of rendering (dot, mermaid, graphml, json-graph)
+    #[arg(long, value_enum)]
+    pub format: Option<GraphFormat>,
+
+    /// With --format: only issues with this label (plus the root issue)
+    #[arg(long, short = 'l', add = ArgValueCompleter::new(label_completer))]
+    pub label: Option<String>,
+
+    /// With --format: include closed issues
+    #[arg(long)]
+    pub include_closed: bool,
+}
+
+/// Export format for `br graph --format`.
+#[derive(ValueEnum, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum GraphFormat {
+    /// Graphviz DOT
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+    /// GraphML XML
+    Graphml,
+    /// JSON Graph Format
+    JsonGraph,
+}
+
+/// Arguments for the plan command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct PlanArgs {
+    /// Issue to plan (usually an epic); plans all open work when omitted
+    #[arg(add = ArgValueCompleter::new(open_issue_id_completer))]
+    pub id: Option<String>,
+
+    /// Schedule start (YYYY-MM-DD, RFC3339, or relative like +1d; default: now)
+    #[arg(long)]
+    pub start: Option<String>,
+
+    /// Estimate for issues without one, e.g. 2h (overrides plan.default_estimate)
+    #[arg(long)]
+    pub default_estimate: Option<String>,
+
+    /// Working hours per day (overrides plan.hours_per_day)
+    #[arg(long)]
+    pub hours_per_day: Option<String>,
 }
 
 /// Arguments for the agents command.
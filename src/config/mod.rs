@@ -12,12 +12,19 @@
 pub mod routing;
 
 use crate::error::{BeadsError, Result};
+use crate::hooks::{
+    DEFAULT_TIMEOUT_SECS, FailurePolicy, HOOK_ENV, HOOK_LOCKED_ENV, Hook, HookAction, Hooks,
+    TRIGGERS,
+};
 use crate::model::{IssueType, Priority};
-use crate::storage::SqliteStorage;
+use crate::storage::undo::SYSTEM_ACTOR;
+use crate::storage::{ScoreWeights, SqliteStorage};
 use crate::sync::{
-    ExportConfig, ImportConfig, export_to_jsonl_with_policy, finalize_export, import_from_jsonl,
+    ExportConfig, ImportConfig, auto_flush, export_to_jsonl_with_policy, finalize_export,
+    import_from_jsonl,
 };
 use crate::util::id::IdConfig;
+use crate::util::time::{parse_duration_minutes, parse_flexible_timestamp};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::env;
@@ -367,13 +374,25 @@ pub fn open_storage_with_cli(beads_dir: &Path, cli: &CliOverrides) -> Result<Ope
         .db
         .clone()
         .or_else(|| db_override_from_layer(&merged_layer));
-    let resolved_lock_timeout = cli
-        .lock_timeout
-        .or_else(|| lock_timeout_from_layer(&merged_layer))
-        .or(Some(30000));
+    // Under a blocking hook the write lock is held until the hook returns,
+    // so waiting for it would only run the hook into its timeout
+    let resolved_lock_timeout = if env::var_os(HOOK_LOCKED_ENV).is_some() {
+        Some(0)
+    } else {
+        cli.lock_timeout
+            .or_else(|| lock_timeout_from_layer(&merged_layer))
+            .or(Some(30000))
+    };
 
     let paths = ConfigPaths::resolve(beads_dir, resolved_db_override.as_ref())?;
 
+    // Commands run by a hook don't fire hooks themselves
+    let hooks = if env::var_os(HOOK_ENV).is_some() {
+        Hooks::default()
+    } else {
+        hooks_from_layer(&merged_layer)?
+    };
+
     if no_db {
         let mut storage = SqliteStorage::open_memory()?;
         let prefix = resolve_no_db_prefix(beads_dir, &paths.jsonl_path)?;
@@ -393,6 +412,7 @@ pub fn open_storage_with_cli(beads_dir: &Path, cli: &CliOverrides) -> Result<Ope
                 Some(&prefix),
             )?;
         }
+        storage.set_hooks(hooks);
 
         Ok(OpenStorageResult {
             storage,
@@ -400,7 +420,20 @@ pub fn open_storage_with_cli(beads_dir: &Path, cli: &CliOverrides) -> Result<Ope
             no_db,
         })
     } else {
-        let storage = SqliteStorage::open_with_timeout(&paths.db_path, resolved_lock_timeout)?;
+        let mut storage = SqliteStorage::open_with_timeout(&paths.db_path, resolved_lock_timeout)?;
+        storage.set_hooks(hooks);
+        // Claim leases are local to the DB, so expiry only needs handling here
+        match storage.release_expired_leases(SYSTEM_ACTOR) {
+            // Read-only commands skip the post-command flush, so export the
+            // released issues now rather than leave issues.jsonl stale
+            Ok(released) if !released.is_empty() && !no_auto_flush_from_layer(&merged_layer) => {
+                if let Err(err) = auto_flush(&mut storage, beads_dir) {
+                    warn!(error = %err, "Failed to flush released leases");
+                }
+            }
+            Ok(_) => {}
+            Err(err) => warn!(error = %err, "Failed to release expired leases"),
+        }
         Ok(OpenStorageResult {
             storage,
             paths,
@@ -409,10 +442,37 @@ pub fn open_storage_with_cli(beads_dir: &Path, cli: &CliOverrides) -> Result<Ope
     }
 }
 
+/// Like [`open_storage_with_cli`], but with `as_of` the storage is an
+/// in-memory snapshot of the workspace as it stood then (see
+/// [`SqliteStorage::snapshot_as_of`]).
+///
+/// # Errors
+///
+/// Returns an error if `as_of` is not a valid time or the storage cannot be
+/// opened or rewound.
+pub fn open_storage_as_of(
+    beads_dir: &Path,
+    cli: &CliOverrides,
+    as_of: Option<&str>,
+) -> Result<OpenStorageResult> {
+    let mut opened = open_storage_with_cli(beads_dir, cli)?;
+    if let Some(value) = as_of {
+        let as_of = parse_flexible_timestamp(value, "as_of")?;
+        opened.storage = opened.storage.snapshot_as_of(as_of)?;
+    }
+    Ok(opened)
+}
+
 fn no_db_from_layer(layer: &ConfigLayer) -> Option<bool> {
     get_startup_value(layer, &["no-db", "no_db", "no.db"]).and_then(|value| parse_bool(value))
 }
 
+fn no_auto_flush_from_layer(layer: &ConfigLayer) -> bool {
+    get_startup_value(layer, &["no-auto-flush", "no_auto_flush"])
+        .and_then(|value| parse_bool(value))
+        .unwrap_or(false)
+}
+
 fn resolve_no_db_prefix(beads_dir: &Path, jsonl_path: &Path) -> Result<String> {
     let project_layer = load_project_config(beads_dir)?;
     if let Some(prefix) = get_value(&project_layer, &["issue_prefix", "issue-prefix", "prefix"]) {
@@ -917,14 +977,15 @@ pub fn external_projects_from_layer(
 /// Resolve external project DB paths from config.
 ///
 /// Projects are expected to be either a `.beads` directory or a project root
-/// containing `.beads/`.
+/// containing `.beads/`. Projects reachable through routes are also available
+/// under their directory name; a configured name takes precedence.
 #[must_use]
 pub fn external_project_db_paths(
     layer: &ConfigLayer,
     beads_dir: &Path,
 ) -> HashMap<String, PathBuf> {
     let projects = external_projects_from_layer(layer, beads_dir);
-    let mut db_paths = HashMap::new();
+    let mut db_paths = routed_project_db_paths(beads_dir);
 
     for (name, path) in projects {
         let beads_path = if path.file_name().is_some_and(|name| name == ".beads") {
@@ -960,6 +1021,442 @@ pub fn external_project_db_paths(
     db_paths
 }
 
+/// DB paths of the routed projects other than the current one, by name.
+fn routed_project_db_paths(beads_dir: &Path) -> HashMap<String, PathBuf> {
+    let projects = match routing::discover_routed_projects(beads_dir) {
+        Ok(projects) => projects,
+        Err(err) => {
+            warn!(error = %err, "Failed to read routes for external projects");
+            return HashMap::new();
+        }
+    };
+    projects
+        .into_iter()
+        .filter(|project| !project.is_local)
+        .filter_map(|project| {
+            ConfigPaths::resolve(&project.beads_dir, None)
+                .ok()
+                .map(|paths| (project.name, paths.db_path))
+        })
+        .collect()
+}
+
+/// A routed project opened for a cross-project (`--town`) view.
+pub struct TownProject {
+    /// Project name shown on each row.
+    pub name: String,
+    pub beads_dir: PathBuf,
+    pub storage: SqliteStorage,
+    /// DB paths for resolving `external:<project>:<capability>` blockers: every
+    /// routed project by name, overridden by the project's `external_projects`.
+    pub external_db_paths: HashMap<String, PathBuf>,
+}
+
+/// Open the current project and every project reachable through routes.
+///
+/// The current project is opened with `cli` (so `--db` and friends apply);
+/// routed projects use their own config. Projects that fail to open are
+/// skipped with a warning.
+///
+/// # Errors
+///
+/// Returns an error if the routes cannot be read or the current project
+/// cannot be opened.
+pub fn open_town_projects(beads_dir: &Path, cli: &CliOverrides) -> Result<Vec<TownProject>> {
+    let projects = routing::discover_routed_projects(beads_dir)?;
+    let mut routed_db_paths = HashMap::new();
+    for project in &projects {
+        if let Ok(paths) = ConfigPaths::resolve(&project.beads_dir, None) {
+            routed_db_paths.insert(project.name.clone(), paths.db_path);
+        }
+    }
+
+    let mut opened = Vec::with_capacity(projects.len());
+    for project in projects {
+        let overrides = if project.is_local {
+            cli.clone()
+        } else {
+            CliOverrides::default()
+        };
+        let storage = match open_storage_with_cli(&project.beads_dir, &overrides) {
+            Ok(result) => result.storage,
+            Err(err) if !project.is_local => {
+                warn!(
+                    project = %project.name,
+                    path = %project.beads_dir.display(),
+                    error = %err,
+                    "Skipping routed project that cannot be opened"
+                );
+                continue;
+            }
+            Err(err) => return Err(err),
+        };
+        let layer = load_config(&project.beads_dir, Some(&storage), &overrides)?;
+        let mut external_db_paths = routed_db_paths.clone();
+        external_db_paths.remove(&project.name);
+        external_db_paths.extend(external_project_db_paths(&layer, &project.beads_dir));
+        opened.push(TownProject {
+            name: project.name,
+            beads_dir: project.beads_dir,
+            storage,
+            external_db_paths,
+        });
+    }
+    Ok(opened)
+}
+
+/// Resolve the external summarizer command for `br compact`.
+///
+/// Startup-only (YAML or environment), since the value is executed.
+#[must_use]
+pub fn compact_summarizer_from_layer(layer: &ConfigLayer) -> Option<String> {
+    get_startup_value(layer, &["compact.summarizer", "compact-summarizer"])
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+/// Issues left out of `br changelog` regardless of command-line flags.
+#[derive(Debug, Clone, Default)]
+pub struct ChangelogExclusions {
+    /// Labels whose issues are skipped.
+    pub labels: Vec<String>,
+    /// Issue types that are skipped.
+    pub types: Vec<String>,
+    /// Close reasons (case-insensitive substrings) that are skipped.
+    pub reasons: Vec<String>,
+}
+
+/// Resolve `br changelog` exclusion rules from config.
+///
+/// Keys: `changelog.exclude_labels`, `changelog.exclude_types` and
+/// `changelog.exclude_reasons`, each a list or comma-separated string.
+#[must_use]
+pub fn changelog_exclusions_from_layer(layer: &ConfigLayer) -> ChangelogExclusions {
+    let mut exclusions = ChangelogExclusions::default();
+    let iter = layer.startup.iter().chain(layer.runtime.iter());
+
+    for (key, value) in iter {
+        let key_lower = key.to_lowercase().replace('-', "_");
+        let target = match key_lower.as_str() {
+            "changelog.exclude_labels" => &mut exclusions.labels,
+            "changelog.exclude_types" => &mut exclusions.types,
+            "changelog.exclude_reasons" => &mut exclusions.reasons,
+            _ => continue,
+        };
+        for item in value.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            if !target.iter().any(|existing| existing == item) {
+                target.push(item.to_string());
+            }
+        }
+    }
+
+    exclusions
+}
+
+/// Default estimate for `br plan` when neither the issue nor its type has one.
+pub const DEFAULT_PLAN_ESTIMATE_MINUTES: i64 = 60;
+
+/// Default working hours per day for `br plan` (matches `1d` in durations).
+pub const DEFAULT_PLAN_HOURS_PER_DAY: f64 = 8.0;
+
+/// Scheduling inputs for `br plan`.
+#[derive(Debug, Clone)]
+pub struct PlanConfig {
+    /// Estimate (minutes) for issues with no `estimated_minutes`.
+    pub default_estimate: i64,
+    /// Per-type estimate overrides (minutes), keyed by issue type.
+    pub type_estimates: HashMap<String, i64>,
+    /// Working hours per day for assignees without a capacity entry.
+    pub hours_per_day: f64,
+    /// Per-assignee working hours per day.
+    pub capacity: HashMap<String, f64>,
+}
+
+impl Default for PlanConfig {
+    fn default() -> Self {
+        Self {
+            default_estimate: DEFAULT_PLAN_ESTIMATE_MINUTES,
+            type_estimates: HashMap::new(),
+            hours_per_day: DEFAULT_PLAN_HOURS_PER_DAY,
+            capacity: HashMap::new(),
+        }
+    }
+}
+
+impl PlanConfig {
+    /// Estimate (minutes) used for an issue of `issue_type` with no estimate.
+    #[must_use]
+    pub fn estimate_for(&self, issue_type: &IssueType) -> i64 {
+        self.type_estimates
+            .get(issue_type.as_str())
+            .copied()
+            .unwrap_or(self.default_estimate)
+    }
+
+    /// Working hours per day for `assignee` (unassigned work uses the default).
+    #[must_use]
+    pub fn hours_for(&self, assignee: Option<&str>) -> f64 {
+        assignee
+            .and_then(|name| self.capacity.get(name))
+            .copied()
+            .unwrap_or(self.hours_per_day)
+    }
+}
+
+/// Resolve `br plan` scheduling inputs from config.
+///
+/// Keys: `plan.default_estimate` (duration, e.g. `2h`), `plan.estimate.<type>`
+/// (duration), `plan.hours_per_day`, and `plan.capacity.<assignee>` (hours per
+/// day).
+///
+/// # Errors
+///
+/// Returns an error if a duration or hour value is invalid.
+pub fn plan_config_from_layer(layer: &ConfigLayer) -> Result<PlanConfig> {
+    let mut config = PlanConfig::default();
+    let iter = layer.startup.iter().chain(layer.runtime.iter());
+
+    for (key, value) in iter {
+        let key_lower = key.to_lowercase().replace('-', "_");
+        let Some(rest) = key_lower.strip_prefix("plan.") else {
+            continue;
+        };
+        let value = value.trim();
+        if rest == "default_estimate" {
+            config.default_estimate = parse_duration_minutes(value, key)?;
+        } else if rest == "hours_per_day" {
+            config.hours_per_day = parse_hours_per_day(value, key)?;
+        } else if let Some(issue_type) = rest.strip_prefix("estimate.") {
+            config
+                .type_estimates
+                .insert(issue_type.to_string(), parse_duration_minutes(value, key)?);
+        } else if rest.starts_with("capacity.") {
+            // Keep the assignee's original case
+            let assignee = key.split_once(".capacity.").map_or("", |(_, name)| name);
+            config.capacity.insert(
+                assignee.trim().to_string(),
+                parse_hours_per_day(value, key)?,
+            );
+        }
+    }
+
+    Ok(config)
+}
+
+/// Parse a positive number of working hours per day (at most 24).
+///
+/// # Errors
+///
+/// Returns an error if the value is not a number in `(0, 24]`.
+pub fn parse_hours_per_day(value: &str, field_name: &str) -> Result<f64> {
+    match value.trim().parse::<f64>() {
+        Ok(hours) if hours > 0.0 && hours <= 24.0 => Ok(hours),
+        _ => Err(BeadsError::validation(
+            field_name,
+            format!("expected hours per day between 0 and 24, got '{value}'"),
+        )),
+    }
+}
+
+/// Resolve `br ready --sort score` weights from config.
+///
+/// Keys: `ready.score.priority`, `ready.score.age`, `ready.score.due`,
+/// `ready.score.unblocks`, `ready.score.staleness`, and
+/// `ready.score.label.<label>` (added for issues with that label).
+///
+/// # Errors
+///
+/// Returns an error if a weight is not a finite number.
+pub fn score_weights_from_layer(layer: &ConfigLayer) -> Result<ScoreWeights> {
+    let mut weights = ScoreWeights::default();
+    let iter = layer.startup.iter().chain(layer.runtime.iter());
+
+    for (key, value) in iter {
+        let key_lower = key.to_lowercase().replace('-', "_");
+        let Some(rest) = key_lower.strip_prefix("ready.score.") else {
+            continue;
+        };
+        let weight = match value.trim().parse::<f64>() {
+            Ok(weight) if weight.is_finite() => weight,
+            _ => {
+                return Err(BeadsError::validation(
+                    key,
+                    format!("expected a number, got '{value}'"),
+                ));
+            }
+        };
+        match rest {
+            "priority" => weights.priority = weight,
+            "age" => weights.age = weight,
+            "due" => weights.due = weight,
+            "unblocks" => weights.unblocks = weight,
+            "staleness" => weights.staleness = weight,
+            _ => {
+                if rest.starts_with("label.") {
+                    // Keep the label's original case
+                    let label = key.split_once(".label.").map_or("", |(_, name)| name);
+                    weights.labels.insert(label.trim().to_string(), weight);
+                }
+            }
+        }
+    }
+
+    Ok(weights)
+}
+
+/// Settings of one hook, gathered from its `hooks.*` keys.
+struct HookFields<'a> {
+    trigger: String,
+    /// `(field, key, value)`
+    fields: Vec<(String, &'a str, &'a str)>,
+}
+
+/// Resolve lifecycle hooks from config.
+///
+/// `hooks.<trigger>: <command or URL>` and `hooks.<trigger>.<name>: <command
+/// or URL>` are shorthands; otherwise hooks are configured with
+/// `hooks.<trigger>.<field>` or `hooks.<trigger>.<name>.<field>`, where the
+/// fields are `command`, `url`, `labels`, `types`, `timeout` (seconds) and
+/// `on_failure` (`warn` or `block`). Keys with an unknown trigger are ignored
+/// with a warning.
+///
+/// # Errors
+///
+/// Returns an error if a hook has no (or both a) command and URL, or a field
+/// is unknown or has an invalid value.
+#[allow(clippy::too_many_lines)]
+pub fn hooks_from_layer(layer: &ConfigLayer) -> Result<Hooks> {
+    const FIELDS: &[&str] = &["command", "url", "labels", "types", "timeout", "on_failure"];
+    let command_or_url = |value: &str| {
+        let value = value.trim();
+        if value.starts_with("http://") || value.starts_with("https://") {
+            "url"
+        } else {
+            "command"
+        }
+    };
+
+    let mut specs: HashMap<String, HookFields<'_>> = HashMap::new();
+    for (key, value) in layer.startup.iter().chain(layer.runtime.iter()) {
+        let key_lower = key.to_lowercase().replace('-', "_");
+        let Some(rest) = key_lower.strip_prefix("hooks.") else {
+            continue;
+        };
+        let parts: Vec<&str> = rest.split('.').collect();
+        let trigger = parts[0];
+        if !TRIGGERS.contains(&trigger) {
+            warn!(key = %key, "Ignoring hook with unknown trigger (expected one of {})", TRIGGERS.join(", "));
+            continue;
+        }
+        // Keep the hook name's original case
+        let name = key.split('.').nth(2).unwrap_or_default().trim();
+        let (name, field) = match parts.as_slice() {
+            [_] => (trigger.to_string(), command_or_url(value)),
+            [_, field] if FIELDS.contains(field) => (trigger.to_string(), *field),
+            [_, _] => (format!("{trigger}.{name}"), command_or_url(value)),
+            [_, _, field] => (format!("{trigger}.{name}"), *field),
+            _ => {
+                return Err(BeadsError::validation(
+                    key,
+                    "expected hooks.<trigger>[.<name>].<field>",
+                ));
+            }
+        };
+        specs
+            .entry(name)
+            .or_insert_with(|| HookFields {
+                trigger: trigger.to_string(),
+                fields: Vec::new(),
+            })
+            .fields
+            .push((field.to_string(), key.as_str(), value.as_str()));
+    }
+
+    let mut hooks = Vec::new();
+    for (name, spec) in specs {
+        let mut action = None;
+        let mut labels = Vec::new();
+        let mut types = Vec::new();
+        let mut timeout_secs = DEFAULT_TIMEOUT_SECS;
+        let mut on_failure = FailurePolicy::default();
+        let split = |value: &str| {
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        };
+
+        for (field, key, value) in spec.fields {
+            let value = value.trim();
+            match field.as_str() {
+                "command" | "url" => {
+                    if action.is_some() {
+                        return Err(BeadsError::validation(
+                            key,
+                            format!("hook {name} has both a command and a url"),
+                        ));
+                    }
+                    action = Some(if field == "url" {
+                        HookAction::Url(value.to_string())
+                    } else {
+                        HookAction::Command(value.to_string())
+                    });
+                }
+                "labels" => labels = split(value),
+                "types" => types = split(value),
+                "timeout" => {
+                    timeout_secs = value.parse().map_err(|_| {
+                        BeadsError::validation(key, format!("expected seconds, got '{value}'"))
+                    })?;
+                }
+                "on_failure" => {
+                    on_failure = match value.to_lowercase().as_str() {
+                        "warn" => FailurePolicy::Warn,
+                        "block" => FailurePolicy::Block,
+                        _ => {
+                            return Err(BeadsError::validation(
+                                key,
+                                format!("expected warn or block, got '{value}'"),
+                            ));
+                        }
+                    };
+                }
+                _ => {
+                    return Err(BeadsError::validation(
+                        key,
+                        format!(
+                            "unknown hook setting (expected one of {})",
+                            FIELDS.join(", ")
+                        ),
+                    ));
+                }
+            }
+        }
+
+        let action = action
+            .filter(|action| match action {
+                HookAction::Command(target) | HookAction::Url(target) => !target.is_empty(),
+            })
+            .ok_or_else(|| {
+                BeadsError::validation(format!("hooks.{name}"), "expected a command or url")
+            })?;
+        hooks.push(Hook {
+            name,
+            trigger: spec.trigger,
+            action,
+            labels,
+            types,
+            timeout_secs,
+            on_failure,
+        });
+    }
+
+    Ok(Hooks::new(hooks))
+}
+
 /// Resolve actor from a merged config layer.
 #[must_use]
 pub fn actor_from_layer(layer: &ConfigLayer) -> Option<String> {
@@ -996,6 +1493,8 @@ pub fn is_startup_key(key: &str) -> bool {
         || normalized.starts_with("directory.")
         || normalized.starts_with("sync.")
         || normalized.starts_with("external-projects.")
+        || normalized.starts_with("compact.")
+        || normalized.starts_with("hooks.")
     {
         return true;
     }
@@ -1810,6 +2309,101 @@ routing:
         assert_eq!(actor, "test_actor");
     }
 
+    #[test]
+    fn plan_config_from_layer_reads_estimates_and_capacity() {
+        let mut layer = ConfigLayer::default();
+        layer
+            .startup
+            .insert("plan.estimate.bug".to_string(), "3h".to_string());
+        layer
+            .startup
+            .insert("plan.capacity.Alice".to_string(), "4".to_string());
+        layer
+            .runtime
+            .insert("plan.default-estimate".to_string(), "2h".to_string());
+
+        let config = plan_config_from_layer(&layer).expect("plan config");
+        assert_eq!(config.estimate_for(&IssueType::Bug), 180);
+        assert_eq!(config.estimate_for(&IssueType::Task), 120);
+        assert!((config.hours_for(Some("Alice")) - 4.0).abs() < f64::EPSILON);
+        assert!((config.hours_for(None) - DEFAULT_PLAN_HOURS_PER_DAY).abs() < f64::EPSILON);
+
+        layer
+            .runtime
+            .insert("plan.hours_per_day".to_string(), "30".to_string());
+        assert!(plan_config_from_layer(&layer).is_err());
+    }
+
+    #[test]
+    fn changelog_exclusions_from_layer_splits_lists() {
+        let mut layer = ConfigLayer::default();
+        layer.startup.insert(
+            "changelog.exclude-labels".to_string(),
+            "internal, wontfix".to_string(),
+        );
+        layer
+            .runtime
+            .insert("changelog.exclude_types".to_string(), "chore".to_string());
+
+        let exclusions = changelog_exclusions_from_layer(&layer);
+        assert_eq!(exclusions.labels, vec!["internal", "wontfix"]);
+        assert_eq!(exclusions.types, vec!["chore"]);
+        assert!(exclusions.reasons.is_empty());
+    }
+
+    #[test]
+    fn score_weights_from_layer_reads_factors_and_labels() {
+        let mut layer = ConfigLayer::default();
+        layer
+            .startup
+            .insert("ready.score.unblocks".to_string(), "5".to_string());
+        layer
+            .startup
+            .insert("ready.score.label.Customer".to_string(), "-1.5".to_string());
+
+        let weights = score_weights_from_layer(&layer).expect("score weights");
+        assert!((weights.unblocks - 5.0).abs() < f64::EPSILON);
+        assert!((weights.priority - ScoreWeights::default().priority).abs() < f64::EPSILON);
+        assert_eq!(weights.labels.get("Customer"), Some(&-1.5));
+
+        layer
+            .runtime
+            .insert("ready.score.age".to_string(), "lots".to_string());
+        assert!(score_weights_from_layer(&layer).is_err());
+    }
+
+    #[test]
+    fn hooks_from_layer_reads_shorthand_and_named_hooks() {
+        let mut layer = ConfigLayer::default();
+        for (key, value) in [
+            ("hooks.on_close", "./notify.sh"),
+            ("hooks.on_create.Triage.url", "https://example.com/hook"),
+            ("hooks.on_create.Triage.types", "bug,feature"),
+            ("hooks.on_create.Triage.on_failure", "block"),
+            ("hooks.on_create.Triage.timeout", "5"),
+            ("hooks.on_typo", "true"),
+        ] {
+            layer.startup.insert(key.to_string(), value.to_string());
+        }
+
+        let hooks = hooks_from_layer(&layer).expect("hooks");
+        assert_eq!(hooks.hooks().len(), 2);
+        let close = hooks.get("on_close").expect("shorthand hook");
+        assert_eq!(close.action, HookAction::Command("./notify.sh".to_string()));
+        assert_eq!(close.on_failure, FailurePolicy::Warn);
+        let triage = hooks.get("on_create.Triage").expect("named hook");
+        assert_eq!(triage.trigger, "on_create");
+        assert_eq!(triage.types, vec!["bug", "feature"]);
+        assert_eq!(triage.timeout_secs, 5);
+        assert_eq!(triage.on_failure, FailurePolicy::Block);
+
+        layer.startup.insert(
+            "hooks.on_create.Triage.on_failure".to_string(),
+            "ignore".to_string(),
+        );
+        assert!(hooks_from_layer(&layer).is_err());
+    }
+
     #[test]
     fn resolve_actor_falls_back_to_unknown() {
         let layer = ConfigLayer::default();
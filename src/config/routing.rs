@@ -20,6 +20,7 @@
 
 use crate::error::{BeadsError, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::fs::{self, File};
 use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
@@ -318,6 +319,85 @@ fn resolve_route_entry(
     }
 }
 
+/// A project reachable through routes, for cross-project (`--town`) views.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoutedProject {
+    /// Display name: the project directory (the parent of `.beads`).
+    pub name: String,
+    /// The resolved beads directory.
+    pub beads_dir: PathBuf,
+    /// Whether this is the current project.
+    pub is_local: bool,
+}
+
+/// Name a project after the directory holding its `.beads`.
+#[must_use]
+pub fn project_name(beads_dir: &Path) -> String {
+    beads_dir.parent().and_then(Path::file_name).map_or_else(
+        || beads_dir.display().to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    )
+}
+
+/// List the current project and every project named in the local and town
+/// `routes.jsonl`, current project first.
+///
+/// Each beads directory appears once. Routes whose target cannot be resolved
+/// are skipped with a warning.
+///
+/// # Errors
+///
+/// Returns an error if a routes file exists but cannot be read or parsed.
+pub fn discover_routed_projects(local_beads_dir: &Path) -> Result<Vec<RoutedProject>> {
+    let project_root = local_beads_dir.parent().unwrap_or(local_beads_dir);
+    let mut sources = vec![(
+        load_routes(&local_beads_dir.join("routes.jsonl"))?,
+        project_root.to_path_buf(),
+    )];
+    if let Some(town_root) = find_town_root(project_root) {
+        let town_beads_dir = town_root.join(".beads");
+        if town_beads_dir != *local_beads_dir && town_beads_dir.is_dir() {
+            sources.push((
+                load_routes(&town_beads_dir.join("routes.jsonl"))?,
+                town_root,
+            ));
+        }
+    }
+
+    let canonical = |path: &Path| path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let mut seen = HashSet::from([canonical(local_beads_dir)]);
+    let mut projects = vec![RoutedProject {
+        name: project_name(local_beads_dir),
+        beads_dir: local_beads_dir.to_path_buf(),
+        is_local: true,
+    }];
+
+    for (routes, base_dir) in &sources {
+        for route in routes {
+            match resolve_route_entry(route, base_dir, local_beads_dir) {
+                Ok(result) => {
+                    if seen.insert(canonical(&result.beads_dir)) {
+                        projects.push(RoutedProject {
+                            name: project_name(&result.beads_dir),
+                            beads_dir: result.beads_dir,
+                            is_local: false,
+                        });
+                    }
+                }
+                Err(err) => warn!(
+                    prefix = %route.prefix,
+                    path = %route.path,
+                    error = %err,
+                    "Skipping route that cannot be resolved"
+                ),
+            }
+        }
+    }
+
+    debug!(count = projects.len(), "Discovered routed projects");
+    Ok(projects)
+}
+
 /// Check if an issue ID would be routed externally.
 ///
 /// Quick check without fully resolving the route.
@@ -507,4 +587,58 @@ mod tests {
         let result = find_town_root(dir.path());
         assert!(result.is_none());
     }
+
+    #[test]
+    fn discover_routed_projects_lists_local_and_routes() {
+        let dir = TempDir::new().unwrap();
+        let local = dir.path().join("backend").join(".beads");
+        let frontend = dir.path().join("frontend").join(".beads");
+        fs::create_dir_all(&local).unwrap();
+        fs::create_dir_all(&frontend).unwrap();
+        fs::write(
+            local.join("routes.jsonl"),
+            r#"{"prefix":"fe-","path":"../frontend"}
+{"prefix":"be-","path":"."}
+{"prefix":"gone-","path":"../missing"}
+"#,
+        )
+        .unwrap();
+
+        let projects = discover_routed_projects(&local).unwrap();
+        let names: Vec<&str> = projects.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["backend", "frontend"]);
+        assert!(projects[0].is_local);
+        assert!(!projects[1].is_local);
+    }
+
+    #[test]
+    fn discover_routed_projects_includes_town_routes_once() {
+        let dir = TempDir::new().unwrap();
+        let town = dir.path().join("town");
+        fs::create_dir_all(town.join("mayor")).unwrap();
+        fs::write(town.join("mayor/town.json"), "{}").unwrap();
+        for project in ["backend", "frontend", "infra"] {
+            fs::create_dir_all(town.join(project).join(".beads")).unwrap();
+        }
+        fs::create_dir_all(town.join(".beads")).unwrap();
+        let local = town.join("backend").join(".beads");
+        fs::write(
+            local.join("routes.jsonl"),
+            r#"{"prefix":"fe-","path":"../frontend"}"#,
+        )
+        .unwrap();
+        // The town routes repeat frontend and the local project, and add infra
+        fs::write(
+            town.join(".beads/routes.jsonl"),
+            r#"{"prefix":"fe-","path":"frontend"}
+{"prefix":"be-","path":"backend"}
+{"prefix":"in-","path":"infra"}
+"#,
+        )
+        .unwrap();
+
+        let projects = discover_routed_projects(&local).unwrap();
+        let names: Vec<&str> = projects.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["backend", "frontend", "infra"]);
+    }
 }
@@ -17,6 +17,7 @@ mod structured;
 pub use context::{OptionExt, ResultExt};
 pub use structured::{ErrorCode, StructuredError};
 
+use chrono::{DateTime, Utc};
 use std::path::PathBuf;
 use thiserror::Error;
 
@@ -63,6 +64,14 @@ pub enum BeadsError {
     #[error("Invalid issue ID format: {id}")]
     InvalidId { id: String },
 
+    /// Another actor holds an unexpired lease on the issue.
+    #[error("Issue {id} is leased by {holder} until {expires_at}")]
+    LeaseHeld {
+        id: String,
+        holder: String,
+        expires_at: DateTime<Utc>,
+    },
+
     // === Validation Errors ===
     /// Field validation failed.
     #[error("Validation failed: {field}: {reason}")]
@@ -84,6 +93,10 @@ pub enum BeadsError {
     #[error("Priority must be 0-4, got: {priority}")]
     InvalidPriority { priority: i32 },
 
+    /// A lifecycle hook with `on_failure: block` failed, rolling back the change.
+    #[error("Hook {hook} rejected the change: {reason}")]
+    HookRejected { hook: String, reason: String },
+
     // === JSONL Errors ===
     /// Failed to parse a line in the JSONL file.
     #[error("JSONL parse error at line {line}: {reason}")]
@@ -222,6 +235,10 @@ impl BeadsError {
             Self::NotInitialized => Some("Run: br init"),
             Self::DatabaseNotFound { .. } => Some("Check path or run: br init"),
             Self::AmbiguousId { .. } => Some("Provide more characters of the ID"),
+            Self::LeaseHeld { .. } => Some("Pick another issue or retry after the lease expires"),
+            Self::HookRejected { .. } => {
+                Some("Check the hook's output or replay it with: br hooks test")
+            }
             Self::HasDependents { .. } => Some("Use --force or --cascade to delete anyway"),
             Self::ImportCollision { .. } => Some("Use --force to overwrite or resolve manually"),
             Self::DependencyCycle { .. } => Some("Remove one dependency to break the cycle"),
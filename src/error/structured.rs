@@ -53,6 +53,8 @@ pub enum ErrorCode {
     IdCollision,
     /// Invalid issue ID format
     InvalidId,
+    /// Issue is claimed under another actor's unexpired lease
+    LeaseHeld,
 
     // === Validation Errors (exit code 4) ===
     /// Field validation failed
@@ -65,6 +67,8 @@ pub enum ErrorCode {
     InvalidPriority,
     /// Required field missing
     RequiredField,
+    /// A blocking lifecycle hook failed
+    HookRejected,
 
     // === Dependency Errors (exit code 5) ===
     /// Dependency cycle detected
@@ -128,12 +132,14 @@ impl ErrorCode {
             Self::AmbiguousId => "AMBIGUOUS_ID",
             Self::IdCollision => "ID_COLLISION",
             Self::InvalidId => "INVALID_ID",
+            Self::LeaseHeld => "LEASE_HELD",
             // Validation
             Self::ValidationFailed => "VALIDATION_FAILED",
             Self::InvalidStatus => "INVALID_STATUS",
             Self::InvalidType => "INVALID_TYPE",
             Self::InvalidPriority => "INVALID_PRIORITY",
             Self::RequiredField => "REQUIRED_FIELD",
+            Self::HookRejected => "HOOK_REJECTED",
             // Dependency
             Self::CycleDetected => "CYCLE_DETECTED",
             Self::DependencyNotFound => "DEPENDENCY_NOT_FOUND",
@@ -175,6 +181,7 @@ impl ErrorCode {
                 | Self::InvalidPriority
                 | Self::RequiredField
                 | Self::AmbiguousId
+                | Self::LeaseHeld
         )
     }
 
@@ -200,13 +207,18 @@ impl ErrorCode {
             | Self::NotInitialized
             | Self::AlreadyInitialized => 2,
             // Issue (3)
-            Self::IssueNotFound | Self::AmbiguousId | Self::IdCollision | Self::InvalidId => 3,
+            Self::IssueNotFound
+            | Self::AmbiguousId
+            | Self::IdCollision
+            | Self::InvalidId
+            | Self::LeaseHeld => 3,
             // Validation (4)
             Self::ValidationFailed
             | Self::InvalidStatus
             | Self::InvalidType
             | Self::InvalidPriority
-            | Self::RequiredField => 4,
+            | Self::RequiredField
+            | Self::HookRejected => 4,
             // Dependency (5)
             Self::CycleDetected
             | Self::DependencyNotFound
@@ -508,6 +520,18 @@ impl StructuredError {
             ),
             BeadsError::IdCollision { id } => (ErrorCode::IdCollision, Some(json!({"id": id}))),
             BeadsError::InvalidId { id } => (ErrorCode::InvalidId, Some(json!({"id": id}))),
+            BeadsError::LeaseHeld {
+                id,
+                holder,
+                expires_at,
+            } => (
+                ErrorCode::LeaseHeld,
+                Some(json!({"id": id, "holder": holder, "expires_at": expires_at.to_rfc3339()})),
+            ),
+            BeadsError::HookRejected { hook, reason } => (
+                ErrorCode::HookRejected,
+                Some(json!({"hook": hook, "reason": reason})),
+            ),
             BeadsError::Validation { field, reason } => (
                 ErrorCode::ValidationFailed,
                 Some(json!({"field": field, "reason": reason})),
@@ -909,6 +933,8 @@ mod tests {
         assert_eq!(ErrorCode::IssueNotFound.as_str(), "ISSUE_NOT_FOUND");
         assert_eq!(ErrorCode::CycleDetected.as_str(), "CYCLE_DETECTED");
         assert_eq!(ErrorCode::NotInitialized.as_str(), "NOT_INITIALIZED");
+        assert_eq!(ErrorCode::LeaseHeld.as_str(), "LEASE_HELD");
+        assert_eq!(ErrorCode::HookRejected.as_str(), "HOOK_REJECTED");
     }
 
     #[test]
@@ -918,6 +944,7 @@ mod tests {
         assert!(ErrorCode::DatabaseLocked.is_retryable());
         assert!(ErrorCode::ValidationFailed.is_retryable());
         assert!(ErrorCode::InvalidPriority.is_retryable());
+        assert!(ErrorCode::LeaseHeld.is_retryable());
     }
 
     #[test]
@@ -3,7 +3,9 @@
 //! Provides CSV output for list/export commands. Handles proper escaping
 //! of fields containing commas, quotes, or newlines.
 
+use super::output::TimeSpent;
 use crate::model::Issue;
+use std::collections::HashMap;
 use std::io::{self, Write};
 
 /// Default fields for CSV export.
@@ -35,6 +37,8 @@ pub const ALL_FIELDS: &[&str] = &[
     "defer_until",
     "notes",
     "external_ref",
+    "estimated_minutes",
+    "actual_minutes",
 ];
 
 /// Escape a CSV field value.
@@ -55,6 +59,9 @@ pub fn escape_field(value: &str) -> String {
 }
 
 /// Get a field value from an issue by field name.
+///
+/// `actual_minutes` needs the work log and is always empty here; use
+/// [`format_csv_with_time`] to fill it.
 #[must_use]
 pub fn get_field_value(issue: &Issue, field: &str) -> String {
     match field {
@@ -77,6 +84,9 @@ pub fn get_field_value(issue: &Issue, field: &str) -> String {
             .map_or_else(String::new, |dt| dt.to_rfc3339()),
         "notes" => issue.notes.clone().unwrap_or_default(),
         "external_ref" => issue.external_ref.clone().unwrap_or_default(),
+        "estimated_minutes" => issue
+            .estimated_minutes
+            .map_or_else(String::new, |m| m.to_string()),
         _ => String::new(),
     }
 }
@@ -116,15 +126,49 @@ pub fn format_issue_row(issue: &Issue, fields: &[&str]) -> String {
         .join(",")
 }
 
+/// Whether the selected fields need logged time (see [`format_csv_with_time`]).
+#[must_use]
+pub fn needs_time(fields: &[&str]) -> bool {
+    fields.contains(&"actual_minutes")
+}
+
 /// Write issues as CSV to the given writer.
 ///
 /// # Errors
 ///
 /// Returns an error if writing fails.
 pub fn write_csv<W: Write>(writer: &mut W, issues: &[Issue], fields: &[&str]) -> io::Result<()> {
+    write_csv_with_time(writer, issues, fields, &HashMap::new())
+}
+
+/// Write issues as CSV, filling `actual_minutes` from `time_spent` (keyed by
+/// issue ID; the total includes time logged on descendants).
+///
+/// # Errors
+///
+/// Returns an error if writing fails.
+pub fn write_csv_with_time<W: Write>(
+    writer: &mut W,
+    issues: &[Issue],
+    fields: &[&str],
+    time_spent: &HashMap<String, TimeSpent>,
+) -> io::Result<()> {
     write_header(writer, fields)?;
     for issue in issues {
-        let row = format_issue_row(issue, fields);
+        let row = fields
+            .iter()
+            .map(|&field| {
+                if field == "actual_minutes" {
+                    time_spent
+                        .get(&issue.id)
+                        .map_or(0, |t| t.total_minutes)
+                        .to_string()
+                } else {
+                    escape_field(&get_field_value(issue, field))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",");
         writeln!(writer, "{row}")?;
     }
     Ok(())
@@ -137,9 +181,24 @@ pub fn write_csv<W: Write>(writer: &mut W, issues: &[Issue], fields: &[&str]) ->
 /// Panics if writing to the in-memory buffer fails (which should not happen).
 #[must_use]
 pub fn format_csv(issues: &[Issue], fields: &[&str]) -> String {
+    format_csv_with_time(issues, fields, &HashMap::new())
+}
+
+/// Format issues as a complete CSV string, filling `actual_minutes` from `time_spent`.
+///
+/// # Panics
+///
+/// Panics if writing to the in-memory buffer fails (which should not happen).
+#[must_use]
+pub fn format_csv_with_time(
+    issues: &[Issue],
+    fields: &[&str],
+    time_spent: &HashMap<String, TimeSpent>,
+) -> String {
     let mut output = Vec::new();
-    // write_csv should not fail with Vec<u8>
-    write_csv(&mut output, issues, fields).expect("writing to Vec should not fail");
+    // write_csv_with_time should not fail with Vec<u8>
+    write_csv_with_time(&mut output, issues, fields, time_spent)
+        .expect("writing to Vec should not fail");
     String::from_utf8_lossy(&output).into_owned()
 }
 
@@ -190,6 +249,8 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         }
     }
 
@@ -291,4 +352,24 @@ mod tests {
         write_header(&mut output, &["id", "title", "status"]).unwrap();
         assert_eq!(String::from_utf8(output).unwrap(), "id,title,status\n");
     }
+
+    #[test]
+    fn test_format_csv_with_time() {
+        let mut issue = make_test_issue("bd-1", "Estimated");
+        issue.estimated_minutes = Some(120);
+        let other = make_test_issue("bd-2", "Untracked");
+        let actual = HashMap::from([(
+            "bd-1".to_string(),
+            TimeSpent {
+                logged_minutes: 30,
+                total_minutes: 90,
+            },
+        )]);
+        let fields = &["id", "estimated_minutes", "actual_minutes"];
+        assert!(needs_time(fields));
+        let csv = format_csv_with_time(&[issue, other], fields, &actual);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[1], "bd-1,120,90");
+        assert_eq!(lines[2], "bd-2,,0");
+    }
 }
@@ -0,0 +1,391 @@
+//! Dependency graph export for `br graph --format`.
+//!
+//! Renders issues and the dependencies between them as Graphviz DOT, a
+//! Mermaid flowchart, GraphML, or JSON Graph Format. Every dependency type is
+//! kept; edges point from the dependent issue to the issue it depends on and
+//! are styled by type. Nodes are filled by status, with a heavier border for
+//! P0/P1 issues.
+
+use crate::model::{Dependency, DependencyType, Issue, Status};
+use serde_json::{Map, Value, json};
+use std::fmt::Write;
+
+/// How an edge of a given dependency type is drawn.
+struct EdgeStyle {
+    color: &'static str,
+    /// DOT `style` attribute (solid, dashed, dotted).
+    line: &'static str,
+    mermaid_arrow: &'static str,
+    directed: bool,
+    width: u8,
+}
+
+fn edge_style(dep_type: &DependencyType) -> EdgeStyle {
+    let (color, line, mermaid_arrow, directed, width) = match dep_type {
+        DependencyType::Blocks => ("#e03131", "solid", "==>", true, 2),
+        DependencyType::ParentChild => ("#495057", "solid", "-->", true, 1),
+        DependencyType::ConditionalBlocks => ("#f08c00", "dashed", "-.->", true, 2),
+        DependencyType::WaitsFor => ("#1971c2", "dashed", "-.->", true, 2),
+        DependencyType::Related | DependencyType::RelatesTo => {
+            ("#868e96", "dotted", "-.-", false, 1)
+        }
+        DependencyType::DiscoveredFrom => ("#2f9e44", "dotted", "-.->", true, 1),
+        _ => ("#868e96", "dotted", "-.->", true, 1),
+    };
+    EdgeStyle {
+        color,
+        line,
+        mermaid_arrow,
+        directed,
+        width,
+    }
+}
+
+fn status_fill(status: &Status) -> &'static str {
+    match status {
+        Status::Open => "#ffffff",
+        Status::InProgress => "#fff3bf",
+        Status::Blocked => "#ffc9c9",
+        Status::Deferred => "#e9ecef",
+        Status::Closed => "#d3f9d8",
+        Status::Pinned => "#d0ebff",
+        _ => "#f8f9fa",
+    }
+}
+
+/// Border color and width by priority (P0/P1 stand out).
+const fn priority_border(priority: i32) -> (&'static str, u8) {
+    match priority {
+        0 => ("#c92a2a", 3),
+        1 => ("#e8590c", 2),
+        _ => ("#495057", 1),
+    }
+}
+
+fn dot_escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Render as a Graphviz DOT digraph.
+#[must_use]
+pub fn to_dot(issues: &[Issue], dependencies: &[Dependency]) -> String {
+    let mut out = String::from("digraph beads {\n");
+    out.push_str("  rankdir=LR;\n");
+    out.push_str(
+        "  node [shape=box, style=\"rounded,filled\", fontname=\"Helvetica\", fontsize=10];\n",
+    );
+    out.push_str("  edge [fontname=\"Helvetica\", fontsize=8];\n");
+    for issue in issues {
+        let (border, width) = priority_border(issue.priority.0);
+        let _ = writeln!(
+            out,
+            "  \"{}\" [label=\"{}\\n{}\\nP{} {} {}\", fillcolor=\"{}\", color=\"{}\", penwidth={}];",
+            dot_escape(&issue.id),
+            dot_escape(&issue.id),
+            dot_escape(&issue.title),
+            issue.priority.0,
+            issue.issue_type.as_str(),
+            issue.status.as_str(),
+            status_fill(&issue.status),
+            border,
+            width
+        );
+    }
+    for dep in dependencies {
+        let style = edge_style(&dep.dep_type);
+        let _ = writeln!(
+            out,
+            "  \"{}\" -> \"{}\" [label=\"{}\", color=\"{}\", fontcolor=\"{}\", style={}, penwidth={}{}];",
+            dot_escape(&dep.issue_id),
+            dot_escape(&dep.depends_on_id),
+            dot_escape(dep.dep_type.as_str()),
+            style.color,
+            style.color,
+            style.line,
+            style.width,
+            if style.directed { "" } else { ", dir=none" }
+        );
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn mermaid_id(id: &str) -> String {
+    let sanitized: String = id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("n_{sanitized}")
+}
+
+fn mermaid_escape(value: &str) -> String {
+    value
+        .replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+        .replace('\n', " ")
+}
+
+/// Render as a Mermaid flowchart (left to right).
+#[must_use]
+pub fn to_mermaid(issues: &[Issue], dependencies: &[Dependency]) -> String {
+    let mut out = String::from("flowchart LR\n");
+    let mut statuses: Vec<&Status> = Vec::new();
+    for issue in issues {
+        let _ = writeln!(
+            out,
+            "  {}[\"{}<br/>{}\"]:::{}",
+            mermaid_id(&issue.id),
+            mermaid_escape(&issue.id),
+            mermaid_escape(&issue.title),
+            mermaid_class(&issue.status)
+        );
+        if !statuses.contains(&&issue.status) {
+            statuses.push(&issue.status);
+        }
+    }
+    for dep in dependencies {
+        let style = edge_style(&dep.dep_type);
+        let _ = writeln!(
+            out,
+            "  {} {}|{}| {}",
+            mermaid_id(&dep.issue_id),
+            style.mermaid_arrow,
+            mermaid_escape(dep.dep_type.as_str()),
+            mermaid_id(&dep.depends_on_id)
+        );
+    }
+    for status in statuses {
+        let _ = writeln!(
+            out,
+            "  classDef {} fill:{},stroke:#495057,color:#212529",
+            mermaid_class(status),
+            status_fill(status)
+        );
+    }
+    for priority in [0, 1] {
+        let ids: Vec<String> = issues
+            .iter()
+            .filter(|issue| issue.priority.0 == priority)
+            .map(|issue| mermaid_id(&issue.id))
+            .collect();
+        if !ids.is_empty() {
+            let (border, width) = priority_border(priority);
+            let _ = writeln!(
+                out,
+                "  classDef p{priority} stroke:{border},stroke-width:{width}px\n  class {} p{priority}",
+                ids.join(",")
+            );
+        }
+    }
+    for (index, dep) in dependencies.iter().enumerate() {
+        let style = edge_style(&dep.dep_type);
+        let _ = writeln!(
+            out,
+            "  linkStyle {index} stroke:{},stroke-width:{}px",
+            style.color, style.width
+        );
+    }
+    out
+}
+
+fn mermaid_class(status: &Status) -> String {
+    let name: String = status
+        .as_str()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("status_{name}")
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Render as GraphML.
+#[must_use]
+pub fn to_graphml(issues: &[Issue], dependencies: &[Dependency]) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+    for (id, target, kind) in [
+        ("title", "node", "string"),
+        ("status", "node", "string"),
+        ("priority", "node", "int"),
+        ("issue_type", "node", "string"),
+        ("assignee", "node", "string"),
+        ("fill", "node", "string"),
+        ("type", "edge", "string"),
+        ("color", "edge", "string"),
+        ("style", "edge", "string"),
+    ] {
+        let _ = writeln!(
+            out,
+            "  <key id=\"{id}\" for=\"{target}\" attr.name=\"{id}\" attr.type=\"{kind}\"/>"
+        );
+    }
+    out.push_str("  <graph id=\"beads\" edgedefault=\"directed\">\n");
+    for issue in issues {
+        let _ = writeln!(out, "    <node id=\"{}\">", xml_escape(&issue.id));
+        let mut data = vec![
+            ("title", issue.title.clone()),
+            ("status", issue.status.as_str().to_string()),
+            ("priority", issue.priority.0.to_string()),
+            ("issue_type", issue.issue_type.as_str().to_string()),
+        ];
+        if let Some(assignee) = &issue.assignee {
+            data.push(("assignee", assignee.clone()));
+        }
+        data.push(("fill", status_fill(&issue.status).to_string()));
+        for (key, value) in data {
+            let _ = writeln!(
+                out,
+                "      <data key=\"{key}\">{}</data>",
+                xml_escape(&value)
+            );
+        }
+        out.push_str("    </node>\n");
+    }
+    for (index, dep) in dependencies.iter().enumerate() {
+        let style = edge_style(&dep.dep_type);
+        let _ = writeln!(
+            out,
+            "    <edge id=\"e{index}\" source=\"{}\" target=\"{}\">\n      <data key=\"type\">{}</data>\n      <data key=\"color\">{}</data>\n      <data key=\"style\">{}</data>\n    </edge>",
+            xml_escape(&dep.issue_id),
+            xml_escape(&dep.depends_on_id),
+            xml_escape(dep.dep_type.as_str()),
+            style.color,
+            style.line
+        );
+    }
+    out.push_str("  </graph>\n</graphml>\n");
+    out
+}
+
+/// Render as JSON Graph Format (<https://jsongraphformat.info>).
+#[must_use]
+pub fn to_json_graph(issues: &[Issue], dependencies: &[Dependency]) -> Value {
+    let mut nodes = Map::new();
+    for issue in issues {
+        let mut metadata = json!({
+            "status": issue.status.as_str(),
+            "priority": issue.priority.0,
+            "issue_type": issue.issue_type.as_str(),
+            "fill": status_fill(&issue.status),
+        });
+        if let Some(assignee) = &issue.assignee {
+            metadata["assignee"] = json!(assignee);
+        }
+        nodes.insert(
+            issue.id.clone(),
+            json!({ "label": issue.title, "metadata": metadata }),
+        );
+    }
+    let edges: Vec<Value> = dependencies
+        .iter()
+        .map(|dep| {
+            let style = edge_style(&dep.dep_type);
+            json!({
+                "source": dep.issue_id,
+                "target": dep.depends_on_id,
+                "relation": dep.dep_type.as_str(),
+                "directed": style.directed,
+                "metadata": { "color": style.color, "style": style.line },
+            })
+        })
+        .collect();
+    json!({
+        "graph": {
+            "id": "beads",
+            "type": "beads-dependencies",
+            "directed": true,
+            "nodes": nodes,
+            "edges": edges,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Priority;
+    use chrono::Utc;
+
+    fn issue(id: &str, title: &str, status: Status, priority: i32) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: title.to_string(),
+            status,
+            priority: Priority(priority),
+            ..Issue::default()
+        }
+    }
+
+    fn dep(from: &str, to: &str, dep_type: DependencyType) -> Dependency {
+        Dependency {
+            issue_id: from.to_string(),
+            depends_on_id: to.to_string(),
+            dep_type,
+            created_at: Utc::now(),
+            created_by: None,
+            metadata: None,
+            thread_id: None,
+        }
+    }
+
+    fn sample() -> (Vec<Issue>, Vec<Dependency>) {
+        (
+            vec![
+                issue("bd-1", "Ship \"v2\"", Status::InProgress, 0),
+                issue("bd-2", "Write <docs>", Status::Open, 2),
+            ],
+            vec![
+                dep("bd-2", "bd-1", DependencyType::Blocks),
+                dep("bd-2", "bd-1", DependencyType::Related),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_dot_styles_nodes_and_edges() {
+        let (issues, deps) = sample();
+        let dot = to_dot(&issues, &deps);
+        assert!(dot.starts_with("digraph beads {"));
+        assert!(dot.contains(r#""bd-1" [label="bd-1\nShip \"v2\"\nP0 task in_progress""#));
+        assert!(dot.contains("fillcolor=\"#fff3bf\", color=\"#c92a2a\", penwidth=3"));
+        assert!(dot.contains(r#""bd-2" -> "bd-1" [label="blocks", color="#e03131""#));
+        assert!(dot.contains("style=dotted, penwidth=1, dir=none];"));
+    }
+
+    #[test]
+    fn test_mermaid_sanitizes_ids_and_labels() {
+        let (issues, deps) = sample();
+        let mermaid = to_mermaid(&issues, &deps);
+        assert!(mermaid.starts_with("flowchart LR\n"));
+        assert!(mermaid.contains("n_bd_1[\"bd-1<br/>Ship #quot;v2#quot;\"]:::status_in_progress"));
+        assert!(mermaid.contains("n_bd_2 ==>|blocks| n_bd_1"));
+        assert!(mermaid.contains("n_bd_2 -.-|related| n_bd_1"));
+        assert!(mermaid.contains("class n_bd_1 p0"));
+        assert!(mermaid.contains("linkStyle 1 stroke:#868e96"));
+    }
+
+    #[test]
+    fn test_graphml_and_json_graph() {
+        let (issues, deps) = sample();
+        let graphml = to_graphml(&issues, &deps);
+        assert!(graphml.contains("<data key=\"title\">Write &lt;docs&gt;</data>"));
+        assert!(graphml.contains("<edge id=\"e0\" source=\"bd-2\" target=\"bd-1\">"));
+
+        let graph = to_json_graph(&issues, &deps);
+        assert_eq!(graph["graph"]["nodes"]["bd-1"]["metadata"]["priority"], 0);
+        assert_eq!(graph["graph"]["edges"][1]["relation"], "related");
+        assert_eq!(graph["graph"]["edges"][1]["directed"], false);
+    }
+}
@@ -6,7 +6,8 @@
 //! # Output Types
 //!
 //! These types match the classic bd JSON schemas for CLI compatibility:
-//! - [`IssueWithCounts`] - Issue with dependency/dependent counts (list/search)
+//! - [`IssueWithCounts`] - Issue with dependency/dependent counts (list)
+//! - [`SearchResult`] - Issue with counts, relevance score and snippet (search)
 //! - [`IssueDetails`] - Issue with full relations (show)
 //! - [`BlockedIssue`] - Issue with blocking info (blocked)
 //! - [`TreeNode`] - Issue in dependency tree (dep tree)
@@ -18,6 +19,17 @@
 //! - Configurable field selection via `--fields`
 //! - Proper escaping of commas, quotes, and newlines
 //!
+//! # Graph Export
+//!
+//! The [`graph`] module renders dependency graphs as DOT, Mermaid, GraphML,
+//! and JSON Graph Format for `br graph --format`.
+//!
+//! # Publishing
+//!
+//! The [`publish`] module renders issues as a Markdown document, a Jira CSV
+//! import file, or GitHub issue import records, and [`site`] generates a
+//! static HTML site; both back `br export --format`.
+//!
 //! # Rich Output
 //!
 //! The [`rich`] module provides enhanced terminal output using `rich_rust`:
@@ -34,23 +46,26 @@
 
 pub mod context;
 pub mod csv;
+pub mod graph;
 pub mod markdown;
 mod output;
+pub mod publish;
 pub mod rich;
+pub mod site;
 pub mod syntax;
 mod text;
 pub mod theme;
 
 pub use output::{
     BlockedIssue, BlockedIssueOutput, Breakdown, BreakdownEntry, IssueDetails, IssueWithCounts,
-    IssueWithDependencyMetadata, ReadyIssue, RecentActivity, StaleIssue, Statistics, StatsSummary,
-    TreeNode,
+    IssueWithDependencyMetadata, ProjectScoped, ReadyIssue, RecentActivity, SearchResult,
+    StaleIssue, Statistics, StatsSummary, TimeSpent, TreeNode,
 };
 pub use text::{
-    TextFormatOptions, format_issue_line, format_issue_line_with, format_priority,
-    format_priority_badge, format_priority_label, format_status_icon, format_status_icon_colored,
-    format_status_label, format_type_badge, format_type_badge_colored, terminal_width,
-    truncate_title,
+    TextFormatOptions, describe_blocker_rule, format_issue_line, format_issue_line_with,
+    format_priority, format_priority_badge, format_priority_label, format_status_icon,
+    format_status_icon_colored, format_status_label, format_time_tracking, format_type_badge,
+    format_type_badge_colored, terminal_width, truncate_title,
 };
 
 // Rich output support
@@ -66,6 +66,14 @@ impl From<&Issue> for ReadyIssue {
     }
 }
 
+/// A row from a cross-project (`--town`) view, tagged with its project.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectScoped<T> {
+    pub project: String,
+    #[serde(flatten)]
+    pub item: T,
+}
+
 /// Minimal issue output for blocked command (bd parity).
 ///
 /// Contains only the fields that bd's blocked command outputs, plus `blocked_by` info.
@@ -85,6 +93,9 @@ pub struct BlockedIssueOutput {
     pub status: Status,
     pub title: String,
     pub updated_at: DateTime<Utc>,
+    /// Why conditional-blocks / waits-for dependencies are holding the issue.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<String>,
 }
 
 impl From<&Issue> for StaleIssue {
@@ -110,6 +121,21 @@ pub struct IssueWithCounts {
     pub dependent_count: usize,
 }
 
+/// Search row: issue with counts plus relevance ranking and matched excerpt.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SearchResult {
+    #[serde(flatten)]
+    pub issue: Issue,
+    pub dependency_count: usize,
+    pub dependent_count: usize,
+    /// BM25 relevance score (lower is more relevant); absent for substring-only matches.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+    /// Best-matching excerpt with matched terms wrapped in `[[` and `]]`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+}
+
 /// Issue details with full relations for show view.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct IssueDetails {
@@ -127,6 +153,17 @@ pub struct IssueDetails {
     pub events: Vec<Event>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parent: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_spent: Option<TimeSpent>,
+}
+
+/// Logged work time for an issue (compare with `estimated_minutes`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct TimeSpent {
+    /// Minutes logged directly on the issue.
+    pub logged_minutes: i64,
+    /// Minutes logged on the issue and its parent-child descendants.
+    pub total_minutes: i64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -173,6 +210,12 @@ pub struct StatsSummary {
     pub epics_eligible_for_closure: usize,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub average_lead_time_hours: Option<f64>,
+    /// Sum of `estimated_minutes` (only set when time is estimated or logged).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_minutes: Option<i64>,
+    /// Sum of logged work (only set when time is estimated or logged).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logged_minutes: Option<i64>,
 }
 
 /// Breakdown statistics by a dimension.
@@ -257,6 +300,8 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         }
     }
 
@@ -286,6 +331,7 @@ mod tests {
             comments: vec![],
             events: vec![],
             parent: Some("bd-parent".to_string()),
+            time_spent: None,
         };
 
         let json = serde_json::to_string(&details).unwrap();
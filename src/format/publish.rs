@@ -0,0 +1,513 @@
+//! Issue exports for readers outside the terminal, used by `br export`.
+//!
+//! - [`to_markdown`]: one Markdown document grouped by status
+//! - [`to_jira_csv`]: a CSV file for Jira's external system import
+//! - [`to_github_json`]: records for GitHub's issue import API
+//!
+//! Issues must have their labels, dependencies and comments populated.
+//! Trackers cannot take br IDs, so Jira rows get sequential `Issue Id`s that
+//! the parent and link columns refer to, and GitHub bodies end with the br ID
+//! and relationships as text.
+
+use super::csv::escape_field;
+use crate::model::{DependencyType, Issue, IssueType, Status};
+use chrono::{DateTime, Utc};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Status sections of the Markdown export, in display order.
+const STATUS_ORDER: [(&str, &[&str]); 5] = [
+    ("In Progress", &["in_progress"]),
+    ("Open", &["open", "pinned"]),
+    ("Blocked", &["blocked"]),
+    ("Deferred", &["deferred"]),
+    ("Closed", &["closed"]),
+];
+
+/// IDs of the issues `issue` depends on through dependencies of `dep_type`.
+fn targets<'a>(issue: &'a Issue, dep_type: &DependencyType) -> Vec<&'a str> {
+    issue
+        .dependencies
+        .iter()
+        .filter(|dep| &dep.dep_type == dep_type)
+        .map(|dep| dep.depends_on_id.as_str())
+        .collect()
+}
+
+/// Render issues as a Markdown document.
+#[must_use]
+pub fn to_markdown(issues: &[Issue], title: &str, generated_at: DateTime<Utc>) -> String {
+    let mut out = String::new();
+    let closed = issues
+        .iter()
+        .filter(|issue| issue.status == Status::Closed)
+        .count();
+    let _ = writeln!(out, "# {title}\n");
+    let _ = writeln!(
+        out,
+        "{} issues ({} open, {closed} closed). Exported {}.",
+        issues.len(),
+        issues.len() - closed,
+        generated_at.format("%Y-%m-%d %H:%M UTC")
+    );
+
+    let mut sections: Vec<(String, Vec<&Issue>)> = STATUS_ORDER
+        .iter()
+        .map(|(heading, statuses)| {
+            let members = issues
+                .iter()
+                .filter(|issue| statuses.contains(&issue.status.as_str()))
+                .collect();
+            ((*heading).to_string(), members)
+        })
+        .collect();
+    let mut custom: Vec<&Issue> = issues
+        .iter()
+        .filter(|issue| matches!(issue.status, Status::Custom(_)))
+        .collect();
+    custom.sort_by(|a, b| a.status.as_str().cmp(b.status.as_str()));
+    for issue in custom {
+        let heading = issue.status.as_str();
+        if let Some((_, members)) = sections.iter_mut().find(|(name, _)| name == heading) {
+            members.push(issue);
+            continue;
+        }
+        sections.push((heading.to_string(), vec![issue]));
+    }
+
+    for (heading, mut members) in sections {
+        if members.is_empty() {
+            continue;
+        }
+        members.sort_by(|a, b| a.priority.cmp(&b.priority).then_with(|| a.id.cmp(&b.id)));
+        let _ = writeln!(out, "\n## {heading} ({})", members.len());
+        for issue in members {
+            write_markdown_issue(&mut out, issue);
+        }
+    }
+    out
+}
+
+fn write_markdown_issue(out: &mut String, issue: &Issue) {
+    let _ = writeln!(out, "\n### {}: {}\n", issue.id, issue.title);
+    let _ = writeln!(
+        out,
+        "- **Status:** {} · **Priority:** {} · **Type:** {}",
+        issue.status.as_str(),
+        issue.priority,
+        issue.issue_type.as_str()
+    );
+    if let Some(assignee) = &issue.assignee {
+        let _ = writeln!(out, "- **Assignee:** {assignee}");
+    }
+    if !issue.labels.is_empty() {
+        let labels: Vec<String> = issue.labels.iter().map(|l| format!("`{l}`")).collect();
+        let _ = writeln!(out, "- **Labels:** {}", labels.join(", "));
+    }
+    for (name, dep_type) in [
+        ("Parent", DependencyType::ParentChild),
+        ("Blocked by", DependencyType::Blocks),
+    ] {
+        let ids = targets(issue, &dep_type);
+        if !ids.is_empty() {
+            let _ = writeln!(out, "- **{name}:** {}", ids.join(", "));
+        }
+    }
+    let _ = write!(
+        out,
+        "- **Created:** {}",
+        issue.created_at.format("%Y-%m-%d")
+    );
+    if let Some(closed_at) = issue.closed_at {
+        let _ = write!(out, " · **Closed:** {}", closed_at.format("%Y-%m-%d"));
+        if let Some(reason) = &issue.close_reason {
+            let _ = write!(out, " ({reason})");
+        }
+    }
+    out.push('\n');
+
+    for (name, text) in [
+        (None, &issue.description),
+        (Some("Design"), &issue.design),
+        (Some("Acceptance Criteria"), &issue.acceptance_criteria),
+        (Some("Notes"), &issue.notes),
+    ] {
+        let Some(text) = text.as_deref().filter(|t| !t.trim().is_empty()) else {
+            continue;
+        };
+        if let Some(name) = name {
+            let _ = writeln!(out, "\n#### {name}");
+        }
+        let _ = writeln!(out, "\n{}", demote_headings(text.trim()));
+    }
+
+    if !issue.comments.is_empty() {
+        let _ = writeln!(out, "\n#### Comments\n");
+        for comment in &issue.comments {
+            let _ = writeln!(
+                out,
+                "- **{}** ({}):",
+                comment.author,
+                comment.created_at.format("%Y-%m-%d %H:%M")
+            );
+            for line in comment.body.trim().lines() {
+                let _ = writeln!(out, "  {line}");
+            }
+        }
+    }
+}
+
+/// Push Markdown headings in user text below the issue's own `###` heading.
+fn demote_headings(text: &str) -> String {
+    let mut in_fence = false;
+    text.lines()
+        .map(|line| {
+            if line.trim_start().starts_with("```") {
+                in_fence = !in_fence;
+            }
+            let level = line.chars().take_while(|c| *c == '#').count();
+            if !in_fence && (1..=6).contains(&level) && line[level..].starts_with(' ') {
+                format!("{}{}", "#".repeat((level + 3).min(6)), &line[level..])
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+const fn jira_priority(priority: i32) -> &'static str {
+    match priority {
+        0 => "Highest",
+        1 => "High",
+        2 => "Medium",
+        3 => "Low",
+        _ => "Lowest",
+    }
+}
+
+const fn jira_status(status: &Status) -> &'static str {
+    match status {
+        Status::InProgress => "In Progress",
+        Status::Closed | Status::Tombstone => "Done",
+        _ => "To Do",
+    }
+}
+
+const fn jira_type(issue_type: &IssueType) -> &'static str {
+    match issue_type {
+        IssueType::Bug => "Bug",
+        IssueType::Feature => "Story",
+        IssueType::Epic => "Epic",
+        _ => "Task",
+    }
+}
+
+/// Jira's default CSV date format (`dd/MMM/yy h:mm a`).
+fn jira_date(dt: DateTime<Utc>) -> String {
+    dt.format("%d/%b/%y %-I:%M %p").to_string()
+}
+
+/// Render issues as a CSV file for Jira's CSV importer.
+///
+/// Multi-valued fields (labels, links, comments) use repeated columns, as
+/// Jira expects. Links to issues outside the export are dropped.
+#[must_use]
+pub fn to_jira_csv(issues: &[Issue]) -> String {
+    let numbers: HashMap<&str, usize> = issues
+        .iter()
+        .enumerate()
+        .map(|(index, issue)| (issue.id.as_str(), index + 1))
+        .collect();
+    let linked = |issue: &Issue, dep_type: &DependencyType| -> Vec<String> {
+        targets(issue, dep_type)
+            .into_iter()
+            .filter_map(|id| numbers.get(id).map(ToString::to_string))
+            .collect()
+    };
+    // A blocks dependency from A to B means B blocks A, so the outward
+    // "Blocks" link goes on B's row.
+    let mut blocks: HashMap<&str, Vec<String>> = HashMap::new();
+    for dep in issues
+        .iter()
+        .flat_map(|issue| &issue.dependencies)
+        .filter(|dep| {
+            dep.dep_type == DependencyType::Blocks
+                && numbers.contains_key(dep.depends_on_id.as_str())
+        })
+    {
+        if let Some(number) = numbers.get(dep.issue_id.as_str()) {
+            blocks
+                .entry(dep.depends_on_id.as_str())
+                .or_default()
+                .push(number.to_string());
+        }
+    }
+
+    let max_labels = issues.iter().map(|i| i.labels.len()).max().unwrap_or(0);
+    let max_blocks = blocks.values().map(Vec::len).max().unwrap_or(0);
+    let max_comments = issues.iter().map(|i| i.comments.len()).max().unwrap_or(0);
+
+    let mut header = vec![
+        "Issue Id",
+        "Parent Id",
+        "Summary",
+        "Issue Type",
+        "Status",
+        "Priority",
+        "Description",
+        "Assignee",
+        "Reporter",
+        "Created",
+        "Resolved",
+        "Resolution",
+        "Due Date",
+        "External ID",
+    ];
+    header.extend(std::iter::repeat_n("Labels", max_labels));
+    header.extend(std::iter::repeat_n(
+        "Outward issue link (Blocks)",
+        max_blocks,
+    ));
+    header.extend(std::iter::repeat_n("Comment", max_comments));
+
+    let mut out = header.join(",");
+    out.push('\n');
+    for issue in issues {
+        let parent = linked(issue, &DependencyType::ParentChild)
+            .into_iter()
+            .next()
+            .unwrap_or_default();
+        let mut row = vec![
+            numbers[issue.id.as_str()].to_string(),
+            parent,
+            issue.title.clone(),
+            jira_type(&issue.issue_type).to_string(),
+            jira_status(&issue.status).to_string(),
+            jira_priority(issue.priority.0).to_string(),
+            issue.description.clone().unwrap_or_default(),
+            issue.assignee.clone().unwrap_or_default(),
+            issue.created_by.clone().unwrap_or_default(),
+            jira_date(issue.created_at),
+            issue.closed_at.map(jira_date).unwrap_or_default(),
+            if issue.status == Status::Closed {
+                "Done".to_string()
+            } else {
+                String::new()
+            },
+            issue
+                .due_at
+                .map(|due| due.format("%d/%b/%y").to_string())
+                .unwrap_or_default(),
+            issue.id.clone(),
+        ];
+        let mut labels = issue.labels.clone();
+        labels.resize(max_labels, String::new());
+        row.extend(labels);
+        let mut outward = blocks.remove(issue.id.as_str()).unwrap_or_default();
+        outward.resize(max_blocks, String::new());
+        row.extend(outward);
+        let mut comments: Vec<String> = issue
+            .comments
+            .iter()
+            .map(|c| format!("{};{};{}", jira_date(c.created_at), c.author, c.body))
+            .collect();
+        comments.resize(max_comments, String::new());
+        row.extend(comments);
+
+        let fields: Vec<String> = row.iter().map(|field| escape_field(field)).collect();
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// GitHub label for an issue type, using GitHub's default label names.
+fn github_type_label(issue_type: &IssueType) -> Option<&str> {
+    match issue_type {
+        IssueType::Bug => Some("bug"),
+        IssueType::Feature => Some("enhancement"),
+        IssueType::Docs => Some("documentation"),
+        IssueType::Question => Some("question"),
+        IssueType::Task => None,
+        other => Some(other.as_str()),
+    }
+}
+
+/// Render issues as an array of GitHub issue import records
+/// (`{"issue": {...}, "comments": [...]}`), one per issue.
+///
+/// The type and priority become labels (`bug`, `P1`). GitHub sets comment
+/// authors to the importing user, so the original author leads each comment.
+#[must_use]
+pub fn to_github_json(issues: &[Issue]) -> Value {
+    Value::Array(issues.iter().map(github_record).collect())
+}
+
+fn github_record(issue: &Issue) -> Value {
+    let mut body = issue.description.clone().unwrap_or_default();
+    for (name, text) in [
+        ("Design", &issue.design),
+        ("Acceptance Criteria", &issue.acceptance_criteria),
+        ("Notes", &issue.notes),
+    ] {
+        if let Some(text) = text.as_deref().filter(|t| !t.trim().is_empty()) {
+            let _ = write!(body, "\n\n## {name}\n\n{}", text.trim());
+        }
+    }
+    let mut footer = vec![format!("br: `{}`", issue.id)];
+    for (name, dep_type) in [
+        ("Parent", DependencyType::ParentChild),
+        ("Blocked by", DependencyType::Blocks),
+    ] {
+        let ids = targets(issue, &dep_type);
+        if !ids.is_empty() {
+            let ids: Vec<String> = ids.iter().map(|id| format!("`{id}`")).collect();
+            footer.push(format!("{name}: {}", ids.join(", ")));
+        }
+    }
+    let _ = write!(body, "\n\n---\n{}", footer.join(" · "));
+
+    let mut labels: Vec<String> = github_type_label(&issue.issue_type)
+        .map(String::from)
+        .into_iter()
+        .collect();
+    labels.push(issue.priority.to_string());
+    labels.extend(issue.labels.iter().cloned());
+
+    let mut record = json!({
+        "title": issue.title,
+        "body": body.trim_start(),
+        "created_at": issue.created_at.to_rfc3339(),
+        "updated_at": issue.updated_at.to_rfc3339(),
+        "closed": issue.status == Status::Closed,
+        "labels": labels,
+    });
+    if let Some(closed_at) = issue.closed_at {
+        record["closed_at"] = json!(closed_at.to_rfc3339());
+    }
+    if let Some(assignee) = &issue.assignee {
+        record["assignee"] = json!(assignee);
+    }
+    let comments: Vec<Value> = issue
+        .comments
+        .iter()
+        .map(|comment| {
+            json!({
+                "created_at": comment.created_at.to_rfc3339(),
+                "body": format!("**{}** wrote:\n\n{}", comment.author, comment.body),
+            })
+        })
+        .collect();
+    json!({ "issue": record, "comments": comments })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Comment, Dependency, Priority};
+    use chrono::TimeZone;
+
+    fn issues() -> Vec<Issue> {
+        let created_at = Utc.with_ymd_and_hms(2024, 3, 12, 15, 4, 0).unwrap();
+        let dep = |issue_id: &str, depends_on_id: &str, dep_type| Dependency {
+            issue_id: issue_id.to_string(),
+            depends_on_id: depends_on_id.to_string(),
+            dep_type,
+            created_at,
+            created_by: None,
+            metadata: None,
+            thread_id: None,
+        };
+        vec![
+            Issue {
+                id: "bd-1".to_string(),
+                title: "Export epic".to_string(),
+                issue_type: IssueType::Epic,
+                priority: Priority::MEDIUM,
+                created_at,
+                updated_at: created_at,
+                ..Issue::default()
+            },
+            Issue {
+                id: "bd-2".to_string(),
+                title: "Crash, on export".to_string(),
+                description: Some("# Steps\nRun \"br export\"".to_string()),
+                issue_type: IssueType::Bug,
+                priority: Priority(0),
+                status: Status::Closed,
+                closed_at: Some(created_at),
+                labels: vec!["cli".to_string(), "export".to_string()],
+                dependencies: vec![
+                    dep("bd-2", "bd-1", DependencyType::ParentChild),
+                    dep("bd-2", "bd-3", DependencyType::Blocks),
+                ],
+                comments: vec![Comment {
+                    id: 1,
+                    issue_id: "bd-2".to_string(),
+                    author: "alice".to_string(),
+                    body: "Fixed".to_string(),
+                    created_at,
+                }],
+                created_at,
+                updated_at: created_at,
+                ..Issue::default()
+            },
+            Issue {
+                id: "bd-3".to_string(),
+                title: "Schema".to_string(),
+                status: Status::InProgress,
+                priority: Priority::MEDIUM,
+                created_at,
+                updated_at: created_at,
+                ..Issue::default()
+            },
+        ]
+    }
+
+    #[test]
+    fn test_markdown_groups_by_status() {
+        let md = to_markdown(&issues(), "demo", Utc::now());
+        let in_progress = md.find("## In Progress (1)").unwrap();
+        let closed = md.find("## Closed (1)").unwrap();
+        assert!(in_progress < md.find("## Open (1)").unwrap());
+        assert!(md[closed..].contains("### bd-2: Crash, on export"));
+        assert!(md.contains("- **Blocked by:** bd-3"));
+        assert!(md.contains("#### Steps"));
+        assert!(md.contains("  Fixed"));
+    }
+
+    #[test]
+    fn test_jira_csv_columns_and_links() {
+        let csv = to_jira_csv(&issues());
+        let lines: Vec<&str> = csv.lines().collect();
+        assert!(lines[0].starts_with("Issue Id,Parent Id,Summary,Issue Type,Status,Priority"));
+        assert!(lines[0].ends_with("Labels,Labels,Outward issue link (Blocks),Comment"));
+        assert!(lines[2].starts_with("2,1,\"Crash, on export\",Bug,Done,Highest,"));
+        assert!(csv.contains("\"# Steps\nRun \"\"br export\"\"\""));
+        assert!(csv.contains("12/Mar/24 3:04 PM;alice;Fixed"));
+        // bd-3 blocks bd-2 (Issue Id 2)
+        let last = csv.trim_end().rsplit('\n').next().unwrap();
+        assert!(last.starts_with("3,,Schema,Task,In Progress,Medium,"));
+        assert!(last.ends_with(",2,"));
+    }
+
+    #[test]
+    fn test_github_records() {
+        let records = to_github_json(&issues());
+        let crash = &records[1];
+        assert_eq!(crash["issue"]["closed"], json!(true));
+        assert_eq!(
+            crash["issue"]["labels"],
+            json!(["bug", "P0", "cli", "export"])
+        );
+        let body = crash["issue"]["body"].as_str().unwrap();
+        assert!(body.ends_with("br: `bd-2` · Parent: `bd-1` · Blocked by: `bd-3`"));
+        assert_eq!(
+            crash["comments"][0]["body"],
+            json!("**alice** wrote:\n\nFixed")
+        );
+        assert!(records[2]["issue"].get("closed_at").is_none());
+    }
+}
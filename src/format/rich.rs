@@ -365,6 +365,8 @@ mod tests {
             is_template: false,
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         }
     }
 
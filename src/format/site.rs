@@ -0,0 +1,963 @@
+//! Static HTML site for `br export --format html`.
+//!
+//! The site is self-contained (no scripts or styles from the network):
+//!
+//! - `index.html`: every issue in a table with client-side filters, plus
+//!   epic progress
+//! - `graph.html`: the dependency graph as inline SVG, prerequisites on the
+//!   left
+//! - `issues/<id>.html`: one page per issue with its Markdown fields
+//!   rendered, relationships and comments
+//! - `style.css`: shared styles
+//!
+//! Issues must have their labels, dependencies and comments populated.
+
+use crate::model::{Dependency, DependencyType, Issue, IssueType, Status};
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt::Write;
+
+/// A file of the generated site, relative to the output directory.
+#[derive(Debug, Clone)]
+pub struct SiteFile {
+    pub path: String,
+    pub contents: String,
+}
+
+const STYLE: &str = r"body{font:15px/1.5 system-ui,sans-serif;margin:0;color:#212529;background:#f8f9fa}
+header{background:#212529;color:#fff;padding:.75rem 1.5rem;display:flex;gap:1.5rem;align-items:baseline}
+header a{color:#fff;text-decoration:none}header .site{font-weight:600;font-size:1.1rem}
+main{max-width:1100px;margin:0 auto;padding:1rem 1.5rem 3rem}
+a{color:#1971c2}table{border-collapse:collapse;width:100%;background:#fff}
+th,td{text-align:left;padding:.35rem .6rem;border-bottom:1px solid #dee2e6;vertical-align:top}
+th{background:#e9ecef;font-weight:600}tr[hidden]{display:none}
+.filters{display:flex;flex-wrap:wrap;gap:.5rem;margin:1rem 0}.filters input{flex:1;min-width:12rem}
+.filters input,.filters select{padding:.3rem;font:inherit}
+.badge{display:inline-block;padding:0 .45rem;border-radius:.6rem;font-size:.8rem;background:#e9ecef;white-space:nowrap}
+.status-open{background:#fff;border:1px solid #ced4da}.status-in_progress{background:#fff3bf}
+.status-blocked{background:#ffc9c9}.status-deferred{background:#e9ecef}
+.status-closed{background:#d3f9d8}.status-pinned{background:#d0ebff}
+.p0{color:#c92a2a;font-weight:700}.p1{color:#e8590c;font-weight:600}
+.label{background:#e7f5ff;color:#1864ab}.muted{color:#868e96}
+dl.meta{display:grid;grid-template-columns:max-content 1fr;gap:.2rem 1rem;background:#fff;padding:1rem;border:1px solid #dee2e6}
+dl.meta dt{font-weight:600}dl.meta dd{margin:0}
+.markdown{background:#fff;padding:.25rem 1rem;border:1px solid #dee2e6}
+.markdown pre{background:#f1f3f5;padding:.6rem;overflow-x:auto}
+.markdown code{background:#f1f3f5;padding:0 .2rem}.markdown pre code{padding:0}
+.markdown blockquote{border-left:3px solid #ced4da;margin-left:0;padding-left:1rem;color:#495057}
+.comment{background:#fff;border:1px solid #dee2e6;margin:.75rem 0}
+.comment .by{background:#f1f3f5;padding:.3rem 1rem;font-size:.9rem}
+.comment .markdown{border:0}
+progress{width:12rem;vertical-align:middle}
+.graph{overflow:auto;background:#fff;border:1px solid #dee2e6}
+.graph rect{stroke:#495057;stroke-width:1;rx:4}.graph text{font-size:12px}
+.graph .node-open rect{fill:#fff}.graph .node-in_progress rect{fill:#fff3bf}
+.graph .node-blocked rect{fill:#ffc9c9}.graph .node-deferred rect{fill:#e9ecef}
+.graph .node-closed rect{fill:#d3f9d8}.graph .node-pinned rect{fill:#d0ebff}
+.graph path{fill:none;stroke:#868e96;stroke-width:1.2;stroke-dasharray:4 3}
+.graph path.edge-blocks{stroke:#e03131;stroke-width:2;stroke-dasharray:none}
+.graph path.edge-parent-child{stroke:#495057;stroke-dasharray:none}
+";
+
+const FILTER_SCRIPT: &str = r"const rows=[...document.querySelectorAll('#issues tbody tr')];
+const search=document.getElementById('search');
+const selects=[...document.querySelectorAll('select[data-filter]')];
+function apply(){const q=search.value.toLowerCase();let shown=0;
+for(const row of rows){let ok=!q||row.dataset.search.includes(q);
+for(const s of selects){if(!s.value)continue;const key=s.dataset.filter;
+ok=ok&&(key==='labels'?row.dataset.labels.split(' ').includes(s.value):row.dataset[key]===s.value);}
+row.hidden=!ok;if(ok)shown++;}
+document.getElementById('shown').textContent=shown;}
+search.addEventListener('input',apply);for(const s of selects)s.addEventListener('change',apply);
+";
+
+/// Render the whole site.
+#[must_use]
+pub fn render_site(issues: &[Issue], title: &str, generated_at: DateTime<Utc>) -> Vec<SiteFile> {
+    let site = Site::new(issues, title, generated_at);
+    let mut files = vec![
+        SiteFile {
+            path: "style.css".to_string(),
+            contents: STYLE.to_string(),
+        },
+        SiteFile {
+            path: "index.html".to_string(),
+            contents: site.index_page(),
+        },
+        SiteFile {
+            path: "graph.html".to_string(),
+            contents: site.graph_page(),
+        },
+    ];
+    files.extend(issues.iter().map(|issue| SiteFile {
+        path: format!("issues/{}", page_name(&issue.id)),
+        contents: site.issue_page(issue),
+    }));
+    files
+}
+
+/// File name of an issue's page.
+#[must_use]
+pub fn page_name(id: &str) -> String {
+    let safe: String = id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{safe}.html")
+}
+
+/// Escape text for HTML content and attribute values.
+#[must_use]
+pub fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+struct Site<'a> {
+    issues: &'a [Issue],
+    by_id: HashMap<&'a str, &'a Issue>,
+    /// Dependencies pointing at each issue (`depends_on_id` -> records).
+    dependents: HashMap<&'a str, Vec<&'a Dependency>>,
+    title: &'a str,
+    generated_at: DateTime<Utc>,
+}
+
+impl<'a> Site<'a> {
+    fn new(issues: &'a [Issue], title: &'a str, generated_at: DateTime<Utc>) -> Self {
+        let by_id = issues.iter().map(|i| (i.id.as_str(), i)).collect();
+        let mut dependents: HashMap<&str, Vec<&Dependency>> = HashMap::new();
+        for dep in issues.iter().flat_map(|i| &i.dependencies) {
+            dependents
+                .entry(dep.depends_on_id.as_str())
+                .or_default()
+                .push(dep);
+        }
+        Self {
+            issues,
+            by_id,
+            dependents,
+            title,
+            generated_at,
+        }
+    }
+
+    /// Wrap page content in the shared layout. `root` is the relative path
+    /// back to the site root ("" or "../").
+    fn page(&self, heading: &str, root: &str, body: &str) -> String {
+        format!(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+             <title>{heading} · {site}</title>\n<link rel=\"stylesheet\" href=\"{root}style.css\">\n\
+             </head>\n<body>\n<header><a class=\"site\" href=\"{root}index.html\">{site}</a>\
+             <a href=\"{root}index.html\">Issues</a><a href=\"{root}graph.html\">Graph</a></header>\n\
+             <main>\n{body}</main>\n</body>\n</html>\n",
+            heading = escape_html(heading),
+            site = escape_html(self.title),
+        )
+    }
+
+    /// Link to an issue page, or the bare ID if the issue is not exported.
+    fn issue_link(&self, id: &str, root: &str) -> String {
+        self.by_id.get(id).map_or_else(
+            || format!("<span class=\"muted\">{}</span>", escape_html(id)),
+            |issue| {
+                format!(
+                    "<a href=\"{root}issues/{}\" title=\"{}\">{}</a>",
+                    page_name(id),
+                    escape_html(&issue.title),
+                    escape_html(id)
+                )
+            },
+        )
+    }
+
+    /// Children of an epic and how many of them are closed.
+    fn epic_progress(&self, epic_id: &str) -> (usize, usize) {
+        let children: Vec<&Issue> = self
+            .dependents
+            .get(epic_id)
+            .into_iter()
+            .flatten()
+            .filter(|dep| dep.dep_type == DependencyType::ParentChild)
+            .filter_map(|dep| self.by_id.get(dep.issue_id.as_str()).copied())
+            .collect();
+        let closed = children
+            .iter()
+            .filter(|child| child.status == Status::Closed)
+            .count();
+        (children.len(), closed)
+    }
+
+    fn progress_bar(total: usize, closed: usize) -> String {
+        format!(
+            "<progress value=\"{closed}\" max=\"{}\"></progress> {closed}/{total}",
+            total.max(1)
+        )
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn index_page(&self) -> String {
+        let mut body = String::new();
+        let closed = self
+            .issues
+            .iter()
+            .filter(|issue| issue.status == Status::Closed)
+            .count();
+        let _ = writeln!(
+            body,
+            "<h1>{}</h1>\n<p class=\"muted\">{} issues ({} open, {closed} closed). \
+             Generated {}.</p>",
+            escape_html(self.title),
+            self.issues.len(),
+            self.issues.len() - closed,
+            self.generated_at.format("%Y-%m-%d %H:%M UTC")
+        );
+
+        let epics: Vec<&Issue> = self
+            .issues
+            .iter()
+            .filter(|issue| issue.issue_type == IssueType::Epic)
+            .collect();
+        if !epics.is_empty() {
+            body.push_str("<h2>Epics</h2>\n<table>\n<thead><tr><th>Epic</th><th>Title</th><th>Status</th><th>Progress</th></tr></thead>\n<tbody>\n");
+            for epic in epics {
+                let (total, done) = self.epic_progress(&epic.id);
+                let _ = writeln!(
+                    body,
+                    "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                    self.issue_link(&epic.id, ""),
+                    escape_html(&epic.title),
+                    status_badge(&epic.status),
+                    Self::progress_bar(total, done)
+                );
+            }
+            body.push_str("</tbody>\n</table>\n");
+        }
+
+        body.push_str("<h2>Issues</h2>\n<div class=\"filters\">\n<input id=\"search\" type=\"search\" placeholder=\"Search ID, title, assignee\">\n");
+        let mut statuses = BTreeSet::new();
+        let mut types = BTreeSet::new();
+        let mut priorities = BTreeSet::new();
+        let mut assignees = BTreeSet::new();
+        let mut labels = BTreeSet::new();
+        for issue in self.issues {
+            statuses.insert(issue.status.as_str());
+            types.insert(issue.issue_type.as_str());
+            priorities.insert(issue.priority.to_string());
+            if let Some(assignee) = &issue.assignee {
+                assignees.insert(assignee.as_str());
+            }
+            labels.extend(issue.labels.iter().map(String::as_str));
+        }
+        let priorities: Vec<&str> = priorities.iter().map(String::as_str).collect();
+        for (key, name, values) in [
+            (
+                "status",
+                "All statuses",
+                statuses.into_iter().collect::<Vec<_>>(),
+            ),
+            ("type", "All types", types.into_iter().collect()),
+            ("priority", "All priorities", priorities),
+            ("assignee", "All assignees", assignees.into_iter().collect()),
+            ("labels", "All labels", labels.into_iter().collect()),
+        ] {
+            if values.is_empty() {
+                continue;
+            }
+            let _ = write!(
+                body,
+                "<select data-filter=\"{key}\"><option value=\"\">{name}</option>"
+            );
+            for value in values {
+                let value = escape_html(value);
+                let _ = write!(body, "<option value=\"{value}\">{value}</option>");
+            }
+            body.push_str("</select>\n");
+        }
+        let _ = writeln!(
+            body,
+            "</div>\n<p class=\"muted\"><span id=\"shown\">{}</span> shown</p>",
+            self.issues.len()
+        );
+
+        body.push_str("<table id=\"issues\">\n<thead><tr><th>ID</th><th>Title</th><th>Status</th><th>Priority</th><th>Type</th><th>Assignee</th><th>Labels</th></tr></thead>\n<tbody>\n");
+        for issue in self.issues {
+            let assignee = issue.assignee.as_deref().unwrap_or("");
+            let search = format!("{} {} {assignee}", issue.id, issue.title).to_lowercase();
+            let _ = writeln!(
+                body,
+                "<tr data-status=\"{}\" data-type=\"{}\" data-priority=\"{}\" data-assignee=\"{}\" \
+                 data-labels=\"{}\" data-search=\"{}\"><td>{}</td><td>{}</td><td>{}</td>\
+                 <td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                escape_html(issue.status.as_str()),
+                escape_html(issue.issue_type.as_str()),
+                issue.priority,
+                escape_html(assignee),
+                escape_html(&issue.labels.join(" ")),
+                escape_html(&search),
+                self.issue_link(&issue.id, ""),
+                escape_html(&issue.title),
+                status_badge(&issue.status),
+                priority_badge(issue.priority.0),
+                escape_html(issue.issue_type.as_str()),
+                escape_html(assignee),
+                label_badges(&issue.labels)
+            );
+        }
+        let _ = writeln!(
+            body,
+            "</tbody>\n</table>\n<script>\n{FILTER_SCRIPT}</script>"
+        );
+        self.page("Issues", "", &body)
+    }
+
+    fn issue_page(&self, issue: &Issue) -> String {
+        let mut body = String::new();
+        let _ = writeln!(
+            body,
+            "<h1>{}: {}</h1>",
+            escape_html(&issue.id),
+            escape_html(&issue.title)
+        );
+
+        let mut meta: Vec<(&str, String)> = vec![
+            ("Status", status_badge(&issue.status)),
+            ("Priority", priority_badge(issue.priority.0)),
+            ("Type", escape_html(issue.issue_type.as_str())),
+        ];
+        let text = |value: &Option<String>| value.as_deref().map(escape_html);
+        let date = |value: Option<DateTime<Utc>>| {
+            value.map(|d| d.format("%Y-%m-%d %H:%M UTC").to_string())
+        };
+        let mut created = issue.created_at.format("%Y-%m-%d %H:%M UTC").to_string();
+        if let Some(by) = &issue.created_by {
+            let _ = write!(created, " by {}", escape_html(by));
+        }
+        let optional = [
+            ("Assignee", text(&issue.assignee)),
+            ("Owner", text(&issue.owner)),
+            (
+                "Labels",
+                (!issue.labels.is_empty()).then(|| label_badges(&issue.labels)),
+            ),
+            ("Created", Some(created)),
+            ("Updated", date(Some(issue.updated_at))),
+            ("Closed", date(issue.closed_at)),
+            ("Close reason", text(&issue.close_reason)),
+            ("Due", date(issue.due_at)),
+            ("Deferred until", date(issue.defer_until)),
+            (
+                "Estimate",
+                issue.estimated_minutes.map(|m| format!("{m} min")),
+            ),
+            ("External ref", text(&issue.external_ref)),
+        ];
+        meta.extend(
+            optional
+                .into_iter()
+                .filter_map(|(name, value)| value.map(|value| (name, value))),
+        );
+        if issue.issue_type == IssueType::Epic {
+            let (total, closed) = self.epic_progress(&issue.id);
+            meta.push(("Progress", Self::progress_bar(total, closed)));
+        }
+        body.push_str("<dl class=\"meta\">\n");
+        for (name, value) in meta {
+            let _ = writeln!(body, "<dt>{name}</dt><dd>{value}</dd>");
+        }
+        body.push_str("</dl>\n");
+
+        for (name, field) in [
+            ("Description", &issue.description),
+            ("Design", &issue.design),
+            ("Acceptance Criteria", &issue.acceptance_criteria),
+            ("Notes", &issue.notes),
+        ] {
+            if let Some(field) = field.as_deref().filter(|f| !f.trim().is_empty()) {
+                let _ = writeln!(
+                    body,
+                    "<h2>{name}</h2>\n<div class=\"markdown\">\n{}</div>",
+                    markdown_to_html(field, 2)
+                );
+            }
+        }
+
+        let relationships = self.relationships(issue);
+        if !relationships.is_empty() {
+            body.push_str("<h2>Relationships</h2>\n<table>\n<tbody>\n");
+            for (name, links) in relationships {
+                let _ = writeln!(
+                    body,
+                    "<tr><th>{}</th><td>{}</td></tr>",
+                    escape_html(&name),
+                    links.join(", ")
+                );
+            }
+            body.push_str("</tbody>\n</table>\n");
+        }
+
+        if !issue.comments.is_empty() {
+            let _ = writeln!(body, "<h2>Comments ({})</h2>", issue.comments.len());
+            for comment in &issue.comments {
+                let _ = writeln!(
+                    body,
+                    "<div class=\"comment\"><div class=\"by\"><strong>{}</strong> \
+                     <span class=\"muted\">{}</span></div>\n<div class=\"markdown\">\n{}</div></div>",
+                    escape_html(&comment.author),
+                    comment.created_at.format("%Y-%m-%d %H:%M UTC"),
+                    markdown_to_html(&comment.body, 2)
+                );
+            }
+        }
+        self.page(&format!("{}: {}", issue.id, issue.title), "../", &body)
+    }
+
+    /// Relationship rows for an issue page: outgoing dependencies by type,
+    /// then children and other dependents.
+    fn relationships(&self, issue: &Issue) -> Vec<(String, Vec<String>)> {
+        let mut rows: Vec<(String, Vec<String>)> = Vec::new();
+        let mut push = |name: String, link: String| {
+            if let Some((_, links)) = rows.iter_mut().find(|(row, _)| *row == name) {
+                links.push(link);
+                return;
+            }
+            rows.push((name, vec![link]));
+        };
+        for dep in &issue.dependencies {
+            let name = match dep.dep_type {
+                DependencyType::ParentChild => "Parent".to_string(),
+                DependencyType::Blocks => "Blocked by".to_string(),
+                ref other => format!("Depends on ({})", other.as_str()),
+            };
+            push(name, self.issue_link(&dep.depends_on_id, "../"));
+        }
+        for dep in self.dependents.get(issue.id.as_str()).into_iter().flatten() {
+            let name = match dep.dep_type {
+                DependencyType::ParentChild => "Children".to_string(),
+                DependencyType::Blocks => "Blocks".to_string(),
+                ref other => format!("Dependents ({})", other.as_str()),
+            };
+            push(name, self.issue_link(&dep.issue_id, "../"));
+        }
+        rows
+    }
+
+    fn graph_page(&self) -> String {
+        let edges: Vec<&Dependency> = self
+            .issues
+            .iter()
+            .flat_map(|issue| &issue.dependencies)
+            .filter(|dep| self.by_id.contains_key(dep.depends_on_id.as_str()))
+            .collect();
+        let mut body = String::from("<h1>Dependency graph</h1>\n");
+        if edges.is_empty() {
+            body.push_str("<p class=\"muted\">No dependencies between exported issues.</p>\n");
+        } else {
+            body.push_str(
+                "<p class=\"muted\">Arrows point from an issue to the one it depends on. \
+                 Red: blocks, dark: parent-child, dashed: other relations.</p>\n",
+            );
+            body.push_str(&self.graph_svg(&edges));
+        }
+        self.page("Graph", "", &body)
+    }
+
+    /// Lay the connected issues out in columns by dependency depth and draw
+    /// them as SVG.
+    fn graph_svg(&self, edges: &[&Dependency]) -> String {
+        const WIDTH: usize = 190;
+        const HEIGHT: usize = 42;
+        const COL_GAP: usize = 70;
+        const ROW_GAP: usize = 14;
+        const MARGIN: usize = 10;
+
+        let mut deps: HashMap<&str, Vec<&str>> = HashMap::new();
+        let mut nodes: BTreeSet<&str> = BTreeSet::new();
+        for dep in edges {
+            deps.entry(dep.issue_id.as_str())
+                .or_default()
+                .push(dep.depends_on_id.as_str());
+            nodes.insert(dep.issue_id.as_str());
+            nodes.insert(dep.depends_on_id.as_str());
+        }
+        let mut depths: HashMap<&str, usize> = HashMap::new();
+        for &node in &nodes {
+            depth_of(node, &deps, &mut depths, &mut HashSet::new());
+        }
+
+        let mut columns: Vec<Vec<&str>> = Vec::new();
+        for &node in &nodes {
+            let depth = depths.get(node).copied().unwrap_or(0);
+            if columns.len() <= depth {
+                columns.resize(depth + 1, Vec::new());
+            }
+            columns[depth].push(node);
+        }
+        let mut position: Vec<(&str, (usize, usize))> = Vec::new();
+        for (col, members) in columns.iter().enumerate() {
+            for (row, &node) in members.iter().enumerate() {
+                let x = MARGIN + col * (WIDTH + COL_GAP);
+                let y = MARGIN + row * (HEIGHT + ROW_GAP);
+                position.push((node, (x, y)));
+            }
+        }
+        let at: HashMap<&str, (usize, usize)> = position.iter().copied().collect();
+        let width = MARGIN * 2 + columns.len() * (WIDTH + COL_GAP) - COL_GAP;
+        let rows = columns.iter().map(Vec::len).max().unwrap_or(0);
+        let height = MARGIN * 2 + rows * (HEIGHT + ROW_GAP) - ROW_GAP;
+
+        let mut svg = format!(
+            "<div class=\"graph\"><svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n\
+             <defs><marker id=\"arrow\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" markerWidth=\"7\" markerHeight=\"7\" orient=\"auto\">\
+             <path d=\"M0,0 L10,5 L0,10 z\" style=\"fill:#495057;stroke:none\"/></marker></defs>\n"
+        );
+        for dep in edges {
+            let from = at[dep.issue_id.as_str()];
+            let to = at[dep.depends_on_id.as_str()];
+            // From the dependent's left edge to the prerequisite's right edge
+            let start = (from.0, from.1 + HEIGHT / 2);
+            let end = (to.0 + WIDTH, to.1 + HEIGHT / 2);
+            let mid = (start.0 + end.0) / 2;
+            let class = match dep.dep_type {
+                DependencyType::Blocks => "edge-blocks",
+                DependencyType::ParentChild => "edge-parent-child",
+                _ => "edge-other",
+            };
+            let _ = writeln!(
+                svg,
+                "<path class=\"{class}\" d=\"M{},{} C{mid},{} {mid},{} {},{}\" marker-end=\"url(#arrow)\"><title>{} {} {}</title></path>",
+                start.0,
+                start.1,
+                start.1,
+                end.1,
+                end.0,
+                end.1,
+                escape_html(&dep.issue_id),
+                escape_html(dep.dep_type.as_str()),
+                escape_html(&dep.depends_on_id)
+            );
+        }
+        for &(node, (x, y)) in &position {
+            let issue = self.by_id[node];
+            let title: String = if issue.title.chars().count() > 26 {
+                format!("{}…", issue.title.chars().take(25).collect::<String>())
+            } else {
+                issue.title.clone()
+            };
+            let _ = writeln!(
+                svg,
+                "<a href=\"issues/{}\" class=\"node-{}\"><title>{}</title><rect x=\"{x}\" y=\"{y}\" width=\"{WIDTH}\" height=\"{HEIGHT}\"/>\
+                 <text x=\"{}\" y=\"{}\" font-weight=\"600\">{}</text><text x=\"{}\" y=\"{}\">{}</text></a>",
+                page_name(node),
+                escape_html(issue.status.as_str()),
+                escape_html(&issue.title),
+                x + 8,
+                y + 16,
+                escape_html(node),
+                x + 8,
+                y + 33,
+                escape_html(&title)
+            );
+        }
+        svg.push_str("</svg></div>\n");
+        svg
+    }
+}
+
+/// Column of a graph node: one past the deepest issue it depends on.
+/// Cycles are cut where they are found.
+fn depth_of<'a>(
+    node: &'a str,
+    deps: &HashMap<&'a str, Vec<&'a str>>,
+    depths: &mut HashMap<&'a str, usize>,
+    visiting: &mut HashSet<&'a str>,
+) -> usize {
+    if let Some(&depth) = depths.get(node) {
+        return depth;
+    }
+    if !visiting.insert(node) {
+        return 0;
+    }
+    let depth = deps
+        .get(node)
+        .into_iter()
+        .flatten()
+        .map(|&target| depth_of(target, deps, depths, visiting) + 1)
+        .max()
+        .unwrap_or(0);
+    visiting.remove(node);
+    depths.insert(node, depth);
+    depth
+}
+
+fn status_badge(status: &Status) -> String {
+    let status = escape_html(status.as_str());
+    format!("<span class=\"badge status-{status}\">{status}</span>")
+}
+
+fn priority_badge(priority: i32) -> String {
+    format!("<span class=\"p{priority}\">P{priority}</span>")
+}
+
+fn label_badges(labels: &[String]) -> String {
+    labels
+        .iter()
+        .map(|label| format!("<span class=\"badge label\">{}</span>", escape_html(label)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Render Markdown to HTML.
+///
+/// Covers what issue text uses: headings, paragraphs, bullet and numbered
+/// lists, block quotes, fenced code, rules, and inline code, emphasis and
+/// links. Raw HTML is escaped and only http(s), mailto and relative links
+/// are kept. Headings are shifted down by `heading_offset` levels.
+#[must_use]
+pub fn markdown_to_html(text: &str, heading_offset: usize) -> String {
+    let mut blocks = Blocks::default();
+    let mut lines = text.lines();
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+        if let Some(lang) = trimmed.strip_prefix("```") {
+            blocks.flush();
+            let mut code = String::new();
+            for line in lines.by_ref() {
+                if line.trim_start().starts_with("```") {
+                    break;
+                }
+                code.push_str(line);
+                code.push('\n');
+            }
+            let lang = lang.trim();
+            let class = if lang.is_empty() {
+                String::new()
+            } else {
+                format!(" class=\"language-{}\"", escape_html(lang))
+            };
+            let _ = writeln!(
+                blocks.html,
+                "<pre><code{class}>{}</code></pre>",
+                escape_html(&code)
+            );
+            continue;
+        }
+        if trimmed.is_empty() {
+            blocks.flush();
+            continue;
+        }
+        let level = trimmed.chars().take_while(|c| *c == '#').count();
+        if (1..=6).contains(&level) && trimmed[level..].starts_with(' ') {
+            blocks.flush();
+            let tag = (level + heading_offset).min(6);
+            let _ = writeln!(
+                blocks.html,
+                "<h{tag}>{}</h{tag}>",
+                inline_html(trimmed[level..].trim())
+            );
+            continue;
+        }
+        if is_rule(trimmed) {
+            blocks.flush();
+            blocks.html.push_str("<hr>\n");
+            continue;
+        }
+        if let Some((ordered, item)) = list_item(trimmed) {
+            blocks.flush_paragraph();
+            blocks.flush_quote();
+            if blocks.list.as_ref().is_some_and(|(o, _)| *o != ordered) {
+                blocks.flush();
+            }
+            blocks
+                .list
+                .get_or_insert_with(|| (ordered, Vec::new()))
+                .1
+                .push(item.to_string());
+            continue;
+        }
+        if let Some(quoted) = trimmed.strip_prefix('>') {
+            blocks.flush_paragraph();
+            blocks.flush_list();
+            blocks.quote.push(quoted.trim().to_string());
+            continue;
+        }
+        if line.starts_with([' ', '\t']) {
+            if let Some((_, items)) = blocks.list.as_mut() {
+                if let Some(last) = items.last_mut() {
+                    last.push(' ');
+                    last.push_str(trimmed);
+                    continue;
+                }
+            }
+        }
+        blocks.flush_list();
+        blocks.flush_quote();
+        blocks.paragraph.push(trimmed.to_string());
+    }
+    blocks.flush();
+    blocks.html
+}
+
+/// Open blocks while converting Markdown.
+#[derive(Default)]
+struct Blocks {
+    html: String,
+    paragraph: Vec<String>,
+    quote: Vec<String>,
+    /// (ordered, items)
+    list: Option<(bool, Vec<String>)>,
+}
+
+impl Blocks {
+    fn flush(&mut self) {
+        self.flush_paragraph();
+        self.flush_quote();
+        self.flush_list();
+    }
+
+    fn flush_paragraph(&mut self) {
+        if !self.paragraph.is_empty() {
+            let _ = writeln!(
+                self.html,
+                "<p>{}</p>",
+                inline_html(&self.paragraph.join("\n"))
+            );
+            self.paragraph.clear();
+        }
+    }
+
+    fn flush_quote(&mut self) {
+        if !self.quote.is_empty() {
+            let _ = writeln!(
+                self.html,
+                "<blockquote><p>{}</p></blockquote>",
+                inline_html(&self.quote.join("\n"))
+            );
+            self.quote.clear();
+        }
+    }
+
+    fn flush_list(&mut self) {
+        if let Some((ordered, items)) = self.list.take() {
+            let tag = if ordered { "ol" } else { "ul" };
+            let _ = writeln!(self.html, "<{tag}>");
+            for item in items {
+                let _ = writeln!(self.html, "<li>{}</li>", inline_html(&item));
+            }
+            let _ = writeln!(self.html, "</{tag}>");
+        }
+    }
+}
+
+/// A horizontal rule: three or more `-`, `*` or `_`, optionally spaced.
+fn is_rule(line: &str) -> bool {
+    let compact: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+    compact.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|&m| compact.chars().all(|c| c == m))
+}
+
+/// A list item line: `- x`, `* x`, `+ x` or `1. x` / `1) x`.
+fn list_item(line: &str) -> Option<(bool, &str)> {
+    for marker in ["- ", "* ", "+ "] {
+        if let Some(item) = line.strip_prefix(marker) {
+            return Some((false, item.trim()));
+        }
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if digits == 0 || digits > 9 {
+        return None;
+    }
+    line[digits..]
+        .strip_prefix(". ")
+        .or_else(|| line[digits..].strip_prefix(") "))
+        .map(|item| (true, item.trim()))
+}
+
+fn safe_url(url: &str) -> bool {
+    let lower = url.trim().to_ascii_lowercase();
+    match lower.split_once(':') {
+        Some((scheme, _)) if !scheme.contains('/') => {
+            matches!(scheme, "http" | "https" | "mailto")
+        }
+        _ => true,
+    }
+}
+
+/// Inline Markdown: `code`, **strong**, *em* / _em_ and [links](url).
+fn inline_html(text: &str) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+    let mut prev: Option<char> = None;
+    while let Some(c) = rest.chars().next() {
+        let after = &rest[c.len_utf8()..];
+        if c == '`' {
+            if let Some(end) = after.find('`') {
+                let _ = write!(out, "<code>{}</code>", escape_html(&after[..end]));
+                rest = &after[end + 1..];
+                prev = Some('`');
+                continue;
+            }
+        }
+        if rest.starts_with("**") || rest.starts_with("__") {
+            let marker = &rest[..2];
+            if let Some(end) = rest[2..].find(marker).filter(|&end| end > 0) {
+                let _ = write!(out, "<strong>{}</strong>", inline_html(&rest[2..2 + end]));
+                rest = &rest[4 + end..];
+                prev = Some(c);
+                continue;
+            }
+        }
+        if (c == '*' || c == '_')
+            && !prev.is_some_and(char::is_alphanumeric)
+            && !after.starts_with(char::is_whitespace)
+        {
+            if let Some(end) = after.find(c).filter(|&end| end > 0) {
+                let _ = write!(out, "<em>{}</em>", inline_html(&after[..end]));
+                rest = &after[end + 1..];
+                prev = Some(c);
+                continue;
+            }
+        }
+        if c == '[' {
+            if let Some(link) = parse_link(rest) {
+                let (label, url, len) = link;
+                let _ = write!(
+                    out,
+                    "<a href=\"{}\">{}</a>",
+                    escape_html(url.trim()),
+                    inline_html(label)
+                );
+                rest = &rest[len..];
+                prev = Some(')');
+                continue;
+            }
+        }
+        if c == '\n' {
+            out.push('\n');
+        } else {
+            out.push_str(&escape_html(&rest[..c.len_utf8()]));
+        }
+        rest = after;
+        prev = Some(c);
+    }
+    out
+}
+
+/// `[label](url)` at the start of `text`: (label, url, bytes consumed).
+fn parse_link(text: &str) -> Option<(&str, &str, usize)> {
+    let close = text.find("](")?;
+    let label = &text[1..close];
+    if label.contains(['[', ']', '\n']) {
+        return None;
+    }
+    let url_start = close + 2;
+    let url_len = text[url_start..].find(')')?;
+    let url = &text[url_start..url_start + url_len];
+    (!url.contains(char::is_whitespace) && safe_url(url)).then_some((
+        label,
+        url,
+        url_start + url_len + 1,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_markdown_to_html() {
+        let html = markdown_to_html(
+            "# Steps\nRun **br** with `--json`\nand *see*.\n\n- one\n- two [docs](https://x.dev)\n\n```sh\na < b\n```\n> quoted\n\nsnake_case_name [bad](javascript:alert(1))",
+            2,
+        );
+        assert!(html.contains("<h3>Steps</h3>"));
+        assert!(html.contains(
+            "<p>Run <strong>br</strong> with <code>--json</code>\nand <em>see</em>.</p>"
+        ));
+        assert!(html.contains(
+            "<ul>\n<li>one</li>\n<li>two <a href=\"https://x.dev\">docs</a></li>\n</ul>"
+        ));
+        assert!(html.contains("<pre><code class=\"language-sh\">a &lt; b\n</code></pre>"));
+        assert!(html.contains("<blockquote><p>quoted</p></blockquote>"));
+        assert!(html.contains("snake_case_name [bad](javascript:alert(1))"));
+        assert!(!html.contains("href=\"javascript"));
+        assert_eq!(markdown_to_html("<script>", 0), "<p>&lt;script&gt;</p>\n");
+    }
+
+    #[test]
+    fn test_render_site() {
+        let at = Utc.with_ymd_and_hms(2024, 3, 12, 15, 4, 0).unwrap();
+        let issue = |id: &str, issue_type: IssueType, status: Status| Issue {
+            id: id.to_string(),
+            title: format!("Title of {id}"),
+            issue_type,
+            status,
+            created_at: at,
+            updated_at: at,
+            ..Issue::default()
+        };
+        let mut child = issue("bd-2", IssueType::Task, Status::Closed);
+        child.labels = vec!["cli".to_string()];
+        child.dependencies.push(Dependency {
+            issue_id: "bd-2".to_string(),
+            depends_on_id: "bd-1".to_string(),
+            dep_type: DependencyType::ParentChild,
+            created_at: at,
+            created_by: None,
+            metadata: None,
+            thread_id: None,
+        });
+        let issues = vec![
+            issue("bd-1", IssueType::Epic, Status::Open),
+            child,
+            issue("bd-3", IssueType::Bug, Status::Open),
+        ];
+        let files = render_site(&issues, "demo <site>", at);
+        let paths: Vec<&str> = files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "style.css",
+                "index.html",
+                "graph.html",
+                "issues/bd-1.html",
+                "issues/bd-2.html",
+                "issues/bd-3.html"
+            ]
+        );
+        let index = &files[1].contents;
+        assert!(index.contains("<title>Issues · demo &lt;site&gt;</title>"));
+        assert!(index.contains("<progress value=\"1\" max=\"1\"></progress> 1/1"));
+        assert!(index.contains("<option value=\"cli\">cli</option>"));
+        assert!(index.contains("data-status=\"closed\""));
+
+        let graph = &files[2].contents;
+        assert!(graph.contains("class=\"edge-parent-child\""));
+        assert!(graph.contains("href=\"issues/bd-2.html\""));
+        assert!(!graph.contains("bd-3"));
+
+        let epic = &files[3].contents;
+        assert!(epic.contains("<tr><th>Children</th><td><a href=\"../issues/bd-2.html\""));
+        let child = &files[4].contents;
+        assert!(child.contains("<tr><th>Parent</th><td><a href=\"../issues/bd-1.html\""));
+    }
+}
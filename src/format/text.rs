@@ -6,7 +6,9 @@
 //! - Type badges ([bug], [feature], etc.)
 //! - Issue line formatting
 
+use super::output::TimeSpent;
 use crate::model::{Issue, IssueType, Priority, Status};
+use crate::util::time::format_minutes;
 use crossterm::style::Stylize;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
@@ -229,6 +231,59 @@ pub fn truncate_title(title: &str, max_len: usize) -> String {
     s
 }
 
+/// Summarize logged time against an estimate, e.g.
+/// `1h 30m logged (45m on children) of 2h estimated`.
+///
+/// Returns `None` when there is neither an estimate nor logged time.
+#[must_use]
+pub fn format_time_tracking(
+    estimated_minutes: Option<i64>,
+    spent: Option<TimeSpent>,
+) -> Option<String> {
+    let logged = spent.filter(|s| s.total_minutes > 0).map(|s| {
+        let children = s.total_minutes - s.logged_minutes;
+        if children > 0 {
+            format!(
+                "{} logged ({} on children)",
+                format_minutes(s.total_minutes),
+                format_minutes(children)
+            )
+        } else {
+            format!("{} logged", format_minutes(s.total_minutes))
+        }
+    });
+    let estimated = estimated_minutes.map(|m| format!("{} estimated", format_minutes(m)));
+    match (logged, estimated) {
+        (Some(logged), Some(estimated)) => Some(format!("{logged} of {estimated}")),
+        (Some(text), None) | (None, Some(text)) => Some(text),
+        (None, None) => None,
+    }
+}
+
+/// Explain a `conditional-blocks` / `waits-for` blocker ref from the blocked
+/// cache (`<id>:<rule>`), e.g. `waits for all children of bd-1 (2/5 closed)`.
+///
+/// Returns `None` for plain refs (`<id>:<status>`), which need no explanation.
+#[must_use]
+pub fn describe_blocker_rule(blocker_ref: &str) -> Option<String> {
+    let (id, rule) = blocker_ref.rsplit_once(':')?;
+    let (name, arg) = rule
+        .strip_suffix(')')
+        .and_then(|r| r.split_once('('))
+        .unwrap_or((rule, ""));
+    match name {
+        "if-failed" if arg == "closed" => Some(format!(
+            "runs only if {id} fails; it closed without failing"
+        )),
+        "if-failed" => Some(format!("runs only if {id} fails ({id} is {arg})")),
+        "all-children" => Some(format!("waits for all children of {id} ({arg} closed)")),
+        "any-children" => Some(format!("waits for any child of {id} ({arg} closed)")),
+        _ => rule
+            .strip_prefix("any-of-")
+            .map(|count| format!("waits for any one of {count} issues to close, including {id}")),
+    }
+}
+
 fn visible_len(text: &str) -> usize {
     UnicodeWidthStr::width(text)
 }
@@ -327,6 +382,8 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         }
     }
 
@@ -464,4 +521,22 @@ mod tests {
         assert!(!line.contains("..."));
         assert!(line.contains("A very long issue title"));
     }
+
+    #[test]
+    fn test_describe_blocker_rule() {
+        assert_eq!(describe_blocker_rule("bd-1:open"), None);
+        assert_eq!(describe_blocker_rule("bd-1:parent-blocked"), None);
+        assert_eq!(
+            describe_blocker_rule("bd-1:if-failed(closed)").as_deref(),
+            Some("runs only if bd-1 fails; it closed without failing")
+        );
+        assert_eq!(
+            describe_blocker_rule("bd-1:all-children(2/5)").as_deref(),
+            Some("waits for all children of bd-1 (2/5 closed)")
+        );
+        assert_eq!(
+            describe_blocker_rule("bd-2:any-of-3").as_deref(),
+            Some("waits for any one of 3 issues to close, including bd-2")
+        );
+    }
 }
@@ -0,0 +1,163 @@
+//! Git hooks installed by `br hooks install`.
+//!
+//! The hook scripts are thin wrappers that call back into hidden `br hooks`
+//! subcommands, so the logic lives here and works the same on every platform:
+//! - `commit-msg`: every issue ID referenced in the message must exist (short
+//!   IDs are resolved like on the command line); with `--require-issue` the
+//!   message must reference at least one issue.
+//! - `pre-commit`: exports the database to JSONL and stages it, so the JSONL
+//!   in a commit is never stale.
+//! - `post-commit`: closes the issues named in `Closes <id>` lines, with the
+//!   commit hash as the reason.
+//!
+//! The scripts exit quietly when `br` is not on `PATH`, so clones used
+//! without `br` are unaffected.
+
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// `git commit -v` drops everything below this line.
+const SCISSORS: &str = "# ------------------------ >8 ------------------------";
+
+/// Marks hook scripts written by `br hooks install`.
+pub const MARKER: &str = "# Installed by `br hooks install`; remove with `br hooks uninstall`.";
+
+/// A closing line: the keyword, then the IDs.
+static CLOSING_LINE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?im)^\s*(?:close[sd]?|fix(?:e[sd])?|resolve[sd]?)\b:?\s+(.+)$").unwrap()
+});
+
+/// The git hooks `br hooks install` manages.
+pub const GIT_HOOKS: &[&str] = &["commit-msg", "pre-commit", "post-commit"];
+
+/// Script for the git hook `name` (one of [`GIT_HOOKS`]).
+#[must_use]
+pub fn hook_script(name: &str, require_issue: bool) -> String {
+    let command = match name {
+        "commit-msg" if require_issue => "br hooks commit-msg --require-issue \"$1\"",
+        "commit-msg" => "br hooks commit-msg \"$1\"",
+        "pre-commit" => "br hooks pre-commit",
+        _ => "br hooks post-commit",
+    };
+    format!("#!/bin/sh\n{MARKER}\ncommand -v br >/dev/null 2>&1 || exit 0\nexec {command}\n")
+}
+
+/// Is `script` a hook written by `br hooks install`?
+#[must_use]
+pub fn is_br_hook(script: &str) -> bool {
+    script.contains(MARKER)
+}
+
+/// The commit message as git will record it: comment lines and everything
+/// below the scissors line (`git commit -v`) are dropped.
+#[must_use]
+pub fn strip_comments(message: &str) -> String {
+    message
+        .lines()
+        .take_while(|line| !line.starts_with(SCISSORS))
+        .filter(|line| !line.starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Does the message need no issue reference (merges, reverts, fixups)?
+#[must_use]
+pub fn is_exempt(message: &str) -> bool {
+    let subject = message.trim_start();
+    ["Merge ", "Revert ", "fixup!", "squash!", "amend!"]
+        .iter()
+        .any(|start| subject.starts_with(start))
+}
+
+/// Issue IDs with `prefix` referenced anywhere in `message`, in order of
+/// first appearance.
+#[must_use]
+pub fn find_issue_refs(message: &str, prefix: &str) -> Vec<String> {
+    let re = id_regex(prefix);
+    let mut refs: Vec<String> = Vec::new();
+    for found in re.find_iter(message) {
+        let id = found.as_str().to_lowercase();
+        if !refs.contains(&id) {
+            refs.push(id);
+        }
+    }
+    refs
+}
+
+/// Issue IDs named in closing lines such as `Closes bd-abc`, `Fixes: bd-abc,
+/// bd-def` or `Resolves bd-abc and bd-def`.
+///
+/// The keyword must be followed by IDs only, so a subject like `Fix crash in
+/// bd-abc import` closes nothing.
+#[must_use]
+pub fn find_closing_refs(message: &str, prefix: &str) -> Vec<String> {
+    let re = id_regex(prefix);
+    let mut refs: Vec<String> = Vec::new();
+    for caps in CLOSING_LINE.captures_iter(message) {
+        let tokens: Vec<&str> = caps[1]
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .map(|token| token.trim_end_matches('.'))
+            .filter(|token| !token.is_empty() && !token.eq_ignore_ascii_case("and"))
+            .collect();
+        let only_ids = tokens.iter().all(|token| {
+            re.find(token)
+                .is_some_and(|found| found.len() == token.len())
+        });
+        if !only_ids {
+            continue;
+        }
+        for token in tokens {
+            let id = token.to_lowercase();
+            if !refs.contains(&id) {
+                refs.push(id);
+            }
+        }
+    }
+    refs
+}
+
+/// Matches `prefix-<hash>` including hierarchical IDs like `bd-abc.1`.
+fn id_regex(prefix: &str) -> Regex {
+    let pattern = format!(r"(?i)\b{}-[a-z0-9]+(?:\.[0-9]+)*\b", regex::escape(prefix));
+    // The prefix is escaped, so the pattern is always valid
+    Regex::new(&pattern).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_refs_and_closing_lines() {
+        let message = strip_comments(
+            "Fix login retry (bd-a1b2)\n\nSee BD-c3d4.1 and bd-a1b2.\n\nCloses bd-a1b2, bd-e5f6\nFixes: bd-9z\n# Please enter the commit message\n# bd-zzzz\n",
+        );
+        assert_eq!(
+            find_issue_refs(&message, "bd"),
+            vec!["bd-a1b2", "bd-c3d4.1", "bd-e5f6", "bd-9z"]
+        );
+        assert_eq!(
+            find_closing_refs(&message, "bd"),
+            vec!["bd-a1b2", "bd-e5f6", "bd-9z"]
+        );
+        assert!(find_issue_refs("abd-123 and bd-", "bd").is_empty());
+        assert!(find_closing_refs("Fix crash in bd-a1b2 import", "bd").is_empty());
+        assert_eq!(
+            find_closing_refs("Resolves bd-a1b2 and bd-e5f6.", "bd"),
+            vec!["bd-a1b2", "bd-e5f6"]
+        );
+    }
+
+    #[test]
+    fn test_hook_script_and_exemptions() {
+        let script = hook_script("commit-msg", true);
+        assert!(script.starts_with("#!/bin/sh\n"));
+        assert!(script.contains("--require-issue \"$1\""));
+        assert!(is_br_hook(&script));
+        assert!(!is_br_hook("#!/bin/sh\nnpx lint-staged\n"));
+
+        assert!(is_exempt("Merge branch 'main'"));
+        assert!(is_exempt("fixup! Fix login"));
+        assert!(!is_exempt("Fix login"));
+    }
+}
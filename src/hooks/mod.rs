@@ -0,0 +1,513 @@
+//! Lifecycle hooks.
+//!
+//! Hooks are configured under `hooks.*` in `config.yaml` and fire for the
+//! audit events a mutation records. A hook either runs a shell command with a
+//! JSON payload on stdin, or POSTs the payload to a URL (through `curl`).
+//!
+//! ```yaml
+//! hooks:
+//!   on_close: ./scripts/notify.sh     # shorthand: a command (or URL)
+//!   on_create:
+//!     triage:
+//!       url: https://example.com/hooks/beads
+//!       types: [bug]
+//!       labels: [customer]
+//!       timeout: 5
+//!       on_failure: block
+//! ```
+//!
+//! The payload is `{"hook": .., "event": .., "issue": ..}`, with the issue as
+//! it stands after the change (labels included). Commands also get `BR_HOOK`,
+//! `BR_EVENT` and `BR_ISSUE_ID` in their environment.
+//!
+//! Hooks with `on_failure: block` run before the transaction commits, so a
+//! failure rolls the change back with [`BeadsError::HookRejected`]. `warn`
+//! hooks run after the commit and only log a warning when they fail.
+//!
+//! A blocking hook runs while its change still holds the database write
+//! lock, so a `br` command it starts can read but not write: writes fail at
+//! once with [`BeadsError::DatabaseLocked`] (see [`HOOK_LOCKED_ENV`]) instead
+//! of waiting out the lock timeout.
+//!
+//! Git hooks installed by `br hooks install` live in [`git`].
+
+pub mod git;
+
+use crate::error::{BeadsError, Result};
+use crate::model::{Event, EventType, Issue};
+use serde::Serialize;
+use std::io::{Read, Write};
+use std::process::{Child, Command, Stdio};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+
+/// Environment variable set for hook commands (to the hook's name).
+///
+/// Storage opened by a `br` command running inside a hook does not fire
+/// hooks, so a hook that edits issues cannot trigger itself.
+pub const HOOK_ENV: &str = "BR_HOOK";
+
+/// Environment variable set for `on_failure: block` hook commands, which run
+/// while the triggering change holds the write lock. Storage opened under it
+/// does not wait for the lock, since it is only released once the hook
+/// returns.
+pub const HOOK_LOCKED_ENV: &str = "BR_HOOK_LOCKED";
+
+/// Default time a hook may run before it is killed, in seconds.
+pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// Trigger names accepted under `hooks.*`.
+pub const TRIGGERS: &[&str] = &[
+    "on_create",
+    "on_update",
+    "on_status_change",
+    "on_priority_change",
+    "on_assign",
+    "on_comment",
+    "on_close",
+    "on_reopen",
+    "on_label",
+    "on_dependency",
+    "on_delete",
+    "on_restore",
+    "on_claim",
+    "on_lease_expired",
+    "on_event",
+];
+
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Does `trigger` fire on events of `event_type`?
+#[must_use]
+pub fn trigger_fires(trigger: &str, event_type: &EventType) -> bool {
+    match trigger {
+        "on_event" => true,
+        "on_create" => matches!(event_type, EventType::Created),
+        "on_update" => matches!(event_type, EventType::Updated),
+        "on_status_change" => matches!(event_type, EventType::StatusChanged),
+        "on_priority_change" => matches!(event_type, EventType::PriorityChanged),
+        "on_assign" => matches!(event_type, EventType::AssigneeChanged),
+        "on_comment" => matches!(event_type, EventType::Commented),
+        "on_close" => matches!(event_type, EventType::Closed),
+        "on_reopen" => matches!(event_type, EventType::Reopened),
+        "on_label" => matches!(event_type, EventType::LabelAdded | EventType::LabelRemoved),
+        "on_dependency" => matches!(
+            event_type,
+            EventType::DependencyAdded | EventType::DependencyRemoved
+        ),
+        "on_delete" => matches!(event_type, EventType::Deleted),
+        "on_restore" => matches!(event_type, EventType::Restored),
+        "on_claim" => matches!(event_type, EventType::Claimed),
+        "on_lease_expired" => matches!(event_type, EventType::LeaseExpired),
+        _ => false,
+    }
+}
+
+/// What happens when a hook fails (non-zero exit, HTTP error or timeout).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailurePolicy {
+    /// Log a warning; the change stands.
+    #[default]
+    Warn,
+    /// Roll the change back.
+    Block,
+}
+
+impl FailurePolicy {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Warn => "warn",
+            Self::Block => "block",
+        }
+    }
+}
+
+/// What a hook does when it fires.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HookAction {
+    /// Run through the shell with the payload on stdin.
+    Command(String),
+    /// POST the payload as `application/json`.
+    Url(String),
+}
+
+/// A configured hook.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Hook {
+    /// `<trigger>` for unnamed hooks, `<trigger>.<name>` otherwise.
+    pub name: String,
+    pub trigger: String,
+    pub action: HookAction,
+    /// Only fire for issues carrying one of these labels.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+    /// Only fire for issues of one of these types.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub types: Vec<String>,
+    pub timeout_secs: u64,
+    pub on_failure: FailurePolicy,
+}
+
+impl Hook {
+    /// Does this hook fire for `payload`?
+    #[must_use]
+    pub fn matches(&self, payload: &HookPayload) -> bool {
+        trigger_fires(&self.trigger, &payload.event.event_type)
+            && self.accepts(payload.issue.as_ref())
+    }
+
+    /// Do the label and type filters accept `issue`?
+    #[must_use]
+    pub fn accepts(&self, issue: Option<&Issue>) -> bool {
+        if self.labels.is_empty() && self.types.is_empty() {
+            return true;
+        }
+        let Some(issue) = issue else {
+            return false;
+        };
+        (self.labels.is_empty() || self.labels.iter().any(|label| issue.labels.contains(label)))
+            && (self.types.is_empty()
+                || self
+                    .types
+                    .iter()
+                    .any(|kind| kind.eq_ignore_ascii_case(issue.issue_type.as_str())))
+    }
+
+    /// Run the hook for `payload`, waiting at most its timeout.
+    #[must_use]
+    pub fn run(&self, payload: &HookPayload) -> HookRun {
+        let started = Instant::now();
+        let envelope = Envelope {
+            hook: &self.name,
+            payload,
+        };
+        let body = serde_json::to_vec(&envelope).unwrap_or_default();
+        let timeout = Duration::from_secs(self.timeout_secs);
+
+        let mut command = match &self.action {
+            HookAction::Command(command) => {
+                let (shell, flag) = if cfg!(windows) {
+                    ("cmd", "/C")
+                } else {
+                    ("sh", "-c")
+                };
+                let mut process = Command::new(shell);
+                process
+                    .args([flag, command.as_str()])
+                    .env(HOOK_ENV, &self.name)
+                    .env("BR_EVENT", payload.event.event_type.as_str())
+                    .env("BR_ISSUE_ID", &payload.event.issue_id);
+                if self.on_failure == FailurePolicy::Block {
+                    process.env(HOOK_LOCKED_ENV, "1");
+                }
+                process
+            }
+            HookAction::Url(url) => {
+                let mut process = Command::new("curl");
+                process.args(["-sS", "-f", "-X", "POST"]);
+                process.args(["-H", "Content-Type: application/json"]);
+                process.args(["--data-binary", "@-", "--max-time"]);
+                process.arg(self.timeout_secs.to_string()).arg(url);
+                process
+            }
+        };
+        let spawned = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn();
+
+        let (error, output) = match spawned {
+            Ok(child) => wait_with_timeout(child, body, timeout),
+            Err(err) => (Some(format!("failed to start: {err}")), String::new()),
+        };
+        debug!(hook = %self.name, event = payload.event.id, ok = error.is_none(), "Ran hook");
+
+        HookRun {
+            hook: self.name.clone(),
+            event_id: payload.event.id,
+            issue_id: payload.event.issue_id.clone(),
+            ok: error.is_none(),
+            error,
+            output,
+            elapsed_ms: u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+        }
+    }
+}
+
+/// An event and the issue it touched, as sent to hooks.
+#[derive(Debug, Clone, Serialize)]
+pub struct HookPayload {
+    pub event: Event,
+    /// The issue after the change; `None` if it no longer exists.
+    pub issue: Option<Issue>,
+}
+
+#[derive(Serialize)]
+struct Envelope<'a> {
+    hook: &'a str,
+    #[serde(flatten)]
+    payload: &'a HookPayload,
+}
+
+/// Outcome of running one hook.
+#[derive(Debug, Clone, Serialize)]
+pub struct HookRun {
+    pub hook: String,
+    pub event_id: i64,
+    pub issue_id: String,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Combined stdout and stderr, trimmed.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub output: String,
+    pub elapsed_ms: u64,
+}
+
+impl HookRun {
+    /// The failure with the hook's output appended, for messages.
+    #[must_use]
+    pub fn failure(&self) -> Option<String> {
+        self.error.as_ref().map(|error| {
+            if self.output.is_empty() {
+                error.clone()
+            } else {
+                format!("{error}: {}", self.output)
+            }
+        })
+    }
+}
+
+/// The configured hooks, ordered by name.
+#[derive(Debug, Clone, Default)]
+pub struct Hooks {
+    hooks: Vec<Hook>,
+}
+
+impl Hooks {
+    #[must_use]
+    pub fn new(mut hooks: Vec<Hook>) -> Self {
+        hooks.sort_by(|a, b| a.name.cmp(&b.name));
+        Self { hooks }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+
+    #[must_use]
+    pub fn hooks(&self) -> &[Hook] {
+        &self.hooks
+    }
+
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Hook> {
+        self.hooks.iter().find(|hook| hook.name == name)
+    }
+
+    /// Could any hook fire on `event_type`? Checked before building payloads.
+    #[must_use]
+    pub fn wants(&self, event_type: &EventType) -> bool {
+        self.hooks
+            .iter()
+            .any(|hook| trigger_fires(&hook.trigger, event_type))
+    }
+
+    /// Run the `block` hooks matching `payloads`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BeadsError::HookRejected`] for the first hook that fails.
+    pub fn run_blocking(&self, payloads: &[HookPayload]) -> Result<()> {
+        for (hook, payload) in self.matching(FailurePolicy::Block, payloads) {
+            let run = hook.run(payload);
+            if let Some(reason) = run.failure() {
+                return Err(BeadsError::HookRejected {
+                    hook: hook.name.clone(),
+                    reason: format!(
+                        "{reason} ({} on {})",
+                        payload.event.event_type.as_str(),
+                        payload.event.issue_id
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Run the `warn` hooks matching `payloads`, logging failures.
+    pub fn run_warning(&self, payloads: &[HookPayload]) {
+        for (hook, payload) in self.matching(FailurePolicy::Warn, payloads) {
+            let run = hook.run(payload);
+            if let Some(reason) = run.failure() {
+                warn!(
+                    hook = %hook.name,
+                    event = payload.event.event_type.as_str(),
+                    issue = %payload.event.issue_id,
+                    "Hook failed: {reason}"
+                );
+            }
+        }
+    }
+
+    fn matching<'a>(
+        &'a self,
+        policy: FailurePolicy,
+        payloads: &'a [HookPayload],
+    ) -> impl Iterator<Item = (&'a Hook, &'a HookPayload)> {
+        payloads.iter().flat_map(move |payload| {
+            self.hooks
+                .iter()
+                .filter(move |hook| hook.on_failure == policy && hook.matches(payload))
+                .map(move |hook| (hook, payload))
+        })
+    }
+}
+
+/// Feed `input` to `child` and wait up to `timeout`, killing it on expiry.
+///
+/// Returns the failure (if any) and the combined output.
+fn wait_with_timeout(
+    mut child: Child,
+    input: Vec<u8>,
+    timeout: Duration,
+) -> (Option<String>, String) {
+    // Feed stdin and drain the pipes from separate threads so a chatty
+    // command can't deadlock us.
+    let mut stdin = child.stdin.take();
+    let writer = std::thread::spawn(move || {
+        if let Some(stdin) = stdin.as_mut() {
+            // A command that ignores its input may close the pipe early.
+            let _ = stdin.write_all(&input);
+        }
+    });
+    let readers = [
+        child.stdout.take().map(drain),
+        child.stderr.take().map(drain),
+    ];
+
+    let deadline = Instant::now() + timeout;
+    let error = loop {
+        match child.try_wait() {
+            Ok(Some(status)) if status.success() => break None,
+            Ok(Some(status)) => break Some(format!("failed ({status})")),
+            Ok(None) if Instant::now() >= deadline => {
+                let _ = child.kill();
+                let _ = child.wait();
+                // Grandchildren may still hold the pipes; don't wait on them.
+                return (
+                    Some(format!("timed out after {}s", timeout.as_secs())),
+                    String::new(),
+                );
+            }
+            Ok(None) => std::thread::sleep(POLL_INTERVAL),
+            Err(err) => break Some(err.to_string()),
+        }
+    };
+
+    let _ = writer.join();
+    let output: String = readers
+        .into_iter()
+        .flatten()
+        .filter_map(|reader| reader.join().ok())
+        .collect();
+    (error, output.trim().to_string())
+}
+
+fn drain<R: Read + Send + 'static>(mut pipe: R) -> JoinHandle<String> {
+    std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = pipe.read_to_end(&mut buf);
+        String::from_utf8_lossy(&buf).into_owned()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::IssueType;
+    use chrono::Utc;
+
+    fn make_hook(trigger: &str, command: &str, on_failure: FailurePolicy) -> Hook {
+        Hook {
+            name: trigger.to_string(),
+            trigger: trigger.to_string(),
+            action: HookAction::Command(command.to_string()),
+            labels: Vec::new(),
+            types: Vec::new(),
+            timeout_secs: 5,
+            on_failure,
+        }
+    }
+
+    fn make_payload(event_type: EventType) -> HookPayload {
+        HookPayload {
+            event: Event {
+                id: 7,
+                issue_id: "bd-1".to_string(),
+                event_type,
+                actor: "tester".to_string(),
+                old_value: None,
+                new_value: None,
+                comment: None,
+                created_at: Utc::now(),
+            },
+            issue: Some(Issue {
+                id: "bd-1".to_string(),
+                issue_type: IssueType::Bug,
+                labels: vec!["customer".to_string()],
+                ..Issue::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn test_hook_matches_trigger_and_filters() {
+        let closed = make_payload(EventType::Closed);
+        let mut hook = make_hook("on_close", "true", FailurePolicy::Warn);
+        assert!(hook.matches(&closed));
+        assert!(!hook.matches(&make_payload(EventType::Created)));
+        assert!(make_hook("on_event", "true", FailurePolicy::Warn).matches(&closed));
+
+        hook.types = vec!["bug".to_string()];
+        hook.labels = vec!["customer".to_string(), "urgent".to_string()];
+        assert!(hook.matches(&closed));
+        hook.labels = vec!["urgent".to_string()];
+        assert!(!hook.matches(&closed));
+        assert!(!hook.accepts(None));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_passes_payload_and_reports_failures() {
+        let payload = make_payload(EventType::Closed);
+        let run =
+            make_hook("on_close", "cat; echo \" $BR_EVENT\"", FailurePolicy::Warn).run(&payload);
+        assert!(run.ok, "{run:?}");
+        assert!(run.output.contains("\"hook\":\"on_close\""));
+        assert!(run.output.ends_with("closed"));
+
+        let hooks = Hooks::new(vec![make_hook("on_close", "exit 3", FailurePolicy::Block)]);
+        let err = hooks
+            .run_blocking(std::slice::from_ref(&payload))
+            .unwrap_err();
+        assert!(matches!(err, BeadsError::HookRejected { .. }));
+        // Blocking hooks ignore events they don't fire on
+        assert!(
+            hooks
+                .run_blocking(&[make_payload(EventType::Created)])
+                .is_ok()
+        );
+
+        let mut slow = make_hook("on_close", "sleep 5", FailurePolicy::Warn);
+        slow.timeout_secs = 0;
+        let run = slow.run(&payload);
+        assert_eq!(run.error.as_deref(), Some("timed out after 0s"));
+    }
+}
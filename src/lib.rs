@@ -14,6 +14,7 @@
 //! - [`config`] - Configuration management
 //! - [`error`] - Error types and handling
 //! - [`format`] - Output formatting (text, JSON)
+//! - [`hooks`] - Lifecycle hooks run on issue events
 //! - [`util`] - Utility functions (hashing, time, paths)
 
 #![forbid(unsafe_code)]
@@ -24,6 +25,7 @@ pub mod cli;
 pub mod config;
 pub mod error;
 pub mod format;
+pub mod hooks;
 pub mod logging;
 pub mod model;
 pub mod output;
@@ -43,9 +43,14 @@ fn main() {
         } => commands::init::execute(prefix, force, None, &output_ctx),
         Commands::Create(args) => commands::create::execute(&args, &overrides, &output_ctx),
         Commands::Update(args) => commands::update::execute(&args, &overrides, &output_ctx),
+        Commands::Claim(args) => commands::claim::execute_claim(&args, &overrides, &output_ctx),
+        Commands::Heartbeat(args) => {
+            commands::claim::execute_heartbeat(&args, &overrides, &output_ctx)
+        }
         Commands::Delete(args) => {
             commands::delete::execute(&args, cli.json, &overrides, &output_ctx)
         }
+        Commands::Move(args) => commands::r#move::execute(&args, &overrides, &output_ctx),
         Commands::List(args) => commands::list::execute(&args, cli.json, &overrides, &output_ctx),
         Commands::Comments(args) => {
             commands::comments::execute(&args, cli.json, &overrides, &output_ctx)
@@ -74,10 +79,14 @@ fn main() {
         Commands::Stale(args) => commands::stale::execute(&args, &overrides, &output_ctx),
         Commands::Lint(args) => commands::lint::execute(&args, cli.json, &overrides, &output_ctx),
         Commands::Ready(args) => commands::ready::execute(&args, cli.json, &overrides, &output_ctx),
+        Commands::Next(args) => commands::next::execute(&args, &overrides, &output_ctx),
         Commands::Blocked(args) => {
             commands::blocked::execute(&args, cli.json || args.robot, &overrides, &output_ctx)
         }
+        Commands::Board(args) => commands::board::execute(&args, &overrides, &output_ctx),
         Commands::Sync(args) => commands::sync::execute(&args, cli.json, &overrides, &output_ctx),
+        Commands::Import(args) => commands::import::execute(&args, &overrides, &output_ctx),
+        Commands::Export(args) => commands::export::execute(&args, &overrides, &output_ctx),
         Commands::Doctor => commands::doctor::execute(&overrides, &output_ctx),
         Commands::Info(args) => commands::info::execute(&args, &overrides, &output_ctx),
         Commands::Schema(args) => commands::schema::execute(&args, &overrides, &output_ctx),
@@ -109,8 +118,19 @@ fn main() {
         Commands::Changelog(args) => {
             commands::changelog::execute(&args, cli.json || args.robot, &overrides, &output_ctx)
         }
+        Commands::Diff(args) => commands::diff::execute(&args, &overrides, &output_ctx),
+        Commands::Undo(args) => commands::undo::execute(&args, &overrides, &output_ctx),
         Commands::Query { command } => commands::query::execute(&command, &overrides, &output_ctx),
+        Commands::Template { command } => {
+            commands::template::execute(&command, &overrides, &output_ctx)
+        }
+        Commands::Recur { command } => commands::recur::execute(&command, &overrides, &output_ctx),
+        Commands::Time { command } => commands::time::execute(&command, &overrides, &output_ctx),
+        Commands::Hooks { command } => commands::hooks::execute(&command, &overrides, &output_ctx),
+        Commands::Compact(args) => commands::compact::execute(&args, &overrides, &output_ctx),
+        Commands::Serve(args) => commands::serve::execute(&args, &overrides, &output_ctx),
         Commands::Graph(args) => commands::graph::execute(&args, &overrides, &output_ctx),
+        Commands::Plan(args) => commands::plan::execute(&args, &overrides, &output_ctx),
         Commands::Agents(args) => {
             let agents_args = commands::agents::AgentsArgs {
                 add: args.add,
@@ -122,6 +142,7 @@ fn main() {
             };
             commands::agents::execute(&agents_args, &output_ctx)
         }
+        Commands::MergeDriver(args) => commands::merge_driver::execute(&args, &output_ctx),
     };
 
     // Handle command result
@@ -140,7 +161,11 @@ const fn is_mutating_command(cmd: &Commands) -> bool {
     match cmd {
         Commands::Create(_)
         | Commands::Update(_)
+        | Commands::Claim(_)
+        | Commands::Heartbeat(_)
+        | Commands::Next(_)
         | Commands::Delete(_)
+        | Commands::Move(_)
         | Commands::Close(_)
         | Commands::Reopen(_)
         | Commands::Q(_)
@@ -148,11 +173,29 @@ const fn is_mutating_command(cmd: &Commands) -> bool {
         | Commands::Label { .. }
         | Commands::Comments(_)
         | Commands::Defer(_)
-        | Commands::Undefer(_) => true,
+        | Commands::Undefer(_)
+        | Commands::Time { .. }
+        | Commands::Board(_) => true,
         Commands::Epic { command } => matches!(
             command,
             beads_rust::cli::EpicCommands::CloseEligible(args) if !args.dry_run
         ),
+        Commands::Template { command } => {
+            matches!(command, beads_rust::cli::TemplateCommands::Create(_))
+        }
+        Commands::Recur { command } => match command {
+            beads_rust::cli::RecurCommands::List => false,
+            beads_rust::cli::RecurCommands::Run(args) => !args.dry_run,
+            beads_rust::cli::RecurCommands::Set(_) | beads_rust::cli::RecurCommands::Clear(_) => {
+                true
+            }
+        },
+        Commands::Hooks { command } => {
+            matches!(command, beads_rust::cli::HooksCommands::PostCommit)
+        }
+        Commands::Compact(args) => !args.dry_run,
+        Commands::Undo(args) => !args.dry_run,
+        Commands::Import(args) => !args.dry_run,
         _ => false,
     }
 }
@@ -167,7 +210,9 @@ const fn should_auto_import(cmd: &Commands) -> bool {
         | Commands::Show(_)
         | Commands::Search(_)
         | Commands::Ready(_)
+        | Commands::Next(_)
         | Commands::Blocked(_)
+        | Commands::Board(_)
         | Commands::Count(_)
         | Commands::Stale(_)
         | Commands::Lint(_)
@@ -175,10 +220,18 @@ const fn should_auto_import(cmd: &Commands) -> bool {
         | Commands::Status(_)
         | Commands::Orphans(_)
         | Commands::Changelog(_)
+        | Commands::Diff(_)
+        | Commands::Undo(_)
         | Commands::Graph(_)
+        | Commands::Export(_)
+        | Commands::Plan(_)
         | Commands::Create(_)
+        | Commands::Import(_)
         | Commands::Update(_)
+        | Commands::Claim(_)
+        | Commands::Heartbeat(_)
         | Commands::Delete(_)
+        | Commands::Move(_)
         | Commands::Close(_)
         | Commands::Reopen(_)
         | Commands::Q(_)
@@ -188,7 +241,13 @@ const fn should_auto_import(cmd: &Commands) -> bool {
         | Commands::Dep { .. }
         | Commands::Label { .. }
         | Commands::Epic { .. }
-        | Commands::Query { .. } => true,
+        | Commands::Query { .. }
+        | Commands::Template { .. }
+        | Commands::Recur { .. }
+        | Commands::Time { .. }
+        | Commands::Hooks { .. }
+        | Commands::Compact(_)
+        | Commands::Serve(_) => true,
 
         // Explicitly excluded: init, sync, diagnostic, and config commands
         Commands::Init { .. }
@@ -202,7 +261,8 @@ const fn should_auto_import(cmd: &Commands) -> bool {
         | Commands::Audit { .. }
         | Commands::Config { .. }
         | Commands::History(_)
-        | Commands::Agents(_) => false,
+        | Commands::Agents(_)
+        | Commands::MergeDriver(_) => false,
 
         #[cfg(feature = "self_update")]
         Commands::Upgrade(_) => false,
@@ -366,6 +426,8 @@ mod tests {
             dry_run: false,
             silent: false,
             file: None,
+            from_template: None,
+            vars: Vec::new(),
         }
     }
 
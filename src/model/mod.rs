@@ -277,6 +277,155 @@ impl FromStr for DependencyType {
     }
 }
 
+/// How a closed issue turned out (`br close --outcome`).
+///
+/// Recorded as an `outcome:<value>` label so it travels with the JSONL.
+/// `conditional-blocks` dependents run only when the blocker failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CloseOutcome {
+    #[default]
+    Success,
+    Failed,
+    WontFix,
+}
+
+impl CloseOutcome {
+    /// Label namespace used to record the outcome.
+    pub const LABEL_PREFIX: &'static str = "outcome:";
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Failed => "failed",
+            Self::WontFix => "wontfix",
+        }
+    }
+
+    /// The `outcome:<value>` label for this outcome.
+    #[must_use]
+    pub fn label(self) -> String {
+        format!("{}{}", Self::LABEL_PREFIX, self.as_str())
+    }
+
+    /// Outcomes that release `conditional-blocks` dependents.
+    #[must_use]
+    pub const fn is_failure(self) -> bool {
+        matches!(self, Self::Failed | Self::WontFix)
+    }
+
+    /// Parse an `outcome:<value>` label; other labels yield `None`.
+    #[must_use]
+    pub fn from_label(label: &str) -> Option<Self> {
+        label
+            .strip_prefix(Self::LABEL_PREFIX)
+            .and_then(|value| value.parse().ok())
+    }
+}
+
+impl fmt::Display for CloseOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for CloseOutcome {
+    type Err = crate::error::BeadsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "success" | "done" => Ok(Self::Success),
+            "failed" | "failure" => Ok(Self::Failed),
+            "wontfix" | "won't-fix" | "wont-fix" => Ok(Self::WontFix),
+            other => Err(crate::error::BeadsError::validation(
+                "outcome",
+                format!("unknown outcome '{other}' (expected success, failed or wontfix)"),
+            )),
+        }
+    }
+}
+
+/// Label namespace for the forwarding pointer left on a moved issue's
+/// tombstone (`br move`): `moved-to:<new-id>`.
+pub const MOVED_TO_LABEL_PREFIX: &str = "moved-to:";
+
+/// The ID an issue was moved to, read from its `moved-to:<id>` label.
+#[must_use]
+pub fn moved_to(labels: &[String]) -> Option<&str> {
+    labels
+        .iter()
+        .find_map(|label| label.strip_prefix(MOVED_TO_LABEL_PREFIX))
+        .filter(|id| !id.is_empty())
+}
+
+/// Release rule for a `waits-for` dependency, stored in its metadata as
+/// `{"gate": "..."}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WaitsForGate {
+    /// Wait until the target closes (no metadata).
+    #[default]
+    All,
+    /// Wait until every child of the target has closed.
+    AllChildren,
+    /// Wait until at least one child of the target has closed.
+    AnyChildren,
+    /// Wait until any one of the issue's `any` targets has closed.
+    Any,
+}
+
+impl WaitsForGate {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::AllChildren => "all-children",
+            Self::AnyChildren => "any-children",
+            Self::Any => "any",
+        }
+    }
+
+    /// Read the gate from dependency metadata; missing or unknown gates mean `All`.
+    #[must_use]
+    pub fn from_metadata(metadata: Option<&str>) -> Self {
+        metadata
+            .and_then(|m| serde_json::from_str::<serde_json::Value>(m).ok())
+            .and_then(|v| v.get("gate")?.as_str()?.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// Dependency metadata recording this gate (`None` for the default).
+    #[must_use]
+    pub fn to_metadata(self) -> Option<String> {
+        match self {
+            Self::All => None,
+            gate => Some(serde_json::json!({ "gate": gate.as_str() }).to_string()),
+        }
+    }
+}
+
+impl fmt::Display for WaitsForGate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for WaitsForGate {
+    type Err = crate::error::BeadsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "all" => Ok(Self::All),
+            "all-children" => Ok(Self::AllChildren),
+            "any-children" => Ok(Self::AnyChildren),
+            "any" => Ok(Self::Any),
+            other => Err(crate::error::BeadsError::validation(
+                "gate",
+                format!("unknown gate '{other}' (expected all, all-children, any-children or any)"),
+            )),
+        }
+    }
+}
+
 /// Audit event type.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum EventType {
@@ -295,6 +444,10 @@ pub enum EventType {
     Compacted,
     Deleted,
     Restored,
+    TimerStarted,
+    WorkLogged,
+    Claimed,
+    LeaseExpired,
     Custom(String),
 }
 
@@ -317,6 +470,10 @@ impl EventType {
             Self::Compacted => "compacted",
             Self::Deleted => "deleted",
             Self::Restored => "restored",
+            Self::TimerStarted => "timer_started",
+            Self::WorkLogged => "work_logged",
+            Self::Claimed => "claimed",
+            Self::LeaseExpired => "lease_expired",
             Self::Custom(value) => value,
         }
     }
@@ -347,6 +504,10 @@ impl<'de> Deserialize<'de> for EventType {
             "compacted" => Self::Compacted,
             "deleted" => Self::Deleted,
             "restored" => Self::Restored,
+            "timer_started" => Self::TimerStarted,
+            "work_logged" => Self::WorkLogged,
+            "claimed" => Self::Claimed,
+            "lease_expired" => Self::LeaseExpired,
             _ => Self::Custom(value),
         };
         Ok(event_type)
@@ -500,6 +661,13 @@ pub struct Issue {
     pub dependencies: Vec<Dependency>,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub comments: Vec<Comment>,
+    /// Recurrence rule, carried by the latest instance of a recurring series.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurrence: Option<Recurrence>,
+
+    /// Logged time (finished entries only; running timers stay local).
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub worklog: Vec<WorkLogEntry>,
 }
 
 impl Default for Issue {
@@ -544,6 +712,8 @@ impl Default for Issue {
             labels: Vec::new(),
             dependencies: Vec::new(),
             comments: Vec::new(),
+            recurrence: None,
+            worklog: Vec::new(),
         }
     }
 }
@@ -651,6 +821,50 @@ pub struct Event {
     pub created_at: DateTime<Utc>,
 }
 
+/// Time logged against an issue (by `br time log` or a stopped timer).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct WorkLogEntry {
+    pub id: i64,
+    pub issue_id: String,
+    pub actor: String,
+    /// Minutes spent; `None` while a timer is still running.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minutes: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// When the timer was started (timer entries only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A time-limited claim on an issue (`br claim`), renewed by `br heartbeat`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct Lease {
+    pub issue_id: String,
+    pub holder: String,
+    pub claimed_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl Lease {
+    /// Whether the lease still holds at `now`.
+    #[must_use]
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at > now
+    }
+}
+
+/// The recurrence rule of a recurring series (`br recur`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct Recurrence {
+    /// Canonical rule text (see [`crate::util::recurrence::RecurrenceRule`]).
+    pub rule: String,
+    /// When the next instance is due; `None` if the rule never fires again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_at: Option<DateTime<Utc>>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -731,6 +945,8 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         };
 
         let json = serde_json::to_string(&issue).unwrap();
@@ -1141,6 +1357,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_close_outcome_labels() {
+        assert_eq!(CloseOutcome::Failed.label(), "outcome:failed");
+        assert_eq!(
+            CloseOutcome::from_label("outcome:wontfix"),
+            Some(CloseOutcome::WontFix)
+        );
+        assert_eq!(CloseOutcome::from_label("provides:auth"), None);
+        assert!(CloseOutcome::WontFix.is_failure());
+        assert!(!CloseOutcome::Success.is_failure());
+    }
+
+    #[test]
+    fn test_waits_for_gate_metadata_roundtrip() {
+        assert_eq!(WaitsForGate::from_metadata(None), WaitsForGate::All);
+        assert_eq!(WaitsForGate::from_metadata(Some("{}")), WaitsForGate::All);
+        let metadata = WaitsForGate::AllChildren.to_metadata();
+        assert_eq!(
+            WaitsForGate::from_metadata(metadata.as_deref()),
+            WaitsForGate::AllChildren
+        );
+        assert_eq!(WaitsForGate::All.to_metadata(), None);
+    }
+
     // ========================================================================
     // ISSUE CONTENT HASH TESTS
     // ========================================================================
@@ -1186,6 +1426,8 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         }
     }
 
@@ -1356,6 +1598,10 @@ mod tests {
         assert_eq!(EventType::Compacted.as_str(), "compacted");
         assert_eq!(EventType::Deleted.as_str(), "deleted");
         assert_eq!(EventType::Restored.as_str(), "restored");
+        assert_eq!(EventType::TimerStarted.as_str(), "timer_started");
+        assert_eq!(EventType::WorkLogged.as_str(), "work_logged");
+        assert_eq!(EventType::Claimed.as_str(), "claimed");
+        assert_eq!(EventType::LeaseExpired.as_str(), "lease_expired");
         assert_eq!(
             EventType::Custom("my_event".to_string()).as_str(),
             "my_event"
@@ -1380,6 +1626,10 @@ mod tests {
             ("\"compacted\"", EventType::Compacted),
             ("\"deleted\"", EventType::Deleted),
             ("\"restored\"", EventType::Restored),
+            ("\"timer_started\"", EventType::TimerStarted),
+            ("\"work_logged\"", EventType::WorkLogged),
+            ("\"claimed\"", EventType::Claimed),
+            ("\"lease_expired\"", EventType::LeaseExpired),
         ];
 
         for (json, expected) in events {
@@ -1,4 +1,4 @@
-use crate::format::{IssueDetails, IssueWithDependencyMetadata};
+use crate::format::{IssueDetails, IssueWithDependencyMetadata, format_time_tracking};
 use crate::model::{Comment, Dependency, Issue};
 use crate::output::{OutputContext, Theme};
 use rich_rust::prelude::*;
@@ -57,6 +57,13 @@ impl<'a> IssuePanel<'a> {
     }
 
     pub fn print(&self, ctx: &OutputContext, wrap: bool) {
+        let panel_width = if wrap { ctx.width() } else { 80 };
+        ctx.render(&self.build(panel_width, wrap));
+    }
+
+    /// Build the styled panel, wrapping content to `panel_width` when `wrap` is set.
+    #[must_use]
+    pub fn build(&self, panel_width: usize, wrap: bool) -> Panel<'static> {
         let mut content = Text::new("");
 
         // Header: ID and Status badges
@@ -112,6 +119,15 @@ impl<'a> IssuePanel<'a> {
             content.append("\n");
         }
 
+        // Estimate vs. logged time
+        if let Some(time) = format_time_tracking(
+            self.issue.estimated_minutes.map(i64::from),
+            self.details.and_then(|d| d.time_spent),
+        ) {
+            content.append_styled("Time:     ", self.theme.dimmed.clone());
+            content.append(&format!("{time}\n"));
+        }
+
         // Timestamps
         content.append_styled("Created:  ", self.theme.dimmed.clone());
         content.append_styled(
@@ -165,19 +181,16 @@ impl<'a> IssuePanel<'a> {
             }
         }
 
-        // Build and print panel
-        let panel_width = if wrap { ctx.width() } else { 80 };
+        // Build panel
         let content = if wrap {
             wrap_rich_text(&content, panel_width)
         } else {
             content
         };
-        let panel = Panel::from_rich_text(&content, panel_width)
+        Panel::from_rich_text(&content, panel_width)
             .title(Text::styled(&self.issue.id, self.theme.panel_title.clone()))
             .box_style(self.theme.box_style)
-            .border_style(self.theme.panel_border.clone());
-
-        ctx.render(&panel);
+            .border_style(self.theme.panel_border.clone())
     }
 }
 
@@ -14,6 +14,7 @@ pub struct IssueTable<'a> {
     title: Option<String>,
     highlight_query: Option<String>,
     context_snippets: Option<HashMap<String, String>>,
+    projects: Option<HashMap<String, String>>,
     width: Option<usize>,
     wrap: bool,
 }
@@ -85,6 +86,7 @@ impl<'a> IssueTable<'a> {
             title: None,
             highlight_query: None,
             context_snippets: None,
+            projects: None,
             width: None,
             wrap: false,
         }
@@ -131,6 +133,14 @@ impl<'a> IssueTable<'a> {
         self
     }
 
+    /// Show a leading Project column (issue ID -> project name), for
+    /// cross-project views.
+    #[must_use]
+    pub fn projects(mut self, projects: HashMap<String, String>) -> Self {
+        self.projects = Some(projects);
+        self
+    }
+
     #[must_use]
     #[allow(clippy::too_many_lines)]
     pub fn build(&self) -> Table {
@@ -152,6 +162,9 @@ impl<'a> IssueTable<'a> {
         }
 
         // Add columns based on config
+        if self.projects.is_some() {
+            table = table.with_column(Column::new("Project").max_width(20));
+        }
         if self.columns.id {
             table = table.with_column(Column::new("ID").min_width(10));
         }
@@ -191,6 +204,10 @@ impl<'a> IssueTable<'a> {
         for issue in self.issues {
             let mut cells: Vec<Cell> = vec![];
 
+            if let Some(projects) = &self.projects {
+                let project = projects.get(&issue.id).map_or("", String::as_str);
+                cells.push(Cell::new(Text::new(project)).style(self.theme.accent.clone()));
+            }
             if self.columns.id {
                 cells.push(Cell::new(Text::new(&issue.id)).style(self.theme.issue_id.clone()));
             }
@@ -457,6 +457,23 @@ pub fn get_all_events(conn: &Connection, limit: usize) -> Result<Vec<Event>> {
     Ok(events)
 }
 
+/// Get a single event by ID.
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn get_event(conn: &Connection, id: i64) -> Result<Option<Event>> {
+    let mut stmt = conn.prepare(
+        r"
+        SELECT id, issue_id, event_type, actor, old_value, new_value, comment, created_at
+        FROM events
+        WHERE id = ?1
+        ",
+    )?;
+    let mut rows = stmt.query_map(params![id], event_from_row)?;
+    Ok(rows.next().transpose()?)
+}
+
 /// Get event count for an issue.
 ///
 /// # Errors
@@ -489,6 +506,10 @@ fn parse_event_type(s: &str) -> EventType {
         "compacted" => EventType::Compacted,
         "deleted" => EventType::Deleted,
         "restored" => EventType::Restored,
+        "timer_started" => EventType::TimerStarted,
+        "work_logged" => EventType::WorkLogged,
+        "claimed" => EventType::Claimed,
+        "lease_expired" => EventType::LeaseExpired,
         other => EventType::Custom(other.to_string()),
     }
 }
@@ -0,0 +1,497 @@
+//! Point-in-time views rebuilt from the audit event log.
+//!
+//! Mutations record the old and new value of each change, so the state of an
+//! issue at time `t` is its current state with every later event undone,
+//! newest first. [`SqliteStorage::snapshot_as_of`] does this for the whole
+//! workspace and loads the result into an in-memory database, which lets
+//! read-only commands (`show`, `list`, `stats`, `ready`) run unchanged
+//! against the past.
+//!
+//! Changes that never produced events (JSONL imports, comments dropped by
+//! compaction) cannot be undone; those fields keep their current values.
+
+use crate::error::Result;
+use crate::model::{Dependency, DependencyType, Event, EventType, Issue, Priority, Status};
+use crate::storage::SqliteStorage;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// Comment prefix of `updated` events written by
+/// [`MutationContext::record_field_update`](super::sqlite::MutationContext::record_field_update).
+const UPDATED_FIELD_PREFIX: &str = "Updated field: ";
+
+/// One field-level change taken from the event log.
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldChange {
+    pub event_id: i64,
+    pub issue_id: String,
+    /// Field name (`status`, `title`, `labels`, `dependencies`, ...), or
+    /// `created` for new issues.
+    pub field: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_value: Option<String>,
+    pub actor: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// The field-level changes among `events`, in the given order. Events that
+/// don't change a field (claims, time logging, compaction) are skipped.
+#[must_use]
+pub fn field_changes(events: &[Event]) -> Vec<FieldChange> {
+    events
+        .iter()
+        .filter_map(|event| {
+            let (field, old_value, new_value) = change_of(event)?;
+            Some(FieldChange {
+                event_id: event.id,
+                issue_id: event.issue_id.clone(),
+                field,
+                old_value,
+                new_value,
+                actor: event.actor.clone(),
+                created_at: event.created_at,
+            })
+        })
+        .collect()
+}
+
+fn change_of(event: &Event) -> Option<(String, Option<String>, Option<String>)> {
+    let old = event.old_value.clone();
+    let new = event.new_value.clone();
+    let change = match event.event_type {
+        EventType::Created => (
+            "created".to_string(),
+            None,
+            event
+                .comment
+                .as_deref()
+                .map(|comment| comment.trim_start_matches("Created issue: ").to_string()),
+        ),
+        EventType::Updated => (updated_field(event)?.to_string(), old, new),
+        EventType::StatusChanged => ("status".to_string(), old, new),
+        EventType::PriorityChanged => (
+            "priority".to_string(),
+            old.map(|p| format!("P{p}")),
+            new.map(|p| format!("P{p}")),
+        ),
+        EventType::AssigneeChanged => ("assignee".to_string(), old, new),
+        EventType::LabelAdded => ("labels".to_string(), None, Some(label_of(event)?)),
+        EventType::LabelRemoved => ("labels".to_string(), Some(label_of(event)?), None),
+        EventType::DependencyAdded => {
+            let (id, dep_type) = dependency_of(event)?;
+            (
+                "dependencies".to_string(),
+                None,
+                Some(format!("{id} ({dep_type})")),
+            )
+        }
+        EventType::DependencyRemoved => {
+            let (id, dep_type) = dependency_of(event)?;
+            (
+                "dependencies".to_string(),
+                Some(format!("{id} ({dep_type})")),
+                None,
+            )
+        }
+        EventType::Deleted => ("status".to_string(), old, Some("tombstone".to_string())),
+        EventType::Commented => ("comments".to_string(), None, event.comment.clone()),
+        _ => return None,
+    };
+    Some(change)
+}
+
+/// The field named by an `updated` event (title changes predate the
+/// `Updated field:` comment).
+fn updated_field(event: &Event) -> Option<&str> {
+    let comment = event.comment.as_deref()?;
+    if comment == "Title changed" {
+        return Some("title");
+    }
+    comment.strip_prefix(UPDATED_FIELD_PREFIX)
+}
+
+/// The label of a label event; older events only carry it in the comment.
+fn label_of(event: &Event) -> Option<String> {
+    let value = match event.event_type {
+        EventType::LabelAdded => event.new_value.as_ref(),
+        _ => event.old_value.as_ref(),
+    };
+    if let Some(label) = value {
+        return Some(label.clone());
+    }
+    let comment = event.comment.as_deref()?;
+    comment
+        .strip_prefix("Added label ")
+        .or_else(|| comment.strip_prefix("Removed label "))
+        .map(str::to_string)
+}
+
+/// The target and type of a dependency event. The type comes from the
+/// comment's trailing `(type)`.
+fn dependency_of(event: &Event) -> Option<(String, DependencyType)> {
+    let comment = event.comment.as_deref().unwrap_or_default();
+    let value = match event.event_type {
+        EventType::DependencyAdded => event.new_value.clone(),
+        _ => event.old_value.clone(),
+    };
+    let target = value.or_else(|| {
+        let rest = comment
+            .strip_prefix("Added dependency on ")
+            .or_else(|| comment.strip_prefix("Removed dependency on "))?;
+        Some(rest.split(" (").next().unwrap_or(rest).to_string())
+    })?;
+    let dep_type = if comment == "Removed parent" {
+        DependencyType::ParentChild
+    } else {
+        comment
+            .rsplit_once(" (")
+            .and_then(|(_, rest)| rest.strip_suffix(')'))
+            .and_then(|name| name.parse().ok())
+            .unwrap_or(DependencyType::Blocks)
+    };
+    Some((target, dep_type))
+}
+
+fn parse_time(value: Option<&String>) -> Option<DateTime<Utc>> {
+    value
+        .and_then(|value| DateTime::parse_from_rfc3339(value).ok())
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Restore `field` to `value`.
+fn set_field(issue: &mut Issue, field: &str, value: Option<&String>) {
+    match field {
+        "title" => issue.title = value.cloned().unwrap_or_default(),
+        "description" => issue.description = value.cloned(),
+        "design" => issue.design = value.cloned(),
+        "acceptance_criteria" => issue.acceptance_criteria = value.cloned(),
+        "notes" => issue.notes = value.cloned(),
+        "owner" => issue.owner = value.cloned(),
+        "external_ref" => issue.external_ref = value.cloned(),
+        "close_reason" => issue.close_reason = value.cloned(),
+        "issue_type" => {
+            if let Some(issue_type) = value.and_then(|value| value.parse().ok()) {
+                issue.issue_type = issue_type;
+            }
+        }
+        "estimated_minutes" => {
+            issue.estimated_minutes = value.and_then(|value| value.parse().ok());
+        }
+        "due_at" => issue.due_at = parse_time(value),
+        "defer_until" => issue.defer_until = parse_time(value),
+        _ => {}
+    }
+}
+
+/// Undo one event. Returns `false` for the event that created the issue.
+fn undo(issue: &mut Issue, event: &Event) -> bool {
+    let old = event.old_value.as_ref();
+    match event.event_type {
+        EventType::Created => return false,
+        EventType::StatusChanged => {
+            if let Some(status) = old.and_then(|value| value.parse::<Status>().ok()) {
+                issue.status = status;
+            }
+        }
+        EventType::Deleted => {
+            issue.status = old
+                .and_then(|value| value.parse::<Status>().ok())
+                .unwrap_or(Status::Open);
+            issue.deleted_at = None;
+            issue.deleted_by = None;
+            issue.delete_reason = None;
+        }
+        EventType::PriorityChanged => {
+            if let Some(priority) = old.and_then(|value| value.parse::<i32>().ok()) {
+                issue.priority = Priority(priority);
+            }
+        }
+        EventType::AssigneeChanged => issue.assignee = old.cloned(),
+        EventType::Updated => {
+            if let Some(field) = updated_field(event) {
+                set_field(issue, field, old);
+            }
+        }
+        EventType::LabelAdded => {
+            if let Some(label) = label_of(event) {
+                issue.labels.retain(|existing| *existing != label);
+            }
+        }
+        EventType::LabelRemoved => {
+            if let Some(label) = label_of(event) {
+                if !issue.labels.contains(&label) {
+                    issue.labels.push(label);
+                }
+            }
+        }
+        EventType::DependencyAdded => {
+            if let Some((target, _)) = dependency_of(event) {
+                issue.dependencies.retain(|dep| dep.depends_on_id != target);
+            }
+        }
+        EventType::DependencyRemoved => {
+            if let Some((target, dep_type)) = dependency_of(event) {
+                if !issue
+                    .dependencies
+                    .iter()
+                    .any(|dep| dep.depends_on_id == target)
+                {
+                    issue.dependencies.push(Dependency {
+                        issue_id: issue.id.clone(),
+                        depends_on_id: target,
+                        dep_type,
+                        created_at: event.created_at,
+                        created_by: None,
+                        metadata: None,
+                        thread_id: None,
+                    });
+                }
+            }
+        }
+        _ => {}
+    }
+    true
+}
+
+/// Whether `event` left the issue closed.
+fn closes(event: &Event) -> bool {
+    event.event_type == EventType::Closed
+        || (event.event_type == EventType::StatusChanged
+            && event.new_value.as_deref() == Some(Status::Closed.as_str()))
+}
+
+/// Rebuild `issue` (with labels, dependencies and comments populated) as it
+/// stood at `as_of`. `events` are the issue's events, newest first. Returns
+/// `None` if the issue did not exist yet.
+#[must_use]
+pub fn rewind_issue(mut issue: Issue, events: &[Event], as_of: DateTime<Utc>) -> Option<Issue> {
+    if issue.created_at > as_of {
+        return None;
+    }
+    for event in events.iter().take_while(|event| event.created_at > as_of) {
+        if !undo(&mut issue, event) {
+            return None;
+        }
+    }
+    let earlier = || events.iter().filter(|event| event.created_at <= as_of);
+
+    issue.comments.retain(|comment| comment.created_at <= as_of);
+    issue.labels.sort();
+    if issue.status == Status::Closed {
+        if issue.closed_at.is_none_or(|closed_at| closed_at > as_of) {
+            issue.closed_at = earlier().find(|event| closes(event)).map(|e| e.created_at);
+        }
+    } else {
+        issue.closed_at = None;
+    }
+    if issue.updated_at > as_of {
+        issue.updated_at = earlier()
+            .next()
+            .map_or(issue.created_at, |event| event.created_at)
+            .max(issue.created_at);
+    }
+    issue.content_hash = Some(issue.compute_content_hash());
+    Some(issue)
+}
+
+impl SqliteStorage {
+    /// Build an in-memory copy of the workspace as it stood at `as_of`.
+    ///
+    /// Issues created later are left out, along with dependencies on them.
+    /// Config and the events up to `as_of` are copied so that commands
+    /// reading either behave as they would have then.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading this database or writing the copy fails.
+    pub fn snapshot_as_of(&self, as_of: DateTime<Utc>) -> Result<Self> {
+        let mut labels = self.get_all_labels()?;
+        let mut dependencies = self.get_all_dependency_records()?;
+        let mut comments = self.get_all_comments()?;
+        // get_all_events is newest first, so each issue's list is too
+        let mut events: HashMap<String, Vec<Event>> = HashMap::new();
+        for event in self.get_all_events(0)? {
+            events
+                .entry(event.issue_id.clone())
+                .or_default()
+                .push(event);
+        }
+
+        let mut snapshot = Self::open_memory()?;
+        for (key, value) in self.get_all_config()? {
+            snapshot.set_config(&key, &value)?;
+        }
+
+        let mut kept = Vec::new();
+        for mut issue in self.get_all_issues_for_export()? {
+            issue.labels = labels.remove(&issue.id).unwrap_or_default();
+            issue.dependencies = dependencies.remove(&issue.id).unwrap_or_default();
+            issue.comments = comments.remove(&issue.id).unwrap_or_default();
+            let history = events.get(&issue.id).map_or(&[][..], Vec::as_slice);
+            let Some(issue) = rewind_issue(issue, history, as_of) else {
+                continue;
+            };
+            snapshot.upsert_issue_for_import(&issue)?;
+            snapshot.sync_labels_for_import(&issue.id, &issue.labels)?;
+            snapshot.sync_comments_for_import(&issue.id, &issue.comments)?;
+            kept.push(issue);
+        }
+
+        let ids: HashSet<&str> = kept.iter().map(|issue| issue.id.as_str()).collect();
+        for issue in &kept {
+            let deps: Vec<Dependency> = issue
+                .dependencies
+                .iter()
+                .filter(|dep| {
+                    ids.contains(dep.depends_on_id.as_str())
+                        || dep.depends_on_id.starts_with("external:")
+                })
+                .cloned()
+                .collect();
+            snapshot.sync_dependencies_for_import(&issue.id, &deps)?;
+        }
+
+        let tx = snapshot.conn.transaction()?;
+        for event in events
+            .iter()
+            .filter(|(issue_id, _)| ids.contains(issue_id.as_str()))
+            .flat_map(|(_, history)| history)
+            .filter(|event| event.created_at <= as_of)
+        {
+            tx.execute(
+                "INSERT INTO events (id, issue_id, event_type, actor, old_value, new_value, comment, created_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                rusqlite::params![
+                    event.id,
+                    event.issue_id,
+                    event.event_type.as_str(),
+                    event.actor,
+                    event.old_value,
+                    event.new_value,
+                    event.comment,
+                    event.created_at.to_rfc3339()
+                ],
+            )?;
+        }
+        tx.commit()?;
+
+        snapshot.rebuild_blocked_cache(true)?;
+        Ok(snapshot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn event(
+        event_type: EventType,
+        old_value: Option<&str>,
+        new_value: Option<&str>,
+        comment: Option<&str>,
+        created_at: DateTime<Utc>,
+    ) -> Event {
+        Event {
+            id: 0,
+            issue_id: "bd-1".to_string(),
+            event_type,
+            actor: "alice".to_string(),
+            old_value: old_value.map(str::to_string),
+            new_value: new_value.map(str::to_string),
+            comment: comment.map(str::to_string),
+            created_at,
+        }
+    }
+
+    #[test]
+    fn rewind_issue_undoes_later_events() {
+        let t0 = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let issue = Issue {
+            id: "bd-1".to_string(),
+            title: "New title".to_string(),
+            status: Status::Closed,
+            priority: Priority::HIGH,
+            labels: vec!["urgent".to_string()],
+            created_at: t0,
+            updated_at: t0 + Duration::days(3),
+            closed_at: Some(t0 + Duration::days(3)),
+            ..Default::default()
+        };
+        // Newest first
+        let events = vec![
+            event(
+                EventType::StatusChanged,
+                Some("open"),
+                Some("closed"),
+                None,
+                t0 + Duration::days(3),
+            ),
+            event(
+                EventType::LabelAdded,
+                None,
+                Some("urgent"),
+                None,
+                t0 + Duration::days(2),
+            ),
+            event(
+                EventType::Updated,
+                Some("Old title"),
+                Some("New title"),
+                Some("Title changed"),
+                t0 + Duration::days(2),
+            ),
+            event(
+                EventType::PriorityChanged,
+                Some("2"),
+                Some("1"),
+                None,
+                t0 + Duration::days(1),
+            ),
+            event(EventType::Created, None, None, None, t0),
+        ];
+
+        let past = rewind_issue(issue.clone(), &events, t0 + Duration::hours(36)).unwrap();
+        assert_eq!(past.title, "Old title");
+        assert_eq!(past.status, Status::Open);
+        assert_eq!(past.priority, Priority::HIGH);
+        assert!(past.labels.is_empty());
+        assert!(past.closed_at.is_none());
+        assert_eq!(past.updated_at, t0 + Duration::days(1));
+
+        let earliest = rewind_issue(issue.clone(), &events, t0).unwrap();
+        assert_eq!(earliest.priority, Priority::MEDIUM);
+
+        assert!(rewind_issue(issue, &events, t0 - Duration::hours(1)).is_none());
+    }
+
+    #[test]
+    fn field_changes_reads_legacy_comments() {
+        let at = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let events = vec![
+            event(
+                EventType::DependencyAdded,
+                None,
+                None,
+                Some("Added dependency on bd-2 (parent-child)"),
+                at,
+            ),
+            event(
+                EventType::LabelRemoved,
+                None,
+                None,
+                Some("Removed label ui"),
+                at,
+            ),
+            event(EventType::Claimed, None, Some("2026-01-02"), None, at),
+        ];
+        let changes = field_changes(&events);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].field, "dependencies");
+        assert_eq!(changes[0].new_value.as_deref(), Some("bd-2 (parent-child)"));
+        assert_eq!(changes[1].old_value.as_deref(), Some("ui"));
+    }
+}
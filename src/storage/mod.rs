@@ -9,11 +9,22 @@
 //! # Submodules
 //!
 //! - [`events`] - Audit event storage (insertion, retrieval)
+//! - [`history`] - Point-in-time snapshots rebuilt from events
 //! - [`schema`] - Database schema definitions
+//! - [`score`] - Ready-work scoring for the `score` sort policy
 //! - [`sqlite`] - Main `SQLite` storage implementation
+//! - [`undo`] - Undo and redo of mutation batches
 
 pub mod events;
+pub mod history;
 pub mod schema;
+pub mod score;
 pub mod sqlite;
+pub mod undo;
 
-pub use sqlite::{IssueUpdate, ListFilters, ReadyFilters, ReadySortPolicy, SqliteStorage};
+pub use score::{ScoreBreakdown, ScoreWeights};
+
+pub use sqlite::{
+    IssueUpdate, ListFilters, ReadyFilters, ReadySortPolicy, RecurrenceRecord,
+    SEARCH_SNIPPET_CLOSE, SEARCH_SNIPPET_OPEN, SearchHit, SqliteStorage,
+};
@@ -140,6 +140,7 @@ pub const SCHEMA_SQL: &str = r"
         new_value TEXT,
         comment TEXT,
         created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        batch_id INTEGER,  -- mutation_batches row of an undoable change
         FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
     );
     CREATE INDEX IF NOT EXISTS idx_events_issue ON events(issue_id);
@@ -191,6 +192,89 @@ pub const SCHEMA_SQL: &str = r"
         last_child INTEGER NOT NULL DEFAULT 0,
         FOREIGN KEY (parent_id) REFERENCES issues(id) ON DELETE CASCADE
     );
+
+    -- Recurrence Rules (one per recurring series, attached to its latest instance)
+    CREATE TABLE IF NOT EXISTS recurrence_rules (
+        issue_id TEXT PRIMARY KEY,
+        rule TEXT NOT NULL,
+        next_at DATETIME,
+        created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        created_by TEXT NOT NULL DEFAULT '',
+        FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
+    );
+    CREATE INDEX IF NOT EXISTS idx_recurrence_rules_next_at ON recurrence_rules(next_at);
+
+    -- Work Log (time spent, compared against issues.estimated_minutes)
+    CREATE TABLE IF NOT EXISTS worklog (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        issue_id TEXT NOT NULL,
+        actor TEXT NOT NULL DEFAULT '',
+        minutes INTEGER,  -- NULL while a timer is running
+        note TEXT,
+        started_at DATETIME,
+        created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
+    );
+    CREATE INDEX IF NOT EXISTS idx_worklog_issue ON worklog(issue_id);
+
+    -- Claim leases (br claim / br heartbeat); released when they expire
+    CREATE TABLE IF NOT EXISTS leases (
+        issue_id TEXT PRIMARY KEY,
+        holder TEXT NOT NULL,
+        claimed_at DATETIME NOT NULL,
+        expires_at DATETIME NOT NULL,
+        FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
+    );
+    CREATE INDEX IF NOT EXISTS idx_leases_holder ON leases(holder);
+
+    -- Undo log (br undo): one batch per undoable mutation, with full images of
+    -- each issue it touched (JSON with labels, dependencies and comments;
+    -- NULL when the issue did not exist)
+    CREATE TABLE IF NOT EXISTS mutation_batches (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        op TEXT NOT NULL,
+        actor TEXT NOT NULL DEFAULT '',
+        reverts INTEGER,      -- batch restored by an undo or redo
+        reverted_at DATETIME, -- set when this batch is undone (or this undo redone)
+        created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+    );
+    CREATE TABLE IF NOT EXISTS mutation_images (
+        batch_id INTEGER NOT NULL,
+        issue_id TEXT NOT NULL,
+        before_image TEXT,
+        after_image TEXT,
+        PRIMARY KEY (batch_id, issue_id),
+        FOREIGN KEY (batch_id) REFERENCES mutation_batches(id) ON DELETE CASCADE
+    );
+
+    -- Full-text search index (FTS5)
+    -- Standalone table maintained by the storage layer on every mutation.
+    CREATE VIRTUAL TABLE IF NOT EXISTS issues_fts USING fts5(
+        issue_id UNINDEXED,
+        title,
+        description,
+        design,
+        acceptance_criteria,
+        notes,
+        comments,
+        tokenize = 'unicode61 remove_diacritics 2',
+        prefix = '2 3'
+    );
+";
+
+/// Populate `issues_fts` from `issues` and their comments.
+///
+/// Callers append a `WHERE` clause on `i` to restrict which issues are indexed.
+pub const SEARCH_INDEX_INSERT_SQL: &str = r"
+    INSERT INTO issues_fts (issue_id, title, description, design, acceptance_criteria, notes, comments)
+    SELECT i.id,
+           i.title,
+           COALESCE(i.description, ''),
+           COALESCE(i.design, ''),
+           COALESCE(i.acceptance_criteria, ''),
+           COALESCE(i.notes, ''),
+           COALESCE((SELECT group_concat(c.text, char(10)) FROM comments c WHERE c.issue_id = i.id), '')
+    FROM issues i
 ";
 
 /// Apply the schema to the database.
@@ -303,6 +387,7 @@ const EVENT_COLUMNS: &[(&str, &str)] = &[
     ("new_value", "TEXT"),
     ("comment", "TEXT"),
     ("created_at", "DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP"),
+    ("batch_id", "INTEGER"),
 ];
 
 fn ensure_columns(conn: &Connection, table: &str, columns: &[(&str, &str)]) -> Result<()> {
@@ -511,6 +596,15 @@ fn run_migrations(conn: &Connection) -> Result<()> {
         )?;
     }
 
+    // Migration: backfill the full-text index for databases created before it existed
+    if table_exists(conn, "issues_fts") {
+        let indexed: i64 =
+            conn.query_row("SELECT count(*) FROM issues_fts", [], |row| row.get(0))?;
+        if indexed == 0 {
+            conn.execute(SEARCH_INDEX_INSERT_SQL, [])?;
+        }
+    }
+
     Ok(())
 }
 
@@ -1035,6 +1129,32 @@ mod tests {
         }
     }
 
+    /// Migration: databases created before the FTS index get it backfilled.
+    #[test]
+    fn test_migration_backfills_search_index() {
+        let conn = Connection::open_in_memory().unwrap();
+        apply_schema(&conn).unwrap();
+        conn.execute_batch(
+            r"
+            INSERT INTO issues (id, title, notes) VALUES ('bd-1', 'Legacy issue', 'flux capacitor');
+            INSERT INTO comments (issue_id, author, text) VALUES ('bd-1', 'alice', 'needs plutonium');
+            DROP TABLE issues_fts;
+        ",
+        )
+        .unwrap();
+
+        apply_schema(&conn).unwrap();
+
+        let hits: Vec<String> = conn
+            .prepare("SELECT issue_id FROM issues_fts WHERE issues_fts MATCH 'plutonium'")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(hits, vec!["bd-1".to_string()]);
+    }
+
     /// Migration: add missing dependency type column for older schemas.
     #[test]
     fn test_migration_adds_missing_dependency_type() {
@@ -0,0 +1,298 @@
+//! Ready-work scoring for [`ReadySortPolicy::Score`](super::ReadySortPolicy).
+//!
+//! Each factor is normalized to `0..=1` and multiplied by its weight from
+//! `ready.score.*` config; label weights are added as-is. Higher scores rank
+//! first.
+//!
+//! | Factor | Value |
+//! |--------|-------|
+//! | `priority` | `(4 - priority) / 4` |
+//! | `age` | `days / (days + 30)` since creation |
+//! | `due` | `1` when overdue, else `7 / (7 + days left)`; `0` without a due date |
+//! | `unblocks` | `n / (n + 3)` for `n` unfinished transitive dependents over blocking edges |
+//! | `staleness` | `days / (days + 14)` since the last update |
+
+use crate::error::Result;
+use crate::model::{DependencyType, Issue};
+use crate::storage::SqliteStorage;
+use chrono::{DateTime, Utc};
+use rusqlite::Connection;
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+/// Weights for the ready score. Negative weights push issues down.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreWeights {
+    pub priority: f64,
+    pub age: f64,
+    pub due: f64,
+    pub unblocks: f64,
+    pub staleness: f64,
+    /// Added to the score of issues carrying the label.
+    pub labels: HashMap<String, f64>,
+}
+
+impl Default for ScoreWeights {
+    fn default() -> Self {
+        Self {
+            priority: 4.0,
+            age: 1.0,
+            due: 2.0,
+            unblocks: 3.0,
+            staleness: 0.5,
+            labels: HashMap::new(),
+        }
+    }
+}
+
+/// Weighted contribution of each factor to an issue's score.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ScoreBreakdown {
+    pub total: f64,
+    pub priority: f64,
+    pub age: f64,
+    pub due: f64,
+    pub unblocks: f64,
+    pub labels: f64,
+    pub staleness: f64,
+    /// Issues that transitively wait on this one over blocking dependencies.
+    pub dependents: usize,
+}
+
+/// Score one issue.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn score_issue(
+    issue: &Issue,
+    labels: &[String],
+    dependents: usize,
+    weights: &ScoreWeights,
+    now: DateTime<Utc>,
+) -> ScoreBreakdown {
+    let days = |from: DateTime<Utc>, to: DateTime<Utc>| (to - from).num_minutes() as f64 / 1440.0;
+    let saturate = |value: f64, half: f64| {
+        let value = value.max(0.0);
+        value / (value + half)
+    };
+
+    let priority = f64::from((4 - issue.priority.0).clamp(0, 4)) / 4.0;
+    let age = saturate(days(issue.created_at, now), 30.0);
+    let due = issue.due_at.map_or(0.0, |due_at| {
+        let left = days(now, due_at);
+        if left <= 0.0 { 1.0 } else { 7.0 / (7.0 + left) }
+    });
+    let unblocks = saturate(dependents as f64, 3.0);
+    let staleness = saturate(days(issue.updated_at, now), 14.0);
+    let label_bonus: f64 = labels
+        .iter()
+        .filter_map(|label| weights.labels.get(label))
+        .sum();
+
+    let mut breakdown = ScoreBreakdown {
+        total: 0.0,
+        priority: round(weights.priority * priority),
+        age: round(weights.age * age),
+        due: round(weights.due * due),
+        unblocks: round(weights.unblocks * unblocks),
+        labels: round(label_bonus),
+        staleness: round(weights.staleness * staleness),
+        dependents,
+    };
+    breakdown.total = round(
+        breakdown.priority
+            + breakdown.age
+            + breakdown.due
+            + breakdown.unblocks
+            + breakdown.labels
+            + breakdown.staleness,
+    );
+    breakdown
+}
+
+fn round(value: f64) -> f64 {
+    (value * 1000.0).round() / 1000.0
+}
+
+/// Order issues by descending score, then priority and age like
+/// [`ReadySortPolicy::Priority`](super::ReadySortPolicy::Priority).
+pub fn sort_by_score(issues: &mut [Issue], scores: &HashMap<String, ScoreBreakdown>) {
+    let total = |issue: &Issue| scores.get(&issue.id).map_or(0.0, |score| score.total);
+    issues.sort_by(|a, b| {
+        total(b)
+            .partial_cmp(&total(a))
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.priority.cmp(&b.priority))
+            .then_with(|| a.created_at.cmp(&b.created_at))
+    });
+}
+
+impl SqliteStorage {
+    /// Score `issues` for [`ReadySortPolicy::Score`](super::ReadySortPolicy::Score).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if labels or dependencies cannot be read.
+    pub fn score_issues(
+        &self,
+        issues: &[Issue],
+        weights: &ScoreWeights,
+        now: DateTime<Utc>,
+    ) -> Result<HashMap<String, ScoreBreakdown>> {
+        score_on(&self.conn, issues, weights, now)
+    }
+}
+
+/// Score `issues` on `conn` (usable inside a mutation transaction).
+pub(super) fn score_on(
+    conn: &Connection,
+    issues: &[Issue],
+    weights: &ScoreWeights,
+    now: DateTime<Utc>,
+) -> Result<HashMap<String, ScoreBreakdown>> {
+    let wanted: HashSet<&str> = issues.iter().map(|issue| issue.id.as_str()).collect();
+    let mut labels: HashMap<String, Vec<String>> = HashMap::new();
+    let mut stmt = conn.prepare_cached("SELECT issue_id, label FROM labels")?;
+    for row in stmt.query_map([], |row| Ok((row.get::<_, String>(0)?, row.get(1)?)))? {
+        let (issue_id, label) = row?;
+        if wanted.contains(issue_id.as_str()) {
+            labels.entry(issue_id).or_default().push(label);
+        }
+    }
+
+    // Reverse blocking edges: depends_on_id -> unfinished issues waiting on it
+    let mut waiting: HashMap<String, Vec<String>> = HashMap::new();
+    let mut stmt = conn.prepare_cached(
+        "SELECT d.issue_id, d.depends_on_id, d.type FROM dependencies d
+         JOIN issues i ON i.id = d.issue_id
+         WHERE i.status NOT IN ('closed', 'tombstone')",
+    )?;
+    for row in stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, Option<String>>(2)?,
+        ))
+    })? {
+        let (issue_id, depends_on_id, dep_type) = row?;
+        let dep_type = dep_type
+            .and_then(|value| value.parse::<DependencyType>().ok())
+            .unwrap_or(DependencyType::Blocks);
+        if dep_type.is_blocking() {
+            waiting.entry(depends_on_id).or_default().push(issue_id);
+        }
+    }
+
+    Ok(issues
+        .iter()
+        .map(|issue| {
+            let dependents = transitive_dependents(&issue.id, &waiting);
+            let issue_labels = labels.get(&issue.id).map_or(&[][..], Vec::as_slice);
+            (
+                issue.id.clone(),
+                score_issue(issue, issue_labels, dependents, weights, now),
+            )
+        })
+        .collect())
+}
+
+/// Count the issues reachable from `id` over reversed blocking edges.
+fn transitive_dependents(id: &str, waiting: &HashMap<String, Vec<String>>) -> usize {
+    let mut seen: HashSet<&str> = HashSet::from([id]);
+    let mut stack = vec![id];
+    while let Some(current) = stack.pop() {
+        for next in waiting.get(current).into_iter().flatten() {
+            if seen.insert(next) {
+                stack.push(next);
+            }
+        }
+    }
+    seen.len() - 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Priority, Status};
+    use chrono::Duration;
+
+    fn make_issue(id: &str, priority: i32, created_days_ago: i64) -> Issue {
+        let now = Utc::now();
+        Issue {
+            id: id.to_string(),
+            title: id.to_string(),
+            status: Status::Open,
+            priority: Priority(priority),
+            created_at: now - Duration::days(created_days_ago),
+            updated_at: now - Duration::days(created_days_ago),
+            ..Issue::default()
+        }
+    }
+
+    #[test]
+    fn test_score_issue_factors() {
+        let now = Utc::now();
+        let weights = ScoreWeights {
+            labels: HashMap::from([("customer".to_string(), 1.5)]),
+            ..ScoreWeights::default()
+        };
+
+        let mut issue = make_issue("bd-1", 0, 0);
+        issue.due_at = Some(now - Duration::days(1));
+        let score = score_issue(&issue, &["customer".to_string()], 3, &weights, now);
+        assert!((score.priority - 4.0).abs() < 1e-9);
+        assert!((score.due - 2.0).abs() < 1e-9);
+        assert!((score.unblocks - 1.5).abs() < 1e-9);
+        assert!((score.labels - 1.5).abs() < 1e-9);
+        assert_eq!(score.dependents, 3);
+
+        let plain = score_issue(&make_issue("bd-2", 4, 0), &[], 0, &weights, now);
+        assert!(plain.total < score.total);
+        assert!(plain.due.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_score_issues_counts_transitive_dependents() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        for id in ["bd-1", "bd-2", "bd-3", "bd-4"] {
+            storage
+                .create_issue(&make_issue(id, 2, 1), "tester")
+                .unwrap();
+        }
+        storage
+            .add_dependency("bd-2", "bd-1", "blocks", "tester")
+            .unwrap();
+        storage
+            .add_dependency("bd-3", "bd-2", "blocks", "tester")
+            .unwrap();
+        storage
+            .add_dependency("bd-4", "bd-1", "related", "tester")
+            .unwrap();
+
+        let issues = vec![
+            storage.get_issue("bd-4").unwrap().unwrap(),
+            storage.get_issue("bd-1").unwrap().unwrap(),
+        ];
+        let scores = storage
+            .score_issues(&issues, &ScoreWeights::default(), Utc::now())
+            .unwrap();
+        assert_eq!(scores["bd-1"].dependents, 2);
+        assert_eq!(scores["bd-4"].dependents, 0);
+
+        let mut ranked = issues.clone();
+        sort_by_score(&mut ranked, &scores);
+        assert_eq!(ranked[0].id, "bd-1");
+
+        // Finished work no longer counts
+        let close = crate::storage::IssueUpdate {
+            status: Some(Status::Closed),
+            closed_at: Some(Some(Utc::now())),
+            ..Default::default()
+        };
+        storage.update_issue("bd-3", &close, "tester").unwrap();
+        let scores = storage
+            .score_issues(&issues, &ScoreWeights::default(), Utc::now())
+            .unwrap();
+        assert_eq!(scores["bd-1"].dependents, 1);
+    }
+}
@@ -1,12 +1,18 @@
 //! `SQLite` storage implementation.
 
 use crate::error::{BeadsError, Result};
-use crate::format::{IssueDetails, IssueWithDependencyMetadata};
-use crate::model::{Comment, DependencyType, Event, EventType, Issue, IssueType, Priority, Status};
+use crate::format::{IssueDetails, IssueWithDependencyMetadata, TimeSpent};
+use crate::hooks::{HookPayload, Hooks};
+use crate::model::{
+    CloseOutcome, Comment, DependencyType, Event, EventType, Issue, IssueType, Lease, Priority,
+    Recurrence, Status, WaitsForGate, WorkLogEntry,
+};
 use crate::storage::events::get_events;
-use crate::storage::schema::apply_schema;
+use crate::storage::schema::{SEARCH_INDEX_INSERT_SQL, apply_schema};
+use crate::storage::score::{ScoreWeights, score_on, sort_by_score};
 use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
-use rusqlite::{Connection, OpenFlags, OptionalExtension, Transaction};
+use rusqlite::{Connection, OpenFlags, OptionalExtension};
+use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
 use std::fmt::Write as _;
 use std::path::{Path, PathBuf};
@@ -16,16 +22,25 @@ use tracing::warn;
 /// SQLite-based storage backend.
 #[derive(Debug)]
 pub struct SqliteStorage {
-    conn: Connection,
+    pub(super) conn: Connection,
+    pub(super) hooks: Hooks,
+    /// The open batch of [`Self::batch`], which nested mutations join.
+    batch: Option<MutationContext>,
 }
 
 /// Context for a mutation operation, tracking side effects.
+#[derive(Debug)]
 pub struct MutationContext {
     pub op_name: String,
     pub actor: String,
     pub events: Vec<Event>,
     pub dirty_ids: HashSet<String>,
     pub invalidate_blocked_cache: bool,
+    /// Issues as they stood before this mutation (`None` if they did not
+    /// exist yet), kept by [`capture`](Self::capture) for `br undo`.
+    pub before_images: HashMap<String, Option<Issue>>,
+    /// The batch this mutation restores, when it is an undo or redo.
+    pub reverts: Option<i64>,
 }
 
 impl MutationContext {
@@ -37,6 +52,8 @@ impl MutationContext {
             events: Vec::new(),
             dirty_ids: HashSet::new(),
             invalidate_blocked_cache: false,
+            before_images: HashMap::new(),
+            reverts: None,
         }
     }
 
@@ -74,6 +91,28 @@ impl MutationContext {
         });
     }
 
+    /// Record an `updated` event for `field` when its value changed.
+    ///
+    /// The event's comment is `Updated field: <field>`, which is how
+    /// point-in-time queries find the field to rewind.
+    pub fn record_field_update(
+        &mut self,
+        issue_id: &str,
+        field: &str,
+        old_value: Option<String>,
+        new_value: Option<String>,
+    ) {
+        if old_value != new_value {
+            self.record_field_change(
+                EventType::Updated,
+                issue_id,
+                old_value,
+                new_value,
+                Some(format!("Updated field: {field}")),
+            );
+        }
+    }
+
     pub fn mark_dirty(&mut self, issue_id: &str) {
         self.dirty_ids.insert(issue_id.to_string());
     }
@@ -81,6 +120,19 @@ impl MutationContext {
     pub const fn invalidate_cache(&mut self) {
         self.invalidate_blocked_cache = true;
     }
+
+    /// Keep the image of `issue_id` from before this mutation changes it, so
+    /// `br undo` can restore it. Only the first call per issue counts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue cannot be read.
+    pub fn capture(&mut self, conn: &Connection, issue_id: &str) -> Result<()> {
+        if let Entry::Vacant(entry) = self.before_images.entry(issue_id.to_string()) {
+            entry.insert(SqliteStorage::load_image(conn, issue_id)?);
+        }
+        Ok(())
+    }
 }
 
 impl SqliteStorage {
@@ -104,7 +156,11 @@ impl SqliteStorage {
             conn.busy_timeout(Duration::from_millis(timeout))?;
         }
         apply_schema(&conn)?;
-        Ok(Self { conn })
+        Ok(Self {
+            conn,
+            hooks: Hooks::default(),
+            batch: None,
+        })
     }
 
     /// Open an in-memory database for testing.
@@ -115,7 +171,16 @@ impl SqliteStorage {
     pub fn open_memory() -> Result<Self> {
         let conn = Connection::open_in_memory()?;
         apply_schema(&conn)?;
-        Ok(Self { conn })
+        Ok(Self {
+            conn,
+            hooks: Hooks::default(),
+            batch: None,
+        })
+    }
+
+    /// Fire `hooks` for the events recorded by later mutations.
+    pub fn set_hooks(&mut self, hooks: Hooks) {
+        self.hooks = hooks;
     }
 
     /// Get audit events for a specific issue.
@@ -127,6 +192,15 @@ impl SqliteStorage {
         crate::storage::events::get_events(&self.conn, issue_id, limit)
     }
 
+    /// Get a single audit event by ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_event(&self, id: i64) -> Result<Option<Event>> {
+        crate::storage::events::get_event(&self.conn, id)
+    }
+
     /// Get all audit events (for summary).
     ///
     /// # Errors
@@ -138,26 +212,114 @@ impl SqliteStorage {
 
     /// Execute a mutation with the 4-step transaction protocol.
     ///
+    /// Inside [`Self::batch`], the mutation joins the open batch instead of
+    /// starting its own transaction.
+    ///
     /// # Errors
     ///
     /// Returns an error if any step fails (e.g. database error, logic error).
     /// The transaction is rolled back on error.
     pub fn mutate<F, R>(&mut self, op: &str, actor: &str, f: F) -> Result<R>
     where
-        F: FnOnce(&Transaction, &mut MutationContext) -> Result<R>,
+        F: FnOnce(&Connection, &mut MutationContext) -> Result<R>,
     {
-        let tx = self
+        if let Some(ctx) = self.batch.as_mut() {
+            return f(&self.conn, ctx);
+        }
+
+        let tx = match self
             .conn
-            .transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)?;
+            .transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)
+        {
+            Ok(tx) => tx,
+            Err(err) => return Err(self.write_lock_error(err)),
+        };
         let mut ctx = MutationContext::new(op, actor);
 
         let result = f(&tx, &mut ctx)?;
+        let payloads = Self::finish_mutation(&tx, ctx, &self.hooks)?;
+
+        tx.commit()?;
+
+        self.hooks.run_warning(&payloads);
+
+        Ok(result)
+    }
+
+    /// The error for a write transaction that could not start: a database
+    /// still busy after the lock timeout is [`BeadsError::DatabaseLocked`].
+    pub(super) fn write_lock_error(&self, err: rusqlite::Error) -> BeadsError {
+        if err.sqlite_error_code() == Some(rusqlite::ErrorCode::DatabaseBusy) {
+            BeadsError::DatabaseLocked {
+                path: PathBuf::from(self.conn.path().unwrap_or_default()),
+            }
+        } else {
+            err.into()
+        }
+    }
+
+    /// Run several mutations as one: a single transaction that commits or
+    /// rolls back as a whole, recorded as a single `op` batch for `br undo`.
+    /// Every [`Self::mutate`] call made by `f` joins it; a nested `batch`
+    /// joins the outer one.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error of `f` or of the commit, after rolling back
+    /// every change made by `f`.
+    pub fn batch<F, R>(&mut self, op: &str, actor: &str, f: F) -> Result<R>
+    where
+        F: FnOnce(&mut Self) -> Result<R>,
+    {
+        if self.batch.is_some() {
+            return f(self);
+        }
+
+        self.conn
+            .execute_batch("BEGIN IMMEDIATE")
+            .map_err(|err| self.write_lock_error(err))?;
+        self.batch = Some(MutationContext::new(op, actor));
+        let outcome = f(self).and_then(|result| {
+            let ctx = self.batch.take().ok_or_else(|| {
+                BeadsError::Config(format!("mutation batch {op} was closed early"))
+            })?;
+            let payloads = Self::finish_mutation(&self.conn, ctx, &self.hooks)?;
+            self.conn.execute_batch("COMMIT")?;
+            Ok((result, payloads))
+        });
+        self.batch = None;
+
+        match outcome {
+            Ok((result, payloads)) => {
+                self.hooks.run_warning(&payloads);
+                Ok(result)
+            }
+            Err(err) => {
+                if !self.conn.is_autocommit() {
+                    self.conn.execute_batch("ROLLBACK")?;
+                }
+                Err(err)
+            }
+        }
+    }
 
-        // Write events
+    /// Write the side effects of a finished mutation (undo images, events,
+    /// dirty marks, blocked cache) and run its blocking hooks. Returns the
+    /// payloads for the warning hooks to run once committed.
+    pub(super) fn finish_mutation(
+        conn: &Connection,
+        ctx: MutationContext,
+        hooks: &Hooks,
+    ) -> Result<Vec<HookPayload>> {
+        // Keep before/after images of the touched issues for `br undo`
+        let batch_id = Self::record_batch(conn, &ctx)?;
+
+        // Write events, keeping those a hook may fire on
+        let mut hooked = Vec::new();
         for event in ctx.events {
-            tx.execute(
-                "INSERT INTO events (issue_id, event_type, actor, old_value, new_value, comment, created_at)
-                 VALUES (?, ?, ?, ?, ?, ?, ?)",
+            conn.execute(
+                "INSERT INTO events (issue_id, event_type, actor, old_value, new_value, comment, created_at, batch_id)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
                 rusqlite::params![
                     event.issue_id,
                     event.event_type.as_str(),
@@ -165,30 +327,57 @@ impl SqliteStorage {
                     event.old_value,
                     event.new_value,
                     event.comment,
-                    event.created_at.to_rfc3339()
+                    event.created_at.to_rfc3339(),
+                    batch_id
                 ],
             )?;
+            if hooks.wants(&event.event_type) {
+                hooked.push(Event {
+                    id: conn.last_insert_rowid(),
+                    ..event
+                });
+            }
         }
 
-        // Mark dirty
+        // Mark dirty and keep the full-text index in step with the changed issues
         for id in ctx.dirty_ids {
-            tx.execute(
+            conn.execute(
                 "INSERT OR REPLACE INTO dirty_issues (issue_id, marked_at) VALUES (?, ?)",
                 rusqlite::params![id, Utc::now().to_rfc3339()],
             )?;
+            Self::refresh_search_index(conn, &id)?;
         }
 
-        // Track if we need to rebuild cache
-        let needs_cache_rebuild = ctx.invalidate_blocked_cache;
-
         // Rebuild blocked cache inside the transaction if needed
-        if needs_cache_rebuild {
-            Self::rebuild_blocked_cache_impl(&tx)?;
+        if ctx.invalidate_blocked_cache {
+            Self::rebuild_blocked_cache_impl(conn)?;
         }
 
-        tx.commit()?;
+        // Blocking hooks run before the commit so a failure rolls back the change
+        let payloads = Self::hook_payloads(conn, hooked)?;
+        hooks.run_blocking(&payloads)?;
+        Ok(payloads)
+    }
 
-        Ok(result)
+    /// Pair each hooked event with its issue as it stands in the transaction.
+    fn hook_payloads(conn: &Connection, events: Vec<Event>) -> Result<Vec<HookPayload>> {
+        events
+            .into_iter()
+            .map(|event| {
+                let issue = Self::fetch_issue(conn, &event.issue_id)?
+                    .map(|issue| -> Result<Issue> {
+                        let mut stmt = conn.prepare_cached(
+                            "SELECT label FROM labels WHERE issue_id = ? ORDER BY label",
+                        )?;
+                        let labels = stmt
+                            .query_map([&issue.id], |row| row.get(0))?
+                            .collect::<std::result::Result<Vec<String>, _>>()?;
+                        Ok(Issue { labels, ..issue })
+                    })
+                    .transpose()?;
+                Ok(HookPayload { event, issue })
+            })
+            .collect()
     }
 
     /// Create a new issue.
@@ -199,6 +388,7 @@ impl SqliteStorage {
     #[allow(clippy::too_many_lines)]
     pub fn create_issue(&mut self, issue: &Issue, actor: &str) -> Result<()> {
         self.mutate("create_issue", actor, |tx, ctx| {
+            ctx.capture(tx, &issue.id)?;
             let status_str = issue.status.as_str();
             let issue_type_str = issue.issue_type.as_str();
             let created_at_str = issue.created_at.to_rfc3339();
@@ -264,9 +454,11 @@ impl SqliteStorage {
                     "INSERT INTO labels (issue_id, label) VALUES (?, ?)",
                     rusqlite::params![issue.id, label],
                 )?;
-                ctx.record_event(
+                ctx.record_field_change(
                     EventType::LabelAdded,
                     &issue.id,
+                    None,
+                    Some(label.clone()),
                     Some(format!("Added label {label}")),
                 );
             }
@@ -297,9 +489,11 @@ impl SqliteStorage {
                     ],
                 )?;
 
-                ctx.record_event(
+                ctx.record_field_change(
                     EventType::DependencyAdded,
                     &issue.id,
+                    None,
+                    Some(dep.depends_on_id.clone()),
                     Some(format!(
                         "Added dependency on {} ({})",
                         dep.depends_on_id, dep.dep_type
@@ -381,20 +575,34 @@ impl SqliteStorage {
 
     /// Update an issue's fields.
     ///
+    /// While another actor holds a live lease on the issue, its status and
+    /// assignee cannot change. A lease ends when its holder closes the issue
+    /// or hands it to someone else.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the issue doesn't exist or the update fails.
+    /// Returns [`BeadsError::LeaseHeld`] if the update changes status or
+    /// assignee under another actor's lease, or an error if the issue doesn't
+    /// exist or the update fails.
     #[allow(clippy::too_many_lines)]
     pub fn update_issue(&mut self, id: &str, updates: &IssueUpdate, actor: &str) -> Result<Issue> {
-        let mut issue = self
-            .get_issue(id)?
-            .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })?;
-
         if updates.is_empty() {
-            return Ok(issue);
+            return self
+                .get_issue(id)?
+                .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() });
         }
 
         self.mutate("update_issue", actor, |tx, ctx| {
+            let mut issue = Self::fetch_issue(tx, id)?
+                .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })?;
+            let leased_fields = updates.status.is_some() || updates.assignee.is_some();
+            if updates.claim {
+                check_claimable(tx, &issue, actor, Utc::now())?;
+            } else if leased_fields {
+                check_lease(tx, id, actor, Utc::now())?;
+            }
+            ctx.capture(tx, id)?;
+            let was_terminal = issue.status.is_terminal();
             let mut set_clauses: Vec<String> = vec![];
             let mut params: Vec<Box<dyn rusqlite::ToSql>> = vec![];
 
@@ -420,6 +628,7 @@ impl SqliteStorage {
 
             // Simple text fields - use empty string instead of NULL for bd compatibility
             if let Some(ref val) = updates.description {
+                ctx.record_field_update(id, "description", issue.description.clone(), val.clone());
                 issue.description.clone_from(val);
                 add_update(
                     "description",
@@ -427,10 +636,17 @@ impl SqliteStorage {
                 );
             }
             if let Some(ref val) = updates.design {
+                ctx.record_field_update(id, "design", issue.design.clone(), val.clone());
                 issue.design.clone_from(val);
                 add_update("design", Box::new(val.as_deref().unwrap_or("").to_string()));
             }
             if let Some(ref val) = updates.acceptance_criteria {
+                ctx.record_field_update(
+                    id,
+                    "acceptance_criteria",
+                    issue.acceptance_criteria.clone(),
+                    val.clone(),
+                );
                 issue.acceptance_criteria.clone_from(val);
                 add_update(
                     "acceptance_criteria",
@@ -438,6 +654,7 @@ impl SqliteStorage {
                 );
             }
             if let Some(ref val) = updates.notes {
+                ctx.record_field_update(id, "notes", issue.notes.clone(), val.clone());
                 issue.notes.clone_from(val);
                 add_update("notes", Box::new(val.as_deref().unwrap_or("").to_string()));
             }
@@ -495,6 +712,12 @@ impl SqliteStorage {
 
             // Issue type
             if let Some(ref issue_type) = updates.issue_type {
+                ctx.record_field_update(
+                    id,
+                    "issue_type",
+                    Some(issue.issue_type.as_str().to_string()),
+                    Some(issue_type.as_str().to_string()),
+                );
                 issue.issue_type.clone_from(issue_type);
                 add_update("issue_type", Box::new(issue_type.as_str().to_string()));
             }
@@ -517,19 +740,38 @@ impl SqliteStorage {
 
             // Simple Option fields - use empty string instead of NULL for bd compatibility
             if let Some(ref val) = updates.owner {
+                ctx.record_field_update(id, "owner", issue.owner.clone(), val.clone());
                 issue.owner.clone_from(val);
                 add_update("owner", Box::new(val.as_deref().unwrap_or("").to_string()));
             }
             if let Some(ref val) = updates.estimated_minutes {
+                ctx.record_field_update(
+                    id,
+                    "estimated_minutes",
+                    issue.estimated_minutes.map(|m| m.to_string()),
+                    val.map(|m| m.to_string()),
+                );
                 issue.estimated_minutes = *val;
                 add_update("estimated_minutes", Box::new(*val));
             }
             if let Some(ref val) = updates.external_ref {
+                ctx.record_field_update(
+                    id,
+                    "external_ref",
+                    issue.external_ref.clone(),
+                    val.clone(),
+                );
                 issue.external_ref.clone_from(val);
                 add_update("external_ref", Box::new(val.clone()));
             }
             // Use empty string instead of NULL for bd compatibility
             if let Some(ref val) = updates.close_reason {
+                ctx.record_field_update(
+                    id,
+                    "close_reason",
+                    issue.close_reason.clone(),
+                    val.clone(),
+                );
                 issue.close_reason.clone_from(val);
                 add_update(
                     "close_reason",
@@ -567,10 +809,22 @@ impl SqliteStorage {
 
             // Date fields
             if let Some(ref val) = updates.due_at {
+                ctx.record_field_update(
+                    id,
+                    "due_at",
+                    issue.due_at.map(|d| d.to_rfc3339()),
+                    val.map(|d| d.to_rfc3339()),
+                );
                 issue.due_at = *val;
                 add_update("due_at", Box::new(val.map(|d| d.to_rfc3339())));
             }
             if let Some(ref val) = updates.defer_until {
+                ctx.record_field_update(
+                    id,
+                    "defer_until",
+                    issue.defer_until.map(|d| d.to_rfc3339()),
+                    val.map(|d| d.to_rfc3339()),
+                );
                 issue.defer_until = *val;
                 add_update("defer_until", Box::new(val.map(|d| d.to_rfc3339())));
             }
@@ -579,6 +833,22 @@ impl SqliteStorage {
                 add_update("closed_at", Box::new(val.map(|d| d.to_rfc3339())));
             }
 
+            // The holder's lease ends once the issue is closed or reassigned
+            if leased_fields {
+                tx.execute(
+                    "DELETE FROM leases WHERE issue_id = ?1 AND (?2 OR holder IS NOT ?3)",
+                    rusqlite::params![id, issue.status.is_terminal(), issue.assignee.as_deref()],
+                )?;
+            }
+
+            // Close outcome: recorded with the close, dropped on any reopen
+            let reopened = was_terminal && !issue.status.is_terminal();
+            match updates.close_outcome {
+                Some(outcome) => Self::set_close_outcome_in_tx(tx, ctx, id, outcome)?,
+                None if reopened => Self::set_close_outcome_in_tx(tx, ctx, id, None)?,
+                None => {}
+            }
+
             // Always update updated_at
             set_clauses.push("updated_at = ?".to_string());
             params.push(Box::new(Utc::now().to_rfc3339()));
@@ -625,6 +895,7 @@ impl SqliteStorage {
         let timestamp = deleted_at.unwrap_or_else(Utc::now);
 
         self.mutate("delete_issue", actor, |tx, ctx| {
+            ctx.capture(tx, id)?;
             tx.execute(
                 "UPDATE issues SET
                     status = 'tombstone',
@@ -644,9 +915,11 @@ impl SqliteStorage {
                 ],
             )?;
 
-            ctx.record_event(
+            ctx.record_field_change(
                 EventType::Deleted,
                 id,
+                Some(issue.status.as_str().to_string()),
+                Some(Status::Tombstone.as_str().to_string()),
                 Some(format!("Deleted issue: {reason}")),
             );
             ctx.mark_dirty(id);
@@ -665,6 +938,11 @@ impl SqliteStorage {
     ///
     /// Returns an error if the database query fails.
     pub fn get_issue(&self, id: &str) -> Result<Option<Issue>> {
+        Self::fetch_issue(&self.conn, id)
+    }
+
+    /// Get an issue by ID on `conn` (usable inside a [`mutate`](Self::mutate) transaction).
+    pub(super) fn fetch_issue(conn: &Connection, id: &str) -> Result<Option<Issue>> {
         let sql = r"
             SELECT id, content_hash, title, description, design, acceptance_criteria, notes,
                    status, priority, issue_type, assignee, owner, estimated_minutes,
@@ -676,8 +954,8 @@ impl SqliteStorage {
             FROM issues WHERE id = ?
         ";
 
-        let mut stmt = self.conn.prepare_cached(sql)?;
-        let result = stmt.query_row([id], |row| self.issue_from_row(row));
+        let mut stmt = conn.prepare_cached(sql)?;
+        let result = stmt.query_row([id], Self::issue_from_row);
 
         match result {
             Ok(issue) => Ok(Some(issue)),
@@ -719,7 +997,7 @@ impl SqliteStorage {
 
             let mut stmt = self.conn.prepare(&sql)?;
             let chunk_issues = stmt
-                .query_map(params.as_slice(), |row| self.issue_from_row(row))?
+                .query_map(params.as_slice(), Self::issue_from_row)?
                 .collect::<std::result::Result<Vec<_>, _>>()?;
             issues.extend(chunk_issues);
         }
@@ -882,7 +1160,7 @@ impl SqliteStorage {
         let mut stmt = self.conn.prepare(&sql)?;
         let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
         let issues = stmt
-            .query_map(params_refs.as_slice(), |row| self.issue_from_row(row))?
+            .query_map(params_refs.as_slice(), Self::issue_from_row)?
             .collect::<std::result::Result<Vec<_>, _>>()?;
 
         Ok(issues)
@@ -890,38 +1168,112 @@ impl SqliteStorage {
 
     /// Search issues by query with optional filters.
     ///
+    /// Results are ordered by relevance; see [`Self::search_issues_ranked`].
+    ///
     /// # Errors
     ///
-    /// Returns an error if the database query fails.
-    #[allow(clippy::too_many_lines)]
+    /// Returns an error if the database query fails or the query syntax is invalid.
     pub fn search_issues(&self, query: &str, filters: &ListFilters) -> Result<Vec<Issue>> {
+        Ok(self
+            .search_issues_ranked(query, filters)?
+            .into_iter()
+            .map(|hit| hit.issue)
+            .collect())
+    }
+
+    /// Full-text search over the `issues_fts` index with BM25 ranking.
+    ///
+    /// Plain queries are split into terms that must all match, each as a prefix
+    /// (`auth` finds `authentication`); issues whose title, description or ID
+    /// contain the raw query as a substring are also returned, ranked after the
+    /// full-text hits. Queries using FTS5 syntax (`"phrases"`, `prefix*`,
+    /// `AND`/`OR`/`NOT`, `NEAR(...)`, `column:term`) are passed through as-is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or the query syntax is invalid.
+    #[allow(clippy::too_many_lines)]
+    pub fn search_issues_ranked(
+        &self,
+        query: &str,
+        filters: &ListFilters,
+    ) -> Result<Vec<SearchHit>> {
         let trimmed = query.trim();
         if trimmed.is_empty() {
             return Ok(Vec::new());
         }
 
-        let mut sql = String::from(
+        let advanced = is_fts_syntax(trimmed);
+        let fts_query = if advanced {
+            Some(trimmed.to_string())
+        } else {
+            plain_fts_query(trimmed)
+        };
+
+        if advanced {
+            let probe = self.conn.query_row(
+                "SELECT count(*) FROM issues_fts WHERE issues_fts MATCH ?",
+                [trimmed],
+                |row| row.get::<_, i64>(0),
+            );
+            if let Err(e) = probe {
+                return Err(BeadsError::validation(
+                    "query",
+                    format!("invalid search syntax: {e}"),
+                ));
+            }
+        }
+
+        let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+        let mut sql = String::new();
+
+        if let Some(ref fts_query) = fts_query {
+            let _ = write!(
+                sql,
+                r"WITH fts AS MATERIALIZED (
+                    SELECT issue_id AS fts_issue_id,
+                           bm25(issues_fts, {SEARCH_BM25_WEIGHTS}) AS fts_score,
+                           snippet(issues_fts, -1, '{SEARCH_SNIPPET_OPEN}', '{SEARCH_SNIPPET_CLOSE}', '…', {SEARCH_SNIPPET_TOKENS}) AS fts_snippet
+                    FROM issues_fts
+                    WHERE issues_fts MATCH ?
+                  )
+                  "
+            );
+            params.push(Box::new(fts_query.clone()));
+        }
+
+        sql.push_str(
             r"SELECT id, content_hash, title, description, design, acceptance_criteria, notes,
                      status, priority, issue_type, assignee, owner, estimated_minutes,
                      created_at, created_by, updated_at, closed_at, close_reason, closed_by_session,
                      due_at, defer_until, external_ref, source_system, source_repo,
                      deleted_at, deleted_by, delete_reason, original_type,
                      compaction_level, compacted_at, compacted_at_commit, original_size,
-                     sender, ephemeral, pinned, is_template
-              FROM issues
-              WHERE 1=1",
+                     sender, ephemeral, pinned, is_template,",
         );
 
-        let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+        if fts_query.is_some() {
+            sql.push_str(" fts_score, fts_snippet FROM issues LEFT JOIN fts ON fts_issue_id = id");
+        } else {
+            sql.push_str(" NULL, NULL FROM issues");
+        }
 
-        sql.push_str(
-            " AND (title LIKE ? ESCAPE '\\' OR description LIKE ? ESCAPE '\\' OR id LIKE ? ESCAPE '\\')",
-        );
-        let escaped = escape_like_pattern(trimmed);
-        let pattern = format!("%{escaped}%");
-        params.push(Box::new(pattern.clone()));
-        params.push(Box::new(pattern.clone()));
-        params.push(Box::new(pattern));
+        if advanced {
+            sql.push_str(" WHERE fts_issue_id IS NOT NULL");
+        } else {
+            sql.push_str(" WHERE (");
+            if fts_query.is_some() {
+                sql.push_str("fts_issue_id IS NOT NULL OR ");
+            }
+            sql.push_str(
+                "title LIKE ? ESCAPE '\\' OR description LIKE ? ESCAPE '\\' OR id LIKE ? ESCAPE '\\')",
+            );
+            let escaped = escape_like_pattern(trimmed);
+            let pattern = format!("%{escaped}%");
+            params.push(Box::new(pattern.clone()));
+            params.push(Box::new(pattern.clone()));
+            params.push(Box::new(pattern));
+        }
 
         if let Some(ref statuses) = filters.statuses {
             if !statuses.is_empty() {
@@ -998,7 +1350,14 @@ impl SqliteStorage {
             params.push(Box::new(format!("%{escaped}%")));
         }
 
-        sql.push_str(" ORDER BY priority ASC, created_at DESC");
+        if fts_query.is_some() {
+            // Full-text hits first by BM25 (more negative is better), then substring-only hits
+            sql.push_str(
+                " ORDER BY fts_score IS NULL, fts_score ASC, priority ASC, created_at DESC",
+            );
+        } else {
+            sql.push_str(" ORDER BY priority ASC, created_at DESC");
+        }
 
         if let Some(limit) = filters.limit {
             if limit > 0 {
@@ -1009,11 +1368,42 @@ impl SqliteStorage {
 
         let mut stmt = self.conn.prepare(&sql)?;
         let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
-        let issues = stmt
-            .query_map(params_refs.as_slice(), |row| self.issue_from_row(row))?
+        let hits = stmt
+            .query_map(params_refs.as_slice(), |row| {
+                Ok(SearchHit {
+                    issue: Self::issue_from_row(row)?,
+                    score: row.get::<_, Option<f64>>(36)?,
+                    snippet: Self::empty_to_none(row.get::<_, Option<String>>(37)?),
+                })
+            })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
 
-        Ok(issues)
+        Ok(hits)
+    }
+
+    /// Rebuild the full-text search index from scratch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub fn rebuild_search_index(&mut self) -> Result<usize> {
+        let tx = self.conn.transaction()?;
+        tx.execute("DELETE FROM issues_fts", [])?;
+        let count = tx.execute(SEARCH_INDEX_INSERT_SQL, [])?;
+        tx.commit()?;
+        Ok(count)
+    }
+
+    /// Re-index a single issue (title, text fields and comments).
+    ///
+    /// Removes the index row when the issue no longer exists.
+    fn refresh_search_index(conn: &Connection, issue_id: &str) -> Result<()> {
+        conn.execute("DELETE FROM issues_fts WHERE issue_id = ?", [issue_id])?;
+        conn.execute(
+            &format!("{SEARCH_INDEX_INSERT_SQL} WHERE i.id = ?"),
+            [issue_id],
+        )?;
+        Ok(())
     }
 
     /// Get ready issues (unblocked, not deferred, not pinned, not ephemeral).
@@ -1024,15 +1414,26 @@ impl SqliteStorage {
     /// 3. `defer_until` is NULL or <= now (unless `include_deferred`)
     /// 4. `pinned = 0` (not pinned)
     /// 5. `ephemeral = 0` AND ID does not contain `-wisp-`
+    /// 6. No unexpired lease (`br claim`)
     ///
     /// # Errors
     ///
     /// Returns an error if the database query fails.
-    #[allow(clippy::too_many_lines)]
     pub fn get_ready_issues(
         &self,
         filters: &ReadyFilters,
         sort: ReadySortPolicy,
+    ) -> Result<Vec<Issue>> {
+        Self::query_ready_issues(&self.conn, filters, sort)
+    }
+
+    /// Ready-issue query behind [`Self::get_ready_issues`], usable inside a
+    /// [`mutate`](Self::mutate) transaction.
+    #[allow(clippy::too_many_lines)]
+    fn query_ready_issues(
+        conn: &Connection,
+        filters: &ReadyFilters,
+        sort: ReadySortPolicy,
     ) -> Result<Vec<Issue>> {
         let mut sql = String::from(
             r"SELECT id, content_hash, title, description, design, acceptance_criteria, notes,
@@ -1075,6 +1476,12 @@ impl SqliteStorage {
         // Exclude templates
         sql.push_str(" AND (is_template = 0 OR is_template IS NULL)");
 
+        // Ready condition 6: not leased by an agent
+        sql.push_str(
+            " AND NOT EXISTS (SELECT 1 FROM leases WHERE leases.issue_id = issues.id
+                              AND datetime(leases.expires_at) > datetime('now'))",
+        );
+
         // Filter by types
         if let Some(ref types) = filters.types {
             if !types.is_empty() {
@@ -1135,7 +1542,7 @@ impl SqliteStorage {
                 // P0/P1 first by `created_at` ASC, then others by `created_at` ASC
                 sql.push_str(" ORDER BY CASE WHEN priority <= 1 THEN 0 ELSE 1 END, created_at ASC");
             }
-            ReadySortPolicy::Priority => {
+            ReadySortPolicy::Priority | ReadySortPolicy::Score => {
                 sql.push_str(" ORDER BY priority ASC, created_at ASC");
             }
             ReadySortPolicy::Oldest => {
@@ -1152,10 +1559,10 @@ impl SqliteStorage {
             }
         }
 
-        let mut stmt = self.conn.prepare(&sql)?;
+        let mut stmt = conn.prepare(&sql)?;
         let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
         let issues: Vec<Issue> = stmt
-            .query_map(params_refs.as_slice(), |row| self.issue_from_row(row))?
+            .query_map(params_refs.as_slice(), Self::issue_from_row)?
             .collect::<std::result::Result<Vec<_>, _>>()?;
 
         Ok(issues)
@@ -1222,10 +1629,15 @@ impl SqliteStorage {
     ///
     /// This computes which issues are blocked based on their dependencies
     /// and the status of their blockers. An issue is blocked if it has a
-    /// blocking-type dependency on an issue that is not closed/tombstone.
+    /// `blocks` dependency on an issue that is not closed/tombstone, or a
+    /// `conditional-blocks` / `waits-for` dependency whose rule is unmet:
+    ///
+    /// - `conditional-blocks`: released only when the blocker closes with a
+    ///   failed or wontfix outcome (`outcome:` label from `br close --outcome`)
+    /// - `waits-for`: released by its gate (target closed, all or any children
+    ///   of the target closed, or any one of the issue's `any` targets closed)
     ///
-    /// Blocking dependency types: blocks, parent-child, conditional-blocks, waits-for
-    /// Blocking statuses: any non-terminal status (not closed/tombstone)
+    /// Children of blocked issues inherit the blocked state via parent-child.
     ///
     /// # Errors
     ///
@@ -1254,8 +1666,7 @@ impl SqliteStorage {
         // its parent epic is open. However, if the parent is blocked by something else,
         // that blocking propagates to children (handled in the transitive section below).
         //
-        // For conditional-blocks, we also need to check if the blocker closed with failure
-        // but for simplicity in this initial implementation, we treat it like blocks.
+        // conditional-blocks and waits-for follow their own rules (see add_gated_blockers).
         let mut blocked_issues_map: std::collections::HashMap<String, Vec<String>> =
             std::collections::HashMap::new();
         {
@@ -1263,7 +1674,7 @@ impl SqliteStorage {
                 r"SELECT DISTINCT d.issue_id, d.depends_on_id || ':' || COALESCE(i.status, 'unknown')
                   FROM dependencies d
                   LEFT JOIN issues i ON d.depends_on_id = i.id
-                  WHERE d.type IN ('blocks', 'conditional-blocks', 'waits-for')
+                  WHERE d.type = 'blocks'
                     AND (
                       -- The blocker is in a blocking state (anything not terminal)
                       i.status NOT IN ('closed', 'tombstone')
@@ -1285,6 +1696,7 @@ impl SqliteStorage {
                     .push(blocker_ref);
             }
         }
+        Self::add_gated_blockers(conn, &mut blocked_issues_map)?;
 
         // Insert blocked issues into cache
         let mut count = 0;
@@ -1368,6 +1780,126 @@ impl SqliteStorage {
         Ok(count)
     }
 
+    /// Add blockers from `conditional-blocks` and `waits-for` dependencies.
+    ///
+    /// Refs are `<id>:<rule>`: `if-failed(<status>)` for conditional blocks
+    /// (`if-failed(closed)` once the blocker closed without failing), the
+    /// target's status for plain waits-for, `all-children(c/n)` or
+    /// `any-children(c/n)` for child gates, and `any-of-<n>` for `any` gates.
+    fn add_gated_blockers(
+        conn: &Connection,
+        blocked: &mut HashMap<String, Vec<String>>,
+    ) -> Result<()> {
+        let statuses: HashMap<String, String> = conn
+            .prepare("SELECT id, status FROM issues")?
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<std::result::Result<_, _>>()?;
+        let failed: HashSet<String> = conn
+            .prepare("SELECT issue_id, label FROM labels WHERE label LIKE 'outcome:%'")?
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?
+            .into_iter()
+            .filter(|(_, label)| {
+                CloseOutcome::from_label(label).is_some_and(CloseOutcome::is_failure)
+            })
+            .map(|(id, _)| id)
+            .collect();
+        let mut children: HashMap<String, Vec<String>> = HashMap::new();
+        for edge in conn
+            .prepare(
+                "SELECT issue_id, depends_on_id FROM dependencies WHERE type = 'parent-child'",
+            )?
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?
+        {
+            let (child, parent) = edge?;
+            children.entry(parent).or_default().push(child);
+        }
+        let gated: Vec<(String, String, String, Option<String>)> = conn
+            .prepare(
+                "SELECT issue_id, depends_on_id, type, metadata FROM dependencies
+                 WHERE type IN ('conditional-blocks', 'waits-for')
+                   AND depends_on_id NOT LIKE 'external:%'",
+            )?
+            .query_map([], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+            })?
+            .collect::<std::result::Result<_, _>>()?;
+
+        let is_closed = |id: &str| {
+            statuses
+                .get(id)
+                .is_some_and(|status| status == "closed" || status == "tombstone")
+        };
+        // The blocker's status while it is open ("unknown" for a missing issue).
+        let open_status = |id: &str| -> Option<String> {
+            if is_closed(id) {
+                None
+            } else {
+                Some(
+                    statuses
+                        .get(id)
+                        .cloned()
+                        .unwrap_or_else(|| "unknown".to_string()),
+                )
+            }
+        };
+
+        let mut any_groups: HashMap<String, Vec<String>> = HashMap::new();
+        for (issue_id, target, dep_type, metadata) in gated {
+            let rule = if dep_type == "conditional-blocks" {
+                if is_closed(&target) {
+                    (!failed.contains(&target)).then(|| "if-failed(closed)".to_string())
+                } else {
+                    open_status(&target).map(|status| format!("if-failed({status})"))
+                }
+            } else {
+                let gate = WaitsForGate::from_metadata(metadata.as_deref());
+                let kids = children.get(&target).map_or(&[][..], Vec::as_slice);
+                match gate {
+                    WaitsForGate::Any => {
+                        any_groups.entry(issue_id).or_default().push(target);
+                        continue;
+                    }
+                    WaitsForGate::AllChildren | WaitsForGate::AnyChildren if !kids.is_empty() => {
+                        let closed = kids.iter().filter(|kid| is_closed(kid)).count();
+                        let released = if gate == WaitsForGate::AllChildren {
+                            closed == kids.len()
+                        } else {
+                            closed > 0
+                        };
+                        (!released).then(|| format!("{}({closed}/{})", gate.as_str(), kids.len()))
+                    }
+                    // Plain waits-for, or a child gate on an issue with no children yet
+                    _ => open_status(&target),
+                }
+            };
+            if let Some(rule) = rule {
+                blocked
+                    .entry(issue_id)
+                    .or_default()
+                    .push(format!("{target}:{rule}"));
+            }
+        }
+
+        for (issue_id, targets) in any_groups {
+            if targets.iter().any(|target| is_closed(target)) {
+                continue;
+            }
+            let count = targets.len();
+            let entry = blocked.entry(issue_id).or_default();
+            entry.extend(
+                targets
+                    .into_iter()
+                    .map(|target| format!("{target}:any-of-{count}")),
+            );
+        }
+        Ok(())
+    }
+
     /// Get issues that are blocked, along with what's blocking them.
     ///
     /// # Errors
@@ -1391,7 +1923,7 @@ impl SqliteStorage {
 
         let results = stmt
             .query_map([], |row| {
-                let issue = self.issue_from_row(row)?;
+                let issue = Self::issue_from_row(row)?;
                 let blockers_json: String = row.get(36)?;
                 Ok((issue, blockers_json))
             })?
@@ -1687,6 +2219,23 @@ impl SqliteStorage {
         depends_on_id: &str,
         dep_type: &str,
         actor: &str,
+    ) -> Result<bool> {
+        self.add_dependency_with_metadata(issue_id, depends_on_id, dep_type, None, actor)
+    }
+
+    /// Add a dependency carrying JSON metadata (e.g. a `waits-for` gate).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dependency would create a cycle or the database
+    /// update fails.
+    pub fn add_dependency_with_metadata(
+        &mut self,
+        issue_id: &str,
+        depends_on_id: &str,
+        dep_type: &str,
+        metadata: Option<&str>,
+        actor: &str,
     ) -> Result<bool> {
         // Check for cycles if this is a blocking dependency
         if let Ok(dt) = dep_type.parse::<DependencyType>() {
@@ -1700,6 +2249,7 @@ impl SqliteStorage {
         }
 
         self.mutate("add_dependency", actor, |tx, ctx| {
+            ctx.capture(tx, issue_id)?;
             let exists: i64 = tx.query_row(
                 "SELECT count(*) FROM dependencies WHERE issue_id = ? AND depends_on_id = ?",
                 rusqlite::params![issue_id, depends_on_id],
@@ -1711,14 +2261,15 @@ impl SqliteStorage {
             }
 
             tx.execute(
-                "INSERT INTO dependencies (issue_id, depends_on_id, type, created_at, created_by)
-                 VALUES (?, ?, ?, ?, ?)",
+                "INSERT INTO dependencies (issue_id, depends_on_id, type, created_at, created_by, metadata)
+                 VALUES (?, ?, ?, ?, ?, COALESCE(?, '{}'))",
                 rusqlite::params![
                     issue_id,
                     depends_on_id,
                     dep_type,
                     Utc::now().to_rfc3339(),
-                    actor
+                    actor,
+                    metadata
                 ],
             )?;
 
@@ -1728,9 +2279,11 @@ impl SqliteStorage {
                 rusqlite::params![Utc::now().to_rfc3339(), issue_id],
             )?;
 
-            ctx.record_event(
+            ctx.record_field_change(
                 EventType::DependencyAdded,
                 issue_id,
+                None,
+                Some(depends_on_id.to_string()),
                 Some(format!("Added dependency on {depends_on_id} ({dep_type})")),
             );
             ctx.mark_dirty(issue_id);
@@ -1752,6 +2305,14 @@ impl SqliteStorage {
         actor: &str,
     ) -> Result<bool> {
         self.mutate("remove_dependency", actor, |tx, ctx| {
+            ctx.capture(tx, issue_id)?;
+            let dep_type: Option<String> = tx
+                .query_row(
+                    "SELECT type FROM dependencies WHERE issue_id = ? AND depends_on_id = ?",
+                    rusqlite::params![issue_id, depends_on_id],
+                    |row| row.get(0),
+                )
+                .optional()?;
             let rows = tx.execute(
                 "DELETE FROM dependencies WHERE issue_id = ? AND depends_on_id = ?",
                 rusqlite::params![issue_id, depends_on_id],
@@ -1764,10 +2325,16 @@ impl SqliteStorage {
                     rusqlite::params![Utc::now().to_rfc3339(), issue_id],
                 )?;
 
-                ctx.record_event(
+                let dep_type =
+                    dep_type.unwrap_or_else(|| DependencyType::Blocks.as_str().to_string());
+                ctx.record_field_change(
                     EventType::DependencyRemoved,
                     issue_id,
-                    Some(format!("Removed dependency on {depends_on_id}")),
+                    Some(depends_on_id.to_string()),
+                    None,
+                    Some(format!(
+                        "Removed dependency on {depends_on_id} ({dep_type})"
+                    )),
                 );
                 ctx.mark_dirty(issue_id);
                 ctx.invalidate_cache();
@@ -1792,6 +2359,19 @@ impl SqliteStorage {
             let affected: Vec<String> = stmt
                 .query_map(rusqlite::params![issue_id, issue_id], |row| row.get(0))?
                 .collect::<std::result::Result<Vec<_>, _>>()?;
+            let mut stmt = tx.prepare(
+                "SELECT issue_id, depends_on_id, type FROM dependencies
+                 WHERE issue_id = ?1 OR depends_on_id = ?1",
+            )?;
+            let links: Vec<(String, String, String)> = stmt
+                .query_map([issue_id], |row| {
+                    Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+                })?
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            ctx.capture(tx, issue_id)?;
+            for (from, _, _) in &links {
+                ctx.capture(tx, from)?;
+            }
 
             let outgoing = tx.execute("DELETE FROM dependencies WHERE issue_id = ?", [issue_id])?;
             let incoming = tx.execute(
@@ -1817,11 +2397,16 @@ impl SqliteStorage {
                     )?;
                 }
 
-                ctx.record_event(
-                    EventType::DependencyRemoved,
-                    issue_id,
-                    Some(format!("Removed {total} dependency links")),
-                );
+                // One event per link, on the issue that held it
+                for (from, to, dep_type) in &links {
+                    ctx.record_field_change(
+                        EventType::DependencyRemoved,
+                        from,
+                        Some(to.clone()),
+                        None,
+                        Some(format!("Removed dependency on {to} ({dep_type})")),
+                    );
+                }
                 ctx.mark_dirty(issue_id);
                 for affected_id in affected {
                     ctx.mark_dirty(&affected_id);
@@ -1840,6 +2425,14 @@ impl SqliteStorage {
     /// Returns an error if the database update fails.
     pub fn remove_parent(&mut self, issue_id: &str, actor: &str) -> Result<bool> {
         self.mutate("remove_parent", actor, |tx, ctx| {
+            ctx.capture(tx, issue_id)?;
+            let parent_id: Option<String> = tx
+                .query_row(
+                    "SELECT depends_on_id FROM dependencies WHERE issue_id = ? AND type = 'parent-child'",
+                    [issue_id],
+                    |row| row.get(0),
+                )
+                .optional()?;
             let rows = tx.execute(
                 "DELETE FROM dependencies WHERE issue_id = ? AND type = 'parent-child'",
                 rusqlite::params![issue_id],
@@ -1852,9 +2445,11 @@ impl SqliteStorage {
                     rusqlite::params![Utc::now().to_rfc3339(), issue_id],
                 )?;
 
-                ctx.record_event(
+                ctx.record_field_change(
                     EventType::DependencyRemoved,
                     issue_id,
+                    parent_id,
+                    None,
                     Some("Removed parent".to_string()),
                 );
                 ctx.mark_dirty(issue_id);
@@ -1872,6 +2467,7 @@ impl SqliteStorage {
     /// Returns an error if the database update fails.
     pub fn add_label(&mut self, issue_id: &str, label: &str, actor: &str) -> Result<bool> {
         self.mutate("add_label", actor, |tx, ctx| {
+            ctx.capture(tx, issue_id)?;
             let exists: i64 = tx.query_row(
                 "SELECT count(*) FROM labels WHERE issue_id = ? AND label = ?",
                 rusqlite::params![issue_id, label],
@@ -1887,12 +2483,18 @@ impl SqliteStorage {
                 rusqlite::params![issue_id, label],
             )?;
 
-            ctx.record_event(
+            ctx.record_field_change(
                 EventType::LabelAdded,
                 issue_id,
+                None,
+                Some(label.to_string()),
                 Some(format!("Added label {label}")),
             );
             ctx.mark_dirty(issue_id);
+            // Close outcomes release conditional-blocks dependents
+            if label.starts_with(CloseOutcome::LABEL_PREFIX) {
+                ctx.invalidate_cache();
+            }
 
             // Update timestamp
             tx.execute(
@@ -1911,6 +2513,7 @@ impl SqliteStorage {
     /// Returns an error if the database update fails.
     pub fn remove_label(&mut self, issue_id: &str, label: &str, actor: &str) -> Result<bool> {
         self.mutate("remove_label", actor, |tx, ctx| {
+            ctx.capture(tx, issue_id)?;
             let rows = tx.execute(
                 "DELETE FROM labels WHERE issue_id = ? AND label = ?",
                 rusqlite::params![issue_id, label],
@@ -1923,18 +2526,104 @@ impl SqliteStorage {
                     rusqlite::params![Utc::now().to_rfc3339(), issue_id],
                 )?;
 
-                ctx.record_event(
+                ctx.record_field_change(
                     EventType::LabelRemoved,
                     issue_id,
+                    Some(label.to_string()),
+                    None,
                     Some(format!("Removed label {label}")),
                 );
                 ctx.mark_dirty(issue_id);
+                if label.starts_with(CloseOutcome::LABEL_PREFIX) {
+                    ctx.invalidate_cache();
+                }
             }
 
             Ok(rows > 0)
         })
     }
 
+    /// Record (or with `None`, clear) the close outcome of an issue.
+    ///
+    /// The outcome is stored as a single `outcome:<value>` label.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn set_close_outcome(
+        &mut self,
+        issue_id: &str,
+        outcome: Option<CloseOutcome>,
+        actor: &str,
+    ) -> Result<()> {
+        self.mutate("set_close_outcome", actor, |tx, ctx| {
+            Self::set_close_outcome_in_tx(tx, ctx, issue_id, outcome)
+        })
+    }
+
+    /// Record or clear the close outcome inside a mutation (see
+    /// [`Self::set_close_outcome`]).
+    fn set_close_outcome_in_tx(
+        tx: &Connection,
+        ctx: &mut MutationContext,
+        issue_id: &str,
+        outcome: Option<CloseOutcome>,
+    ) -> Result<()> {
+        let wanted = outcome.map(CloseOutcome::label);
+        let mut stmt = tx.prepare("SELECT label FROM labels WHERE issue_id = ? ORDER BY label")?;
+        let labels: Vec<String> = stmt
+            .query_map([issue_id], |row| row.get(0))?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        drop(stmt);
+
+        let stale: Vec<&String> = labels
+            .iter()
+            .filter(|label| {
+                label.starts_with(CloseOutcome::LABEL_PREFIX) && Some(*label) != wanted.as_ref()
+            })
+            .collect();
+        let missing = wanted.as_ref().filter(|label| !labels.contains(label));
+        if stale.is_empty() && missing.is_none() {
+            return Ok(());
+        }
+
+        ctx.capture(tx, issue_id)?;
+        for label in stale {
+            tx.execute(
+                "DELETE FROM labels WHERE issue_id = ? AND label = ?",
+                rusqlite::params![issue_id, label],
+            )?;
+            ctx.record_field_change(
+                EventType::LabelRemoved,
+                issue_id,
+                Some(label.clone()),
+                None,
+                Some(format!("Removed label {label}")),
+            );
+        }
+        if let Some(label) = missing {
+            tx.execute(
+                "INSERT INTO labels (issue_id, label) VALUES (?, ?)",
+                rusqlite::params![issue_id, label],
+            )?;
+            ctx.record_field_change(
+                EventType::LabelAdded,
+                issue_id,
+                None,
+                Some(label.clone()),
+                Some(format!("Added label {label}")),
+            );
+        }
+        tx.execute(
+            "UPDATE issues SET updated_at = ? WHERE id = ?",
+            rusqlite::params![Utc::now().to_rfc3339(), issue_id],
+        )?;
+        ctx.mark_dirty(issue_id);
+        // Close outcomes release conditional-blocks dependents
+        ctx.invalidate_cache();
+        Ok(())
+    }
+
     /// Remove all labels from an issue.
     ///
     /// # Errors
@@ -1942,6 +2631,14 @@ impl SqliteStorage {
     /// Returns an error if the database update fails.
     pub fn remove_all_labels(&mut self, issue_id: &str, actor: &str) -> Result<usize> {
         self.mutate("remove_all_labels", actor, |tx, ctx| {
+            ctx.capture(tx, issue_id)?;
+            let mut stmt =
+                tx.prepare("SELECT label FROM labels WHERE issue_id = ? ORDER BY label")?;
+            let old_labels: Vec<String> = stmt
+                .query_map([issue_id], |row| row.get(0))?
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            drop(stmt);
+
             let rows = tx.execute(
                 "DELETE FROM labels WHERE issue_id = ?",
                 rusqlite::params![issue_id],
@@ -1954,11 +2651,16 @@ impl SqliteStorage {
                     rusqlite::params![Utc::now().to_rfc3339(), issue_id],
                 )?;
 
-                ctx.record_event(
-                    EventType::LabelRemoved,
-                    issue_id,
-                    Some(format!("Removed {rows} labels")),
-                );
+                for label in old_labels {
+                    let comment = format!("Removed label {label}");
+                    ctx.record_field_change(
+                        EventType::LabelRemoved,
+                        issue_id,
+                        Some(label),
+                        None,
+                        Some(comment),
+                    );
+                }
                 ctx.mark_dirty(issue_id);
             }
 
@@ -1973,6 +2675,7 @@ impl SqliteStorage {
     /// Returns an error if the database update fails.
     pub fn set_labels(&mut self, issue_id: &str, labels: &[String], actor: &str) -> Result<()> {
         self.mutate("set_labels", actor, |tx, ctx| {
+            ctx.capture(tx, issue_id)?;
             let mut stmt = tx.prepare("SELECT label FROM labels WHERE issue_id = ?")?;
             let old_labels: Vec<String> = stmt
                 .query_map([issue_id], |row| row.get(0))?
@@ -1993,32 +2696,24 @@ impl SqliteStorage {
             let added: Vec<_> = labels.iter().filter(|l| !old_labels.contains(l)).collect();
 
             if !removed.is_empty() || !added.is_empty() {
-                let mut details = Vec::new();
-                if !removed.is_empty() {
-                    details.push(format!(
-                        "removed: {}",
-                        removed
-                            .iter()
-                            .map(|s| s.as_str())
-                            .collect::<Vec<_>>()
-                            .join(", ")
-                    ));
+                for label in removed {
+                    ctx.record_field_change(
+                        EventType::LabelRemoved,
+                        issue_id,
+                        Some(label.clone()),
+                        None,
+                        Some(format!("Removed label {label}")),
+                    );
                 }
-                if !added.is_empty() {
-                    details.push(format!(
-                        "added: {}",
-                        added
-                            .iter()
-                            .map(|s| s.as_str())
-                            .collect::<Vec<_>>()
-                            .join(", ")
-                    ));
+                for label in added {
+                    ctx.record_field_change(
+                        EventType::LabelAdded,
+                        issue_id,
+                        None,
+                        Some(label.clone()),
+                        Some(format!("Added label {label}")),
+                    );
                 }
-                ctx.record_event(
-                    EventType::Updated,
-                    issue_id,
-                    Some(format!("Labels {}", details.join("; "))),
-                );
                 ctx.mark_dirty(issue_id);
 
                 // Bump updated_at
@@ -2152,6 +2847,9 @@ impl SqliteStorage {
                 .query_map([old_name], |row| row.get(0))?
                 .collect::<std::result::Result<Vec<_>, _>>()?;
             drop(stmt);
+            for issue_id in &issue_ids {
+                ctx.capture(tx, issue_id)?;
+            }
 
             // Check if any issues already have the new label (would cause duplicates)
             let mut check_stmt =
@@ -2178,12 +2876,24 @@ impl SqliteStorage {
 
             // Mark all affected issues as dirty and record events
             let now = Utc::now().to_rfc3339();
+            let comment = format!("Renamed label {old_name} to {new_name}");
             for issue_id in &issue_ids {
-                ctx.record_event(
+                ctx.record_field_change(
                     EventType::LabelRemoved,
                     issue_id,
-                    Some(format!("Renamed label {old_name} to {new_name}")),
+                    Some(old_name.to_string()),
+                    None,
+                    Some(comment.clone()),
                 );
+                if !conflicts.contains(issue_id) {
+                    ctx.record_field_change(
+                        EventType::LabelAdded,
+                        issue_id,
+                        None,
+                        Some(new_name.to_string()),
+                        Some(comment.clone()),
+                    );
+                }
                 ctx.mark_dirty(issue_id);
 
                 // Update timestamp
@@ -2232,6 +2942,7 @@ impl SqliteStorage {
     /// Returns an error if the database update fails.
     pub fn add_comment(&mut self, issue_id: &str, author: &str, text: &str) -> Result<Comment> {
         self.mutate("add_comment", author, |tx, ctx| {
+            ctx.capture(tx, issue_id)?;
             let comment_id = insert_comment_row(tx, issue_id, author, text)?;
 
             tx.execute(
@@ -2544,35 +3255,710 @@ impl SqliteStorage {
             let (key, value) = row?;
             map.insert(key, value);
         }
-        Ok(map)
+        Ok(map)
+    }
+
+    /// Set a config value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn set_config(&mut self, key: &str, value: &str) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO config (key, value) VALUES (?, ?)
+             ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+            rusqlite::params![key, value],
+        )?;
+        Ok(())
+    }
+
+    /// Delete a config value.
+    ///
+    /// Returns `true` if a value was deleted, `false` if the key didn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database delete fails.
+    pub fn delete_config(&mut self, key: &str) -> Result<bool> {
+        let deleted = self
+            .conn
+            .execute("DELETE FROM config WHERE key = ?", rusqlite::params![key])?;
+        Ok(deleted > 0)
+    }
+
+    // ========================================================================
+    // Recurrence rules
+    // ========================================================================
+
+    /// Attach (or replace) the recurrence rule for an issue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn set_recurrence(
+        &mut self,
+        issue_id: &str,
+        rule: &str,
+        next_at: Option<DateTime<Utc>>,
+        actor: &str,
+    ) -> Result<()> {
+        self.mutate("set_recurrence", actor, |tx, ctx| {
+            ctx.capture(tx, issue_id)?;
+            let old = fetch_recurrence(tx, issue_id)?;
+            tx.execute(
+                "INSERT INTO recurrence_rules (issue_id, rule, next_at, created_at, created_by)
+                 VALUES (?, ?, ?, ?, ?)
+                 ON CONFLICT(issue_id) DO UPDATE SET rule = excluded.rule, next_at = excluded.next_at",
+                rusqlite::params![
+                    issue_id,
+                    rule,
+                    next_at.map(|dt| dt.to_rfc3339()),
+                    Utc::now().to_rfc3339(),
+                    actor
+                ],
+            )?;
+            ctx.record_field_update(
+                issue_id,
+                "recurrence",
+                old.map(|record| record.rule),
+                Some(rule.to_string()),
+            );
+            ctx.mark_dirty(issue_id);
+            Ok(())
+        })
+    }
+
+    /// Fetch the recurrence rule attached to an issue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_recurrence(&self, issue_id: &str) -> Result<Option<RecurrenceRecord>> {
+        fetch_recurrence(&self.conn, issue_id)
+    }
+
+    /// List all recurrence rules, ordered by issue ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn list_recurrences(&self) -> Result<Vec<RecurrenceRecord>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT issue_id, rule, next_at, created_at, created_by
+             FROM recurrence_rules ORDER BY issue_id",
+        )?;
+        let records = stmt
+            .query_map([], recurrence_from_row)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(records)
+    }
+
+    /// Detach the recurrence rule from an issue.
+    ///
+    /// Returns `true` if a rule was removed, `false` if the issue had none.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database delete fails.
+    pub fn remove_recurrence(&mut self, issue_id: &str, actor: &str) -> Result<bool> {
+        self.mutate("remove_recurrence", actor, |tx, ctx| {
+            let Some(old) = fetch_recurrence(tx, issue_id)? else {
+                return Ok(false);
+            };
+            ctx.capture(tx, issue_id)?;
+            tx.execute(
+                "DELETE FROM recurrence_rules WHERE issue_id = ?",
+                rusqlite::params![issue_id],
+            )?;
+            ctx.record_field_update(issue_id, "recurrence", Some(old.rule), None);
+            ctx.mark_dirty(issue_id);
+            Ok(true)
+        })
+    }
+
+    /// Move a recurrence rule to the series' newest instance and schedule its
+    /// next occurrence.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn advance_recurrence(
+        &mut self,
+        from_id: &str,
+        to_id: &str,
+        next_at: Option<DateTime<Utc>>,
+        actor: &str,
+    ) -> Result<()> {
+        self.mutate("advance_recurrence", actor, |tx, ctx| {
+            let Some(old) = fetch_recurrence(tx, from_id)? else {
+                return Ok(());
+            };
+            ctx.capture(tx, from_id)?;
+            ctx.capture(tx, to_id)?;
+            tx.execute(
+                "UPDATE recurrence_rules SET issue_id = ?, next_at = ? WHERE issue_id = ?",
+                rusqlite::params![to_id, next_at.map(|dt| dt.to_rfc3339()), from_id],
+            )?;
+            ctx.record_field_update(from_id, "recurrence", Some(old.rule.clone()), None);
+            ctx.record_field_update(to_id, "recurrence", None, Some(old.rule));
+            ctx.mark_dirty(from_id);
+            ctx.mark_dirty(to_id);
+            Ok(())
+        })
+    }
+
+    // ========================================================================
+    // Work log (time tracking)
+    // ========================================================================
+
+    /// Start a timer on an issue for `actor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `actor` already has a timer running on the issue
+    /// or the database insert fails.
+    pub fn start_timer(&mut self, issue_id: &str, actor: &str) -> Result<WorkLogEntry> {
+        self.mutate("start_timer", actor, |tx, ctx| {
+            if find_running_timer(tx, issue_id, actor)?.is_some() {
+                return Err(BeadsError::validation(
+                    "id",
+                    format!("a timer is already running on {issue_id} for {actor}"),
+                ));
+            }
+            let now = Utc::now().to_rfc3339();
+            tx.execute(
+                "INSERT INTO worklog (issue_id, actor, started_at, created_at) VALUES (?, ?, ?, ?)",
+                rusqlite::params![issue_id, actor, now, now],
+            )?;
+            ctx.record_event(EventType::TimerStarted, issue_id, None);
+            fetch_worklog_entry(tx, tx.last_insert_rowid())
+        })
+    }
+
+    /// Stop `actor`'s running timer on an issue and log the elapsed time
+    /// (rounded to the nearest minute, at least one).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no timer is running or the database update fails.
+    pub fn stop_timer(
+        &mut self,
+        issue_id: &str,
+        actor: &str,
+        note: Option<&str>,
+    ) -> Result<WorkLogEntry> {
+        self.mutate("stop_timer", actor, |tx, ctx| {
+            let Some((entry_id, started_at)) = find_running_timer(tx, issue_id, actor)? else {
+                return Err(BeadsError::validation(
+                    "id",
+                    format!("no timer running on {issue_id} for {actor}"),
+                ));
+            };
+            let now = Utc::now();
+            let minutes = ((now - started_at).num_seconds() + 30) / 60;
+            let minutes = minutes.max(1);
+            tx.execute(
+                "UPDATE worklog SET minutes = ?, note = ?, created_at = ? WHERE id = ?",
+                rusqlite::params![minutes, note, now.to_rfc3339(), entry_id],
+            )?;
+            ctx.record_field_change(
+                EventType::WorkLogged,
+                issue_id,
+                None,
+                Some(minutes.to_string()),
+                note.map(str::to_string),
+            );
+            ctx.mark_dirty(issue_id);
+            fetch_worklog_entry(tx, entry_id)
+        })
+    }
+
+    /// Log time spent on an issue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database insert fails.
+    pub fn log_work(
+        &mut self,
+        issue_id: &str,
+        actor: &str,
+        minutes: i64,
+        note: Option<&str>,
+    ) -> Result<WorkLogEntry> {
+        self.mutate("log_work", actor, |tx, ctx| {
+            tx.execute(
+                "INSERT INTO worklog (issue_id, actor, minutes, note, created_at) VALUES (?, ?, ?, ?, ?)",
+                rusqlite::params![issue_id, actor, minutes, note, Utc::now().to_rfc3339()],
+            )?;
+            ctx.record_field_change(
+                EventType::WorkLogged,
+                issue_id,
+                None,
+                Some(minutes.to_string()),
+                note.map(str::to_string),
+            );
+            ctx.mark_dirty(issue_id);
+            fetch_worklog_entry(tx, tx.last_insert_rowid())
+        })
+    }
+
+    /// Get work log entries for an issue, oldest first (running timers included).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_worklog(&self, issue_id: &str) -> Result<Vec<WorkLogEntry>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT id, issue_id, actor, minutes, note, started_at, created_at
+             FROM worklog WHERE issue_id = ? ORDER BY created_at ASC, id ASC",
+        )?;
+        let entries = stmt
+            .query_map([issue_id], worklog_from_row)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(entries)
+    }
+
+    /// Get the finished work log entries of all issues (running timers are
+    /// left out), oldest first.
+    ///
+    /// Returns a map from `issue_id` to its entries, for export.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_all_worklog(&self) -> Result<HashMap<String, Vec<WorkLogEntry>>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT id, issue_id, actor, minutes, note, started_at, created_at
+             FROM worklog WHERE minutes IS NOT NULL
+             ORDER BY issue_id, created_at ASC, id ASC",
+        )?;
+        let rows = stmt.query_map([], worklog_from_row)?;
+
+        let mut map: HashMap<String, Vec<WorkLogEntry>> = HashMap::new();
+        for row in rows {
+            let entry = row?;
+            map.entry(entry.issue_id.clone()).or_default().push(entry);
+        }
+        Ok(map)
+    }
+
+    /// Logged time per issue, with each issue's total including the time
+    /// logged on its parent-child descendants (so epics roll up their work).
+    ///
+    /// Issues with no logged time on themselves or any descendant are omitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_time_rollup(&self) -> Result<HashMap<String, TimeSpent>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT issue_id, SUM(minutes) FROM worklog
+             WHERE minutes IS NOT NULL GROUP BY issue_id",
+        )?;
+        let logged = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT issue_id, depends_on_id FROM dependencies WHERE type = 'parent-child'",
+        )?;
+        let parents: HashMap<String, String> = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<std::result::Result<_, _>>()?;
+
+        let mut rollup: HashMap<String, TimeSpent> = HashMap::new();
+        for (issue_id, minutes) in logged {
+            rollup.entry(issue_id.clone()).or_default().logged_minutes += minutes;
+            // Walk up the parent chain, guarding against malformed cycles.
+            let mut seen = HashSet::new();
+            let mut current = Some(issue_id);
+            while let Some(id) = current {
+                if !seen.insert(id.clone()) {
+                    break;
+                }
+                current = parents.get(&id).cloned();
+                rollup.entry(id).or_default().total_minutes += minutes;
+            }
+        }
+        Ok(rollup)
+    }
+
+    // ========================================================================
+    // Leases (br claim / br heartbeat)
+    // ========================================================================
+
+    /// Claim an issue for `actor` under a lease lasting `ttl`: the issue is
+    /// assigned to `actor` and moved to `in_progress`. Claiming an issue you
+    /// already hold renews the lease.
+    ///
+    /// Expired leases are released first, so a lapsed claim never blocks.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BeadsError::LeaseHeld`] if another actor holds an unexpired
+    /// lease, a validation error if the issue is closed or assigned to someone
+    /// else, or an error if the database update fails.
+    pub fn claim_issue(
+        &mut self,
+        issue_id: &str,
+        actor: &str,
+        ttl: chrono::Duration,
+    ) -> Result<Lease> {
+        self.mutate("claim_issue", actor, |tx, ctx| {
+            let now = Utc::now();
+            Self::release_expired_leases_in_tx(tx, ctx, now)?;
+            Self::claim_in_tx(tx, ctx, issue_id, ttl, now)
+        })
+    }
+
+    /// Extend `actor`'s leases to expire `ttl` from now. With no `issue_ids`,
+    /// every lease `actor` holds is extended.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BeadsError::LeaseHeld`] if another actor holds one of the
+    /// leases, a validation error if `actor` holds no lease on a named issue
+    /// (for example because it already expired), or an error if the database
+    /// update fails.
+    pub fn heartbeat(
+        &mut self,
+        actor: &str,
+        issue_ids: &[String],
+        ttl: chrono::Duration,
+    ) -> Result<Vec<Lease>> {
+        self.mutate("heartbeat", actor, |tx, ctx| {
+            let now = Utc::now();
+            Self::release_expired_leases_in_tx(tx, ctx, now)?;
+
+            let ids = if issue_ids.is_empty() {
+                let mut stmt =
+                    tx.prepare("SELECT issue_id FROM leases WHERE holder = ? ORDER BY issue_id")?;
+                stmt.query_map([actor], |row| row.get(0))?
+                    .collect::<std::result::Result<Vec<String>, _>>()?
+            } else {
+                issue_ids.to_vec()
+            };
+
+            let expires_at = now + ttl;
+            let mut leases = Vec::with_capacity(ids.len());
+            for id in &ids {
+                let mut lease = match fetch_lease(tx, id)? {
+                    Some(lease) if lease.holder == actor => lease,
+                    Some(lease) => return Err(lease_held(lease)),
+                    None => {
+                        return Err(BeadsError::validation(
+                            "id",
+                            format!(
+                                "{actor} holds no lease on {id} (claim it with `br claim {id}`)"
+                            ),
+                        ));
+                    }
+                };
+                tx.execute(
+                    "UPDATE leases SET expires_at = ? WHERE issue_id = ?",
+                    rusqlite::params![expires_at.to_rfc3339(), id],
+                )?;
+                lease.expires_at = expires_at;
+                leases.push(lease);
+            }
+            Ok(leases)
+        })
+    }
+
+    /// Select the top ready issue and claim it for `actor`, in one
+    /// transaction so concurrent callers never get the same issue.
+    ///
+    /// Candidates are the ready issues matching `filters` that are unassigned
+    /// or already assigned to `actor`, minus `excluded` (for example issues
+    /// held by unresolved `external:` blockers), ranked by `sort` (with
+    /// `weights` for [`ReadySortPolicy::Score`]). Returns `None` when nothing
+    /// is ready.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query or update fails.
+    pub fn claim_next(
+        &mut self,
+        filters: &ReadyFilters,
+        sort: ReadySortPolicy,
+        weights: &ScoreWeights,
+        excluded: &HashSet<String>,
+        actor: &str,
+        ttl: chrono::Duration,
+    ) -> Result<Option<Lease>> {
+        let filters = ReadyFilters {
+            limit: None,
+            ..filters.clone()
+        };
+        self.mutate("claim_next", actor, |tx, ctx| {
+            let now = Utc::now();
+            Self::release_expired_leases_in_tx(tx, ctx, now)?;
+
+            let mut candidates = Self::query_ready_issues(tx, &filters, sort)?;
+            candidates.retain(|issue| {
+                !excluded.contains(&issue.id)
+                    && issue
+                        .assignee
+                        .as_deref()
+                        .is_none_or(|assignee| assignee == actor)
+            });
+            if sort == ReadySortPolicy::Score {
+                let scores = score_on(tx, &candidates, weights, now)?;
+                sort_by_score(&mut candidates, &scores);
+            }
+
+            match candidates.first() {
+                Some(top) => Self::claim_in_tx(tx, ctx, &top.id, ttl, now).map(Some),
+                None => Ok(None),
+            }
+        })
+    }
+
+    /// Get the lease on an issue, if any. The lease may have expired but not
+    /// yet been released.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_lease(&self, issue_id: &str) -> Result<Option<Lease>> {
+        fetch_lease(&self.conn, issue_id)
+    }
+
+    /// Release expired leases. Each issue still `in_progress` under the lease
+    /// holder goes back to `open` and unassigned, with a `lease_expired` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn release_expired_leases(&mut self, actor: &str) -> Result<Vec<Lease>> {
+        // Check first so commands that find nothing to release stay read-only
+        let any_expired: bool = self.conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM leases WHERE datetime(expires_at) <= datetime('now'))",
+            [],
+            |row| row.get(0),
+        )?;
+        if !any_expired {
+            return Ok(Vec::new());
+        }
+        self.mutate("release_expired_leases", actor, |tx, ctx| {
+            Self::release_expired_leases_in_tx(tx, ctx, Utc::now())
+        })
+    }
+
+    fn release_expired_leases_in_tx(
+        tx: &Connection,
+        ctx: &mut MutationContext,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<Lease>> {
+        let mut stmt = tx.prepare("SELECT issue_id, holder, claimed_at, expires_at FROM leases")?;
+        let expired: Vec<Lease> = stmt
+            .query_map([], lease_from_row)?
+            .collect::<std::result::Result<Vec<_>, _>>()?
+            .into_iter()
+            .filter(|lease| !lease.is_active(now))
+            .collect();
+        drop(stmt);
+
+        for lease in &expired {
+            let id = lease.issue_id.as_str();
+            tx.execute("DELETE FROM leases WHERE issue_id = ?", [id])?;
+            let Some(mut issue) = Self::fetch_issue(tx, id)? else {
+                continue;
+            };
+            // Leave the issue alone if it moved on since the claim
+            if issue.status != Status::InProgress
+                || issue.assignee.as_deref() != Some(lease.holder.as_str())
+            {
+                continue;
+            }
+            ctx.capture(tx, id)?;
+            issue.status = Status::Open;
+            issue.assignee = None;
+            tx.execute(
+                "UPDATE issues SET status = 'open', assignee = NULL, content_hash = ?, updated_at = ?
+                 WHERE id = ?",
+                rusqlite::params![issue.compute_content_hash(), now.to_rfc3339(), id],
+            )?;
+            ctx.record_field_change(
+                EventType::LeaseExpired,
+                id,
+                Some(lease.holder.clone()),
+                None,
+                Some(format!(
+                    "Lease expired at {}",
+                    lease.expires_at.to_rfc3339()
+                )),
+            );
+            ctx.record_field_change(
+                EventType::StatusChanged,
+                id,
+                Some(Status::InProgress.as_str().to_string()),
+                Some(Status::Open.as_str().to_string()),
+                None,
+            );
+            ctx.mark_dirty(id);
+        }
+        Ok(expired)
     }
 
-    /// Set a config value.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the database update fails.
-    pub fn set_config(&mut self, key: &str, value: &str) -> Result<()> {
-        self.conn.execute(
-            "INSERT INTO config (key, value) VALUES (?, ?)
-             ON CONFLICT(key) DO UPDATE SET value = excluded.value",
-            rusqlite::params![key, value],
+    /// Claim `issue_id` for `ctx.actor` inside a mutation (see [`Self::claim_issue`]).
+    fn claim_in_tx(
+        tx: &Connection,
+        ctx: &mut MutationContext,
+        issue_id: &str,
+        ttl: chrono::Duration,
+        now: DateTime<Utc>,
+    ) -> Result<Lease> {
+        let actor = ctx.actor.clone();
+        let mut issue =
+            Self::fetch_issue(tx, issue_id)?.ok_or_else(|| BeadsError::IssueNotFound {
+                id: issue_id.to_string(),
+            })?;
+        if issue.status.is_terminal() {
+            return Err(BeadsError::validation(
+                "id",
+                format!("{issue_id} is {} and cannot be claimed", issue.status),
+            ));
+        }
+
+        check_claimable(tx, &issue, &actor, now)?;
+        let existing = fetch_lease(tx, issue_id)?;
+
+        let lease = Lease {
+            issue_id: issue_id.to_string(),
+            holder: actor.clone(),
+            claimed_at: existing
+                .filter(|lease| lease.holder == actor)
+                .map_or(now, |lease| lease.claimed_at),
+            expires_at: now + ttl,
+        };
+        tx.execute(
+            "INSERT OR REPLACE INTO leases (issue_id, holder, claimed_at, expires_at)
+             VALUES (?, ?, ?, ?)",
+            rusqlite::params![
+                lease.issue_id,
+                lease.holder,
+                lease.claimed_at.to_rfc3339(),
+                lease.expires_at.to_rfc3339()
+            ],
         )?;
-        Ok(())
+
+        let old_status = issue.status.clone();
+        let old_assignee = issue.assignee.clone();
+        if old_status != Status::InProgress || old_assignee.is_none() {
+            ctx.capture(tx, issue_id)?;
+            issue.status = Status::InProgress;
+            issue.assignee = Some(actor.clone());
+            tx.execute(
+                "UPDATE issues SET status = 'in_progress', assignee = ?, content_hash = ?, updated_at = ?
+                 WHERE id = ?",
+                rusqlite::params![
+                    actor,
+                    issue.compute_content_hash(),
+                    now.to_rfc3339(),
+                    issue_id
+                ],
+            )?;
+            if old_status != Status::InProgress {
+                ctx.record_field_change(
+                    EventType::StatusChanged,
+                    issue_id,
+                    Some(old_status.as_str().to_string()),
+                    Some(Status::InProgress.as_str().to_string()),
+                    None,
+                );
+            }
+            if old_assignee.is_none() {
+                ctx.record_field_change(
+                    EventType::AssigneeChanged,
+                    issue_id,
+                    None,
+                    Some(actor.clone()),
+                    None,
+                );
+            }
+            ctx.mark_dirty(issue_id);
+        }
+        ctx.record_field_change(
+            EventType::Claimed,
+            issue_id,
+            None,
+            Some(lease.expires_at.to_rfc3339()),
+            None,
+        );
+        Ok(lease)
     }
 
-    /// Delete a config value.
+    // ========================================================================
+    // Compaction
+    // ========================================================================
+
+    /// Replace an issue's long-form text with a digest.
     ///
-    /// Returns `true` if a value was deleted, `false` if the key didn't exist.
+    /// The digest becomes the description; design, notes and comments are
+    /// cleared. `compaction_level` is incremented, `compacted_at` and
+    /// `compacted_at_commit` are stamped, and `original_size` keeps the size
+    /// from before the first compaction. Records a `compacted` event.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database delete fails.
-    pub fn delete_config(&mut self, key: &str) -> Result<bool> {
-        let deleted = self
-            .conn
-            .execute("DELETE FROM config WHERE key = ?", rusqlite::params![key])?;
-        Ok(deleted > 0)
+    /// Returns an error if the issue doesn't exist or the update fails.
+    pub fn compact_issue(
+        &mut self,
+        id: &str,
+        digest: &str,
+        size: usize,
+        commit: Option<&str>,
+        actor: &str,
+    ) -> Result<Issue> {
+        self.mutate("compact_issue", actor, |tx, ctx| {
+            let mut issue = Self::fetch_issue(tx, id)?
+                .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })?;
+            ctx.capture(tx, id)?;
+
+            let level = issue.compaction_level.unwrap_or(0) + 1;
+            let original_size = issue
+                .original_size
+                .filter(|size| *size > 0)
+                .unwrap_or_else(|| i32::try_from(size).unwrap_or(i32::MAX));
+            let now = Utc::now();
+            issue.description = Some(digest.to_string());
+            issue.design = None;
+            issue.notes = None;
+            let content_hash = issue.compute_content_hash();
+
+            tx.execute(
+                "UPDATE issues
+                 SET description = ?, design = '', notes = '', compaction_level = ?,
+                     compacted_at = ?, compacted_at_commit = ?, original_size = ?,
+                     content_hash = ?, updated_at = ?
+                 WHERE id = ?",
+                rusqlite::params![
+                    digest,
+                    level,
+                    now.to_rfc3339(),
+                    commit,
+                    original_size,
+                    content_hash,
+                    now.to_rfc3339(),
+                    id
+                ],
+            )?;
+            tx.execute("DELETE FROM comments WHERE issue_id = ?", [id])?;
+
+            ctx.record_field_change(
+                EventType::Compacted,
+                id,
+                Some(format!("{size} chars")),
+                Some(format!("{} chars", digest.chars().count())),
+                Some(format!("Compacted to level {level}")),
+            );
+            ctx.mark_dirty(id);
+            Self::fetch_issue(tx, id)?
+                .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })
+        })
     }
 
     // ========================================================================
@@ -2601,10 +3987,22 @@ impl SqliteStorage {
                     ORDER BY id ASC";
 
         let mut stmt = self.conn.prepare_cached(sql)?;
-        let issues = stmt
-            .query_map([], |row| self.issue_from_row(row))?
+        let mut issues = stmt
+            .query_map([], Self::issue_from_row)?
             .collect::<std::result::Result<Vec<_>, _>>()?;
 
+        // Recurrence rules and logged time travel with their issue
+        let mut rules: HashMap<String, Recurrence> = self
+            .list_recurrences()?
+            .into_iter()
+            .map(|record| (record.issue_id.clone(), record.into()))
+            .collect();
+        let mut worklog = self.get_all_worklog()?;
+        for issue in &mut issues {
+            issue.recurrence = rules.remove(&issue.id);
+            issue.worklog = worklog.remove(&issue.id).unwrap_or_default();
+        }
+
         Ok(issues)
     }
 
@@ -2715,6 +4113,59 @@ impl SqliteStorage {
         Ok(ids)
     }
 
+    /// Get the dependencies pointing at an issue as full Dependency structs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_dependents_full(&self, issue_id: &str) -> Result<Vec<crate::model::Dependency>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT issue_id, depends_on_id, type, created_at, created_by, metadata, thread_id
+             FROM dependencies
+             WHERE depends_on_id = ?
+             ORDER BY issue_id",
+        )?;
+
+        let deps = stmt
+            .query_map([issue_id], |row| {
+                let created_at_str: String = row.get(3)?;
+                Ok(crate::model::Dependency {
+                    issue_id: row.get(0)?,
+                    depends_on_id: row.get(1)?,
+                    dep_type: row
+                        .get::<_, Option<String>>(2)?
+                        .and_then(|s| s.parse().ok())
+                        .unwrap_or(crate::model::DependencyType::Blocks),
+                    created_at: parse_datetime(&created_at_str),
+                    created_by: row.get(4)?,
+                    metadata: row.get(5)?,
+                    thread_id: row.get(6)?,
+                })
+            })?
+            .filter_map(std::result::Result::ok)
+            .collect();
+
+        Ok(deps)
+    }
+
+    /// Append audit events carried over from another issue (`br move`).
+    ///
+    /// Actors and timestamps are kept; the events are re-keyed to `issue_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database insert fails.
+    pub fn append_history(&mut self, issue_id: &str, events: &[Event], actor: &str) -> Result<()> {
+        self.mutate("append_history", actor, |_tx, ctx| {
+            ctx.events.extend(events.iter().map(|event| Event {
+                id: 0,
+                issue_id: issue_id.to_string(),
+                ..event.clone()
+            }));
+            Ok(())
+        })
+    }
+
     /// Clear dirty flags for the given issue IDs.
     ///
     /// Call this after successful export to the default JSONL path.
@@ -2951,6 +4402,7 @@ impl SqliteStorage {
             vec![]
         };
         let parent = self.get_parent_id(id)?;
+        let time_spent = self.get_time_rollup()?.remove(id);
 
         Ok(Some(IssueDetails {
             issue,
@@ -2960,6 +4412,7 @@ impl SqliteStorage {
             comments,
             events,
             parent,
+            time_spent,
         }))
     }
 
@@ -2971,8 +4424,7 @@ impl SqliteStorage {
         s.filter(|v| !v.is_empty())
     }
 
-    #[allow(clippy::unused_self)]
-    fn issue_from_row(&self, row: &rusqlite::Row) -> rusqlite::Result<Issue> {
+    fn issue_from_row(row: &rusqlite::Row) -> rusqlite::Result<Issue> {
         Ok(Issue {
             id: row.get(0)?,
             content_hash: row.get::<_, Option<String>>(1)?,
@@ -3028,6 +4480,8 @@ impl SqliteStorage {
             labels: vec![],       // Loaded separately if needed
             dependencies: vec![], // Loaded separately if needed
             comments: vec![],     // Loaded separately if needed
+            recurrence: None,
+            worklog: vec![],
         })
     }
 
@@ -3106,6 +4560,12 @@ pub struct IssueUpdate {
     pub deleted_at: Option<Option<DateTime<Utc>>>,
     pub deleted_by: Option<Option<String>>,
     pub delete_reason: Option<Option<String>>,
+    /// Close outcome to record (`Some(None)` clears it). Reopening an issue
+    /// always clears it.
+    pub close_outcome: Option<Option<CloseOutcome>>,
+    /// Claim the issue for the acting user: the update fails if someone else
+    /// holds a lease on it or it is assigned to someone else.
+    pub claim: bool,
     /// If true, do not rebuild the blocked cache after update.
     /// Caller is responsible for rebuilding cache if needed.
     pub skip_cache_rebuild: bool,
@@ -3134,6 +4594,7 @@ impl IssueUpdate {
             && self.deleted_at.is_none()
             && self.deleted_by.is_none()
             && self.delete_reason.is_none()
+            && self.close_outcome.is_none()
     }
 }
 
@@ -3160,6 +4621,30 @@ pub enum ReadySortPolicy {
     Priority,
     /// Sort by `created_at` ASC only
     Oldest,
+    /// Sort by weighted score (see [`crate::storage::score`]). The query
+    /// returns priority order; callers rank with
+    /// [`SqliteStorage::score_issues`].
+    Score,
+}
+
+impl ReadySortPolicy {
+    /// Order two issues the way `get_ready_issues` does, for merging ready
+    /// lists from several databases. `Score` compares like `Priority`, since
+    /// scores are computed separately.
+    #[must_use]
+    pub fn compare(self, a: &Issue, b: &Issue) -> std::cmp::Ordering {
+        match self {
+            Self::Hybrid => (a.priority.0 > 1)
+                .cmp(&(b.priority.0 > 1))
+                .then_with(|| a.created_at.cmp(&b.created_at)),
+            Self::Priority | Self::Score => a
+                .priority
+                .0
+                .cmp(&b.priority.0)
+                .then_with(|| a.created_at.cmp(&b.created_at)),
+            Self::Oldest => a.created_at.cmp(&b.created_at),
+        }
+    }
 }
 
 fn parse_status(s: Option<&str>) -> Status {
@@ -3232,7 +4717,7 @@ fn query_external_project_capabilities(
     Ok(satisfied)
 }
 
-fn parse_datetime(s: &str) -> DateTime<Utc> {
+pub(super) fn parse_datetime(s: &str) -> DateTime<Utc> {
     if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(s) {
         return dt.with_timezone(&Utc);
     }
@@ -3249,6 +4734,54 @@ fn parse_datetime(s: &str) -> DateTime<Utc> {
     Utc::now()
 }
 
+/// A recurrence rule attached to the latest instance of a recurring series.
+#[derive(Debug, Clone)]
+pub struct RecurrenceRecord {
+    pub issue_id: String,
+    /// Canonical rule text (see [`crate::util::recurrence::RecurrenceRule`]).
+    pub rule: String,
+    /// When the next instance is due; `None` if the rule never fires again.
+    pub next_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub created_by: String,
+}
+
+impl From<RecurrenceRecord> for Recurrence {
+    fn from(record: RecurrenceRecord) -> Self {
+        Self {
+            rule: record.rule,
+            next_at: record.next_at,
+        }
+    }
+}
+
+pub(super) fn fetch_recurrence(
+    conn: &Connection,
+    issue_id: &str,
+) -> Result<Option<RecurrenceRecord>> {
+    let record = conn
+        .query_row(
+            "SELECT issue_id, rule, next_at, created_at, created_by
+             FROM recurrence_rules WHERE issue_id = ?",
+            [issue_id],
+            recurrence_from_row,
+        )
+        .optional()?;
+    Ok(record)
+}
+
+fn recurrence_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<RecurrenceRecord> {
+    let next_at: Option<String> = row.get(2)?;
+    let created_at: String = row.get(3)?;
+    Ok(RecurrenceRecord {
+        issue_id: row.get(0)?,
+        rule: row.get(1)?,
+        next_at: next_at.as_deref().map(parse_datetime),
+        created_at: parse_datetime(&created_at),
+        created_by: row.get(4)?,
+    })
+}
+
 /// Escape special LIKE pattern characters (%, _, \) for literal matching.
 ///
 /// Use with `LIKE ? ESCAPE '\\'` in SQL queries.
@@ -3258,6 +4791,74 @@ fn escape_like_pattern(s: &str) -> String {
         .replace('_', "\\_")
 }
 
+/// Per-column BM25 weights for `issues_fts`, in column order:
+/// `issue_id` (unindexed), title, description, design, acceptance criteria, notes, comments.
+const SEARCH_BM25_WEIGHTS: &str = "0.0, 10.0, 4.0, 2.0, 2.0, 2.0, 1.0";
+
+/// Marker inserted before a matched term in search snippets.
+pub const SEARCH_SNIPPET_OPEN: &str = "[[";
+
+/// Marker inserted after a matched term in search snippets.
+pub const SEARCH_SNIPPET_CLOSE: &str = "]]";
+
+/// Maximum number of tokens in a search snippet.
+const SEARCH_SNIPPET_TOKENS: usize = 16;
+
+/// A search result with its relevance score and best-matching excerpt.
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub issue: Issue,
+    /// BM25 score (lower is more relevant); `None` for substring-only matches.
+    pub score: Option<f64>,
+    /// Excerpt with matched terms wrapped in [`SEARCH_SNIPPET_OPEN`]/[`SEARCH_SNIPPET_CLOSE`].
+    pub snippet: Option<String>,
+}
+
+/// Returns true if the query uses FTS5 syntax that should be passed through verbatim.
+fn is_fts_syntax(query: &str) -> bool {
+    const COLUMNS: [&str; 6] = [
+        "title:",
+        "description:",
+        "design:",
+        "acceptance_criteria:",
+        "notes:",
+        "comments:",
+    ];
+
+    query.contains('"')
+        || query.contains('*')
+        || query.split_whitespace().any(|token| {
+            matches!(token, "AND" | "OR" | "NOT")
+                || token.starts_with("NEAR(")
+                || COLUMNS.iter().any(|col| token.starts_with(col))
+        })
+}
+
+/// Convert a plain query into an FTS5 query where every term must match.
+///
+/// Word-only terms become prefix queries (`"auth"*`); terms containing
+/// punctuation (`C++`, `2.0`) become exact phrases. Returns `None` when no
+/// term contains searchable characters.
+fn plain_fts_query(query: &str) -> Option<String> {
+    let terms: Vec<String> = query
+        .split_whitespace()
+        .filter(|term| term.chars().any(char::is_alphanumeric))
+        .map(|term| {
+            if term.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                format!("\"{term}\"*")
+            } else {
+                format!("\"{term}\"")
+            }
+        })
+        .collect();
+
+    if terms.is_empty() {
+        None
+    } else {
+        Some(terms.join(" "))
+    }
+}
+
 // ============================================================================
 // EXPORT/SYNC METHODS
 // ============================================================================
@@ -3279,6 +4880,9 @@ impl SqliteStorage {
         issue.labels = self.get_labels(id)?;
         issue.dependencies = self.get_dependencies_full(id)?;
         issue.comments = self.get_comments(id)?;
+        issue.recurrence = self.get_recurrence(id)?.map(Into::into);
+        issue.worklog = self.get_worklog(id)?;
+        issue.worklog.retain(|entry| entry.minutes.is_some());
 
         Ok(Some(issue))
     }
@@ -3496,7 +5100,7 @@ impl SqliteStorage {
                      pinned, is_template
                FROM issues WHERE external_ref = ?",
             [external_ref],
-            |row| self.issue_from_row(row),
+            Self::issue_from_row,
         );
         match result {
             Ok(issue) => Ok(Some(issue)),
@@ -3521,7 +5125,7 @@ impl SqliteStorage {
                      pinned, is_template
                FROM issues WHERE content_hash = ?",
             [content_hash],
-            |row| self.issue_from_row(row),
+            Self::issue_from_row,
         );
         match result {
             Ok(issue) => Ok(Some(issue)),
@@ -3619,6 +5223,7 @@ impl SqliteStorage {
                 issue.is_template,
             ],
         )?;
+        Self::refresh_search_index(&self.conn, &issue.id)?;
 
         Ok(rows > 0)
     }
@@ -3661,9 +5266,14 @@ impl SqliteStorage {
         // Add new dependencies
         for dep in dependencies {
             self.conn.execute(
-                "INSERT OR IGNORE INTO dependencies (issue_id, depends_on_id, type, created_at, created_by)
-                 VALUES (?, ?, ?, CURRENT_TIMESTAMP, 'import')",
-                rusqlite::params![issue_id, dep.depends_on_id, dep.dep_type.as_str()],
+                "INSERT OR IGNORE INTO dependencies (issue_id, depends_on_id, type, created_at, created_by, metadata)
+                 VALUES (?, ?, ?, CURRENT_TIMESTAMP, 'import', COALESCE(?, '{}'))",
+                rusqlite::params![
+                    issue_id,
+                    dep.depends_on_id,
+                    dep.dep_type.as_str(),
+                    dep.metadata
+                ],
             )?;
         }
 
@@ -3696,6 +5306,73 @@ impl SqliteStorage {
                 ],
             )?;
         }
+        Self::refresh_search_index(&self.conn, issue_id)?;
+
+        Ok(())
+    }
+
+    /// Sync the recurrence rule for an issue (replace or remove it).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub fn sync_recurrence_for_import(
+        &mut self,
+        issue_id: &str,
+        recurrence: Option<&Recurrence>,
+    ) -> Result<()> {
+        let Some(recurrence) = recurrence else {
+            self.conn.execute(
+                "DELETE FROM recurrence_rules WHERE issue_id = ?",
+                [issue_id],
+            )?;
+            return Ok(());
+        };
+        self.conn.execute(
+            "INSERT INTO recurrence_rules (issue_id, rule, next_at, created_at, created_by)
+             VALUES (?, ?, ?, ?, 'import')
+             ON CONFLICT(issue_id) DO UPDATE SET rule = excluded.rule, next_at = excluded.next_at",
+            rusqlite::params![
+                issue_id,
+                recurrence.rule,
+                recurrence.next_at.map(|dt| dt.to_rfc3339()),
+                Utc::now().to_rfc3339()
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Sync the finished work log entries for an issue (remove existing, add
+    /// new). Running timers are local and left alone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub fn sync_worklog_for_import(
+        &mut self,
+        issue_id: &str,
+        worklog: &[WorkLogEntry],
+    ) -> Result<()> {
+        self.conn.execute(
+            "DELETE FROM worklog WHERE issue_id = ? AND minutes IS NOT NULL",
+            [issue_id],
+        )?;
+
+        for entry in worklog.iter().filter(|entry| entry.minutes.is_some()) {
+            self.conn.execute(
+                "INSERT INTO worklog (issue_id, actor, minutes, note, started_at, created_at)
+                 VALUES (?, ?, ?, ?, ?, ?)",
+                rusqlite::params![
+                    issue_id,
+                    entry.actor,
+                    entry.minutes,
+                    entry.note,
+                    entry.started_at.map(|dt| dt.to_rfc3339()),
+                    entry.created_at.to_rfc3339()
+                ],
+            )?;
+        }
 
         Ok(())
     }
@@ -3724,12 +5401,7 @@ impl crate::validation::DependencyStore for SqliteStorage {
     }
 }
 
-fn insert_comment_row(
-    tx: &Transaction<'_>,
-    issue_id: &str,
-    author: &str,
-    text: &str,
-) -> Result<i64> {
+fn insert_comment_row(tx: &Connection, issue_id: &str, author: &str, text: &str) -> Result<i64> {
     tx.execute(
         "INSERT INTO comments (issue_id, author, text, created_at)
          VALUES (?, ?, ?, CURRENT_TIMESTAMP)",
@@ -3738,7 +5410,112 @@ fn insert_comment_row(
     Ok(tx.last_insert_rowid())
 }
 
-fn fetch_comment(tx: &Transaction<'_>, comment_id: i64) -> Result<Comment> {
+fn find_running_timer(
+    tx: &Connection,
+    issue_id: &str,
+    actor: &str,
+) -> Result<Option<(i64, DateTime<Utc>)>> {
+    let running = tx
+        .query_row(
+            "SELECT id, started_at FROM worklog
+             WHERE issue_id = ? AND actor = ? AND minutes IS NULL
+             ORDER BY id DESC LIMIT 1",
+            rusqlite::params![issue_id, actor],
+            |row| {
+                let started_at: Option<String> = row.get(1)?;
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    started_at.as_deref().map_or_else(Utc::now, parse_datetime),
+                ))
+            },
+        )
+        .optional()?;
+    Ok(running)
+}
+
+fn fetch_lease(conn: &Connection, issue_id: &str) -> Result<Option<Lease>> {
+    let lease = conn
+        .query_row(
+            "SELECT issue_id, holder, claimed_at, expires_at FROM leases WHERE issue_id = ?",
+            [issue_id],
+            lease_from_row,
+        )
+        .optional()?;
+    Ok(lease)
+}
+
+fn lease_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<Lease> {
+    Ok(Lease {
+        issue_id: row.get(0)?,
+        holder: row.get(1)?,
+        claimed_at: parse_datetime(&row.get::<_, String>(2)?),
+        expires_at: parse_datetime(&row.get::<_, String>(3)?),
+    })
+}
+
+fn lease_held(lease: Lease) -> BeadsError {
+    BeadsError::LeaseHeld {
+        id: lease.issue_id,
+        holder: lease.holder,
+        expires_at: lease.expires_at,
+    }
+}
+
+/// Refuse to change an issue for `actor` when someone else holds an
+/// unexpired lease on it.
+fn check_lease(conn: &Connection, issue_id: &str, actor: &str, now: DateTime<Utc>) -> Result<()> {
+    if let Some(lease) = fetch_lease(conn, issue_id)? {
+        if lease.holder != actor && lease.is_active(now) {
+            return Err(lease_held(lease));
+        }
+    }
+    Ok(())
+}
+
+/// Refuse to claim `issue` for `actor` when someone else holds an unexpired
+/// lease on it or it is already assigned to someone else.
+fn check_claimable(
+    conn: &Connection,
+    issue: &Issue,
+    actor: &str,
+    now: DateTime<Utc>,
+) -> Result<()> {
+    check_lease(conn, &issue.id, actor, now)?;
+    if let Some(current) = issue.assignee.as_deref() {
+        if current != actor {
+            return Err(BeadsError::validation(
+                "claim",
+                format!("issue already assigned to {current}"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn fetch_worklog_entry(tx: &Connection, entry_id: i64) -> Result<WorkLogEntry> {
+    tx.query_row(
+        "SELECT id, issue_id, actor, minutes, note, started_at, created_at
+         FROM worklog WHERE id = ?",
+        rusqlite::params![entry_id],
+        worklog_from_row,
+    )
+    .map_err(BeadsError::from)
+}
+
+fn worklog_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<WorkLogEntry> {
+    let started_at: Option<String> = row.get(5)?;
+    Ok(WorkLogEntry {
+        id: row.get(0)?,
+        issue_id: row.get(1)?,
+        actor: row.get(2)?,
+        minutes: row.get(3)?,
+        note: row.get(4)?,
+        started_at: started_at.as_deref().map(parse_datetime),
+        created_at: parse_datetime(&row.get::<_, String>(6)?),
+    })
+}
+
+fn fetch_comment(tx: &Connection, comment_id: i64) -> Result<Comment> {
     tx.query_row(
         "SELECT id, issue_id, author, text, created_at FROM comments WHERE id = ?",
         rusqlite::params![comment_id],
@@ -3826,6 +5603,8 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         }
     }
 
@@ -3878,6 +5657,8 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         };
 
         storage.create_issue(&issue, "tester").unwrap();
@@ -3937,11 +5718,42 @@ mod tests {
 
         assert!(result.is_err());
 
-        // Verify side effects (event) are gone
-        let events = storage.get_events("bd-tx1", 100).unwrap();
-        // Should only have the creation event
-        assert_eq!(events.len(), 1);
-        assert_eq!(events[0].event_type, EventType::Created);
+        // Verify side effects (event) are gone
+        let events = storage.get_events("bd-tx1", 100).unwrap();
+        // Should only have the creation event
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, EventType::Created);
+    }
+
+    #[test]
+    fn test_batch_commits_or_rolls_back_as_one() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let a = make_issue("bd-b1", "First", Status::Open, 2, None, Utc::now(), None);
+        let b = make_issue("bd-b2", "Second", Status::Open, 2, None, Utc::now(), None);
+
+        let result: Result<()> = storage.batch("pair", "tester", |storage| {
+            storage.create_issue(&a, "tester")?;
+            storage.add_label("bd-b1", "kept", "tester")?;
+            Err(BeadsError::Config("Planned failure".to_string()))
+        });
+        assert!(result.is_err());
+        assert!(storage.get_issue("bd-b1").unwrap().is_none());
+        assert!(storage.get_events("bd-b1", 100).unwrap().is_empty());
+
+        storage
+            .batch("pair", "tester", |storage| {
+                storage.create_issue(&a, "tester")?;
+                storage.create_issue(&b, "tester")?;
+                storage.add_dependency("bd-b2", "bd-b1", "blocks", "tester")?;
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(storage.get_dependencies("bd-b2").unwrap(), vec!["bd-b1"]);
+
+        // One undo step covers the whole batch
+        let batches = storage.undo_stack(10, None).unwrap();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].op, "pair");
     }
 
     #[test]
@@ -4053,6 +5865,196 @@ mod tests {
         assert_eq!(blocked_issues[0].1.len(), 1);
     }
 
+    fn close_for_test(storage: &mut SqliteStorage, id: &str) {
+        let update = IssueUpdate {
+            status: Some(Status::Closed),
+            closed_at: Some(Some(Utc::now())),
+            ..Default::default()
+        };
+        storage.update_issue(id, &update, "tester").unwrap();
+    }
+
+    fn blockers_of(storage: &SqliteStorage, id: &str) -> Option<Vec<String>> {
+        storage
+            .get_blocked_issues()
+            .unwrap()
+            .into_iter()
+            .find(|(issue, _)| issue.id == id)
+            .map(|(_, blockers)| blockers)
+    }
+
+    #[test]
+    fn test_conditional_blocks_released_only_on_failure() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+        for id in ["bd-try", "bd-fallback", "bd-try2", "bd-fallback2"] {
+            let issue = make_issue(id, id, Status::Open, 2, None, t1, None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        storage
+            .add_dependency("bd-fallback", "bd-try", "conditional-blocks", "tester")
+            .unwrap();
+        storage
+            .add_dependency("bd-fallback2", "bd-try2", "conditional-blocks", "tester")
+            .unwrap();
+
+        assert_eq!(
+            blockers_of(&storage, "bd-fallback"),
+            Some(vec!["bd-try:if-failed(open)".to_string()])
+        );
+
+        // Closing without a failure outcome keeps the fallback held
+        close_for_test(&mut storage, "bd-try");
+        assert_eq!(
+            blockers_of(&storage, "bd-fallback"),
+            Some(vec!["bd-try:if-failed(closed)".to_string()])
+        );
+
+        // Closing as failed releases it
+        storage
+            .set_close_outcome("bd-try2", Some(CloseOutcome::Failed), "tester")
+            .unwrap();
+        close_for_test(&mut storage, "bd-try2");
+        assert_eq!(blockers_of(&storage, "bd-fallback2"), None);
+    }
+
+    #[test]
+    fn test_close_outcome_recorded_with_close_and_cleared_on_reopen() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+        let issue = make_issue("bd-out", "Try it", Status::Open, 2, None, t1, None);
+        storage.create_issue(&issue, "tester").unwrap();
+        let before = storage.undo_stack(10, None).unwrap().len();
+
+        let close = IssueUpdate {
+            status: Some(Status::Closed),
+            closed_at: Some(Some(Utc::now())),
+            close_outcome: Some(Some(CloseOutcome::Failed)),
+            ..Default::default()
+        };
+        storage.update_issue("bd-out", &close, "tester").unwrap();
+        assert_eq!(
+            storage.get_labels("bd-out").unwrap(),
+            vec![CloseOutcome::Failed.label()]
+        );
+        // Close and outcome land in a single batch
+        assert_eq!(storage.undo_stack(10, None).unwrap().len(), before + 1);
+
+        // Any reopen, not only `br reopen`, drops the outcome
+        let reopen = IssueUpdate {
+            status: Some(Status::Open),
+            closed_at: Some(None),
+            ..Default::default()
+        };
+        storage.update_issue("bd-out", &reopen, "tester").unwrap();
+        assert!(storage.get_labels("bd-out").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_waits_for_gates() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+        for id in [
+            "bd-epic",
+            "bd-kid1",
+            "bd-kid2",
+            "bd-all",
+            "bd-anykid",
+            "bd-a",
+            "bd-b",
+            "bd-either",
+        ] {
+            let issue = make_issue(id, id, Status::Open, 2, None, t1, None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        for kid in ["bd-kid1", "bd-kid2"] {
+            storage
+                .add_dependency(kid, "bd-epic", "parent-child", "tester")
+                .unwrap();
+        }
+        let all = WaitsForGate::AllChildren.to_metadata();
+        let any_kid = WaitsForGate::AnyChildren.to_metadata();
+        let any = WaitsForGate::Any.to_metadata();
+        storage
+            .add_dependency_with_metadata(
+                "bd-all",
+                "bd-epic",
+                "waits-for",
+                all.as_deref(),
+                "tester",
+            )
+            .unwrap();
+        storage
+            .add_dependency_with_metadata(
+                "bd-anykid",
+                "bd-epic",
+                "waits-for",
+                any_kid.as_deref(),
+                "tester",
+            )
+            .unwrap();
+        for target in ["bd-a", "bd-b"] {
+            storage
+                .add_dependency_with_metadata(
+                    "bd-either",
+                    target,
+                    "waits-for",
+                    any.as_deref(),
+                    "tester",
+                )
+                .unwrap();
+        }
+
+        assert_eq!(
+            blockers_of(&storage, "bd-all"),
+            Some(vec!["bd-epic:all-children(0/2)".to_string()])
+        );
+        assert_eq!(
+            blockers_of(&storage, "bd-either").as_ref().map(Vec::len),
+            Some(2)
+        );
+
+        close_for_test(&mut storage, "bd-kid1");
+        close_for_test(&mut storage, "bd-a");
+        assert_eq!(
+            blockers_of(&storage, "bd-all"),
+            Some(vec!["bd-epic:all-children(1/2)".to_string()])
+        );
+        assert_eq!(blockers_of(&storage, "bd-anykid"), None);
+        assert_eq!(blockers_of(&storage, "bd-either"), None);
+
+        close_for_test(&mut storage, "bd-kid2");
+        assert_eq!(blockers_of(&storage, "bd-all"), None);
+    }
+
+    #[test]
+    fn test_ready_sort_policy_compare_matches_sql_order() {
+        let t1 = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let t2 = Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap();
+        let old_low = make_issue("bd-1", "Old P3", Status::Open, 3, None, t1, None);
+        let new_p1 = make_issue("bd-2", "New P1", Status::Open, 1, None, t2, None);
+        let new_p0 = make_issue("bd-3", "New P0", Status::Open, 0, None, t2, None);
+
+        let sorted = |policy: ReadySortPolicy| {
+            let mut issues = vec![old_low.clone(), new_p1.clone(), new_p0.clone()];
+            issues.sort_by(|a, b| policy.compare(a, b));
+            issues.into_iter().map(|i| i.id).collect::<Vec<_>>()
+        };
+        // Hybrid keeps P0/P1 ahead but orders them by age (stable for ties)
+        assert_eq!(
+            sorted(ReadySortPolicy::Hybrid),
+            vec!["bd-2", "bd-3", "bd-1"]
+        );
+        assert_eq!(
+            sorted(ReadySortPolicy::Priority),
+            vec!["bd-3", "bd-2", "bd-1"]
+        );
+        assert_eq!(
+            sorted(ReadySortPolicy::Oldest),
+            vec!["bd-1", "bd-2", "bd-3"]
+        );
+    }
+
     #[test]
     fn test_add_and_remove_labels_sorted() {
         let mut storage = SqliteStorage::open_memory().unwrap();
@@ -4176,6 +6178,8 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         };
         storage.create_issue(&issue, "tester").unwrap();
 
@@ -4245,6 +6249,8 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         };
         storage.create_issue(&issue, "tester").unwrap();
 
@@ -4261,6 +6267,463 @@ mod tests {
         assert_eq!(comments[0], comment);
     }
 
+    #[test]
+    fn test_worklog_timer_and_rollup() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 4, 0, 0, 0).unwrap();
+        for id in ["bd-epic", "bd-child", "bd-other"] {
+            let issue = make_issue(id, id, Status::Open, 2, None, t1, None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        storage
+            .add_dependency("bd-child", "bd-epic", "parent-child", "tester")
+            .unwrap();
+
+        let entry = storage
+            .log_work("bd-child", "alice", 45, Some("triage"))
+            .unwrap();
+        assert_eq!(entry.minutes, Some(45));
+        assert_eq!(entry.note.as_deref(), Some("triage"));
+        storage.log_work("bd-epic", "alice", 15, None).unwrap();
+
+        storage.start_timer("bd-other", "alice").unwrap();
+        assert!(storage.start_timer("bd-other", "alice").is_err());
+        let stopped = storage.stop_timer("bd-other", "alice", None).unwrap();
+        assert_eq!(stopped.minutes, Some(1));
+        assert!(stopped.started_at.is_some());
+        assert!(storage.stop_timer("bd-other", "alice", None).is_err());
+
+        let rollup = storage.get_time_rollup().unwrap();
+        assert_eq!(
+            rollup["bd-epic"],
+            TimeSpent {
+                logged_minutes: 15,
+                total_minutes: 60,
+            }
+        );
+        assert_eq!(rollup["bd-child"].total_minutes, 45);
+        assert_eq!(storage.get_worklog("bd-other").unwrap().len(), 1);
+
+        let events = storage.get_events("bd-child", 10).unwrap();
+        assert_eq!(events[0].event_type, EventType::WorkLogged);
+        assert_eq!(events[0].new_value.as_deref(), Some("45"));
+    }
+
+    #[test]
+    fn test_worklog_round_trips_through_export() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 4, 0, 0, 0).unwrap();
+        let issue = make_issue("bd-1", "Logged", Status::Open, 2, None, t1, None);
+        storage.create_issue(&issue, "tester").unwrap();
+        storage.clear_all_dirty_flags().unwrap();
+
+        storage
+            .log_work("bd-1", "alice", 30, Some("review"))
+            .unwrap();
+        storage.start_timer("bd-1", "bob").unwrap();
+        assert_eq!(storage.get_dirty_issue_ids().unwrap(), vec!["bd-1"]);
+
+        // Only the finished entry is exported
+        let exported = storage.get_all_issues_for_export().unwrap();
+        assert_eq!(exported[0].worklog.len(), 1);
+        assert_eq!(exported[0].worklog[0].minutes, Some(30));
+
+        let mut clone = SqliteStorage::open_memory().unwrap();
+        clone.create_issue(&issue, "tester").unwrap();
+        clone.start_timer("bd-1", "carol").unwrap();
+        clone.upsert_issue_for_import(&exported[0]).unwrap();
+        clone
+            .sync_worklog_for_import("bd-1", &exported[0].worklog)
+            .unwrap();
+        clone
+            .sync_worklog_for_import("bd-1", &exported[0].worklog)
+            .unwrap();
+
+        let worklog = clone.get_worklog("bd-1").unwrap();
+        assert_eq!(worklog.len(), 2, "import is idempotent: {worklog:?}");
+        assert_eq!(clone.get_time_rollup().unwrap()["bd-1"].logged_minutes, 30);
+        assert!(
+            worklog
+                .iter()
+                .any(|entry| entry.actor == "carol" && entry.minutes.is_none()),
+            "the local running timer survives import"
+        );
+    }
+
+    #[test]
+    fn test_claim_lease_conflict_heartbeat_and_expiry() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 4, 0, 0, 0).unwrap();
+        for id in ["bd-1", "bd-2"] {
+            let issue = make_issue(id, id, Status::Open, 2, None, t1, None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        let ttl = chrono::Duration::minutes(30);
+
+        let lease = storage.claim_issue("bd-1", "alice", ttl).unwrap();
+        assert_eq!(lease.holder, "alice");
+        let issue = storage.get_issue("bd-1").unwrap().unwrap();
+        assert_eq!(issue.status, Status::InProgress);
+        assert_eq!(issue.assignee.as_deref(), Some("alice"));
+
+        let err = storage.claim_issue("bd-1", "bob", ttl).unwrap_err();
+        assert!(matches!(err, BeadsError::LeaseHeld { ref holder, .. } if holder == "alice"));
+        assert!(
+            storage
+                .heartbeat("bob", &["bd-1".to_string()], ttl)
+                .is_err()
+        );
+
+        let renewed = storage
+            .heartbeat("alice", &[], chrono::Duration::hours(2))
+            .unwrap();
+        assert_eq!(renewed.len(), 1);
+        assert!(renewed[0].expires_at > lease.expires_at);
+
+        let ready = storage
+            .get_ready_issues(&ReadyFilters::default(), ReadySortPolicy::Oldest)
+            .unwrap();
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].id, "bd-2");
+
+        // A lapsed lease is released on the next sweep or claim
+        storage
+            .claim_issue("bd-2", "alice", chrono::Duration::seconds(-1))
+            .unwrap();
+        let released = storage.release_expired_leases("system").unwrap();
+        assert_eq!(released.len(), 1);
+        let issue = storage.get_issue("bd-2").unwrap().unwrap();
+        assert_eq!(issue.status, Status::Open);
+        assert_eq!(issue.assignee, None);
+        let events = storage.get_events("bd-2", 10).unwrap();
+        assert!(
+            events
+                .iter()
+                .any(|event| event.event_type == EventType::LeaseExpired)
+        );
+        assert!(storage.claim_issue("bd-2", "bob", ttl).is_ok());
+    }
+
+    #[test]
+    fn test_live_lease_blocks_status_and_assignee_changes() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 4, 0, 0, 0).unwrap();
+        let issue = make_issue("bd-1", "Leased", Status::Open, 2, None, t1, None);
+        storage.create_issue(&issue, "tester").unwrap();
+        storage
+            .claim_issue("bd-1", "alice", chrono::Duration::minutes(30))
+            .unwrap();
+
+        let close = IssueUpdate {
+            status: Some(Status::Closed),
+            closed_at: Some(Some(Utc::now())),
+            ..IssueUpdate::default()
+        };
+        let reassign = IssueUpdate {
+            assignee: Some(Some("bob".to_string())),
+            ..IssueUpdate::default()
+        };
+        for update in [&close, &reassign] {
+            let err = storage.update_issue("bd-1", update, "bob").unwrap_err();
+            assert!(matches!(err, BeadsError::LeaseHeld { ref holder, .. } if holder == "alice"));
+        }
+        let issue = storage.get_issue("bd-1").unwrap().unwrap();
+        assert_eq!(issue.status, Status::InProgress);
+        assert_eq!(issue.assignee.as_deref(), Some("alice"));
+
+        // Other fields stay editable
+        let retitle = IssueUpdate {
+            title: Some("Renamed".to_string()),
+            ..IssueUpdate::default()
+        };
+        storage.update_issue("bd-1", &retitle, "bob").unwrap();
+
+        // The holder closing the issue ends the lease
+        storage.update_issue("bd-1", &close, "alice").unwrap();
+        assert!(storage.get_lease("bd-1").unwrap().is_none());
+        let reopen = IssueUpdate {
+            status: Some(Status::Open),
+            closed_at: Some(None),
+            ..IssueUpdate::default()
+        };
+        storage.update_issue("bd-1", &reopen, "bob").unwrap();
+    }
+
+    #[test]
+    fn test_claim_next_hands_out_each_issue_once() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 4, 0, 0, 0).unwrap();
+        for (id, priority) in [("bd-1", 3), ("bd-2", 0), ("bd-3", 1), ("bd-4", 2)] {
+            let issue = make_issue(id, id, Status::Open, priority, None, t1, None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        storage
+            .update_issue(
+                "bd-4",
+                &IssueUpdate {
+                    assignee: Some(Some("carol".to_string())),
+                    ..IssueUpdate::default()
+                },
+                "tester",
+            )
+            .unwrap();
+        let ttl = chrono::Duration::minutes(30);
+        let weights = ScoreWeights::default();
+        let excluded = HashSet::from(["bd-3".to_string()]);
+        let mut next = |actor: &str| {
+            storage
+                .claim_next(
+                    &ReadyFilters::default(),
+                    ReadySortPolicy::Priority,
+                    &weights,
+                    &excluded,
+                    actor,
+                    ttl,
+                )
+                .unwrap()
+                .map(|lease| lease.issue_id)
+        };
+
+        // bd-3 is excluded and bd-4 belongs to carol, so nothing is left
+        assert_eq!(next("alice").as_deref(), Some("bd-2"));
+        assert_eq!(next("bob").as_deref(), Some("bd-1"));
+        assert_eq!(next("dave"), None);
+        // Carol still gets the issue assigned to her
+        assert_eq!(next("carol").as_deref(), Some("bd-4"));
+    }
+
+    #[test]
+    fn test_claim_next_score_policy_uses_weights() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 4, 0, 0, 0).unwrap();
+        for (id, priority) in [("bd-1", 1), ("bd-2", 3)] {
+            let issue = make_issue(id, id, Status::Open, priority, None, t1, None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        storage.add_label("bd-2", "hot", "tester").unwrap();
+        let weights = ScoreWeights {
+            labels: HashMap::from([("hot".to_string(), 100.0)]),
+            ..ScoreWeights::default()
+        };
+        let ttl = chrono::Duration::minutes(30);
+        let mut next = |sort: ReadySortPolicy, actor: &str| {
+            storage
+                .claim_next(
+                    &ReadyFilters::default(),
+                    sort,
+                    &weights,
+                    &HashSet::new(),
+                    actor,
+                    ttl,
+                )
+                .unwrap()
+                .map(|lease| lease.issue_id)
+        };
+
+        // The label weight outranks bd-1's higher priority
+        assert_eq!(
+            next(ReadySortPolicy::Score, "alice").as_deref(),
+            Some("bd-2")
+        );
+        assert_eq!(next(ReadySortPolicy::Score, "bob").as_deref(), Some("bd-1"));
+    }
+
+    #[test]
+    fn test_claim_next_applies_label_and_type_filters() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 4, 0, 0, 0).unwrap();
+        for (id, priority, issue_type) in [
+            ("bd-1", 0, IssueType::Task),
+            ("bd-2", 1, IssueType::Bug),
+            ("bd-3", 2, IssueType::Bug),
+        ] {
+            let mut issue = make_issue(id, id, Status::Open, priority, None, t1, None);
+            issue.issue_type = issue_type;
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        storage.add_label("bd-1", "backend", "tester").unwrap();
+        storage.add_label("bd-3", "backend", "tester").unwrap();
+        let weights = ScoreWeights::default();
+        let ttl = chrono::Duration::minutes(30);
+        let mut next = |filters: &ReadyFilters| {
+            storage
+                .claim_next(
+                    filters,
+                    ReadySortPolicy::Priority,
+                    &weights,
+                    &HashSet::new(),
+                    "alice",
+                    ttl,
+                )
+                .unwrap()
+                .map(|lease| lease.issue_id)
+        };
+
+        let backend_bugs = ReadyFilters {
+            labels_and: vec!["backend".to_string()],
+            types: Some(vec![IssueType::Bug]),
+            ..ReadyFilters::default()
+        };
+        assert_eq!(next(&backend_bugs).as_deref(), Some("bd-3"));
+        assert_eq!(next(&backend_bugs), None);
+        let bugs = ReadyFilters {
+            types: Some(vec![IssueType::Bug]),
+            ..ReadyFilters::default()
+        };
+        assert_eq!(next(&bugs).as_deref(), Some("bd-2"));
+    }
+
+    #[test]
+    fn test_claim_next_lease_expires_after_ttl() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 4, 0, 0, 0).unwrap();
+        let issue = make_issue("bd-1", "Only", Status::Open, 2, None, t1, None);
+        storage.create_issue(&issue, "tester").unwrap();
+        let weights = ScoreWeights::default();
+        let next = |storage: &mut SqliteStorage, actor: &str, ttl: chrono::Duration| {
+            storage
+                .claim_next(
+                    &ReadyFilters::default(),
+                    ReadySortPolicy::Hybrid,
+                    &weights,
+                    &HashSet::new(),
+                    actor,
+                    ttl,
+                )
+                .unwrap()
+        };
+
+        let before = Utc::now();
+        let lease = next(&mut storage, "alice", chrono::Duration::hours(2)).unwrap();
+        assert!(lease.expires_at >= before + chrono::Duration::hours(2));
+        assert!(lease.expires_at <= Utc::now() + chrono::Duration::hours(2));
+        // A live lease keeps the issue from anyone else
+        assert!(next(&mut storage, "bob", chrono::Duration::minutes(30)).is_none());
+
+        // Once a lease has lapsed, the next claim releases and takes the issue
+        let issue = make_issue("bd-2", "Second", Status::Open, 2, None, t1, None);
+        storage.create_issue(&issue, "tester").unwrap();
+        let lapsed = next(&mut storage, "alice", chrono::Duration::seconds(-1)).unwrap();
+        assert_eq!(lapsed.issue_id, "bd-2");
+        let taken = next(&mut storage, "bob", chrono::Duration::minutes(30)).unwrap();
+        assert_eq!(taken.issue_id, "bd-2");
+        assert_eq!(taken.holder, "bob");
+        let issue = storage.get_issue("bd-2").unwrap().unwrap();
+        assert_eq!(issue.assignee.as_deref(), Some("bob"));
+        assert_eq!(storage.get_lease("bd-1").unwrap().unwrap().holder, "alice");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_blocking_hook_failure_rolls_back_mutation() {
+        use crate::hooks::{FailurePolicy, Hook, HookAction};
+
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 4, 0, 0, 0).unwrap();
+        let failing_hook = |on_failure| Hook {
+            name: "on_create".to_string(),
+            trigger: "on_create".to_string(),
+            action: HookAction::Command("cat >/dev/null; exit 1".to_string()),
+            labels: Vec::new(),
+            types: Vec::new(),
+            timeout_secs: 5,
+            on_failure,
+        };
+
+        storage.set_hooks(Hooks::new(vec![failing_hook(FailurePolicy::Block)]));
+        let issue = make_issue("bd-1", "Hooked", Status::Open, 2, None, t1, None);
+        let err = storage.create_issue(&issue, "tester").unwrap_err();
+        assert!(matches!(err, BeadsError::HookRejected { .. }));
+        assert!(storage.get_issue("bd-1").unwrap().is_none());
+        assert!(storage.get_all_events(0).unwrap().is_empty());
+
+        storage.set_hooks(Hooks::new(vec![failing_hook(FailurePolicy::Warn)]));
+        storage.create_issue(&issue, "tester").unwrap();
+        assert!(storage.get_issue("bd-1").unwrap().is_some());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_blocking_hook_is_told_the_write_lock_is_held() {
+        use crate::hooks::{FailurePolicy, HOOK_LOCKED_ENV, Hook, HookAction};
+
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 4, 0, 0, 0).unwrap();
+        storage.set_hooks(Hooks::new(vec![Hook {
+            name: "on_create".to_string(),
+            trigger: "on_create".to_string(),
+            action: HookAction::Command(format!("cat >/dev/null; test \"${HOOK_LOCKED_ENV}\" = 1")),
+            labels: Vec::new(),
+            types: Vec::new(),
+            timeout_secs: 5,
+            on_failure: FailurePolicy::Block,
+        }]));
+        let issue = make_issue("bd-1", "Hooked", Status::Open, 2, None, t1, None);
+        storage.create_issue(&issue, "tester").unwrap();
+    }
+
+    #[test]
+    fn test_write_fails_fast_while_another_connection_holds_the_lock() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("beads.db");
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 4, 0, 0, 0).unwrap();
+        let mut holder = SqliteStorage::open(&path).unwrap();
+        let mut reentrant = SqliteStorage::open_with_timeout(&path, Some(0)).unwrap();
+
+        holder
+            .batch("hooked", "tester", |holder| {
+                let issue = make_issue("bd-1", "Outer", Status::Open, 2, None, t1, None);
+                holder.create_issue(&issue, "tester")?;
+
+                // What a `br` started by a blocking hook sees: reads work, writes
+                // fail at once instead of waiting for the lock
+                assert!(reentrant.get_issue("bd-1").unwrap().is_none());
+                let started = std::time::Instant::now();
+                let inner = make_issue("bd-2", "Inner", Status::Open, 2, None, t1, None);
+                let err = reentrant.create_issue(&inner, "hook").unwrap_err();
+                assert!(matches!(err, BeadsError::DatabaseLocked { .. }), "{err}");
+                assert!(started.elapsed() < Duration::from_secs(1));
+                Ok(())
+            })
+            .unwrap();
+
+        assert!(reentrant.get_issue("bd-1").unwrap().is_some());
+        assert!(reentrant.get_issue("bd-2").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_compact_issue_keeps_first_original_size() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 4, 0, 0, 0).unwrap();
+        let mut issue = make_issue("bd-old", "Old work", Status::Closed, 2, None, t1, None);
+        issue.design = Some("Long design".to_string());
+        storage.create_issue(&issue, "tester").unwrap();
+        storage.add_comment("bd-old", "alice", "Done.").unwrap();
+
+        let compacted = storage
+            .compact_issue("bd-old", "Digest", 1200, Some("abc123"), "tester")
+            .unwrap();
+        assert_eq!(compacted.description.as_deref(), Some("Digest"));
+        assert!(compacted.design.is_none());
+        assert_eq!(compacted.compaction_level, Some(1));
+        assert_eq!(compacted.original_size, Some(1200));
+        assert_eq!(compacted.compacted_at_commit.as_deref(), Some("abc123"));
+        assert_eq!(
+            compacted.content_hash,
+            Some(compacted.compute_content_hash())
+        );
+        assert!(storage.get_comments("bd-old").unwrap().is_empty());
+
+        let again = storage
+            .compact_issue("bd-old", "Shorter", 6, None, "tester")
+            .unwrap();
+        assert_eq!(again.compaction_level, Some(2));
+        assert_eq!(again.original_size, Some(1200));
+
+        let events = storage.get_events("bd-old", 10).unwrap();
+        assert_eq!(events[0].event_type, EventType::Compacted);
+    }
+
     #[test]
     fn test_add_comment_marks_dirty() {
         let mut storage = SqliteStorage::open_memory().unwrap();
@@ -4306,6 +6769,8 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         };
         storage.create_issue(&issue, "tester").unwrap();
 
@@ -4700,6 +7165,47 @@ mod tests {
         assert_eq!(results[0].id, "bd-s1");
     }
 
+    #[test]
+    fn test_search_index_tracks_updates_and_imports() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let issue = make_issue("bd-f1", "Original wording", Status::Open, 2, None, t1, None);
+        storage.create_issue(&issue, "tester").unwrap();
+
+        let update = IssueUpdate {
+            title: Some("Replacement wording".to_string()),
+            design: Some(Some("use a lockfree queue".to_string())),
+            ..IssueUpdate::default()
+        };
+        storage.update_issue("bd-f1", &update, "tester").unwrap();
+
+        let filters = ListFilters::default();
+        assert!(
+            storage
+                .search_issues("original", &filters)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            storage.search_issues("lockfree", &filters).unwrap().len(),
+            1
+        );
+
+        let mut imported = make_issue("bd-f2", "Imported item", Status::Open, 2, None, t1, None);
+        imported.notes = Some("zeppelin".to_string());
+        storage.upsert_issue_for_import(&imported).unwrap();
+        assert_eq!(
+            storage.search_issues("zeppelin", &filters).unwrap().len(),
+            1
+        );
+
+        assert_eq!(storage.rebuild_search_index().unwrap(), 2);
+        assert_eq!(
+            storage.search_issues("zeppelin", &filters).unwrap().len(),
+            1
+        );
+    }
+
     #[test]
     fn test_list_issues_filter_by_updated_date() {
         let mut storage = SqliteStorage::open_memory().unwrap();
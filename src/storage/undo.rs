@@ -0,0 +1,1067 @@
+//! Undo and redo of mutation batches.
+//!
+//! Each [`SqliteStorage::mutate`] call whose operation captures the issues
+//! it touches (create, update, delete, labels, dependencies, comments) is
+//! recorded as one batch in `mutation_batches`. The batch keeps the full
+//! image of each of those issues (row, labels, dependencies, comments and
+//! recurrence rule) from before and after the change in `mutation_images`,
+//! and its events point at it through `events.batch_id`.
+//!
+//! Undoing a batch restores its before images as a new `undo` batch, once
+//! it has checked that nothing changed those issues since. Redo restores
+//! the before images of that `undo` batch. A restored issue that is no
+//! longer `in_progress` under its lease holder loses the lease. Timers and
+//! work logs are not captured and cannot be undone.
+//!
+//! Only the latest [`UNDO_RETENTION`] batches are kept. Batches made by
+//! [`SYSTEM_ACTOR`] (such as lease expiry) stay off the default stacks.
+
+use super::history::{FieldChange, field_changes};
+use super::sqlite::{MutationContext, fetch_recurrence, parse_datetime};
+use crate::error::{BeadsError, Result};
+use crate::model::{Comment, Dependency, DependencyType, EventType, Issue, Status};
+use crate::storage::SqliteStorage;
+use chrono::{DateTime, Utc};
+use rusqlite::Connection;
+use serde::Serialize;
+
+/// Op name of the batches written by `br undo`.
+pub const UNDO_OP: &str = "undo";
+/// Op name of the batches written by `br undo --redo`.
+pub const REDO_OP: &str = "redo";
+
+/// Actor of changes `br` makes on its own (lease expiry, merge deletions).
+/// Their batches are only undone when asked for with `--actor system`.
+pub const SYSTEM_ACTOR: &str = "system";
+
+/// Number of most recent batches whose images are kept for undo.
+pub const UNDO_RETENTION: i64 = 1000;
+
+/// Delete reason of issues whose creation was undone.
+const UNDONE_REASON: &str = "undone";
+
+/// Issue columns written when restoring an image, `id` first.
+const IMAGE_COLUMNS: [&str; 36] = [
+    "id",
+    "content_hash",
+    "title",
+    "description",
+    "design",
+    "acceptance_criteria",
+    "notes",
+    "status",
+    "priority",
+    "issue_type",
+    "assignee",
+    "owner",
+    "estimated_minutes",
+    "created_at",
+    "created_by",
+    "updated_at",
+    "closed_at",
+    "close_reason",
+    "closed_by_session",
+    "due_at",
+    "defer_until",
+    "external_ref",
+    "source_system",
+    "source_repo",
+    "deleted_at",
+    "deleted_by",
+    "delete_reason",
+    "original_type",
+    "compaction_level",
+    "compacted_at",
+    "compacted_at_commit",
+    "original_size",
+    "sender",
+    "ephemeral",
+    "pinned",
+    "is_template",
+];
+
+/// One undoable mutation.
+#[derive(Debug, Clone, Serialize)]
+pub struct MutationBatch {
+    pub id: i64,
+    pub op: String,
+    pub actor: String,
+    pub created_at: DateTime<Utc>,
+    /// The batch this undo or redo restored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reverts: Option<i64>,
+}
+
+/// A batch that was (or with `--dry-run`, would be) reverted, with the
+/// field-level changes that restored it.
+#[derive(Debug, Clone, Serialize)]
+pub struct Reversal {
+    pub batch: MutationBatch,
+    pub changes: Vec<FieldChange>,
+}
+
+/// The images of one issue in a batch (`None` where it did not exist).
+struct BatchImage {
+    issue_id: String,
+    before: Option<Issue>,
+    after: Option<Issue>,
+}
+
+/// One change made while restoring an image, recorded as an event.
+struct Change {
+    event_type: EventType,
+    old_value: Option<String>,
+    new_value: Option<String>,
+    comment: Option<String>,
+}
+
+impl Change {
+    fn new(
+        event_type: EventType,
+        old_value: Option<String>,
+        new_value: Option<String>,
+        comment: impl Into<String>,
+    ) -> Self {
+        Self {
+            event_type,
+            old_value,
+            new_value,
+            comment: Some(comment.into()),
+        }
+    }
+}
+
+impl SqliteStorage {
+    /// The batches `br undo` reverts next, newest first: changes and redos
+    /// that have not been undone, optionally only those made by `actor`.
+    /// Without `actor`, [`SYSTEM_ACTOR`] batches are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn undo_stack(&self, steps: usize, actor: Option<&str>) -> Result<Vec<MutationBatch>> {
+        self.query_batches(
+            "SELECT id, op, actor, created_at, reverts FROM mutation_batches
+             WHERE op != ?1 AND reverted_at IS NULL
+               AND (actor = ?3 OR (?3 IS NULL AND actor != ?5))
+             ORDER BY id DESC LIMIT ?4",
+            steps,
+            actor,
+        )
+    }
+
+    /// The undo batches `br undo --redo` reverts next, newest first. Any
+    /// change made after an undo clears it from the stack, except those made
+    /// by [`SYSTEM_ACTOR`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn redo_stack(&self, steps: usize, actor: Option<&str>) -> Result<Vec<MutationBatch>> {
+        self.query_batches(
+            "SELECT id, op, actor, created_at, reverts FROM mutation_batches
+             WHERE op = ?1 AND reverted_at IS NULL AND (?3 IS NULL OR actor = ?3)
+               AND id > (SELECT COALESCE(MAX(id), 0) FROM mutation_batches
+                         WHERE op NOT IN (?1, ?2) AND actor != ?5)
+             ORDER BY id DESC LIMIT ?4",
+            steps,
+            actor,
+        )
+    }
+
+    fn query_batches(
+        &self,
+        sql: &str,
+        steps: usize,
+        actor: Option<&str>,
+    ) -> Result<Vec<MutationBatch>> {
+        let limit = i64::try_from(steps).unwrap_or(i64::MAX);
+        let mut stmt = self.conn.prepare(sql)?;
+        let batches = stmt
+            .query_map(
+                rusqlite::params![UNDO_OP, REDO_OP, actor, limit, SYSTEM_ACTOR],
+                |row| {
+                    Ok(MutationBatch {
+                        id: row.get(0)?,
+                        op: row.get(1)?,
+                        actor: row.get(2)?,
+                        created_at: parse_datetime(&row.get::<_, String>(3)?),
+                        reverts: row.get(4)?,
+                    })
+                },
+            )?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(batches)
+    }
+
+    /// Restore the before images of `batches`, in order, recording each as
+    /// its own `op` batch ([`UNDO_OP`] or [`REDO_OP`]) in one transaction.
+    /// With `dry_run` the changes are worked out and rolled back.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an issue changed after its batch was recorded, or
+    /// a database operation fails. Nothing is reverted then.
+    pub fn revert_batches(
+        &mut self,
+        batches: &[MutationBatch],
+        op: &str,
+        actor: &str,
+        dry_run: bool,
+    ) -> Result<Vec<Reversal>> {
+        let tx = match self
+            .conn
+            .transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)
+        {
+            Ok(tx) => tx,
+            Err(err) => return Err(self.write_lock_error(err)),
+        };
+
+        let mut reversals = Vec::new();
+        let mut payloads = Vec::new();
+        for batch in batches {
+            let mut ctx = MutationContext::new(op, actor);
+            ctx.reverts = Some(batch.id);
+            Self::restore_batch(&tx, &mut ctx, batch)?;
+            reversals.push(Reversal {
+                batch: batch.clone(),
+                changes: field_changes(&ctx.events),
+            });
+            if !dry_run {
+                payloads.extend(Self::finish_mutation(&tx, ctx, &self.hooks)?);
+            }
+        }
+        if dry_run {
+            // Dropping the transaction rolls the preview back
+            return Ok(reversals);
+        }
+
+        tx.commit()?;
+        self.hooks.run_warning(&payloads);
+        Ok(reversals)
+    }
+
+    /// An issue with its labels, dependencies and comments, read on `conn`.
+    pub(super) fn load_image(conn: &Connection, issue_id: &str) -> Result<Option<Issue>> {
+        let Some(mut issue) = Self::fetch_issue(conn, issue_id)? else {
+            return Ok(None);
+        };
+
+        let mut stmt =
+            conn.prepare_cached("SELECT label FROM labels WHERE issue_id = ? ORDER BY label")?;
+        issue.labels = stmt
+            .query_map([issue_id], |row| row.get(0))?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let mut stmt = conn.prepare_cached(
+            "SELECT depends_on_id, type, created_at, created_by, metadata, thread_id
+             FROM dependencies WHERE issue_id = ? ORDER BY depends_on_id",
+        )?;
+        issue.dependencies = stmt
+            .query_map([issue_id], |row| {
+                Ok(Dependency {
+                    issue_id: issue_id.to_string(),
+                    depends_on_id: row.get(0)?,
+                    dep_type: row
+                        .get::<_, Option<String>>(1)?
+                        .and_then(|s| s.parse().ok())
+                        .unwrap_or(DependencyType::Blocks),
+                    created_at: parse_datetime(&row.get::<_, String>(2)?),
+                    created_by: row.get(3)?,
+                    metadata: row.get(4)?,
+                    thread_id: row.get(5)?,
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let mut stmt = conn.prepare_cached(
+            "SELECT id, author, text, created_at FROM comments
+             WHERE issue_id = ? ORDER BY created_at, id",
+        )?;
+        issue.comments = stmt
+            .query_map([issue_id], |row| {
+                Ok(Comment {
+                    id: row.get(0)?,
+                    issue_id: issue_id.to_string(),
+                    author: row.get(1)?,
+                    body: row.get(2)?,
+                    created_at: parse_datetime(&row.get::<_, String>(3)?),
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        issue.recurrence = fetch_recurrence(conn, issue_id)?.map(Into::into);
+
+        Ok(Some(issue))
+    }
+
+    /// Write the batch of a finished mutation: the before and after images
+    /// of each captured issue that changed. Returns the batch ID, or `None`
+    /// when nothing captured changed. An undo or redo also marks the batch
+    /// it restored as reverted.
+    pub(super) fn record_batch(conn: &Connection, ctx: &MutationContext) -> Result<Option<i64>> {
+        let mut images = Vec::new();
+        for (issue_id, before) in &ctx.before_images {
+            let after = Self::load_image(conn, issue_id)?;
+            if after != *before {
+                images.push((issue_id, before, after));
+            }
+        }
+        let now = Utc::now().to_rfc3339();
+        if let Some(reverted) = ctx.reverts {
+            conn.execute(
+                "UPDATE mutation_batches SET reverted_at = ? WHERE id = ?",
+                rusqlite::params![now, reverted],
+            )?;
+        }
+        if images.is_empty() {
+            return Ok(None);
+        }
+
+        conn.execute(
+            "INSERT INTO mutation_batches (op, actor, reverts, created_at) VALUES (?, ?, ?, ?)",
+            rusqlite::params![ctx.op_name, ctx.actor, ctx.reverts, now],
+        )?;
+        let batch_id = conn.last_insert_rowid();
+        // Images of older batches go with them
+        conn.execute(
+            "DELETE FROM mutation_batches WHERE id <= ?",
+            [batch_id - UNDO_RETENTION],
+        )?;
+        for (issue_id, before, after) in images {
+            conn.execute(
+                "INSERT INTO mutation_images (batch_id, issue_id, before_image, after_image)
+                 VALUES (?, ?, ?, ?)",
+                rusqlite::params![
+                    batch_id,
+                    issue_id,
+                    before.as_ref().map(serde_json::to_string).transpose()?,
+                    after.as_ref().map(serde_json::to_string).transpose()?
+                ],
+            )?;
+        }
+        Ok(Some(batch_id))
+    }
+
+    fn batch_images(conn: &Connection, batch_id: i64) -> Result<Vec<BatchImage>> {
+        let mut stmt = conn.prepare(
+            "SELECT issue_id, before_image, after_image FROM mutation_images
+             WHERE batch_id = ? ORDER BY issue_id",
+        )?;
+        let rows = stmt
+            .query_map([batch_id], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, Option<String>>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                ))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        rows.into_iter()
+            .map(|(issue_id, before, after)| {
+                Ok(BatchImage {
+                    issue_id,
+                    before: before.as_deref().map(serde_json::from_str).transpose()?,
+                    after: after.as_deref().map(serde_json::from_str).transpose()?,
+                })
+            })
+            .collect()
+    }
+
+    /// Put every issue of `batch` back to its before image.
+    fn restore_batch(
+        conn: &Connection,
+        ctx: &mut MutationContext,
+        batch: &MutationBatch,
+    ) -> Result<()> {
+        for image in Self::batch_images(conn, batch.id)? {
+            let current = Self::load_image(conn, &image.issue_id)?;
+            if !same_state(current.as_ref(), image.after.as_ref()) {
+                return Err(BeadsError::validation(
+                    "undo",
+                    format!(
+                        "{} changed after batch {} ({} by {}); undo the later changes first",
+                        image.issue_id, batch.id, batch.op, batch.actor
+                    ),
+                ));
+            }
+            if same_state(current.as_ref(), image.before.as_ref()) {
+                continue;
+            }
+            ctx.capture(conn, &image.issue_id)?;
+            for change in restore_changes(current.as_ref(), image.before.as_ref()) {
+                ctx.record_field_change(
+                    change.event_type,
+                    &image.issue_id,
+                    change.old_value,
+                    change.new_value,
+                    change.comment,
+                );
+            }
+            let now = Utc::now();
+            match &image.before {
+                Some(before) => write_image(conn, before, now)?,
+                // Undoing a create leaves a tombstone so that sync removes it
+                None => {
+                    conn.execute(
+                        "UPDATE issues SET status = 'tombstone', deleted_at = ?, deleted_by = ?,
+                             delete_reason = ?, original_type = issue_type, updated_at = ?
+                         WHERE id = ?",
+                        rusqlite::params![
+                            now.to_rfc3339(),
+                            ctx.actor,
+                            UNDONE_REASON,
+                            now.to_rfc3339(),
+                            image.issue_id
+                        ],
+                    )?;
+                    conn.execute(
+                        "DELETE FROM recurrence_rules WHERE issue_id = ?",
+                        [image.issue_id.as_str()],
+                    )?;
+                }
+            }
+            // A lease only stands for an issue in progress under its holder
+            conn.execute(
+                "DELETE FROM leases WHERE issue_id = ?1 AND holder IS NOT
+                     (SELECT assignee FROM issues WHERE id = ?1 AND status = 'in_progress')",
+                [image.issue_id.as_str()],
+            )?;
+            ctx.mark_dirty(&image.issue_id);
+            ctx.invalidate_cache();
+        }
+        Ok(())
+    }
+}
+
+/// Whether two images hold the same content. Timestamps every write bumps
+/// and row details that a re-import rewrites are left out.
+fn same_state(a: Option<&Issue>, b: Option<&Issue>) -> bool {
+    a.map(comparable) == b.map(comparable)
+}
+
+fn comparable(issue: &Issue) -> Issue {
+    let mut issue = issue.clone();
+    let created_at = issue.created_at;
+    issue.updated_at = created_at;
+    issue.content_hash = None;
+    for dep in &mut issue.dependencies {
+        dep.created_at = created_at;
+        dep.created_by = None;
+    }
+    for comment in &mut issue.comments {
+        comment.id = 0;
+    }
+    issue
+}
+
+/// The events that describe restoring `current` to `target`.
+#[allow(clippy::too_many_lines)]
+fn restore_changes(current: Option<&Issue>, target: Option<&Issue>) -> Vec<Change> {
+    let (from, to) = match (current, target) {
+        (Some(from), Some(to)) => (from, to),
+        (None, Some(to)) => {
+            return vec![Change::new(
+                EventType::Created,
+                None,
+                None,
+                format!("Created issue: {}", to.title),
+            )];
+        }
+        (Some(from), None) if from.status != Status::Tombstone => {
+            return vec![Change::new(
+                EventType::Deleted,
+                Some(from.status.as_str().to_string()),
+                Some(Status::Tombstone.as_str().to_string()),
+                format!("Deleted issue: {UNDONE_REASON}"),
+            )];
+        }
+        _ => return Vec::new(),
+    };
+
+    let mut changes = Vec::new();
+    if from.status != to.status {
+        let event_type = if to.status == Status::Tombstone {
+            EventType::Deleted
+        } else {
+            EventType::StatusChanged
+        };
+        changes.push(Change {
+            event_type,
+            old_value: Some(from.status.as_str().to_string()),
+            new_value: Some(to.status.as_str().to_string()),
+            comment: None,
+        });
+    }
+    if from.title != to.title {
+        changes.push(Change::new(
+            EventType::Updated,
+            Some(from.title.clone()),
+            Some(to.title.clone()),
+            "Title changed",
+        ));
+    }
+    if from.priority != to.priority {
+        changes.push(Change {
+            event_type: EventType::PriorityChanged,
+            old_value: Some(from.priority.0.to_string()),
+            new_value: Some(to.priority.0.to_string()),
+            comment: None,
+        });
+    }
+    if from.assignee != to.assignee {
+        changes.push(Change {
+            event_type: EventType::AssigneeChanged,
+            old_value: from.assignee.clone(),
+            new_value: to.assignee.clone(),
+            comment: None,
+        });
+    }
+
+    let time = |value: Option<DateTime<Utc>>| value.map(|dt| dt.to_rfc3339());
+    let minutes = |value: Option<i32>| value.map(|m| m.to_string());
+    let fields = [
+        (
+            "description",
+            from.description.clone(),
+            to.description.clone(),
+        ),
+        ("design", from.design.clone(), to.design.clone()),
+        (
+            "acceptance_criteria",
+            from.acceptance_criteria.clone(),
+            to.acceptance_criteria.clone(),
+        ),
+        ("notes", from.notes.clone(), to.notes.clone()),
+        (
+            "issue_type",
+            Some(from.issue_type.as_str().to_string()),
+            Some(to.issue_type.as_str().to_string()),
+        ),
+        ("owner", from.owner.clone(), to.owner.clone()),
+        (
+            "estimated_minutes",
+            minutes(from.estimated_minutes),
+            minutes(to.estimated_minutes),
+        ),
+        (
+            "external_ref",
+            from.external_ref.clone(),
+            to.external_ref.clone(),
+        ),
+        (
+            "close_reason",
+            from.close_reason.clone(),
+            to.close_reason.clone(),
+        ),
+        ("due_at", time(from.due_at), time(to.due_at)),
+        ("defer_until", time(from.defer_until), time(to.defer_until)),
+        (
+            "recurrence",
+            from.recurrence.as_ref().map(|r| r.rule.clone()),
+            to.recurrence.as_ref().map(|r| r.rule.clone()),
+        ),
+    ];
+    for (field, old_value, new_value) in fields {
+        if old_value != new_value {
+            changes.push(Change::new(
+                EventType::Updated,
+                old_value,
+                new_value,
+                format!("Updated field: {field}"),
+            ));
+        }
+    }
+
+    for label in from
+        .labels
+        .iter()
+        .filter(|label| !to.labels.contains(label))
+    {
+        changes.push(Change::new(
+            EventType::LabelRemoved,
+            Some(label.clone()),
+            None,
+            format!("Removed label {label}"),
+        ));
+    }
+    for label in to
+        .labels
+        .iter()
+        .filter(|label| !from.labels.contains(label))
+    {
+        changes.push(Change::new(
+            EventType::LabelAdded,
+            None,
+            Some(label.clone()),
+            format!("Added label {label}"),
+        ));
+    }
+
+    let has_dep = |issue: &Issue, dep: &Dependency| {
+        issue
+            .dependencies
+            .iter()
+            .any(|other| other.depends_on_id == dep.depends_on_id && other.dep_type == dep.dep_type)
+    };
+    for dep in from.dependencies.iter().filter(|dep| !has_dep(to, dep)) {
+        changes.push(Change::new(
+            EventType::DependencyRemoved,
+            Some(dep.depends_on_id.clone()),
+            None,
+            format!(
+                "Removed dependency on {} ({})",
+                dep.depends_on_id, dep.dep_type
+            ),
+        ));
+    }
+    for dep in to.dependencies.iter().filter(|dep| !has_dep(from, dep)) {
+        changes.push(Change::new(
+            EventType::DependencyAdded,
+            None,
+            Some(dep.depends_on_id.clone()),
+            format!(
+                "Added dependency on {} ({})",
+                dep.depends_on_id, dep.dep_type
+            ),
+        ));
+    }
+
+    let has_comment =
+        |issue: &Issue, id: i64| issue.comments.iter().any(|comment| comment.id == id);
+    for comment in from.comments.iter().filter(|c| !has_comment(to, c.id)) {
+        changes.push(Change::new(
+            EventType::Updated,
+            Some(comment.body.clone()),
+            None,
+            "Updated field: comments",
+        ));
+    }
+    for comment in to.comments.iter().filter(|c| !has_comment(from, c.id)) {
+        changes.push(Change {
+            event_type: EventType::Commented,
+            old_value: None,
+            new_value: None,
+            comment: Some(comment.body.clone()),
+        });
+    }
+    changes
+}
+
+/// Overwrite the issue row, labels, dependencies, comments and recurrence
+/// rule with `image`.
+#[allow(clippy::too_many_lines)]
+fn write_image(conn: &Connection, image: &Issue, now: DateTime<Utc>) -> Result<()> {
+    let issue = Issue {
+        updated_at: now,
+        ..image.clone()
+    };
+    let time = |value: Option<DateTime<Utc>>| value.map(|dt| dt.to_rfc3339());
+    let sql = format!(
+        "INSERT INTO issues ({}) VALUES ({}) ON CONFLICT(id) DO UPDATE SET {}",
+        IMAGE_COLUMNS.join(", "),
+        ["?"; IMAGE_COLUMNS.len()].join(", "),
+        IMAGE_COLUMNS[1..]
+            .iter()
+            .map(|column| format!("{column} = excluded.{column}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    // Text columns bd scans as strings are stored as '' rather than NULL
+    conn.execute(
+        &sql,
+        rusqlite::params![
+            issue.id,
+            issue.compute_content_hash(),
+            issue.title,
+            issue.description.as_deref().unwrap_or(""),
+            issue.design.as_deref().unwrap_or(""),
+            issue.acceptance_criteria.as_deref().unwrap_or(""),
+            issue.notes.as_deref().unwrap_or(""),
+            issue.status.as_str(),
+            issue.priority.0,
+            issue.issue_type.as_str(),
+            issue.assignee,
+            issue.owner,
+            issue.estimated_minutes,
+            issue.created_at.to_rfc3339(),
+            issue.created_by,
+            issue.updated_at.to_rfc3339(),
+            time(issue.closed_at),
+            issue.close_reason,
+            issue.closed_by_session,
+            time(issue.due_at),
+            time(issue.defer_until),
+            issue.external_ref,
+            issue.source_system,
+            issue.source_repo.as_deref().unwrap_or("."),
+            time(issue.deleted_at),
+            issue.deleted_by,
+            issue.delete_reason,
+            issue.original_type,
+            issue.compaction_level.unwrap_or(0),
+            time(issue.compacted_at),
+            issue.compacted_at_commit,
+            issue.original_size,
+            issue.sender,
+            issue.ephemeral,
+            issue.pinned,
+            issue.is_template,
+        ],
+    )?;
+
+    conn.execute("DELETE FROM labels WHERE issue_id = ?", [issue.id.as_str()])?;
+    for label in &issue.labels {
+        conn.execute(
+            "INSERT INTO labels (issue_id, label) VALUES (?, ?)",
+            rusqlite::params![issue.id, label],
+        )?;
+    }
+
+    conn.execute(
+        "DELETE FROM dependencies WHERE issue_id = ?",
+        [issue.id.as_str()],
+    )?;
+    for dep in &issue.dependencies {
+        conn.execute(
+            "INSERT INTO dependencies (issue_id, depends_on_id, type, created_at, created_by, metadata, thread_id)
+             VALUES (?, ?, ?, ?, COALESCE(?, ''), COALESCE(?, '{}'), COALESCE(?, ''))",
+            rusqlite::params![
+                issue.id,
+                dep.depends_on_id,
+                dep.dep_type.as_str(),
+                dep.created_at.to_rfc3339(),
+                dep.created_by,
+                dep.metadata,
+                dep.thread_id
+            ],
+        )?;
+    }
+
+    conn.execute(
+        "DELETE FROM comments WHERE issue_id = ?",
+        [issue.id.as_str()],
+    )?;
+    for comment in &issue.comments {
+        conn.execute(
+            "INSERT INTO comments (id, issue_id, author, text, created_at) VALUES (?, ?, ?, ?, ?)",
+            rusqlite::params![
+                comment.id,
+                issue.id,
+                comment.author,
+                comment.body,
+                comment.created_at.to_rfc3339()
+            ],
+        )?;
+    }
+
+    conn.execute(
+        "DELETE FROM recurrence_rules WHERE issue_id = ?",
+        [issue.id.as_str()],
+    )?;
+    if let Some(recurrence) = &issue.recurrence {
+        conn.execute(
+            "INSERT INTO recurrence_rules (issue_id, rule, next_at, created_at, created_by)
+             VALUES (?, ?, ?, ?, '')",
+            rusqlite::params![
+                issue.id,
+                recurrence.rule,
+                recurrence.next_at.map(|dt| dt.to_rfc3339()),
+                now.to_rfc3339()
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::IssueUpdate;
+
+    fn issue(id: &str, title: &str) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: title.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn undo_and_redo_restore_status_labels_and_dependencies() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        storage
+            .create_issue(&issue("bd-1", "Fix login"), "alice")
+            .unwrap();
+        storage
+            .create_issue(&issue("bd-2", "Add SSO"), "alice")
+            .unwrap();
+        storage.add_label("bd-1", "auth", "alice").unwrap();
+        storage
+            .add_dependency("bd-1", "bd-2", "blocks", None, "alice")
+            .unwrap();
+
+        // A fat-fingered close, label wipe and dependency removal
+        let close = IssueUpdate {
+            status: Some(Status::Closed),
+            close_reason: Some(Some("done".to_string())),
+            ..Default::default()
+        };
+        storage.update_issue("bd-1", &close, "alice").unwrap();
+        storage.set_labels("bd-1", &[], "alice").unwrap();
+        storage.remove_dependency("bd-1", "bd-2", "alice").unwrap();
+
+        let batches = storage.undo_stack(3, None).unwrap();
+        let ops: Vec<&str> = batches.iter().map(|batch| batch.op.as_str()).collect();
+        assert_eq!(ops, ["remove_dependency", "set_labels", "update_issue"]);
+
+        let preview = storage
+            .revert_batches(&batches, UNDO_OP, "alice", true)
+            .unwrap();
+        assert_eq!(preview.len(), 3);
+        assert_eq!(preview[2].changes[0].field, "status");
+        let unchanged = storage.get_issue("bd-1").unwrap().unwrap();
+        assert_eq!(unchanged.status, Status::Closed);
+
+        storage
+            .revert_batches(&batches, UNDO_OP, "alice", false)
+            .unwrap();
+        let restored = storage.get_issue("bd-1").unwrap().unwrap();
+        assert_eq!(restored.status, Status::Open);
+        assert!(restored.closed_at.is_none());
+        assert!(restored.close_reason.is_none());
+        assert_eq!(storage.get_labels("bd-1").unwrap(), ["auth"]);
+        assert_eq!(storage.get_dependencies_full("bd-1").unwrap().len(), 1);
+
+        // Redo the close only: its undo ran last, so it tops the stack
+        let redo = storage.redo_stack(3, None).unwrap();
+        assert_eq!(redo.len(), 3);
+        assert_eq!(redo[0].reverts, Some(batches[2].id));
+        storage
+            .revert_batches(&redo[..1], REDO_OP, "alice", false)
+            .unwrap();
+        assert_eq!(
+            storage.get_issue("bd-1").unwrap().unwrap().status,
+            Status::Closed
+        );
+
+        // A new change clears the redo stack
+        storage.add_label("bd-2", "sso", "alice").unwrap();
+        assert!(storage.redo_stack(3, None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn undo_create_leaves_tombstone_and_refuses_stale_batches() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        storage
+            .create_issue(&issue("bd-1", "Draft"), "bob")
+            .unwrap();
+        let rename = IssueUpdate {
+            title: Some("Renamed".to_string()),
+            ..Default::default()
+        };
+        storage.update_issue("bd-1", &rename, "alice").unwrap();
+
+        // bob's create is older than alice's rename of the same issue
+        let bobs = storage.undo_stack(1, Some("bob")).unwrap();
+        assert_eq!(bobs[0].op, "create_issue");
+        assert!(
+            storage
+                .revert_batches(&bobs, UNDO_OP, "bob", false)
+                .is_err()
+        );
+
+        let all = storage.undo_stack(2, None).unwrap();
+        storage
+            .revert_batches(&all, UNDO_OP, "alice", false)
+            .unwrap();
+        let issue = storage.get_issue("bd-1").unwrap().unwrap();
+        assert_eq!(issue.status, Status::Tombstone);
+        assert_eq!(issue.title, "Draft");
+        assert!(storage.undo_stack(5, None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn claims_lease_expiry_and_compaction_are_undoable() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let mut draft = issue("bd-1", "Fix login");
+        draft.description = Some("A long investigation".to_string());
+        storage.create_issue(&draft, "alice").unwrap();
+        let rename = IssueUpdate {
+            title: Some("Fix SSO login".to_string()),
+            ..Default::default()
+        };
+        storage.update_issue("bd-1", &rename, "alice").unwrap();
+
+        // A claim whose lease has already run out, then the sweep
+        storage
+            .claim_issue("bd-1", "bob", chrono::Duration::seconds(-1))
+            .unwrap();
+        assert_eq!(
+            storage.release_expired_leases(SYSTEM_ACTOR).unwrap().len(),
+            1
+        );
+
+        let sweep = storage.undo_stack(1, Some(SYSTEM_ACTOR)).unwrap();
+        storage
+            .revert_batches(&sweep, UNDO_OP, "bob", false)
+            .unwrap();
+        let reclaimed = storage.get_issue("bd-1").unwrap().unwrap();
+        assert_eq!(reclaimed.status, Status::InProgress);
+        assert_eq!(reclaimed.assignee.as_deref(), Some("bob"));
+
+        let claim = storage.undo_stack(1, None).unwrap();
+        assert_eq!(claim[0].op, "claim_issue");
+        storage
+            .revert_batches(&claim, UNDO_OP, "bob", false)
+            .unwrap();
+        let unclaimed = storage.get_issue("bd-1").unwrap().unwrap();
+        assert_eq!(unclaimed.status, Status::Open);
+        assert!(unclaimed.assignee.is_none());
+
+        // The change before the claim can be undone again
+        let earlier = storage.undo_stack(1, None).unwrap();
+        assert_eq!(earlier[0].op, "update_issue");
+        storage
+            .revert_batches(&earlier, UNDO_OP, "alice", false)
+            .unwrap();
+
+        storage
+            .compact_issue("bd-1", "Digest", 20, None, "alice")
+            .unwrap();
+        let compaction = storage.undo_stack(1, None).unwrap();
+        assert_eq!(compaction[0].op, "compact_issue");
+        storage
+            .revert_batches(&compaction, UNDO_OP, "alice", false)
+            .unwrap();
+        let restored = storage.get_issue("bd-1").unwrap().unwrap();
+        assert_eq!(
+            restored.description.as_deref(),
+            Some("A long investigation")
+        );
+        assert_eq!(restored.compaction_level.unwrap_or(0), 0);
+    }
+
+    #[test]
+    fn undoing_a_live_claim_drops_its_lease() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        storage
+            .create_issue(&issue("bd-1", "Fix login"), "alice")
+            .unwrap();
+        storage
+            .claim_issue("bd-1", "bob", chrono::Duration::hours(1))
+            .unwrap();
+        assert!(storage.get_lease("bd-1").unwrap().is_some());
+
+        let claim = storage.undo_stack(1, None).unwrap();
+        storage
+            .revert_batches(&claim, UNDO_OP, "bob", false)
+            .unwrap();
+        assert!(storage.get_lease("bd-1").unwrap().is_none());
+        assert!(
+            storage
+                .get_issue("bd-1")
+                .unwrap()
+                .unwrap()
+                .assignee
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn multi_step_revert_is_all_or_nothing() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        storage
+            .create_issue(&issue("bd-1", "Draft"), "bob")
+            .unwrap();
+        let rename = IssueUpdate {
+            title: Some("Renamed".to_string()),
+            ..Default::default()
+        };
+        storage.update_issue("bd-1", &rename, "alice").unwrap();
+        storage
+            .create_issue(&issue("bd-2", "Spike"), "bob")
+            .unwrap();
+
+        // bd-2's create reverts cleanly, bd-1's is stale and fails
+        let bobs = storage.undo_stack(2, Some("bob")).unwrap();
+        assert_eq!(bobs.len(), 2);
+        assert!(
+            storage
+                .revert_batches(&bobs, UNDO_OP, "bob", false)
+                .is_err()
+        );
+
+        let spike = storage.get_issue("bd-2").unwrap().unwrap();
+        assert_eq!(spike.status, Status::Open);
+        assert_eq!(storage.undo_stack(5, None).unwrap().len(), 3);
+        assert!(storage.redo_stack(5, None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn system_batches_stay_off_the_default_stacks() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        storage
+            .create_issue(&issue("bd-1", "Fix login"), "alice")
+            .unwrap();
+        let assign = IssueUpdate {
+            assignee: Some(Some("alice".to_string())),
+            ..Default::default()
+        };
+        storage.update_issue("bd-1", &assign, "alice").unwrap();
+        storage.add_label("bd-1", "auth", "alice").unwrap();
+        let undone = storage.undo_stack(1, None).unwrap();
+        storage
+            .revert_batches(&undone, UNDO_OP, "alice", false)
+            .unwrap();
+
+        // An expired lease hands the issue back
+        let release = IssueUpdate {
+            assignee: Some(None),
+            ..Default::default()
+        };
+        storage
+            .update_issue("bd-1", &release, SYSTEM_ACTOR)
+            .unwrap();
+
+        // The system change neither tops the undo stack nor clears redo
+        let ops: Vec<String> = storage
+            .undo_stack(5, None)
+            .unwrap()
+            .into_iter()
+            .map(|batch| batch.op)
+            .collect();
+        assert_eq!(ops, ["update_issue", "create_issue"]);
+        assert_eq!(storage.redo_stack(5, None).unwrap().len(), 1);
+
+        let system = storage.undo_stack(5, Some(SYSTEM_ACTOR)).unwrap();
+        assert_eq!(system.len(), 1);
+        assert_eq!(system[0].actor, SYSTEM_ACTOR);
+    }
+
+    #[test]
+    fn record_batch_prunes_beyond_retention() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        storage
+            .create_issue(&issue("bd-1", "Fix login"), "alice")
+            .unwrap();
+        for n in 0..UNDO_RETENTION {
+            storage
+                .add_label("bd-1", &format!("l{n}"), "alice")
+                .unwrap();
+        }
+
+        let count = |table: &str| -> i64 {
+            storage
+                .conn
+                .query_row(&format!("SELECT COUNT(*) FROM {table}"), [], |row| {
+                    row.get(0)
+                })
+                .unwrap()
+        };
+        assert_eq!(count("mutation_batches"), UNDO_RETENTION);
+        assert_eq!(count("mutation_images"), UNDO_RETENTION);
+        let oldest = storage.undo_stack(UNDO_RETENTION + 1, None).unwrap();
+        assert_eq!(oldest.last().unwrap().op, "add_label");
+    }
+}
@@ -3,14 +3,21 @@
 //! This module handles:
 //! - Creating timestamped backups of `issues.jsonl` before export
 //! - Rotating backups based on count and age
+//! - Archiving original issue records before compaction (kept apart from
+//!   the backups)
 //! - Listing and restoring backups
 
 use crate::error::{BeadsError, Result};
+use crate::model::Issue;
 use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
 use std::fs::{self, File};
-use std::io::{BufReader, Read};
+use std::io::{BufReader, BufWriter, Read, Write};
 use std::path::{Path, PathBuf};
 
+/// Subdirectory of `.br_history` holding archives written by
+/// [`archive_issues`]; backup listing, pruning and restore skip it.
+pub const ARCHIVE_DIR: &str = "archive";
+
 /// Configuration for history backups.
 #[derive(Debug, Clone)]
 pub struct HistoryConfig {
@@ -95,6 +102,53 @@ pub fn backup_before_export(
     Ok(())
 }
 
+/// Write full copies of issues to a timestamped archive under the history
+/// directory.
+///
+/// Used before destructive rewrites (such as compaction) so the original
+/// records stay recoverable. Archives go to `.br_history/archive/` as
+/// `<stem>-<timestamp>.jsonl` (with a `-<n>` suffix when that name is taken),
+/// so they are never listed, pruned, rotated or restored as export backups,
+/// and an existing archive is never overwritten.
+///
+/// # Errors
+///
+/// Returns an error if the directory or file cannot be written.
+pub fn archive_issues(beads_dir: &Path, stem: &str, issues: &[Issue]) -> Result<PathBuf> {
+    let archive_dir = beads_dir.join(".br_history").join(ARCHIVE_DIR);
+    fs::create_dir_all(&archive_dir).map_err(BeadsError::Io)?;
+
+    let timestamp = Utc::now().format("%Y%m%d_%H%M%S");
+    let mut attempt = 0;
+    let (archive_path, file) = loop {
+        let name = if attempt == 0 {
+            format!("{stem}-{timestamp}.jsonl")
+        } else {
+            format!("{stem}-{timestamp}-{attempt}.jsonl")
+        };
+        let path = archive_dir.join(name);
+        match File::options().write(true).create_new(true).open(&path) {
+            Ok(file) => break (path, file),
+            Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => attempt += 1,
+            Err(err) => return Err(BeadsError::Io(err)),
+        }
+    };
+
+    let mut writer = BufWriter::new(file);
+    for issue in issues {
+        serde_json::to_writer(&mut writer, issue)?;
+        writer.write_all(b"\n").map_err(BeadsError::Io)?;
+    }
+    writer.flush().map_err(BeadsError::Io)?;
+    tracing::debug!(
+        "Archived {} issue(s) to {}",
+        issues.len(),
+        archive_path.display()
+    );
+
+    Ok(archive_path)
+}
+
 /// Rotate history backups based on config limits.
 ///
 /// # Errors
@@ -434,4 +488,26 @@ mod tests {
         assert_eq!(deleted_age, 1);
         assert_eq!(list_backups(history_dir, None).unwrap().len(), 2);
     }
+
+    #[test]
+    fn test_archives_are_unique_and_not_backups() {
+        let temp = TempDir::new().unwrap();
+        let beads_dir = temp.path().join(".beads");
+        let history_dir = beads_dir.join(".br_history");
+        let issue = Issue {
+            id: "bd-1".to_string(),
+            title: "Original".to_string(),
+            ..Issue::default()
+        };
+
+        // Same second, same stem: the second archive must not overwrite the first
+        let first = archive_issues(&beads_dir, "compacted", std::slice::from_ref(&issue)).unwrap();
+        let second = archive_issues(&beads_dir, "compacted", &[]).unwrap();
+        assert_ne!(first, second);
+        assert!(fs::read_to_string(&first).unwrap().contains("Original"));
+
+        assert!(list_backups(&history_dir, None).unwrap().is_empty());
+        assert_eq!(prune_backups(&history_dir, 0, None).unwrap(), 0);
+        assert!(first.exists() && second.exists());
+    }
 }
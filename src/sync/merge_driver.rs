@@ -0,0 +1,198 @@
+//! Git merge driver for `issues.jsonl`.
+//!
+//! Git calls a custom merge driver with three temporary files: the common
+//! ancestor (`%O`), the current branch (`%A`), and the other branch (`%B`).
+//! The driver must leave the merged result in `%A` and exit non-zero if the
+//! merge could not be completed.
+//!
+//! Instead of merging line by line, the three versions are parsed as issues and
+//! merged with [`three_way_merge`], so concurrent edits to different issues (or
+//! different fields of one issue) never produce textual conflicts. The result
+//! is written sorted by ID in the same shape as `br sync --flush-only`.
+//!
+//! The driver is registered for `.beads/issues.jsonl` by `.beads/.gitattributes`
+//! (written by `br init`) plus a one-time `git config` entry:
+//!
+//! ```text
+//! git config merge.beads.driver "br merge-driver %O %A %B"
+//! ```
+//!
+//! This module never invokes git itself.
+
+use super::{
+    ConflictResolution, MergeContext, MergeReport, read_issues_from_jsonl, three_way_merge,
+};
+use crate::error::{BeadsError, Result};
+use crate::model::Issue;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// Contents of `.beads/.gitattributes`.
+pub const GITATTRIBUTES_CONTENT: &str =
+    "# Merge issues.jsonl by issue instead of by line (see `br merge-driver --help`)
+issues.jsonl merge=beads
+";
+
+/// Command that registers the merge driver in the repository's git config.
+pub const MERGE_DRIVER_SETUP_COMMAND: &str =
+    "git config merge.beads.driver \"br merge-driver %O %A %B\"";
+
+/// Load the three JSONL versions and merge them.
+///
+/// A missing or empty ancestor is treated as "no common history", so issues
+/// present on only one side are kept.
+///
+/// # Errors
+///
+/// Returns an error if any file cannot be read or contains invalid JSON
+/// (including leftover conflict markers).
+pub fn merge_jsonl_files(
+    base: &Path,
+    local: &Path,
+    external: &Path,
+    strategy: ConflictResolution,
+) -> Result<MergeReport> {
+    let context = MergeContext::new(
+        load_issue_map(base)?,
+        load_issue_map(local)?,
+        load_issue_map(external)?,
+    );
+    three_way_merge(&context, strategy, None)
+}
+
+fn load_issue_map(path: &Path) -> Result<HashMap<String, Issue>> {
+    if !path.exists() {
+        return Ok(HashMap::new());
+    }
+    let issues = read_issues_from_jsonl(path)
+        .map_err(|e| BeadsError::Config(format!("Failed to parse {}: {e}", path.display())))?;
+    Ok(issues
+        .into_iter()
+        .map(|issue| (issue.id.clone(), issue))
+        .collect())
+}
+
+/// Merged issues in export order: sorted by ID, with relations in the order
+/// the database exports them.
+#[must_use]
+pub fn merged_issues(report: &MergeReport) -> Vec<Issue> {
+    let mut issues = report.kept.clone();
+    for issue in &mut issues {
+        issue.labels.sort();
+        issue.labels.dedup();
+        issue
+            .dependencies
+            .sort_by(|a, b| a.depends_on_id.cmp(&b.depends_on_id));
+        issue.comments.sort_by_key(|comment| comment.created_at);
+    }
+    issues.sort_by(|a, b| a.id.cmp(&b.id));
+    issues
+}
+
+/// Write issues as JSONL to `path`, replacing it atomically.
+///
+/// # Errors
+///
+/// Returns an error if the temp file cannot be written or renamed.
+pub fn write_merged_jsonl(path: &Path, issues: &[Issue]) -> Result<()> {
+    let temp_path = path.with_extension("merge.tmp");
+    let file = File::create(&temp_path)?;
+    let mut writer = BufWriter::new(file);
+
+    for issue in issues {
+        let json = serde_json::to_string(issue).map_err(|e| {
+            BeadsError::Config(format!("Failed to serialize issue {}: {}", issue.id, e))
+        })?;
+        writeln!(writer, "{json}")?;
+    }
+    writer.flush()?;
+    writer
+        .into_inner()
+        .map_err(|e| BeadsError::Io(e.into_error()))?
+        .sync_all()?;
+
+    fs::rename(&temp_path, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use tempfile::TempDir;
+
+    fn issue(id: &str, title: &str, seconds: i64) -> Issue {
+        let at = Utc.timestamp_opt(seconds, 0).unwrap();
+        Issue {
+            id: id.to_string(),
+            title: title.to_string(),
+            created_at: at,
+            updated_at: at,
+            ..Issue::default()
+        }
+    }
+
+    fn write(dir: &TempDir, name: &str, issues: &[Issue]) -> std::path::PathBuf {
+        let path = dir.path().join(name);
+        write_merged_jsonl(&path, issues).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_merges_edits_to_different_issues_and_fields() {
+        let dir = TempDir::new().unwrap();
+        let base = [issue("bd-2", "Two", 100), issue("bd-1", "One", 100)];
+
+        let mut ours_one = issue("bd-1", "One", 200);
+        ours_one.labels = vec!["backend".to_string()];
+        let ours = [
+            issue("bd-2", "Two (ours)", 200),
+            ours_one,
+            issue("bd-3", "New", 200),
+        ];
+
+        let mut theirs_one = issue("bd-1", "One", 300);
+        theirs_one.notes = Some("theirs".to_string());
+        let theirs = [
+            issue("bd-2", "Two", 100),
+            theirs_one,
+            issue("bd-0", "Theirs", 300),
+        ];
+
+        let report = merge_jsonl_files(
+            &write(&dir, "base", &base),
+            &write(&dir, "ours", &ours),
+            &write(&dir, "theirs", &theirs),
+            ConflictResolution::PreferNewer,
+        )
+        .unwrap();
+        assert!(!report.has_conflicts());
+
+        let merged = merged_issues(&report);
+        let ids: Vec<_> = merged.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["bd-0", "bd-1", "bd-2", "bd-3"]);
+        assert_eq!(merged[1].labels, vec!["backend"]);
+        assert_eq!(merged[1].notes.as_deref(), Some("theirs"));
+        assert_eq!(merged[2].title, "Two (ours)");
+    }
+
+    #[test]
+    fn test_manual_strategy_reports_conflicts_and_bad_input_fails() {
+        let dir = TempDir::new().unwrap();
+        let base = write(&dir, "base", &[issue("bd-1", "Base", 100)]);
+        let ours = write(&dir, "ours", &[issue("bd-1", "Ours", 200)]);
+        let theirs = write(&dir, "theirs", &[issue("bd-1", "Theirs", 300)]);
+
+        let report = merge_jsonl_files(&base, &ours, &theirs, ConflictResolution::Manual).unwrap();
+        assert_eq!(report.conflicts.len(), 1);
+
+        let report =
+            merge_jsonl_files(&base, &ours, &theirs, ConflictResolution::PreferNewer).unwrap();
+        assert_eq!(merged_issues(&report)[0].title, "Theirs");
+
+        fs::write(&theirs, "<<<<<<< HEAD\n").unwrap();
+        assert!(merge_jsonl_files(&base, &ours, &theirs, ConflictResolution::PreferNewer).is_err());
+    }
+}
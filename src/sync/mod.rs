@@ -8,7 +8,9 @@
 //! - Path validation and allowlist enforcement
 
 pub mod history;
+pub mod merge_driver;
 pub mod path;
+pub mod resolve;
 
 pub use path::{
     ALLOWED_EXACT_NAMES, ALLOWED_EXTENSIONS, PathValidation, is_sync_path_allowed,
@@ -2282,7 +2284,8 @@ fn process_import_action(
     Ok(())
 }
 
-/// Sync labels, dependencies, and comments for an imported issue.
+/// Sync labels, dependencies, comments, the recurrence rule, and the work log
+/// for an imported issue.
 fn sync_issue_relations(storage: &mut SqliteStorage, issue: &Issue) -> Result<()> {
     // Sync labels
     storage.sync_labels_for_import(&issue.id, &issue.labels)?;
@@ -2293,6 +2296,12 @@ fn sync_issue_relations(storage: &mut SqliteStorage, issue: &Issue) -> Result<()
     // Sync comments
     storage.sync_comments_for_import(&issue.id, &issue.comments)?;
 
+    // Sync recurrence rule
+    storage.sync_recurrence_for_import(&issue.id, issue.recurrence.as_ref())?;
+
+    // Sync work log
+    storage.sync_worklog_for_import(&issue.id, &issue.worklog)?;
+
     Ok(())
 }
 
@@ -2326,12 +2335,38 @@ pub fn compute_jsonl_hash(path: &Path) -> Result<String> {
 // ============================================================================
 
 /// Types of conflicts that can occur during 3-way merge.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ConflictType {
     /// Issue was modified locally but deleted externally (or vice versa).
     DeleteVsModify,
     /// Issue was created in both local and external with different content.
     ConvergentCreation,
+    /// Both sides changed the same field(s) to different values.
+    FieldConflict,
+}
+
+/// A field that both sides of a 3-way merge changed to different values.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldConflict {
+    /// JSONL field name (`status` also covers the close/tombstone fields,
+    /// `compaction_level` the other compaction fields).
+    pub field: String,
+    /// Value in the base snapshot (`null` when absent).
+    pub base: serde_json::Value,
+    /// Value in the local (`SQLite`) state.
+    pub local: serde_json::Value,
+    /// Value in the external (JSONL) state.
+    pub external: serde_json::Value,
+}
+
+/// Outcome of a field-level merge of an issue modified on both sides.
+#[derive(Debug, Clone)]
+pub struct FieldMerge {
+    /// The merged issue (conflicting fields resolved by the strategy).
+    pub issue: Issue,
+    /// Fields changed differently on both sides.
+    pub conflicts: Vec<FieldConflict>,
 }
 
 /// Result of merging a single issue across base, left (local), and right (external).
@@ -2392,6 +2427,8 @@ pub struct MergeReport {
     pub deleted: Vec<String>,
     /// Conflicts that were detected.
     pub conflicts: Vec<(String, ConflictType)>,
+    /// Same-field conflicts with both values (auto-resolved unless the strategy is `Manual`).
+    pub field_conflicts: Vec<(String, FieldConflict)>,
     /// Issues that were skipped due to tombstone protection.
     pub tombstone_protected: Vec<String>,
     /// Notes about merge decisions.
@@ -2432,22 +2469,37 @@ pub enum ConflictResolution {
 /// - New local issues are kept
 /// - New external issues are imported
 /// - Deletions are handled based on whether the other side modified
-/// - Both-modified uses `updated_at` as tiebreaker (or specified strategy)
+/// - Both-modified merges field by field against the base (see [`merge_issue_fields`]);
+///   only same-field conflicts fall back to the strategy (`Manual` reports them)
 ///
 /// # Arguments
 /// * `base` - The issue in the base (common ancestor) state, if it existed
 /// * `left` - The issue in the local (`SQLite`) state, if it exists
 /// * `right` - The issue in the external (JSONL) state, if it exists
 /// * `strategy` - How to resolve conflicts when both sides modified
-#[must_use]
-#[allow(clippy::too_many_lines)]
+///
+/// # Errors
+///
+/// Returns an error if a field-merged issue cannot be rebuilt.
 pub fn merge_issue(
     base: Option<&Issue>,
     left: Option<&Issue>,
     right: Option<&Issue>,
     strategy: ConflictResolution,
-) -> MergeResult {
-    match (base, left, right) {
+) -> Result<MergeResult> {
+    Ok(merge_issue_with_conflicts(base, left, right, strategy)?.0)
+}
+
+/// Like [`merge_issue`], but also returns the same-field conflicts found when
+/// both sides modified the issue.
+#[allow(clippy::too_many_lines)]
+fn merge_issue_with_conflicts(
+    base: Option<&Issue>,
+    left: Option<&Issue>,
+    right: Option<&Issue>,
+    strategy: ConflictResolution,
+) -> Result<(MergeResult, Vec<FieldConflict>)> {
+    let result = match (base, left, right) {
         // Case 1: Only in base (deleted in both local and external) -> no action
         (Some(_), None, None) => MergeResult::Delete,
 
@@ -2516,54 +2568,46 @@ pub fn merge_issue(
 
         // Case 6: In all three (potentially modified in one or both)
         (Some(b), Some(l), Some(r)) => {
-            let left_changed = l.content_hash != b.content_hash;
-            let right_changed = r.content_hash != b.content_hash;
+            let left_changed = issue_changed_since(b, l);
+            let right_changed = issue_changed_since(b, r);
 
             match (left_changed, right_changed) {
                 // Neither changed OR only left changed - keep left
                 (false | true, false) => MergeResult::Keep(l.clone()),
                 // Only right changed - keep right
                 (false, true) => MergeResult::Keep(r.clone()),
-                // Both changed - use strategy
-                (true, true) => match strategy {
-                    ConflictResolution::PreferLocal => MergeResult::KeepWithNote(
-                        l.clone(),
-                        "Both modified - kept local".to_string(),
-                    ),
-                    ConflictResolution::PreferExternal => MergeResult::KeepWithNote(
-                        r.clone(),
-                        "Both modified - kept external".to_string(),
-                    ),
-                    ConflictResolution::PreferNewer => {
-                        if l.updated_at >= r.updated_at {
-                            MergeResult::KeepWithNote(
-                                l.clone(),
-                                "Both modified - kept local (newer)".to_string(),
-                            )
-                        } else {
-                            MergeResult::KeepWithNote(
-                                r.clone(),
-                                "Both modified - kept external (newer)".to_string(),
-                            )
-                        }
-                    }
-                    ConflictResolution::Manual => {
-                        // For manual, we still need to pick one, so use newer but mark as note
-                        if l.updated_at >= r.updated_at {
-                            MergeResult::KeepWithNote(
-                                l.clone(),
-                                "Both modified - kept local (newer), review recommended"
-                                    .to_string(),
-                            )
-                        } else {
-                            MergeResult::KeepWithNote(
-                                r.clone(),
-                                "Both modified - kept external (newer), review recommended"
-                                    .to_string(),
-                            )
-                        }
+                // Both changed - merge field by field, strategy only for same-field conflicts
+                (true, true) => {
+                    let merged = merge_issue_fields(b, l, r, strategy)?;
+                    if merged.conflicts.is_empty() {
+                        MergeResult::KeepWithNote(
+                            merged.issue,
+                            "Both modified - merged without conflicts".to_string(),
+                        )
+                    } else if strategy == ConflictResolution::Manual {
+                        return Ok((
+                            MergeResult::Conflict(ConflictType::FieldConflict),
+                            merged.conflicts,
+                        ));
+                    } else {
+                        let winner = match strategy {
+                            ConflictResolution::PreferLocal => "local",
+                            ConflictResolution::PreferExternal => "external",
+                            _ if l.updated_at >= r.updated_at => "local (newer)",
+                            _ => "external (newer)",
+                        };
+                        let fields: Vec<&str> =
+                            merged.conflicts.iter().map(|c| c.field.as_str()).collect();
+                        let note = format!(
+                            "Both modified - kept {winner} for conflicting field(s): {}",
+                            fields.join(", ")
+                        );
+                        return Ok((
+                            MergeResult::KeepWithNote(merged.issue, note),
+                            merged.conflicts,
+                        ));
                     }
-                },
+                }
             }
         }
 
@@ -2602,7 +2646,286 @@ pub fn merge_issue(
 
         // Case 8: Not in any (impossible in practice, but handle gracefully)
         (None, None, None) => MergeResult::NoAction,
+    };
+
+    Ok((result, Vec::new()))
+}
+
+/// Field groups merged as a unit, in JSONL field names.
+///
+/// Status travels with its close/tombstone metadata so a merge can never pair
+/// `status: closed` from one side with a missing `closed_at` from the other.
+/// Fields not listed here (id, timestamps, relations) are handled separately.
+pub(crate) const MERGE_FIELD_GROUPS: &[&[&str]] = &[
+    &["title"],
+    &["description"],
+    &["design"],
+    &["acceptance_criteria"],
+    &["notes"],
+    &[
+        "status",
+        "closed_at",
+        "close_reason",
+        "closed_by_session",
+        "deleted_at",
+        "deleted_by",
+        "delete_reason",
+        "original_type",
+    ],
+    &["priority"],
+    &["issue_type"],
+    &["assignee"],
+    &["owner"],
+    &["estimated_minutes"],
+    &["created_by"],
+    &["due_at"],
+    &["defer_until"],
+    &["external_ref"],
+    &["source_system"],
+    &["source_repo"],
+    &[
+        "compaction_level",
+        "compacted_at",
+        "compacted_at_commit",
+        "original_size",
+    ],
+    &["sender"],
+    &["ephemeral"],
+    &["pinned"],
+    &["is_template"],
+    &["recurrence"],
+];
+
+/// Merge an issue modified on both sides, field by field against `base`.
+///
+/// Fields changed on only one side take that side's value. Fields changed on
+/// both sides to the same value merge cleanly; otherwise they are reported as
+/// [`FieldConflict`]s and resolved by `strategy` (`PreferNewer` and `Manual`
+/// pick the side with the later `updated_at`). Labels, dependencies,
+/// comments, and work log entries merge as sets: additions from either side
+/// are kept and an element is dropped only if one side removed it.
+///
+/// # Errors
+///
+/// Returns an error if the merged fields do not form a valid issue.
+pub fn merge_issue_fields(
+    base: &Issue,
+    left: &Issue,
+    right: &Issue,
+    strategy: ConflictResolution,
+) -> Result<FieldMerge> {
+    let base_fields = issue_field_map(base);
+    let left_fields = issue_field_map(left);
+    let right_fields = issue_field_map(right);
+
+    let prefer_left = match strategy {
+        ConflictResolution::PreferLocal => true,
+        ConflictResolution::PreferExternal => false,
+        ConflictResolution::PreferNewer | ConflictResolution::Manual => {
+            left.updated_at >= right.updated_at
+        }
+    };
+
+    let mut merged = left_fields.clone();
+    let mut conflicts = Vec::new();
+
+    for group in MERGE_FIELD_GROUPS {
+        let b = field_group_value(&base_fields, group);
+        let l = field_group_value(&left_fields, group);
+        let r = field_group_value(&right_fields, group);
+
+        if l == r || r == b {
+            continue;
+        }
+        if l == b {
+            copy_field_group(&mut merged, &right_fields, group);
+            continue;
+        }
+
+        conflicts.push(FieldConflict {
+            field: group[0].to_string(),
+            base: b,
+            local: l,
+            external: r,
+        });
+        if !prefer_left {
+            copy_field_group(&mut merged, &right_fields, group);
+        }
+    }
+
+    let mut issue: Issue = serde_json::from_value(serde_json::Value::Object(merged))?;
+
+    issue.updated_at = left.updated_at.max(right.updated_at);
+
+    issue.labels = merge_relation_sets(&base.labels, &left.labels, &right.labels, Clone::clone);
+    issue.labels.sort();
+    issue.labels.dedup();
+
+    issue.dependencies = merge_relation_sets(
+        &base.dependencies,
+        &left.dependencies,
+        &right.dependencies,
+        |dep| (dep.depends_on_id.clone(), dep.dep_type.as_str().to_string()),
+    );
+
+    issue.comments = merge_relation_sets(
+        &base.comments,
+        &left.comments,
+        &right.comments,
+        comment_merge_key,
+    );
+    issue.comments.sort_by_key(|comment| comment.created_at);
+
+    issue.worklog = merge_relation_sets(
+        &base.worklog,
+        &left.worklog,
+        &right.worklog,
+        worklog_merge_key,
+    );
+    issue.worklog.sort_by_key(|entry| entry.created_at);
+
+    issue.content_hash = Some(issue.compute_content_hash());
+
+    Ok(FieldMerge { issue, conflicts })
+}
+
+/// Whether `issue` differs from `base` in any merged field or relation.
+///
+/// Timestamps that every write touches (`created_at`, `updated_at`) are ignored.
+pub(crate) fn issue_changed_since(base: &Issue, issue: &Issue) -> bool {
+    let base_fields = issue_field_map(base);
+    let fields = issue_field_map(issue);
+
+    let fields_changed = MERGE_FIELD_GROUPS
+        .iter()
+        .any(|group| field_group_value(&base_fields, group) != field_group_value(&fields, group));
+    if fields_changed {
+        return true;
+    }
+
+    let labels = |i: &Issue| i.labels.iter().cloned().collect::<HashSet<_>>();
+    let deps = |i: &Issue| {
+        i.dependencies
+            .iter()
+            .map(|dep| (dep.depends_on_id.clone(), dep.dep_type.as_str().to_string()))
+            .collect::<HashSet<_>>()
+    };
+    let comments = |i: &Issue| {
+        i.comments
+            .iter()
+            .map(comment_merge_key)
+            .collect::<HashSet<_>>()
+    };
+
+    let worklog = |i: &Issue| {
+        i.worklog
+            .iter()
+            .map(worklog_merge_key)
+            .collect::<HashSet<_>>()
+    };
+
+    labels(base) != labels(issue)
+        || deps(base) != deps(issue)
+        || comments(base) != comments(issue)
+        || worklog(base) != worklog(issue)
+}
+
+/// Serialize an issue's scalar fields to a JSON object (relations removed).
+pub(crate) fn issue_field_map(issue: &Issue) -> serde_json::Map<String, serde_json::Value> {
+    let mut map = match serde_json::to_value(issue) {
+        Ok(serde_json::Value::Object(map)) => map,
+        _ => serde_json::Map::new(),
+    };
+    map.remove("labels");
+    map.remove("dependencies");
+    map.remove("comments");
+    map.remove("worklog");
+    map
+}
+
+/// Value of a field group: the bare value for single fields, an object otherwise.
+fn field_group_value(
+    fields: &serde_json::Map<String, serde_json::Value>,
+    group: &[&str],
+) -> serde_json::Value {
+    if let [field] = group {
+        return fields
+            .get(*field)
+            .cloned()
+            .unwrap_or(serde_json::Value::Null);
+    }
+    let mut object = serde_json::Map::new();
+    for field in group {
+        if let Some(value) = fields.get(*field) {
+            if !value.is_null() {
+                object.insert((*field).to_string(), value.clone());
+            }
+        }
+    }
+    serde_json::Value::Object(object)
+}
+
+pub(crate) fn copy_field_group(
+    target: &mut serde_json::Map<String, serde_json::Value>,
+    source: &serde_json::Map<String, serde_json::Value>,
+    group: &[&str],
+) {
+    for field in group {
+        match source.get(*field) {
+            Some(value) => {
+                target.insert((*field).to_string(), value.clone());
+            }
+            None => {
+                target.remove(*field);
+            }
+        }
+    }
+}
+
+/// Comments are matched by content, since local and exported IDs can differ.
+fn comment_merge_key(comment: &crate::model::Comment) -> (String, String, i64) {
+    (
+        comment.author.clone(),
+        comment.body.clone(),
+        comment.created_at.timestamp(),
+    )
+}
+
+/// Identity of a work log entry across machines (row IDs are local).
+fn worklog_merge_key(entry: &crate::model::WorkLogEntry) -> (String, Option<i64>, i64) {
+    (
+        entry.actor.clone(),
+        entry.minutes,
+        entry.created_at.timestamp(),
+    )
+}
+
+/// Three-way set merge: keep an element if both sides have it, or if it was
+/// added (absent from base) on either side. Order follows `left`, then `right`.
+fn merge_relation_sets<T: Clone, K: Eq + std::hash::Hash>(
+    base: &[T],
+    left: &[T],
+    right: &[T],
+    key: impl Fn(&T) -> K,
+) -> Vec<T> {
+    let base_keys: HashSet<K> = base.iter().map(&key).collect();
+    let left_keys: HashSet<K> = left.iter().map(&key).collect();
+    let right_keys: HashSet<K> = right.iter().map(&key).collect();
+
+    let mut merged = Vec::new();
+    for item in left {
+        let k = key(item);
+        if right_keys.contains(&k) || !base_keys.contains(&k) {
+            merged.push(item.clone());
+        }
+    }
+    for item in right {
+        let k = key(item);
+        if !left_keys.contains(&k) && !base_keys.contains(&k) {
+            merged.push(item.clone());
+        }
     }
+    merged
 }
 
 /// Perform a 3-way merge across all issues in the context.
@@ -2617,12 +2940,15 @@ pub fn merge_issue(
 ///
 /// # Returns
 /// A `MergeReport` containing all actions taken and any conflicts detected.
-#[must_use]
+///
+/// # Errors
+///
+/// Returns an error if a field-merged issue cannot be rebuilt.
 pub fn three_way_merge(
     context: &MergeContext,
     strategy: ConflictResolution,
     tombstones: Option<&HashSet<String, RandomState>>,
-) -> MergeReport {
+) -> Result<MergeReport> {
     let mut report = MergeReport::default();
     let empty_tombstones: HashSet<String, RandomState> = HashSet::new();
     let tombstones = tombstones.unwrap_or(&empty_tombstones);
@@ -2642,7 +2968,12 @@ pub fn three_way_merge(
             }
         }
 
-        let result = merge_issue(base, left, right, strategy);
+        let (result, field_conflicts) = merge_issue_with_conflicts(base, left, right, strategy)?;
+        report.field_conflicts.extend(
+            field_conflicts
+                .into_iter()
+                .map(|conflict| (id.clone(), conflict)),
+        );
 
         match result {
             MergeResult::NoAction => {}
@@ -2662,7 +2993,7 @@ pub fn three_way_merge(
         }
     }
 
-    report
+    Ok(report)
 }
 
 /// Configuration for a 3-way merge operation.
@@ -2785,6 +3116,8 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         }
     }
 
@@ -2830,6 +3163,8 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         }
     }
 
@@ -4001,6 +4336,8 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            recurrence: None,
+            worklog: vec![],
         }
     }
 
@@ -4008,7 +4345,8 @@ mod tests {
     fn test_merge_new_local_issue_kept() {
         // Issue only in left (new local) should be kept
         let local = make_issue_with_hash("bd-1", "New Local", fixed_time_merge(100), Some("hash1"));
-        let result = merge_issue(None, Some(&local), None, ConflictResolution::PreferNewer);
+        let result =
+            merge_issue(None, Some(&local), None, ConflictResolution::PreferNewer).unwrap();
         assert!(matches!(result, MergeResult::Keep(issue) if issue.id == "bd-1"));
     }
 
@@ -4017,7 +4355,8 @@ mod tests {
         // Issue only in right (new external) should be kept
         let external =
             make_issue_with_hash("bd-2", "New External", fixed_time_merge(100), Some("hash2"));
-        let result = merge_issue(None, None, Some(&external), ConflictResolution::PreferNewer);
+        let result =
+            merge_issue(None, None, Some(&external), ConflictResolution::PreferNewer).unwrap();
         assert!(matches!(result, MergeResult::Keep(issue) if issue.id == "bd-2"));
     }
 
@@ -4025,7 +4364,7 @@ mod tests {
     fn test_merge_deleted_both_sides() {
         // Issue in base but deleted in both local and external -> delete
         let base = make_issue_with_hash("bd-3", "Old", fixed_time_merge(100), Some("hash3"));
-        let result = merge_issue(Some(&base), None, None, ConflictResolution::PreferNewer);
+        let result = merge_issue(Some(&base), None, None, ConflictResolution::PreferNewer).unwrap();
         assert!(matches!(result, MergeResult::Delete));
     }
 
@@ -4038,7 +4377,8 @@ mod tests {
             Some(&base),
             None,
             ConflictResolution::PreferNewer,
-        );
+        )
+        .unwrap();
         assert!(matches!(result, MergeResult::Delete));
     }
 
@@ -4054,7 +4394,8 @@ mod tests {
             Some(&local),
             None,
             ConflictResolution::PreferNewer,
-        );
+        )
+        .unwrap();
         assert!(matches!(result, MergeResult::KeepWithNote(..)));
     }
 
@@ -4074,7 +4415,8 @@ mod tests {
             None,
             Some(&external),
             ConflictResolution::PreferNewer,
-        );
+        )
+        .unwrap();
         assert!(matches!(result, MergeResult::KeepWithNote(issue, _) if issue.title == "Modified"));
     }
 
@@ -4095,7 +4437,8 @@ mod tests {
             Some(&local),
             Some(&external),
             ConflictResolution::PreferNewer,
-        );
+        )
+        .unwrap();
         assert!(matches!(result, MergeResult::Keep(issue) if issue.title == "Modified"));
     }
 
@@ -4116,7 +4459,8 @@ mod tests {
             Some(&local),
             Some(&external),
             ConflictResolution::PreferNewer,
-        );
+        )
+        .unwrap();
         assert!(matches!(result, MergeResult::Keep(issue) if issue.title == "Modified"));
     }
 
@@ -4142,7 +4486,8 @@ mod tests {
             Some(&local),
             Some(&external),
             ConflictResolution::PreferNewer,
-        );
+        )
+        .unwrap();
         assert!(
             matches!(result, MergeResult::KeepWithNote(issue, _) if issue.title == "External Mod")
         );
@@ -4169,12 +4514,108 @@ mod tests {
             Some(&local),
             Some(&external),
             ConflictResolution::PreferLocal,
-        );
+        )
+        .unwrap();
         assert!(
             matches!(result, MergeResult::KeepWithNote(issue, _) if issue.title == "Local Mod")
         );
     }
 
+    #[test]
+    fn test_merge_both_modified_different_fields_merges_cleanly() {
+        let base = make_issue_with_hash("bd-020", "Base", fixed_time_merge(100), None);
+        let mut local = base.clone();
+        local.priority = Priority::CRITICAL;
+        local.updated_at = fixed_time_merge(200);
+        let mut external = base.clone();
+        external.description = Some("Clarified scope".to_string());
+        external.updated_at = fixed_time_merge(300);
+
+        let result = merge_issue(
+            Some(&base),
+            Some(&local),
+            Some(&external),
+            ConflictResolution::PreferNewer,
+        )
+        .unwrap();
+        let MergeResult::KeepWithNote(merged, note) = result else {
+            panic!("expected clean merge, got {result:?}");
+        };
+        assert_eq!(merged.priority, Priority::CRITICAL);
+        assert_eq!(merged.description.as_deref(), Some("Clarified scope"));
+        assert_eq!(merged.updated_at, fixed_time_merge(300));
+        assert!(note.contains("without conflicts"), "note: {note}");
+    }
+
+    #[test]
+    fn test_merge_fields_relations_merge_as_sets() {
+        let mut base = make_issue_with_hash("bd-021", "Base", fixed_time_merge(100), None);
+        base.labels = vec!["backend".to_string(), "stale".to_string()];
+
+        let mut local = base.clone();
+        local.labels = vec![
+            "backend".to_string(),
+            "stale".to_string(),
+            "urgent".to_string(),
+        ];
+        local.title = "Local title".to_string();
+        local.updated_at = fixed_time_merge(200);
+
+        let mut external = base.clone();
+        external.labels = vec!["backend".to_string(), "ui".to_string()];
+        external.comments = vec![crate::model::Comment {
+            id: 7,
+            issue_id: "bd-021".to_string(),
+            author: "bob".to_string(),
+            body: "Looks good".to_string(),
+            created_at: fixed_time_merge(250),
+        }];
+        external.updated_at = fixed_time_merge(300);
+
+        let merged =
+            merge_issue_fields(&base, &local, &external, ConflictResolution::PreferNewer).unwrap();
+        assert!(merged.conflicts.is_empty());
+        assert_eq!(merged.issue.title, "Local title");
+        assert_eq!(merged.issue.labels, vec!["backend", "ui", "urgent"]);
+        assert_eq!(merged.issue.comments.len(), 1);
+    }
+
+    #[test]
+    fn test_merge_same_field_conflict_reported_with_both_values() {
+        let base = make_issue_with_hash("bd-022", "Base", fixed_time_merge(100), None);
+        let mut local = base.clone();
+        local.title = "Local title".to_string();
+        local.updated_at = fixed_time_merge(200);
+        let mut external = base.clone();
+        external.title = "External title".to_string();
+        external.priority = Priority::LOW;
+        external.updated_at = fixed_time_merge(300);
+
+        let mut base_map = std::collections::HashMap::new();
+        base_map.insert(base.id.clone(), base);
+        let mut left = std::collections::HashMap::new();
+        left.insert(local.id.clone(), local);
+        let mut right = std::collections::HashMap::new();
+        right.insert(external.id.clone(), external);
+        let context = MergeContext::new(base_map, left, right);
+
+        let report = three_way_merge(&context, ConflictResolution::Manual, None).unwrap();
+        assert_eq!(report.conflicts.len(), 1);
+        assert_eq!(report.conflicts[0].1, ConflictType::FieldConflict);
+        assert_eq!(report.field_conflicts.len(), 1);
+        let (id, conflict) = &report.field_conflicts[0];
+        assert_eq!(id, "bd-022");
+        assert_eq!(conflict.field, "title");
+        assert_eq!(conflict.local, serde_json::json!("Local title"));
+        assert_eq!(conflict.external, serde_json::json!("External title"));
+
+        let report = three_way_merge(&context, ConflictResolution::PreferLocal, None).unwrap();
+        assert!(report.conflicts.is_empty());
+        assert_eq!(report.field_conflicts.len(), 1);
+        assert_eq!(report.kept[0].title, "Local title");
+        assert_eq!(report.kept[0].priority, Priority::LOW);
+    }
+
     #[test]
     fn test_merge_convergent_creation_same_content() {
         // Both created independently with same content hash -> keep one
@@ -4187,7 +4628,8 @@ mod tests {
             Some(&local),
             Some(&external),
             ConflictResolution::PreferNewer,
-        );
+        )
+        .unwrap();
         assert!(matches!(result, MergeResult::Keep(..)));
     }
 
@@ -4212,7 +4654,8 @@ mod tests {
             Some(&local),
             Some(&external),
             ConflictResolution::PreferNewer,
-        );
+        )
+        .unwrap();
         assert!(matches!(result, MergeResult::KeepWithNote(issue, _) if issue.title == "External"));
     }
 
@@ -4229,7 +4672,8 @@ mod tests {
             Some(&local),
             Some(&external),
             ConflictResolution::PreferNewer,
-        );
+        )
+        .unwrap();
         assert!(matches!(result, MergeResult::Keep(issue) if issue.id == "bd-013"));
     }
 
@@ -4285,7 +4729,7 @@ mod tests {
         right.insert("bd-003".to_string(), external_issue);
 
         let context = MergeContext::new(base, left, right);
-        let report = three_way_merge(&context, ConflictResolution::PreferNewer, None);
+        let report = three_way_merge(&context, ConflictResolution::PreferNewer, None).unwrap();
 
         // Should keep bd-001 (in all three), bd-002 (local only), bd-003 (external only)
         assert_eq!(report.kept.len(), 3);
@@ -4314,7 +4758,8 @@ mod tests {
         let mut tombstones = std::collections::HashSet::new();
         tombstones.insert("bd-tomb".to_string());
 
-        let report = three_way_merge(&context, ConflictResolution::PreferNewer, Some(&tombstones));
+        let report =
+            three_way_merge(&context, ConflictResolution::PreferNewer, Some(&tombstones)).unwrap();
 
         // Should NOT keep the tombstoned issue
         assert!(report.kept.is_empty());
@@ -4341,7 +4786,8 @@ mod tests {
         let mut tombstones = std::collections::HashSet::new();
         tombstones.insert("bd-tomb".to_string());
 
-        let report = three_way_merge(&context, ConflictResolution::PreferNewer, Some(&tombstones));
+        let report =
+            three_way_merge(&context, ConflictResolution::PreferNewer, Some(&tombstones)).unwrap();
 
         // Should keep local even if tombstoned
         assert_eq!(report.kept.len(), 1);
@@ -4361,7 +4807,7 @@ mod tests {
         let right = std::collections::HashMap::new();
 
         let context = MergeContext::new(base, left, right);
-        let report = three_way_merge(&context, ConflictResolution::PreferNewer, None);
+        let report = three_way_merge(&context, ConflictResolution::PreferNewer, None).unwrap();
 
         assert!(report.kept.is_empty());
         assert_eq!(report.deleted.len(), 1);
@@ -4371,7 +4817,7 @@ mod tests {
     #[test]
     fn test_three_way_merge_empty_context() {
         let context = MergeContext::default();
-        let report = three_way_merge(&context, ConflictResolution::PreferNewer, None);
+        let report = three_way_merge(&context, ConflictResolution::PreferNewer, None).unwrap();
 
         assert!(report.kept.is_empty());
         assert!(report.deleted.is_empty());
@@ -4400,7 +4846,7 @@ mod tests {
         let right = std::collections::HashMap::new();
 
         let context = MergeContext::new(base, left, right);
-        let report = three_way_merge(&context, ConflictResolution::Manual, None);
+        let report = three_way_merge(&context, ConflictResolution::Manual, None).unwrap();
 
         // With Manual strategy, delete-vs-modify should be a conflict
         assert_eq!(report.conflicts.len(), 1);
@@ -4440,7 +4886,7 @@ mod tests {
         right.insert("bd-001".to_string(), external_issue);
 
         let context = MergeContext::new(base, left, right);
-        let report = three_way_merge(&context, ConflictResolution::PreferNewer, None);
+        let report = three_way_merge(&context, ConflictResolution::PreferNewer, None).unwrap();
 
         // Should have a note about the merge decision
         assert_eq!(report.kept.len(), 1);
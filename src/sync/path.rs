@@ -21,6 +21,7 @@
 //! | `.beads/*.jsonl.tmp` | Temp files for atomic writes |
 //! | `.beads/.manifest.json` | Export manifest |
 //! | `.beads/metadata.json` | Workspace metadata |
+//! | `.beads/merge-conflicts.json` | Pending manual merge conflicts |
 //!
 //! # External JSONL Paths
 //!
@@ -54,7 +55,8 @@ pub const ALLOWED_EXTENSIONS: &[&str] = &[
 ];
 
 /// Files explicitly allowed by exact name within `.beads/`.
-pub const ALLOWED_EXACT_NAMES: &[&str] = &[".manifest.json", "metadata.json"];
+pub const ALLOWED_EXACT_NAMES: &[&str] =
+    &[".manifest.json", "metadata.json", "merge-conflicts.json"];
 
 /// Result of path validation.
 #[derive(Debug, Clone, PartialEq, Eq)]
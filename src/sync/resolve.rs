@@ -0,0 +1,626 @@
+//! Persisted merge conflicts and their resolution for `br sync resolve`.
+//!
+//! When `br sync --merge --strategy manual` finds conflicts, nothing is applied.
+//! Instead the conflicting issues (base, local, and external versions) are
+//! written to `.beads/merge-conflicts.json`. Each conflict is then resolved per
+//! field, either interactively or from a JSON resolution file, and once every
+//! conflict is resolved the merge is re-run with those choices applied.
+//!
+//! # Resolution file format
+//!
+//! ```json
+//! {
+//!   "bd-abc": { "title": "local", "priority": { "value": 1 } },
+//!   "bd-def": "external"
+//! }
+//! ```
+//!
+//! A side name (`local`, `external`, `base`) applies to every field of that
+//! issue; a map chooses per field, with `{ "value": ... }` for a hand edit.
+//! Delete-vs-modify conflicts are resolved as a whole with a side name.
+
+use super::{
+    ConflictResolution, ConflictType, FieldConflict, MERGE_FIELD_GROUPS, copy_field_group,
+    issue_field_map, merge_issue_fields,
+};
+use crate::error::{BeadsError, Result};
+use crate::model::Issue;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File name of the pending-conflicts state inside `.beads/`.
+pub const MERGE_CONFLICTS_FILE: &str = "merge-conflicts.json";
+
+/// Choice key used for conflicts resolved as a whole (delete vs modify).
+pub const WHOLE_ISSUE_KEY: &str = "issue";
+
+/// Which version of a conflicting value to keep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Side {
+    Local,
+    External,
+    Base,
+}
+
+impl std::str::FromStr for Side {
+    type Err = BeadsError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "local" | "l" | "ours" => Ok(Self::Local),
+            "external" | "e" | "theirs" | "remote" => Ok(Self::External),
+            "base" | "b" => Ok(Self::Base),
+            other => Err(BeadsError::validation(
+                "resolution",
+                format!("unknown side '{other}' (expected local, external, or base)"),
+            )),
+        }
+    }
+}
+
+/// Resolution of a single field: one of the three versions, or a hand-edited value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FieldChoice {
+    Side(Side),
+    Value { value: serde_json::Value },
+}
+
+/// Resolution for one issue as written in a resolution file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ConflictChoice {
+    All(Side),
+    Fields(BTreeMap<String, FieldChoice>),
+}
+
+/// An unresolved (or partially resolved) conflict for one issue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingConflict {
+    pub id: String,
+    pub kind: ConflictType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<Issue>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub local: Option<Issue>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external: Option<Issue>,
+    /// Same-field conflicts (for `field_conflict`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<FieldConflict>,
+    /// Choices made so far, keyed by field (or [`WHOLE_ISSUE_KEY`]).
+    #[serde(default)]
+    pub choices: BTreeMap<String, FieldChoice>,
+}
+
+/// Final outcome for a resolved conflict.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolvedOutcome {
+    Keep(Box<Issue>),
+    Delete,
+}
+
+impl PendingConflict {
+    /// Keys that need a choice before this conflict is resolved.
+    #[must_use]
+    pub fn required_keys(&self) -> Vec<String> {
+        match self.kind {
+            ConflictType::FieldConflict => self.fields.iter().map(|f| f.field.clone()).collect(),
+            ConflictType::DeleteVsModify | ConflictType::ConvergentCreation => {
+                vec![WHOLE_ISSUE_KEY.to_string()]
+            }
+        }
+    }
+
+    /// Keys still awaiting a choice.
+    #[must_use]
+    pub fn unresolved_keys(&self) -> Vec<String> {
+        self.required_keys()
+            .into_iter()
+            .filter(|key| !self.choices.contains_key(key))
+            .collect()
+    }
+
+    #[must_use]
+    pub fn is_resolved(&self) -> bool {
+        self.unresolved_keys().is_empty()
+    }
+
+    fn version(&self, side: Side) -> Option<&Issue> {
+        match side {
+            Side::Local => self.local.as_ref(),
+            Side::External => self.external.as_ref(),
+            Side::Base => self.base.as_ref(),
+        }
+    }
+
+    /// Record a choice for `key` (a conflicting field, or [`WHOLE_ISSUE_KEY`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `key` is not part of this conflict, a hand edit is
+    /// given for a whole-issue conflict, or `base` is picked for a convergent
+    /// creation (which has no base).
+    pub fn choose(&mut self, key: &str, choice: FieldChoice) -> Result<()> {
+        if !self.required_keys().iter().any(|k| k == key) {
+            return Err(BeadsError::validation(
+                "resolution",
+                format!(
+                    "{} has no conflict on '{key}' (conflicting: {})",
+                    self.id,
+                    self.required_keys().join(", ")
+                ),
+            ));
+        }
+        if key == WHOLE_ISSUE_KEY && matches!(choice, FieldChoice::Value { .. }) {
+            return Err(BeadsError::validation(
+                "resolution",
+                format!(
+                    "{}: {} conflicts can only be resolved with local, external, or base",
+                    self.id,
+                    conflict_kind_label(self.kind)
+                ),
+            ));
+        }
+        if self.kind == ConflictType::ConvergentCreation && choice == FieldChoice::Side(Side::Base)
+        {
+            return Err(BeadsError::validation(
+                "resolution",
+                format!(
+                    "{}: {} conflicts have no base version; pick local or external",
+                    self.id,
+                    conflict_kind_label(self.kind)
+                ),
+            ));
+        }
+        self.choices.insert(key.to_string(), choice);
+        Ok(())
+    }
+
+    /// Apply a resolution-file entry for this issue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any referenced field is not in conflict.
+    pub fn apply(&mut self, choice: &ConflictChoice) -> Result<()> {
+        match choice {
+            ConflictChoice::All(side) => {
+                for key in self.required_keys() {
+                    self.choose(&key, FieldChoice::Side(*side))?;
+                }
+            }
+            ConflictChoice::Fields(fields) => {
+                for (key, choice) in fields {
+                    self.choose(key, choice.clone())?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Compute the resolved issue (or deletion) from the recorded choices.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the conflict is not fully resolved or a hand-edited
+    /// value does not fit the field.
+    pub fn outcome(&self) -> Result<ResolvedOutcome> {
+        if !self.is_resolved() {
+            return Err(BeadsError::validation(
+                "resolution",
+                format!(
+                    "{} is unresolved: {}",
+                    self.id,
+                    self.unresolved_keys().join(", ")
+                ),
+            ));
+        }
+
+        if self.kind != ConflictType::FieldConflict {
+            let side = match self.choices.get(WHOLE_ISSUE_KEY) {
+                Some(FieldChoice::Side(side)) => *side,
+                _ => Side::Local,
+            };
+            return Ok(self.version(side).map_or(ResolvedOutcome::Delete, |issue| {
+                ResolvedOutcome::Keep(Box::new(issue.clone()))
+            }));
+        }
+
+        let (Some(base), Some(local), Some(external)) = (&self.base, &self.local, &self.external)
+        else {
+            return Err(BeadsError::Config(format!(
+                "Conflict state for {} is missing an issue version",
+                self.id
+            )));
+        };
+
+        // Non-conflicting fields and relations merge as usual; conflicting
+        // fields start from local and are overwritten by the choices below.
+        let merged = merge_issue_fields(base, local, external, ConflictResolution::PreferLocal)?;
+        let mut fields = issue_field_map(&merged.issue);
+
+        for (key, choice) in &self.choices {
+            let group = MERGE_FIELD_GROUPS
+                .iter()
+                .find(|group| group[0] == key.as_str())
+                .copied()
+                .unwrap_or(&[]);
+            if group.is_empty() {
+                continue;
+            }
+            match choice {
+                FieldChoice::Side(side) => {
+                    let source = self.version(*side).map(issue_field_map).unwrap_or_default();
+                    copy_field_group(&mut fields, &source, group);
+                }
+                FieldChoice::Value { value } => {
+                    set_field_value(&mut fields, group, value)?;
+                }
+            }
+        }
+
+        fields.insert(
+            "labels".to_string(),
+            serde_json::to_value(&merged.issue.labels)?,
+        );
+        fields.insert(
+            "dependencies".to_string(),
+            serde_json::to_value(&merged.issue.dependencies)?,
+        );
+        fields.insert(
+            "comments".to_string(),
+            serde_json::to_value(&merged.issue.comments)?,
+        );
+        fields.insert(
+            "worklog".to_string(),
+            serde_json::to_value(&merged.issue.worklog)?,
+        );
+
+        let mut issue: Issue =
+            serde_json::from_value(serde_json::Value::Object(fields)).map_err(|e| {
+                BeadsError::validation(
+                    "resolution",
+                    format!(
+                        "{}: resolved values do not form a valid issue: {e}",
+                        self.id
+                    ),
+                )
+            })?;
+        issue.content_hash = Some(issue.compute_content_hash());
+        Ok(ResolvedOutcome::Keep(Box::new(issue)))
+    }
+}
+
+/// Write a hand-edited value into a field group.
+///
+/// Grouped fields (`status`, `compaction_level`) take an object of the grouped
+/// keys; a bare value sets just the group's primary field.
+fn set_field_value(
+    fields: &mut serde_json::Map<String, serde_json::Value>,
+    group: &[&str],
+    value: &serde_json::Value,
+) -> Result<()> {
+    match value {
+        serde_json::Value::Object(object) if group.len() > 1 => {
+            if let Some(unknown) = object.keys().find(|k| !group.contains(&k.as_str())) {
+                return Err(BeadsError::validation(
+                    "resolution",
+                    format!("'{unknown}' is not part of the '{}' field group", group[0]),
+                ));
+            }
+            copy_field_group(fields, object, group);
+        }
+        serde_json::Value::Null => {
+            fields.remove(group[0]);
+        }
+        _ => {
+            fields.insert(group[0].to_string(), value.clone());
+        }
+    }
+    Ok(())
+}
+
+/// Human-readable name for a conflict kind.
+#[must_use]
+pub const fn conflict_kind_label(kind: ConflictType) -> &'static str {
+    match kind {
+        ConflictType::DeleteVsModify => "delete-vs-modify",
+        ConflictType::ConvergentCreation => "convergent-creation",
+        ConflictType::FieldConflict => "field",
+    }
+}
+
+/// Merge conflicts awaiting resolution, as persisted under `.beads/`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingMerge {
+    pub created_at: DateTime<Utc>,
+    /// Hash of the external JSONL when the conflicts were detected.
+    pub jsonl_hash: String,
+    pub conflicts: Vec<PendingConflict>,
+}
+
+impl PendingMerge {
+    /// Path of the pending-conflicts file for a `.beads` directory.
+    #[must_use]
+    pub fn path(beads_dir: &Path) -> PathBuf {
+        beads_dir.join(MERGE_CONFLICTS_FILE)
+    }
+
+    /// Load pending conflicts, if a merge is in progress.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be read or parsed.
+    pub fn load(beads_dir: &Path) -> Result<Option<Self>> {
+        let path = Self::path(beads_dir);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let contents = fs::read_to_string(&path)?;
+        let pending = serde_json::from_str(&contents).map_err(|e| {
+            BeadsError::Config(format!(
+                "Invalid merge conflict file {}: {e}",
+                path.display()
+            ))
+        })?;
+        Ok(Some(pending))
+    }
+
+    /// Persist pending conflicts (atomically, via a temp file).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, beads_dir: &Path) -> Result<()> {
+        let path = Self::path(beads_dir);
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, serde_json::to_string_pretty(self)?)?;
+        fs::rename(&temp_path, &path)?;
+        Ok(())
+    }
+
+    /// Remove the pending-conflicts file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be removed.
+    pub fn clear(beads_dir: &Path) -> Result<()> {
+        let path = Self::path(beads_dir);
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    /// Apply a resolution file (`{ "<id>": "<side>" | { "<field>": <choice> } }`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an ID has no pending conflict or a choice is invalid.
+    pub fn apply_resolutions(
+        &mut self,
+        resolutions: &BTreeMap<String, ConflictChoice>,
+    ) -> Result<()> {
+        for (id, choice) in resolutions {
+            self.conflict_mut(id)?.apply(choice)?;
+        }
+        Ok(())
+    }
+
+    /// Look up the pending conflict for an issue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue has no pending conflict.
+    pub fn conflict_mut(&mut self, id: &str) -> Result<&mut PendingConflict> {
+        self.conflicts
+            .iter_mut()
+            .find(|c| c.id == id)
+            .ok_or_else(|| {
+                BeadsError::validation("resolution", format!("no pending merge conflict for {id}"))
+            })
+    }
+
+    /// Number of conflicts that still need choices.
+    #[must_use]
+    pub fn unresolved_count(&self) -> usize {
+        self.conflicts.iter().filter(|c| !c.is_resolved()).count()
+    }
+
+    /// Resolved outcomes keyed by issue ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any conflict is unresolved or cannot be applied.
+    pub fn outcomes(&self) -> Result<BTreeMap<String, ResolvedOutcome>> {
+        self.conflicts
+            .iter()
+            .map(|c| Ok((c.id.clone(), c.outcome()?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Priority;
+    use chrono::TimeZone;
+
+    fn issue(title: &str, seconds: i64) -> Issue {
+        let at = Utc.timestamp_opt(seconds, 0).unwrap();
+        Issue {
+            id: "bd-1".to_string(),
+            title: title.to_string(),
+            created_at: at,
+            updated_at: at,
+            ..Issue::default()
+        }
+    }
+
+    fn field_conflict() -> PendingConflict {
+        let base = issue("Base", 100);
+        let mut local = issue("Local", 200);
+        local.priority = Priority::HIGH;
+        let mut external = issue("External", 300);
+        external.priority = Priority::LOW;
+        external.notes = Some("from external".to_string());
+
+        let merged =
+            merge_issue_fields(&base, &local, &external, ConflictResolution::Manual).unwrap();
+        PendingConflict {
+            id: "bd-1".to_string(),
+            kind: ConflictType::FieldConflict,
+            base: Some(base),
+            local: Some(local),
+            external: Some(external),
+            fields: merged.conflicts,
+            choices: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_field_choices_and_hand_edits() {
+        let mut conflict = field_conflict();
+        assert_eq!(conflict.required_keys(), vec!["title", "priority"]);
+        assert!(conflict.outcome().is_err());
+
+        conflict
+            .choose("title", FieldChoice::Side(Side::External))
+            .unwrap();
+        conflict
+            .choose(
+                "priority",
+                FieldChoice::Value {
+                    value: serde_json::json!(0),
+                },
+            )
+            .unwrap();
+        assert!(
+            conflict
+                .choose("design", FieldChoice::Side(Side::Base))
+                .is_err()
+        );
+
+        let ResolvedOutcome::Keep(resolved) = conflict.outcome().unwrap() else {
+            panic!("expected a kept issue");
+        };
+        assert_eq!(resolved.title, "External");
+        assert_eq!(resolved.priority, Priority::CRITICAL);
+        assert_eq!(resolved.notes.as_deref(), Some("from external"));
+    }
+
+    #[test]
+    fn test_resolved_conflict_keeps_worklog() {
+        let entry = |id: i64, minutes: i64| crate::model::WorkLogEntry {
+            id,
+            issue_id: "bd-1".to_string(),
+            actor: "alice".to_string(),
+            minutes: Some(minutes),
+            note: None,
+            started_at: None,
+            created_at: Utc.timestamp_opt(100 + id, 0).unwrap(),
+        };
+        let mut conflict = field_conflict();
+        for version in [
+            &mut conflict.base,
+            &mut conflict.local,
+            &mut conflict.external,
+        ] {
+            version.as_mut().unwrap().worklog.push(entry(1, 30));
+        }
+        conflict.local.as_mut().unwrap().worklog.push(entry(2, 45));
+
+        conflict
+            .choose("title", FieldChoice::Side(Side::Local))
+            .unwrap();
+        conflict
+            .choose("priority", FieldChoice::Side(Side::External))
+            .unwrap();
+        let ResolvedOutcome::Keep(resolved) = conflict.outcome().unwrap() else {
+            panic!("expected a kept issue");
+        };
+        let minutes: Vec<Option<i64>> = resolved.worklog.iter().map(|e| e.minutes).collect();
+        assert_eq!(minutes, [Some(30), Some(45)]);
+    }
+
+    #[test]
+    fn test_resolution_file_format() {
+        let json =
+            r#"{ "bd-1": { "title": "base", "priority": { "value": 4 } }, "bd-2": "external" }"#;
+        let parsed: BTreeMap<String, ConflictChoice> = serde_json::from_str(json).unwrap();
+
+        let deleted = PendingConflict {
+            id: "bd-2".to_string(),
+            kind: ConflictType::DeleteVsModify,
+            base: Some(issue("Base", 100)),
+            local: Some(issue("Edited", 200)),
+            external: None,
+            fields: Vec::new(),
+            choices: BTreeMap::new(),
+        };
+
+        let mut pending = PendingMerge {
+            created_at: Utc::now(),
+            jsonl_hash: String::new(),
+            conflicts: vec![field_conflict(), deleted],
+        };
+        assert_eq!(pending.unresolved_count(), 2);
+        pending.apply_resolutions(&parsed).unwrap();
+        assert_eq!(pending.unresolved_count(), 0);
+
+        let outcomes = pending.outcomes().unwrap();
+        assert_eq!(outcomes["bd-2"], ResolvedOutcome::Delete);
+        let ResolvedOutcome::Keep(resolved) = &outcomes["bd-1"] else {
+            panic!("expected a kept issue");
+        };
+        assert_eq!(resolved.title, "Base");
+        assert_eq!(resolved.priority, Priority::BACKLOG);
+    }
+
+    #[test]
+    fn test_convergent_creation_rejects_base() {
+        let mut conflict = PendingConflict {
+            id: "bd-3".to_string(),
+            kind: ConflictType::ConvergentCreation,
+            base: None,
+            local: Some(issue("Local", 200)),
+            external: Some(issue("External", 300)),
+            fields: Vec::new(),
+            choices: BTreeMap::new(),
+        };
+        assert!(conflict.apply(&ConflictChoice::All(Side::Base)).is_err());
+        assert!(!conflict.is_resolved());
+
+        conflict
+            .apply(&ConflictChoice::All(Side::External))
+            .unwrap();
+        let ResolvedOutcome::Keep(resolved) = conflict.outcome().unwrap() else {
+            panic!("expected a kept issue");
+        };
+        assert_eq!(resolved.title, "External");
+    }
+
+    #[test]
+    fn test_pending_merge_round_trip() {
+        let dir = tempfile::TempDir::new().unwrap();
+        assert!(PendingMerge::load(dir.path()).unwrap().is_none());
+
+        let pending = PendingMerge {
+            created_at: Utc::now(),
+            jsonl_hash: "abc".to_string(),
+            conflicts: vec![field_conflict()],
+        };
+        pending.save(dir.path()).unwrap();
+
+        let loaded = PendingMerge::load(dir.path()).unwrap().expect("pending");
+        assert_eq!(loaded.conflicts.len(), 1);
+        assert_eq!(loaded.conflicts[0].fields.len(), 2);
+
+        PendingMerge::clear(dir.path()).unwrap();
+        assert!(!PendingMerge::path(dir.path()).exists());
+    }
+}
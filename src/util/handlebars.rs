@@ -0,0 +1,487 @@
+//! Minimal Handlebars-style templates for user-supplied output formats.
+//!
+//! Supported syntax:
+//! - `{{path}}` (escaped by the caller's escaper) and `{{{path}}}` (raw)
+//! - `{{#each path}}`, `{{#if path}}`, `{{#unless path}}`, each with an
+//!   optional `{{else}}`
+//! - `{{! comment }}`
+//!
+//! Paths are dotted (`issue.title`) and resolve in the current context;
+//! `../` reaches the enclosing one, and `this`, `@root`, `@index`, `@first`,
+//! `@last` and `@key` work as in Handlebars. A block tag alone on its line
+//! leaves no blank line behind. Falsy values are `null`, `false`, `0`, `""`
+//! and `[]`.
+
+use crate::error::{BeadsError, Result};
+use serde_json::{Value, json};
+use std::borrow::Cow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Helper {
+    Each,
+    If,
+    Unless,
+}
+
+impl Helper {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "each" => Some(Self::Each),
+            "if" => Some(Self::If),
+            "unless" => Some(Self::Unless),
+            _ => None,
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Each => "each",
+            Self::If => "if",
+            Self::Unless => "unless",
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Token {
+    Text(String),
+    Var { path: String, raw: bool },
+    Open { helper: Helper, path: String },
+    Else,
+    Close(String),
+    Comment,
+}
+
+impl Token {
+    const fn is_block_tag(&self) -> bool {
+        matches!(
+            self,
+            Self::Open { .. } | Self::Else | Self::Close(_) | Self::Comment
+        )
+    }
+}
+
+#[derive(Debug)]
+enum Node {
+    Text(String),
+    Var {
+        path: String,
+        raw: bool,
+    },
+    Block {
+        helper: Helper,
+        path: String,
+        body: Vec<Node>,
+        inverse: Vec<Node>,
+    },
+}
+
+/// A parsed template.
+#[derive(Debug)]
+pub struct Template {
+    nodes: Vec<Node>,
+}
+
+fn template_error(message: impl Into<String>) -> BeadsError {
+    BeadsError::validation("template", message)
+}
+
+impl Template {
+    /// Parse a template.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error for unclosed tags, unknown block helpers,
+    /// and unbalanced blocks.
+    pub fn parse(source: &str) -> Result<Self> {
+        let mut tokens = tokenize(source)?;
+        strip_standalone(&mut tokens);
+        Ok(Self {
+            nodes: build(tokens)?,
+        })
+    }
+
+    /// Render the template against `data`. `{{path}}` values pass through
+    /// `escape`; `{{{path}}}` values do not.
+    #[must_use]
+    pub fn render(&self, data: &Value, escape: fn(&str) -> String) -> String {
+        let mut out = String::new();
+        let mut scopes = vec![Scope {
+            value: data,
+            index: None,
+            len: 0,
+            key: None,
+        }];
+        render_nodes(&self.nodes, &mut scopes, escape, &mut out);
+        out
+    }
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut rest = source;
+    while let Some(start) = rest.find("{{") {
+        if start > 0 {
+            tokens.push(Token::Text(rest[..start].to_string()));
+        }
+        let tag = &rest[start..];
+        let (open, close) = if tag.starts_with("{{{") {
+            (3, "}}}")
+        } else {
+            (2, "}}")
+        };
+        let end = tag[open..].find(close).ok_or_else(|| {
+            let snippet: String = tag.chars().take(30).collect();
+            template_error(format!("unclosed tag at '{snippet}'"))
+        })?;
+        let inner = tag[open..open + end].trim();
+        tokens.push(if open == 3 {
+            Token::Var {
+                path: inner.to_string(),
+                raw: true,
+            }
+        } else {
+            parse_tag(inner)?
+        });
+        rest = &tag[open + end + close.len()..];
+    }
+    if !rest.is_empty() {
+        tokens.push(Token::Text(rest.to_string()));
+    }
+    Ok(tokens)
+}
+
+fn parse_tag(inner: &str) -> Result<Token> {
+    if inner.starts_with('!') {
+        return Ok(Token::Comment);
+    }
+    if let Some(open) = inner.strip_prefix('#') {
+        let (name, path) = open.split_once(char::is_whitespace).unwrap_or((open, ""));
+        let helper = Helper::parse(name)
+            .ok_or_else(|| template_error(format!("unknown block helper '#{name}'")))?;
+        let path = path.trim();
+        if path.is_empty() {
+            return Err(template_error(format!("'#{name}' needs a path")));
+        }
+        return Ok(Token::Open {
+            helper,
+            path: path.to_string(),
+        });
+    }
+    if let Some(name) = inner.strip_prefix('/') {
+        return Ok(Token::Close(name.trim().to_string()));
+    }
+    if inner == "else" {
+        return Ok(Token::Else);
+    }
+    if inner.is_empty() {
+        return Err(template_error("empty tag"));
+    }
+    Ok(Token::Var {
+        path: inner.to_string(),
+        raw: false,
+    })
+}
+
+/// Remove the indentation and line break around block tags that sit alone
+/// on their line.
+fn strip_standalone(tokens: &mut [Token]) {
+    let standalone: Vec<usize> = (0..tokens.len())
+        .filter(|&i| tokens[i].is_block_tag())
+        .filter(|&i| {
+            let before = match i.checked_sub(1).map(|j| &tokens[j]) {
+                None => true,
+                Some(Token::Text(text)) => {
+                    let tail = text.rsplit('\n').next().unwrap_or("");
+                    tail.trim().is_empty() && (text.contains('\n') || i == 1)
+                }
+                Some(_) => false,
+            };
+            let after = match tokens.get(i + 1) {
+                None => true,
+                Some(Token::Text(text)) => {
+                    let head = text.split('\n').next().unwrap_or("");
+                    head.trim().is_empty() && (text.contains('\n') || i + 2 == tokens.len())
+                }
+                Some(_) => false,
+            };
+            before && after
+        })
+        .collect();
+
+    for i in standalone {
+        if let Some(Token::Text(text)) = i.checked_sub(1).map(|j| &mut tokens[j]) {
+            let keep = text.rfind('\n').map_or(0, |pos| pos + 1);
+            text.truncate(keep);
+        }
+        if let Some(Token::Text(text)) = tokens.get_mut(i + 1) {
+            let end = text.find('\n').map_or(text.len(), |pos| pos + 1);
+            text.drain(..end);
+        }
+    }
+}
+
+struct Frame {
+    helper: Helper,
+    path: String,
+    body: Vec<Node>,
+    inverse: Option<Vec<Node>>,
+}
+
+fn build(tokens: Vec<Token>) -> Result<Vec<Node>> {
+    let mut stack: Vec<Frame> = Vec::new();
+    let mut root: Vec<Node> = Vec::new();
+    for token in tokens {
+        let node = match token {
+            Token::Comment => continue,
+            Token::Text(text) if text.is_empty() => continue,
+            Token::Text(text) => Node::Text(text),
+            Token::Var { path, raw } => Node::Var { path, raw },
+            Token::Open { helper, path } => {
+                stack.push(Frame {
+                    helper,
+                    path,
+                    body: Vec::new(),
+                    inverse: None,
+                });
+                continue;
+            }
+            Token::Else => {
+                let frame = stack
+                    .last_mut()
+                    .ok_or_else(|| template_error("'else' outside a block"))?;
+                if frame.inverse.is_some() {
+                    return Err(template_error(format!(
+                        "'#{} {}' has more than one 'else'",
+                        frame.helper.name(),
+                        frame.path
+                    )));
+                }
+                frame.inverse = Some(Vec::new());
+                continue;
+            }
+            Token::Close(name) => {
+                let frame = stack
+                    .pop()
+                    .ok_or_else(|| template_error(format!("'/{name}' without an open block")))?;
+                if name != frame.helper.name() {
+                    return Err(template_error(format!(
+                        "'/{name}' closes '#{} {}'",
+                        frame.helper.name(),
+                        frame.path
+                    )));
+                }
+                Node::Block {
+                    helper: frame.helper,
+                    path: frame.path,
+                    body: frame.body,
+                    inverse: frame.inverse.unwrap_or_default(),
+                }
+            }
+        };
+        let target = stack.last_mut().map_or(&mut root, |frame| {
+            frame.inverse.as_mut().unwrap_or(&mut frame.body)
+        });
+        target.push(node);
+    }
+    if let Some(frame) = stack.last() {
+        return Err(template_error(format!(
+            "'#{} {}' is never closed",
+            frame.helper.name(),
+            frame.path
+        )));
+    }
+    Ok(root)
+}
+
+struct Scope<'a> {
+    value: &'a Value,
+    index: Option<usize>,
+    len: usize,
+    key: Option<&'a str>,
+}
+
+fn lookup<'a>(path: &str, scopes: &[Scope<'a>]) -> Cow<'a, Value> {
+    let mut depth = scopes.len() - 1;
+    let mut path = path.trim();
+    if let Some(rest) = path.strip_prefix("@root") {
+        depth = 0;
+        path = rest.trim_start_matches('.');
+    }
+    while let Some(rest) = path.strip_prefix("../") {
+        depth = depth.saturating_sub(1);
+        path = rest;
+    }
+    let scope = &scopes[depth];
+    match path {
+        "" | "this" | "." => return Cow::Borrowed(scope.value),
+        "@index" => return Cow::Owned(scope.index.map_or(Value::Null, |i| json!(i))),
+        "@first" => return Cow::Owned(Value::Bool(scope.index == Some(0))),
+        "@last" => {
+            return Cow::Owned(Value::Bool(scope.index.is_some_and(|i| i + 1 == scope.len)));
+        }
+        "@key" => return Cow::Owned(scope.key.map_or(Value::Null, |key| json!(key))),
+        _ => {}
+    }
+    let mut value = scope.value;
+    for part in path.strip_prefix("this.").unwrap_or(path).split('.') {
+        let next = match part.parse::<usize>() {
+            Ok(index) if value.is_array() => value.get(index),
+            _ => value.get(part),
+        };
+        let Some(next) = next else {
+            return Cow::Owned(Value::Null);
+        };
+        value = next;
+    }
+    Cow::Borrowed(value)
+}
+
+fn truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64().is_some_and(|n| n != 0.0),
+        Value::String(s) => !s.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Object(_) => true,
+    }
+}
+
+fn display(value: &Value) -> String {
+    match value {
+        Value::Null | Value::Object(_) => String::new(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => s.clone(),
+        Value::Array(items) => items.iter().map(display).collect::<Vec<_>>().join(", "),
+    }
+}
+
+fn render_nodes<'a>(
+    nodes: &[Node],
+    scopes: &mut Vec<Scope<'a>>,
+    escape: fn(&str) -> String,
+    out: &mut String,
+) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Var { path, raw } => {
+                let text = display(&lookup(path, scopes));
+                if *raw {
+                    out.push_str(&text);
+                } else {
+                    out.push_str(&escape(&text));
+                }
+            }
+            Node::Block {
+                helper,
+                path,
+                body,
+                inverse,
+            } => {
+                let value = lookup(path, scopes);
+                match helper {
+                    Helper::If | Helper::Unless => {
+                        let show_body = truthy(&value) == (*helper == Helper::If);
+                        let branch = if show_body { body } else { inverse };
+                        render_nodes(branch, scopes, escape, out);
+                    }
+                    Helper::Each => {
+                        let items: Vec<(Option<&'a str>, &'a Value)> = match value {
+                            Cow::Borrowed(Value::Array(items)) => {
+                                items.iter().map(|item| (None, item)).collect()
+                            }
+                            Cow::Borrowed(Value::Object(map)) => map
+                                .iter()
+                                .map(|(key, item)| (Some(key.as_str()), item))
+                                .collect(),
+                            _ => Vec::new(),
+                        };
+                        if items.is_empty() {
+                            render_nodes(inverse, scopes, escape, out);
+                            continue;
+                        }
+                        let len = items.len();
+                        for (index, (key, item)) in items.into_iter().enumerate() {
+                            scopes.push(Scope {
+                                value: item,
+                                index: Some(index),
+                                len,
+                                key,
+                            });
+                            render_nodes(body, scopes, escape, out);
+                            scopes.pop();
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(text: &str) -> String {
+        text.to_string()
+    }
+
+    fn html(text: &str) -> String {
+        text.replace('&', "&amp;").replace('<', "&lt;")
+    }
+
+    #[test]
+    fn test_variables_and_escaping() {
+        let data = json!({"title": "a < b", "count": 2, "meta": {"tag": "v1"}});
+        let template =
+            Template::parse("{{title}}|{{{title}}}|{{count}}|{{meta.tag}}|{{missing}}").unwrap();
+        assert_eq!(template.render(&data, html), "a &lt; b|a < b|2|v1|");
+    }
+
+    #[test]
+    fn test_blocks_and_standalone_lines() {
+        let data = json!({
+            "name": "Release",
+            "groups": [
+                {"label": "Fixes", "items": ["one", "two"]},
+                {"label": "Empty", "items": []}
+            ]
+        });
+        let source = "\
+{{! header }}
+# {{name}}
+{{#each groups}}
+## {{label}} ({{@index}})
+  {{#each items}}
+- {{this}} in {{../label}} of {{@root.name}}{{#unless @last}},{{/unless}}
+  {{else}}
+Nothing.
+  {{/each}}
+{{/each}}
+{{#if missing}}yes{{else}}no{{/if}}
+";
+        let rendered = Template::parse(source).unwrap().render(&data, plain);
+        assert_eq!(
+            rendered,
+            "# Release\n## Fixes (0)\n- one in Fixes of Release,\n- two in Fixes of Release\n\
+             ## Empty (1)\nNothing.\nno\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for source in [
+            "{{#each items}}",
+            "{{/if}}",
+            "{{#if a}}{{/each}}",
+            "{{#with a}}{{/with}}",
+            "{{title",
+            "{{else}}",
+        ] {
+            assert!(Template::parse(source).is_err(), "{source}");
+        }
+    }
+}
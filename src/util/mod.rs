@@ -7,12 +7,19 @@
 //! - ID generation (base36 adaptive)
 //! - Last-touched tracking
 //! - Progress indicators (for long-running operations)
+//! - Recurrence rules (for repeating issues)
+//! - Summarizers (for issue compaction)
+//! - Handlebars-style templates (for user-supplied output formats)
 
+pub mod handlebars;
 mod hash;
 pub mod id;
 pub mod markdown_import;
 pub mod progress;
+pub mod recurrence;
+pub mod summarize;
 pub mod time;
+pub mod tracker_import;
 
 pub use hash::{ContentHashable, content_hash, content_hash_from_parts};
 pub use id::{
@@ -0,0 +1,649 @@
+//! Recurrence rules for repeating issues.
+//!
+//! Two rule families are supported:
+//!
+//! - Calendar rules, a subset of iCalendar RRULE:
+//!   `FREQ=DAILY|WEEKLY|MONTHLY|YEARLY`, `INTERVAL=n`, `BYDAY=MO,TH`
+//!   (weekly only) and `BYMONTHDAY=15` (monthly and yearly). Shorthands such as
+//!   `weekly`, `every 2 weeks`, `weekly:mon,thu` and `monthly:15` parse to
+//!   the same rules.
+//! - Cron rules: `cron:<minute> <hour> <day-of-month> <month> <day-of-week>`
+//!   with `*`, numbers, lists, ranges and `*/n` steps.
+//!
+//! Calendar rules keep the time of day of the occurrence they advance from.
+//! Monthly and yearly rules should be [anchored](RecurrenceRule::anchored)
+//! to a day of the month, so a series that clamps to a shorter month (Jan 31
+//! to Feb 28) returns to its day afterwards. All computations are in UTC.
+
+use crate::error::{BeadsError, Result};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Timelike, Utc, Weekday};
+use std::fmt;
+use std::str::FromStr;
+
+/// How far ahead cron rules are searched before giving up (about four years,
+/// enough for `29 2` schedules).
+const CRON_SEARCH_DAYS: i64 = 366 * 4 + 1;
+
+/// Calendar frequency of a recurrence rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl Frequency {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Daily => "DAILY",
+            Self::Weekly => "WEEKLY",
+            Self::Monthly => "MONTHLY",
+            Self::Yearly => "YEARLY",
+        }
+    }
+}
+
+impl FromStr for Frequency {
+    type Err = BeadsError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "daily" | "day" | "days" => Ok(Self::Daily),
+            "weekly" | "week" | "weeks" => Ok(Self::Weekly),
+            "monthly" | "month" | "months" => Ok(Self::Monthly),
+            "yearly" | "year" | "years" | "annually" => Ok(Self::Yearly),
+            other => Err(invalid(format!("unknown frequency '{other}'"))),
+        }
+    }
+}
+
+/// A parsed recurrence rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecurrenceRule {
+    Calendar {
+        frequency: Frequency,
+        interval: u32,
+        /// Weekdays for weekly rules (empty = same weekday as the last occurrence).
+        weekdays: Vec<Weekday>,
+        /// Day of month for monthly and yearly rules (`None` = same day as the
+        /// last occurrence).
+        month_day: Option<u32>,
+    },
+    Cron(CronSchedule),
+}
+
+impl RecurrenceRule {
+    /// Pin a monthly or yearly rule without a day of month to the day of
+    /// `at`, the series' first occurrence. Other rules are returned as is.
+    #[must_use]
+    pub fn anchored(self, at: DateTime<Utc>) -> Self {
+        match self {
+            Self::Calendar {
+                frequency: frequency @ (Frequency::Monthly | Frequency::Yearly),
+                interval,
+                weekdays,
+                month_day: None,
+            } => Self::Calendar {
+                frequency,
+                interval,
+                weekdays,
+                month_day: Some(at.day()),
+            },
+            rule => rule,
+        }
+    }
+
+    /// The first occurrence strictly after `after`.
+    ///
+    /// Returns `None` only for cron rules that never match (e.g. `0 0 31 2 *`).
+    #[must_use]
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Calendar {
+                frequency,
+                interval,
+                weekdays,
+                month_day,
+            } => Some(next_calendar(
+                *frequency, *interval, weekdays, *month_day, after,
+            )),
+            Self::Cron(schedule) => schedule.next_after(after),
+        }
+    }
+}
+
+impl FromStr for RecurrenceRule {
+    type Err = BeadsError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(invalid("rule is empty"));
+        }
+        let lower = s.to_ascii_lowercase();
+
+        if let Some(expr) = lower.strip_prefix("cron:") {
+            return Ok(Self::Cron(expr.parse()?));
+        }
+        if let Some(rrule) = lower.strip_prefix("rrule:") {
+            return parse_rrule(rrule);
+        }
+        if lower.starts_with("freq=") {
+            return parse_rrule(&lower);
+        }
+        if let Some(rest) = lower.strip_prefix("every ") {
+            return parse_every(rest);
+        }
+        if let Some(days) = lower.strip_prefix("weekly:") {
+            return Ok(calendar(Frequency::Weekly, 1, parse_weekdays(days)?, None));
+        }
+        if let Some(day) = lower.strip_prefix("monthly:") {
+            return Ok(calendar(
+                Frequency::Monthly,
+                1,
+                Vec::new(),
+                Some(parse_month_day(day)?),
+            ));
+        }
+        if lower.split_whitespace().count() == 5 {
+            return Ok(Self::Cron(lower.parse()?));
+        }
+
+        let frequency = lower.parse().map_err(|_| {
+            invalid(format!(
+                "unrecognized rule '{s}' (try daily, weekly:mon, monthly:15, \
+                 every 2 weeks, FREQ=WEEKLY;BYDAY=MO or cron:0 9 * * 1)"
+            ))
+        })?;
+        Ok(calendar(frequency, 1, Vec::new(), None))
+    }
+}
+
+/// Canonical form: RRULE syntax for calendar rules, `cron:` for cron rules.
+impl fmt::Display for RecurrenceRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Calendar {
+                frequency,
+                interval,
+                weekdays,
+                month_day,
+            } => {
+                write!(f, "FREQ={}", frequency.as_str())?;
+                if *interval != 1 {
+                    write!(f, ";INTERVAL={interval}")?;
+                }
+                if !weekdays.is_empty() {
+                    let days: Vec<&str> = weekdays.iter().map(|d| rrule_weekday(*d)).collect();
+                    write!(f, ";BYDAY={}", days.join(","))?;
+                }
+                if let Some(day) = month_day {
+                    write!(f, ";BYMONTHDAY={day}")?;
+                }
+                Ok(())
+            }
+            Self::Cron(schedule) => write!(f, "cron:{schedule}"),
+        }
+    }
+}
+
+fn invalid(reason: impl Into<String>) -> BeadsError {
+    BeadsError::validation("rule", reason)
+}
+
+fn calendar(
+    frequency: Frequency,
+    interval: u32,
+    mut weekdays: Vec<Weekday>,
+    month_day: Option<u32>,
+) -> RecurrenceRule {
+    weekdays.sort_by_key(Weekday::num_days_from_monday);
+    weekdays.dedup();
+    RecurrenceRule::Calendar {
+        frequency,
+        interval,
+        weekdays,
+        month_day,
+    }
+}
+
+fn parse_interval(s: &str) -> Result<u32> {
+    match s.trim().parse::<u32>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(invalid(format!(
+            "interval must be a positive integer, got '{s}'"
+        ))),
+    }
+}
+
+fn parse_month_day(s: &str) -> Result<u32> {
+    match s.trim().parse::<u32>() {
+        Ok(day) if (1..=31).contains(&day) => Ok(day),
+        _ => Err(invalid(format!("day of month must be 1-31, got '{s}'"))),
+    }
+}
+
+fn parse_weekdays(s: &str) -> Result<Vec<Weekday>> {
+    s.split(',')
+        .map(|day| parse_weekday(day.trim()))
+        .collect::<Result<Vec<_>>>()
+}
+
+fn parse_weekday(s: &str) -> Result<Weekday> {
+    let day = match s {
+        "mo" | "mon" | "monday" => Weekday::Mon,
+        "tu" | "tue" | "tuesday" => Weekday::Tue,
+        "we" | "wed" | "wednesday" => Weekday::Wed,
+        "th" | "thu" | "thursday" => Weekday::Thu,
+        "fr" | "fri" | "friday" => Weekday::Fri,
+        "sa" | "sat" | "saturday" => Weekday::Sat,
+        "su" | "sun" | "sunday" => Weekday::Sun,
+        other => return Err(invalid(format!("unknown weekday '{other}'"))),
+    };
+    Ok(day)
+}
+
+const fn rrule_weekday(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+/// Parse `FREQ=...;INTERVAL=...;BYDAY=...;BYMONTHDAY=...` (already lowercased).
+fn parse_rrule(s: &str) -> Result<RecurrenceRule> {
+    let mut frequency = None;
+    let mut interval = 1;
+    let mut weekdays = Vec::new();
+    let mut month_day = None;
+
+    for part in s.split(';').map(str::trim).filter(|p| !p.is_empty()) {
+        let (key, value) = part
+            .split_once('=')
+            .ok_or_else(|| invalid(format!("expected KEY=VALUE, got '{part}'")))?;
+        match key.trim() {
+            "freq" => frequency = Some(value.parse::<Frequency>()?),
+            "interval" => interval = parse_interval(value)?,
+            "byday" => weekdays = parse_weekdays(value)?,
+            "bymonthday" => month_day = Some(parse_month_day(value)?),
+            other => {
+                return Err(invalid(format!(
+                    "unsupported RRULE part '{}'",
+                    other.to_ascii_uppercase()
+                )));
+            }
+        }
+    }
+
+    let frequency = frequency.ok_or_else(|| invalid("RRULE requires FREQ"))?;
+    if !weekdays.is_empty() && frequency != Frequency::Weekly {
+        return Err(invalid("BYDAY is only supported with FREQ=WEEKLY"));
+    }
+    if month_day.is_some() && !matches!(frequency, Frequency::Monthly | Frequency::Yearly) {
+        return Err(invalid(
+            "BYMONTHDAY is only supported with FREQ=MONTHLY or FREQ=YEARLY",
+        ));
+    }
+    Ok(calendar(frequency, interval, weekdays, month_day))
+}
+
+/// Parse the part after `every `: `day`, `2 weeks`, `3 months`.
+fn parse_every(s: &str) -> Result<RecurrenceRule> {
+    let mut words = s.split_whitespace();
+    let (interval, unit) = match (words.next(), words.next(), words.next()) {
+        (Some(unit), None, None) => (1, unit),
+        (Some(n), Some(unit), None) => (parse_interval(n)?, unit),
+        _ => {
+            return Err(invalid(format!(
+                "expected 'every [N] <unit>', got 'every {s}'"
+            )));
+        }
+    };
+    Ok(calendar(unit.parse()?, interval, Vec::new(), None))
+}
+
+fn next_calendar(
+    frequency: Frequency,
+    interval: u32,
+    weekdays: &[Weekday],
+    month_day: Option<u32>,
+    after: DateTime<Utc>,
+) -> DateTime<Utc> {
+    let interval = i64::from(interval.max(1));
+    match frequency {
+        Frequency::Daily => after + Duration::days(interval),
+        Frequency::Weekly if weekdays.is_empty() => after + Duration::weeks(interval),
+        Frequency::Weekly => {
+            let week_start = after.date_naive()
+                - Duration::days(i64::from(after.weekday().num_days_from_monday()));
+            (1..=7 * interval + 7)
+                .map(|offset| after + Duration::days(offset))
+                .find(|candidate| {
+                    let weeks = (candidate.date_naive() - week_start).num_days() / 7;
+                    weeks % interval == 0 && weekdays.contains(&candidate.weekday())
+                })
+                .unwrap_or_else(|| after + Duration::weeks(interval))
+        }
+        Frequency::Monthly => {
+            let day = month_day.unwrap_or_else(|| after.day());
+            (0..)
+                .map(|step| shift_months(after, step * interval, day))
+                .find(|candidate| *candidate > after)
+                .unwrap_or(after)
+        }
+        Frequency::Yearly => {
+            let day = month_day.unwrap_or_else(|| after.day());
+            (1..)
+                .map(|step| shift_months(after, step * interval * 12, day))
+                .find(|candidate| *candidate > after)
+                .unwrap_or(after)
+        }
+    }
+}
+
+/// `at` moved by `months`, on `day` (clamped to the month's last day), keeping the time.
+fn shift_months(at: DateTime<Utc>, months: i64, day: u32) -> DateTime<Utc> {
+    let total = i64::from(at.year()) * 12 + i64::from(at.month0()) + months;
+    let year = i32::try_from(total.div_euclid(12)).unwrap_or(i32::MAX);
+    let month = u32::try_from(total.rem_euclid(12)).unwrap_or(0) + 1;
+    let day = day.min(days_in_month(year, month));
+    NaiveDate::from_ymd_opt(year, month, day)
+        .and_then(|date| date.and_time(at.time()).and_local_timezone(Utc).single())
+        .unwrap_or(at)
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    NaiveDate::from_ymd_opt(next_year, next_month, 1)
+        .and_then(|first| first.pred_opt())
+        .map_or(28, |last| last.day())
+}
+
+/// A five-field cron schedule (minute, hour, day of month, month, day of week).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    expression: String,
+    minutes: Vec<u32>,
+    hours: Vec<u32>,
+    month_days: Vec<u32>,
+    months: Vec<u32>,
+    /// 0 = Sunday, as in cron (7 is accepted and folded to 0).
+    weekdays: Vec<u32>,
+    month_day_any: bool,
+    weekday_any: bool,
+}
+
+impl CronSchedule {
+    /// The first matching minute strictly after `after`.
+    #[must_use]
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let start = after.date_naive();
+        for offset in 0..CRON_SEARCH_DAYS {
+            let date = start + Duration::days(offset);
+            if !self.matches_date(date) {
+                continue;
+            }
+            for &hour in &self.hours {
+                for &minute in &self.minutes {
+                    let candidate = date
+                        .and_hms_opt(hour, minute, 0)
+                        .and_then(|naive| naive.and_local_timezone(Utc).single())
+                        .filter(|candidate| *candidate > after);
+                    if candidate.is_some() {
+                        return candidate;
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        if !self.months.contains(&date.month()) {
+            return false;
+        }
+        let dom = self.month_days.contains(&date.day());
+        let dow = self
+            .weekdays
+            .contains(&date.weekday().num_days_from_sunday());
+        // Standard cron: when both day fields are restricted, either may match.
+        match (self.month_day_any, self.weekday_any) {
+            (true, true) => true,
+            (true, false) => dow,
+            (false, true) => dom,
+            (false, false) => dom || dow,
+        }
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = BeadsError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let fields: Vec<&str> = s.split_whitespace().collect();
+        let [minute, hour, dom, month, dow] = fields.as_slice() else {
+            return Err(invalid(format!(
+                "cron expression needs 5 fields (minute hour day month weekday), got '{s}'"
+            )));
+        };
+
+        let mut weekdays: Vec<u32> = parse_cron_field(dow, 0, 7, "day of week")?
+            .into_iter()
+            .map(|d| d % 7)
+            .collect();
+        weekdays.sort_unstable();
+        weekdays.dedup();
+
+        Ok(Self {
+            expression: fields.join(" "),
+            minutes: parse_cron_field(minute, 0, 59, "minute")?,
+            hours: parse_cron_field(hour, 0, 23, "hour")?,
+            month_days: parse_cron_field(dom, 1, 31, "day of month")?,
+            months: parse_cron_field(month, 1, 12, "month")?,
+            weekdays,
+            month_day_any: *dom == "*",
+            weekday_any: *dow == "*",
+        })
+    }
+}
+
+impl fmt::Display for CronSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.expression)
+    }
+}
+
+/// Expand one cron field (`*`, `5`, `1-5`, `*/15`, `1,15`, `0-30/10`) to sorted values.
+fn parse_cron_field(field: &str, min: u32, max: u32, name: &str) -> Result<Vec<u32>> {
+    let bad = || invalid(format!("invalid cron {name} field '{field}'"));
+    let mut values = Vec::new();
+
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u32>().map_err(|_| bad())?),
+            None => (part, 1),
+        };
+        if step == 0 {
+            return Err(bad());
+        }
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (
+                start.parse::<u32>().map_err(|_| bad())?,
+                end.parse::<u32>().map_err(|_| bad())?,
+            )
+        } else {
+            let value = range.parse::<u32>().map_err(|_| bad())?;
+            (value, value)
+        };
+        if start < min || end > max || start > end {
+            return Err(bad());
+        }
+        values.extend((start..=end).step_by(step as usize));
+    }
+
+    values.sort_unstable();
+    values.dedup();
+    Ok(values)
+}
+
+/// Latest occurrence of `rule` that is at or before `now`, starting from
+/// `first` (which must itself be an occurrence). Returns `first` when it is
+/// still in the future.
+#[must_use]
+pub fn latest_occurrence(
+    rule: &RecurrenceRule,
+    first: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> DateTime<Utc> {
+    let mut current = first;
+    while let Some(next) = rule.next_after(current) {
+        if next > now {
+            break;
+        }
+        current = next;
+    }
+    current
+}
+
+/// Truncate to whole minutes so cron and calendar rules line up.
+#[must_use]
+pub fn truncate_to_minute(at: DateTime<Utc>) -> DateTime<Utc> {
+    at.with_second(0)
+        .and_then(|t| t.with_nanosecond(0))
+        .unwrap_or(at)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    fn rule(s: &str) -> RecurrenceRule {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_shorthands_to_canonical_form() {
+        assert_eq!(rule("daily").to_string(), "FREQ=DAILY");
+        assert_eq!(rule("Every 2 weeks").to_string(), "FREQ=WEEKLY;INTERVAL=2");
+        assert_eq!(
+            rule("weekly:thu,mon,mon").to_string(),
+            "FREQ=WEEKLY;BYDAY=MO,TH"
+        );
+        assert_eq!(rule("monthly:15").to_string(), "FREQ=MONTHLY;BYMONTHDAY=15");
+        assert_eq!(
+            rule("RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=FR").to_string(),
+            "FREQ=WEEKLY;INTERVAL=2;BYDAY=FR"
+        );
+        assert_eq!(rule("0 9 * * 1-5").to_string(), "cron:0 9 * * 1-5");
+
+        for bad in [
+            "",
+            "fortnightly",
+            "every 0 days",
+            "FREQ=DAILY;BYDAY=MO",
+            "weekly:funday",
+            "cron:61 * * * *",
+            "cron:* * *",
+        ] {
+            assert!(bad.parse::<RecurrenceRule>().is_err(), "accepted {bad:?}");
+        }
+    }
+
+    #[test]
+    fn test_calendar_next_after() {
+        // 2025-01-06 is a Monday.
+        let monday = at(2025, 1, 6, 9, 0);
+        assert_eq!(rule("daily").next_after(monday), Some(at(2025, 1, 7, 9, 0)));
+        assert_eq!(
+            rule("weekly:mon,thu").next_after(monday),
+            Some(at(2025, 1, 9, 9, 0))
+        );
+        assert_eq!(
+            rule("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO").next_after(monday),
+            Some(at(2025, 1, 20, 9, 0))
+        );
+        assert_eq!(
+            rule("monthly:15").next_after(monday),
+            Some(at(2025, 1, 15, 9, 0))
+        );
+        // Day 31 clamps to the end of shorter months.
+        assert_eq!(
+            rule("monthly").next_after(at(2025, 1, 31, 9, 0)),
+            Some(at(2025, 2, 28, 9, 0))
+        );
+        assert_eq!(
+            rule("yearly").next_after(monday),
+            Some(at(2026, 1, 6, 9, 0))
+        );
+    }
+
+    #[test]
+    fn test_anchored_rules_return_to_their_day() {
+        let monthly = rule("monthly").anchored(at(2025, 1, 31, 9, 0));
+        assert_eq!(monthly.to_string(), "FREQ=MONTHLY;BYMONTHDAY=31");
+        let feb = monthly.next_after(at(2025, 1, 31, 9, 0)).unwrap();
+        assert_eq!(feb, at(2025, 2, 28, 9, 0));
+        let mar = monthly.next_after(feb).unwrap();
+        assert_eq!(mar, at(2025, 3, 31, 9, 0));
+        assert_eq!(monthly.next_after(mar), Some(at(2025, 4, 30, 9, 0)));
+
+        // Leap day: Feb 28 in common years, back to Feb 29 in the next leap year
+        let yearly = rule("yearly").anchored(at(2024, 2, 29, 9, 0));
+        assert_eq!(yearly.to_string(), "FREQ=YEARLY;BYMONTHDAY=29");
+        assert_eq!(
+            latest_occurrence(&yearly, at(2024, 2, 29, 9, 0), at(2027, 12, 31, 0, 0)),
+            at(2027, 2, 28, 9, 0)
+        );
+        assert_eq!(
+            yearly.next_after(at(2027, 2, 28, 9, 0)),
+            Some(at(2028, 2, 29, 9, 0))
+        );
+
+        // Rules with a day, or without months, are left alone
+        assert_eq!(
+            rule("monthly:15").anchored(at(2025, 1, 31, 9, 0)),
+            rule("monthly:15")
+        );
+        assert_eq!(
+            rule("weekly").anchored(at(2025, 1, 31, 9, 0)),
+            rule("weekly")
+        );
+    }
+
+    #[test]
+    fn test_cron_next_after_and_catch_up() {
+        let cron = rule("cron:30 9 * * 1,3");
+        let friday = at(2025, 1, 10, 12, 0);
+        assert_eq!(cron.next_after(friday), Some(at(2025, 1, 13, 9, 30)));
+        assert_eq!(
+            rule("cron:0 0 1 * *").next_after(friday),
+            Some(at(2025, 2, 1, 0, 0))
+        );
+        assert_eq!(rule("cron:0 0 31 2 *").next_after(friday), None);
+
+        let first = at(2025, 1, 13, 9, 30);
+        assert_eq!(
+            latest_occurrence(&cron, first, at(2025, 1, 23, 8, 0)),
+            at(2025, 1, 22, 9, 30)
+        );
+        assert_eq!(latest_occurrence(&cron, first, friday), first);
+    }
+}
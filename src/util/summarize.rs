@@ -0,0 +1,300 @@
+//! Summarizers used by `br compact`.
+//!
+//! Compaction replaces the long-form text of an old closed issue with a short
+//! digest. The digest is produced by a [`Summarizer`]:
+//! - [`ExtractiveSummarizer`] (default): keeps the lead sentences of each
+//!   field and the last comment, with no external dependencies.
+//! - [`CommandSummarizer`]: pipes the issue text to an external command
+//!   (configured via `compact.summarizer` or `--summarizer`) and uses its
+//!   stdout as the digest.
+
+use crate::error::{BeadsError, Result};
+use crate::model::Comment;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Default maximum digest length, in characters.
+pub const DEFAULT_DIGEST_CHARS: usize = 500;
+
+/// The text of an issue that compaction summarizes.
+#[derive(Debug, Clone, Copy)]
+pub struct SummaryInput<'a> {
+    pub id: &'a str,
+    pub title: &'a str,
+    pub description: Option<&'a str>,
+    pub design: Option<&'a str>,
+    pub notes: Option<&'a str>,
+    pub comments: &'a [Comment],
+}
+
+impl SummaryInput<'_> {
+    /// Size of the compactable text, in characters.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        [self.description, self.design, self.notes]
+            .into_iter()
+            .flatten()
+            .map(|text| text.chars().count())
+            .sum::<usize>()
+            + self
+                .comments
+                .iter()
+                .map(|comment| comment.body.chars().count())
+                .sum::<usize>()
+    }
+
+    /// Render the compactable text as a Markdown document.
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("# {}: {}\n", self.id, self.title);
+        for (heading, text) in [
+            ("Description", self.description),
+            ("Design", self.design),
+            ("Notes", self.notes),
+        ] {
+            if let Some(text) = text.filter(|text| !text.trim().is_empty()) {
+                out.push_str(&format!("\n## {heading}\n\n{}\n", text.trim()));
+            }
+        }
+        if !self.comments.is_empty() {
+            out.push_str("\n## Comments\n\n");
+            for comment in self.comments {
+                out.push_str(&format!(
+                    "- {} ({}): {}\n",
+                    comment.author,
+                    comment.created_at.format("%Y-%m-%d"),
+                    comment.body.trim()
+                ));
+            }
+        }
+        out
+    }
+}
+
+/// Produces a short digest of an issue's long-form text.
+pub trait Summarizer {
+    /// Summarize the given issue text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the digest cannot be produced.
+    fn summarize(&self, input: &SummaryInput<'_>) -> Result<String>;
+}
+
+/// Built-in summarizer that keeps lead sentences.
+///
+/// The digest holds the first two sentences of the description, the first
+/// sentence of design and notes, and the first sentence of the last comment
+/// (which usually records the resolution), truncated to `max_chars`.
+#[derive(Debug, Clone, Copy)]
+pub struct ExtractiveSummarizer {
+    pub max_chars: usize,
+}
+
+impl Default for ExtractiveSummarizer {
+    fn default() -> Self {
+        Self {
+            max_chars: DEFAULT_DIGEST_CHARS,
+        }
+    }
+}
+
+impl Summarizer for ExtractiveSummarizer {
+    fn summarize(&self, input: &SummaryInput<'_>) -> Result<String> {
+        let mut parts = Vec::new();
+        if let Some(lead) = input.description.and_then(|text| lead_sentences(text, 2)) {
+            parts.push(lead);
+        }
+        if let Some(lead) = input.design.and_then(|text| lead_sentences(text, 1)) {
+            parts.push(format!("Design: {lead}"));
+        }
+        if let Some(lead) = input.notes.and_then(|text| lead_sentences(text, 1)) {
+            parts.push(format!("Notes: {lead}"));
+        }
+        if let Some(last) = input.comments.last() {
+            let count = input.comments.len();
+            let noun = if count == 1 { "comment" } else { "comments" };
+            parts.push(lead_sentences(&last.body, 1).map_or_else(
+                || format!("{count} {noun}"),
+                |lead| format!("{count} {noun}; last from {}: {lead}", last.author),
+            ));
+        }
+        Ok(truncate_chars(&parts.join("\n"), self.max_chars))
+    }
+}
+
+/// Summarizer that delegates to an external command.
+///
+/// The command runs through the shell with the issue rendered as Markdown on
+/// stdin. `BR_ISSUE_ID`, `BR_ISSUE_TITLE` and `BR_DIGEST_MAX_CHARS` are set in
+/// its environment. Its trimmed stdout becomes the digest (truncated to
+/// `max_chars`); a non-zero exit or empty output is an error.
+#[derive(Debug, Clone)]
+pub struct CommandSummarizer {
+    pub command: String,
+    pub max_chars: usize,
+}
+
+impl Summarizer for CommandSummarizer {
+    fn summarize(&self, input: &SummaryInput<'_>) -> Result<String> {
+        let (shell, flag) = if cfg!(windows) {
+            ("cmd", "/C")
+        } else {
+            ("sh", "-c")
+        };
+        let mut child = Command::new(shell)
+            .args([flag, &self.command])
+            .env("BR_ISSUE_ID", input.id)
+            .env("BR_ISSUE_TITLE", input.title)
+            .env("BR_DIGEST_MAX_CHARS", self.max_chars.to_string())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .map_err(|e| {
+                BeadsError::Config(format!("Failed to run summarizer `{}`: {e}", self.command))
+            })?;
+
+        // Feed stdin from a separate thread so a chatty command can't deadlock us.
+        let mut stdin = child.stdin.take();
+        let document = input.to_markdown();
+        let writer = std::thread::spawn(move || {
+            if let Some(stdin) = stdin.as_mut() {
+                // A command that ignores its input may close the pipe early.
+                let _ = stdin.write_all(document.as_bytes());
+            }
+        });
+        let output = child.wait_with_output()?;
+        let _ = writer.join();
+
+        if !output.status.success() {
+            return Err(BeadsError::Config(format!(
+                "Summarizer `{}` failed for {} ({})",
+                self.command, input.id, output.status
+            )));
+        }
+        let digest = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if digest.is_empty() {
+            return Err(BeadsError::Config(format!(
+                "Summarizer `{}` produced no output for {}",
+                self.command, input.id
+            )));
+        }
+        Ok(truncate_chars(&digest, self.max_chars))
+    }
+}
+
+/// First `count` sentences of a Markdown text, as a single line of prose.
+///
+/// Headings and fenced code blocks are skipped; list and quote markers are stripped.
+fn lead_sentences(text: &str, count: usize) -> Option<String> {
+    let mut prose = String::new();
+    let mut in_fence = false;
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence || trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let stripped = trimmed.trim_start_matches(['>', '-', '*', '+', ' ']);
+        if stripped.is_empty() {
+            continue;
+        }
+        if !prose.is_empty() {
+            prose.push(' ');
+        }
+        prose.push_str(stripped);
+    }
+
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = prose.char_indices().peekable();
+    while let Some((idx, ch)) = chars.next() {
+        let at_boundary = matches!(ch, '.' | '!' | '?')
+            && chars.peek().is_none_or(|(_, next)| next.is_whitespace());
+        if at_boundary {
+            let end = idx + ch.len_utf8();
+            sentences.push(prose[start..end].trim());
+            start = end;
+            if sentences.len() == count {
+                break;
+            }
+        }
+    }
+    if sentences.len() < count && start < prose.len() {
+        sentences.push(prose[start..].trim());
+    }
+
+    let lead = sentences
+        .into_iter()
+        .filter(|sentence| !sentence.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    if lead.is_empty() { None } else { Some(lead) }
+}
+
+/// Truncate to at most `max_chars` characters, breaking at a word boundary.
+fn truncate_chars(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let cut: String = text.chars().take(max_chars.saturating_sub(1)).collect();
+    let cut = match cut.rfind(char::is_whitespace) {
+        Some(idx) if idx > 0 => &cut[..idx],
+        _ => cut.as_str(),
+    };
+    format!("{}…", cut.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn comment(author: &str, body: &str) -> Comment {
+        Comment {
+            id: 1,
+            issue_id: "bd-1".to_string(),
+            author: author.to_string(),
+            body: body.to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn extractive_keeps_lead_sentences_and_last_comment() {
+        let comments = vec![
+            comment("alice", "Looking into it."),
+            comment("bob", "Fixed by pinning the version. Will follow up."),
+        ];
+        let input = SummaryInput {
+            id: "bd-1",
+            title: "Flaky build",
+            description: Some(
+                "## Problem\n\nThe build fails at random. It happens on CI only. \
+                 Logs attached below.\n\n```\nerror: linker failed\n```",
+            ),
+            design: None,
+            notes: Some("- Suspect the cache. Or the runner image."),
+            comments: &comments,
+        };
+
+        let digest = ExtractiveSummarizer::default().summarize(&input).unwrap();
+        assert_eq!(
+            digest,
+            "The build fails at random. It happens on CI only.\n\
+             Notes: Suspect the cache.\n\
+             2 comments; last from bob: Fixed by pinning the version."
+        );
+        assert!(digest.chars().count() < input.size());
+    }
+
+    #[test]
+    fn truncate_breaks_at_word_boundary() {
+        assert_eq!(truncate_chars("short", 10), "short");
+        assert_eq!(truncate_chars("one two three four", 10), "one two…");
+    }
+}
@@ -150,6 +150,64 @@ pub fn parse_relative_time(s: &str) -> Option<DateTime<Utc>> {
     }
 }
 
+/// Parse a work duration into whole minutes.
+///
+/// Supports:
+/// - Bare minutes: `45`
+/// - Units: `30m`, `2h`, `1d` (8 working hours)
+/// - Combinations: `1h30m`, `1h 30m`
+///
+/// # Errors
+///
+/// Returns an error if the duration is empty, zero, or uses an unknown unit.
+pub fn parse_duration_minutes(s: &str, field_name: &str) -> Result<i64> {
+    let compact: String = s.split_whitespace().collect();
+    if let Ok(minutes) = compact.parse::<i64>() {
+        return if minutes > 0 {
+            Ok(minutes)
+        } else {
+            Err(BeadsError::validation(
+                field_name,
+                "duration must be positive",
+            ))
+        };
+    }
+
+    let invalid = || BeadsError::validation(field_name, "invalid duration (try: 30m, 2h, 1h30m)");
+    let mut total: i64 = 0;
+    let mut digits = String::new();
+    for c in compact.to_lowercase().chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        let amount = digits.parse::<i64>().map_err(|_| invalid())?;
+        digits.clear();
+        let minutes = match c {
+            'm' => amount,
+            'h' => amount.checked_mul(60).ok_or_else(invalid)?,
+            'd' => amount.checked_mul(8 * 60).ok_or_else(invalid)?,
+            _ => return Err(invalid()),
+        };
+        total = total.checked_add(minutes).ok_or_else(invalid)?;
+    }
+    if !digits.is_empty() || total <= 0 {
+        return Err(invalid());
+    }
+    Ok(total)
+}
+
+/// Format minutes as a compact duration (`45m`, `2h`, `1h 30m`).
+#[must_use]
+pub fn format_minutes(minutes: i64) -> String {
+    let (hours, rest) = (minutes / 60, minutes % 60);
+    match (hours, rest) {
+        (0, m) => format!("{m}m"),
+        (h, 0) => format!("{h}h"),
+        (h, m) => format!("{h}h {m}m"),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -198,4 +256,27 @@ mod tests {
         assert!(parse_relative_time("invalid").is_none());
         assert!(parse_relative_time("2025-01-15").is_none());
     }
+
+    #[test]
+    fn test_parse_duration_minutes() {
+        assert_eq!(parse_duration_minutes("45", "duration").unwrap(), 45);
+        assert_eq!(parse_duration_minutes("30m", "duration").unwrap(), 30);
+        assert_eq!(parse_duration_minutes("1h 30m", "duration").unwrap(), 90);
+        assert_eq!(parse_duration_minutes("1d", "duration").unwrap(), 480);
+        for bad in [
+            "",
+            "0",
+            "-5",
+            "1x",
+            "h",
+            "30m5",
+            "9223372036854775807h",
+            "99999999999999999d",
+        ] {
+            assert!(parse_duration_minutes(bad, "duration").is_err(), "{bad}");
+        }
+        assert_eq!(format_minutes(45), "45m");
+        assert_eq!(format_minutes(120), "2h");
+        assert_eq!(format_minutes(90), "1h 30m");
+    }
 }
@@ -0,0 +1,209 @@
+//! GitHub Issues JSON exports.
+//!
+//! Accepts the output of `gh issue list --json ...` (camelCase fields) and
+//! REST API `/repos/{owner}/{repo}/issues` dumps (snake_case fields). Pull
+//! requests in REST dumps are skipped. Sub-issues (`parent`, `subIssues`)
+//! become parent-child links and `blockedBy`/`blocking` become blocks links.
+//! The repository comes from `url`, `html_url` or `repository_url`; linked
+//! issues without a URL of their own are taken to be in the same repository.
+
+use super::{
+    ExternalComment, ExternalIssue, json_array, json_labels, json_records, json_str, json_str_any,
+    parse_timestamp, priority_from_labels, record_repo, type_from_labels, type_from_name,
+};
+use crate::error::Result;
+use crate::model::Status;
+use serde_json::Value;
+
+/// Fields that may hold the URL of an issue or its repository.
+const URL_KEYS: &[&str] = &["url", "html_url", "repository_url"];
+
+/// External ref for GitHub issue `number` of `repo` (`owner/name`).
+#[must_use]
+pub fn external_ref(repo: Option<&str>, number: u64) -> String {
+    match repo {
+        Some(repo) => format!("gh-{repo}#{number}"),
+        None => format!("gh-{number}"),
+    }
+}
+
+/// Parse a GitHub issues export.
+///
+/// # Errors
+///
+/// Returns an error if the file is not valid JSON.
+pub fn parse(text: &str) -> Result<Vec<ExternalIssue>> {
+    let mut issues: Vec<ExternalIssue> = Vec::new();
+    let mut children: Vec<(String, String)> = Vec::new();
+    for record in json_records(text, &["issues", "items"])? {
+        if record.get("pull_request").is_some_and(|pr| !pr.is_null()) {
+            continue;
+        }
+        let Some(number) = record.get("number").and_then(Value::as_u64) else {
+            continue;
+        };
+        let repo = record_repo(&record, URL_KEYS);
+        let issue_ref = external_ref(repo.as_deref(), number);
+        for child in json_array(&record, "subIssues")
+            .iter()
+            .chain(json_array(&record, "sub_issues"))
+            .filter_map(|child| number_ref(child, repo.as_deref()))
+        {
+            children.push((child, issue_ref.clone()));
+        }
+        issues.push(parse_issue(&record, issue_ref, repo.as_deref()));
+    }
+
+    // Sub-issue lists on the parent fill in children that lack `parent`
+    for (child, parent) in children {
+        if let Some(issue) = issues.iter_mut().find(|issue| issue.external_ref == child) {
+            issue.parent.get_or_insert(parent);
+        }
+    }
+    Ok(issues)
+}
+
+fn parse_issue(record: &Value, external_ref: String, repo: Option<&str>) -> ExternalIssue {
+    let labels = json_labels(record, "labels");
+    let closed =
+        json_str(record, "state").is_some_and(|state| state.eq_ignore_ascii_case("closed"));
+    let login = |value: &Value| json_str(value, "login");
+
+    let assignee = json_array(record, "assignees")
+        .first()
+        .and_then(login)
+        .or_else(|| record.get("assignee").and_then(login));
+    let created_by = record
+        .get("author")
+        .or_else(|| record.get("user"))
+        .and_then(login);
+    let issue_type = ["type", "issueType"]
+        .iter()
+        .filter_map(|key| record.get(*key))
+        .find_map(|value| json_str(value, "name").or_else(|| value.as_str().map(String::from)))
+        .and_then(|name| type_from_name(&name))
+        .or_else(|| type_from_labels(&labels));
+
+    let close_reason = closed.then(|| {
+        match json_str_any(record, &["stateReason", "state_reason"])
+            .map(|reason| reason.to_ascii_lowercase())
+            .as_deref()
+        {
+            Some("not_planned") => "Closed on GitHub as not planned".to_string(),
+            Some("duplicate") => "Closed on GitHub as a duplicate".to_string(),
+            _ => "Closed on GitHub".to_string(),
+        }
+    });
+
+    let comments = json_array(record, "comments")
+        .iter()
+        .filter_map(|comment| {
+            let body = json_str(comment, "body")?;
+            let author = comment
+                .get("author")
+                .or_else(|| comment.get("user"))
+                .and_then(login)
+                .unwrap_or_else(|| "github".to_string());
+            Some(ExternalComment {
+                author,
+                body,
+                created_at: json_str_any(comment, &["createdAt", "created_at"])
+                    .and_then(|value| parse_timestamp(&value)),
+            })
+        })
+        .collect();
+
+    let links = |keys: &[&str]| -> Vec<String> {
+        keys.iter()
+            .flat_map(|key| json_array(record, key))
+            .filter_map(|link| number_ref(link, repo))
+            .collect()
+    };
+
+    ExternalIssue {
+        external_ref,
+        title: json_str(record, "title").unwrap_or_default(),
+        description: json_str(record, "body"),
+        status: if closed { Status::Closed } else { Status::Open },
+        issue_type,
+        priority: priority_from_labels(&labels),
+        assignee,
+        created_by,
+        created_at: json_str_any(record, &["createdAt", "created_at"])
+            .and_then(|value| parse_timestamp(&value)),
+        closed_at: json_str_any(record, &["closedAt", "closed_at"])
+            .and_then(|value| parse_timestamp(&value)),
+        close_reason,
+        due_at: None,
+        labels,
+        comments,
+        parent: ["parent", "parent_issue"]
+            .iter()
+            .filter_map(|key| record.get(*key))
+            .find_map(|parent| number_ref(parent, repo)),
+        blocks: links(&["blocking"]),
+        blocked_by: links(&["blockedBy", "blocked_by"]),
+    }
+}
+
+/// External ref of an issue given as `{ "number": N }` or a bare number,
+/// in `repo` unless it carries a URL of its own.
+fn number_ref(value: &Value, repo: Option<&str>) -> Option<String> {
+    let number = value
+        .get("number")
+        .and_then(Value::as_u64)
+        .or_else(|| value.as_u64())?;
+    let own_repo = record_repo(value, URL_KEYS);
+    Some(external_ref(own_repo.as_deref().or(repo), number))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{IssueType, Priority};
+
+    #[test]
+    fn test_parse_gh_cli_and_rest_exports() {
+        let gh = r#"[
+            {"number": 12, "title": "Crash on login", "body": "Stack trace",
+             "state": "CLOSED", "stateReason": "NOT_PLANNED",
+             "labels": [{"name": "bug"}, {"name": "P1"}, {"name": "good first issue"}],
+             "assignees": [{"login": "octocat"}], "author": {"login": "hubot"},
+             "createdAt": "2024-03-01T10:00:00Z", "closedAt": "2024-03-02T10:00:00Z",
+             "comments": [{"author": {"login": "octocat"}, "body": "Repro'd", "createdAt": "2024-03-01T11:00:00Z"}],
+             "blockedBy": [{"number": 7}]},
+            {"number": 13, "title": "Epic", "state": "OPEN", "subIssues": [{"number": 12}]}
+        ]"#;
+        let issues = parse(gh).unwrap();
+        assert_eq!(issues.len(), 2);
+        let crash = &issues[0];
+        assert_eq!(crash.external_ref, "gh-12");
+        assert_eq!(crash.status, Status::Closed);
+        assert_eq!(crash.issue_type, Some(IssueType::Bug));
+        assert_eq!(crash.priority, Some(Priority(1)));
+        assert_eq!(crash.labels, vec!["bug", "P1", "good-first-issue"]);
+        assert_eq!(crash.assignee.as_deref(), Some("octocat"));
+        assert_eq!(crash.created_by.as_deref(), Some("hubot"));
+        assert_eq!(
+            crash.close_reason.as_deref(),
+            Some("Closed on GitHub as not planned")
+        );
+        assert_eq!(crash.comments.len(), 1);
+        assert_eq!(crash.blocked_by, vec!["gh-7"]);
+        assert_eq!(crash.parent.as_deref(), Some("gh-13"));
+
+        let rest = r#"[
+            {"number": 3, "title": "A PR", "state": "open", "pull_request": {"url": "x"}},
+            {"number": 4, "title": "Docs", "state": "open", "labels": [{"name": "documentation"}],
+             "user": {"login": "mona"}, "created_at": "2024-03-01T10:00:00Z",
+             "html_url": "https://github.com/octo/app/issues/4",
+             "blocked_by": [{"number": 2}, {"number": 9, "html_url": "https://github.com/octo/lib/issues/9"}]}
+        ]"#;
+        let issues = parse(rest).unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].external_ref, "gh-octo/app#4");
+        assert_eq!(issues[0].issue_type, Some(IssueType::Docs));
+        assert_eq!(issues[0].created_by.as_deref(), Some("mona"));
+        assert_eq!(issues[0].blocked_by, vec!["gh-octo/app#2", "gh-octo/lib#9"]);
+    }
+}
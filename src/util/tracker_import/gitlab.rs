@@ -0,0 +1,167 @@
+//! GitLab issues JSON exports.
+//!
+//! Accepts REST API `/projects/:id/issues` dumps and the `issues.ndjson` of a
+//! project export (labels under `label_links`, comments under `notes`; system
+//! notes are skipped). Issue links saved under `links` (the
+//! `/issues/:iid/links` response) become blocks links, and a `parent` with an
+//! `iid` becomes a parent-child link. The project comes from `web_url`;
+//! linked issues without one are taken to be in the same project.
+
+use super::{
+    ExternalComment, ExternalIssue, json_array, json_labels, json_records, json_str, json_str_any,
+    normalize_label, parse_timestamp, priority_from_labels, record_repo, type_from_labels,
+    type_from_name,
+};
+use crate::error::Result;
+use crate::model::Status;
+use serde_json::Value;
+
+/// External ref for GitLab issue `iid` of `project` (its full path).
+#[must_use]
+pub fn external_ref(project: Option<&str>, iid: u64) -> String {
+    match project {
+        Some(project) => format!("gl-{project}#{iid}"),
+        None => format!("gl-{iid}"),
+    }
+}
+
+/// External ref of a linked issue: `{ "iid": N, "web_url": ... }`, in
+/// `project` unless it carries a URL of its own.
+fn link_ref(link: &Value, project: Option<&str>) -> Option<String> {
+    let iid = link.get("iid").and_then(Value::as_u64)?;
+    let own_project = record_repo(link, &["web_url"]);
+    Some(external_ref(own_project.as_deref().or(project), iid))
+}
+
+/// Parse a GitLab issues export.
+///
+/// # Errors
+///
+/// Returns an error if the file is not valid JSON.
+pub fn parse(text: &str) -> Result<Vec<ExternalIssue>> {
+    Ok(json_records(text, &["issues"])?
+        .iter()
+        .filter_map(|record| {
+            let project = record_repo(record, &["web_url"]);
+            let issue_ref = link_ref(record, project.as_deref())?;
+            Some(parse_issue(record, issue_ref, project.as_deref()))
+        })
+        .collect())
+}
+
+fn parse_issue(record: &Value, external_ref: String, project: Option<&str>) -> ExternalIssue {
+    let mut labels = json_labels(record, "labels");
+    for link in json_array(record, "label_links") {
+        if let Some(label) = link
+            .get("label")
+            .and_then(|label| json_str(label, "title"))
+            .as_deref()
+            .and_then(normalize_label)
+        {
+            if !labels.contains(&label) {
+                labels.push(label);
+            }
+        }
+    }
+    let closed = json_str(record, "state").is_some_and(|state| state == "closed");
+    let user = |value: &Value| json_str_any(value, &["username", "name"]);
+
+    let issue_type = json_str(record, "issue_type")
+        .and_then(|name| type_from_name(&name))
+        .or_else(|| type_from_labels(&labels));
+
+    let comments = json_array(record, "notes")
+        .iter()
+        .filter(|note| !note.get("system").and_then(Value::as_bool).unwrap_or(false))
+        .filter_map(|note| {
+            Some(ExternalComment {
+                author: note
+                    .get("author")
+                    .and_then(user)
+                    .unwrap_or_else(|| "gitlab".to_string()),
+                body: json_str_any(note, &["body", "note"])?,
+                created_at: json_str(note, "created_at").and_then(|value| parse_timestamp(&value)),
+            })
+        })
+        .collect();
+
+    let mut blocks = Vec::new();
+    let mut blocked_by = Vec::new();
+    for link in json_array(record, "links") {
+        let Some(link_ref) = link_ref(link, project) else {
+            continue;
+        };
+        match json_str(link, "link_type").as_deref() {
+            Some("blocks") => blocks.push(link_ref),
+            Some("is_blocked_by") => blocked_by.push(link_ref),
+            _ => {}
+        }
+    }
+
+    ExternalIssue {
+        external_ref,
+        title: json_str(record, "title").unwrap_or_default(),
+        description: json_str(record, "description"),
+        status: if closed { Status::Closed } else { Status::Open },
+        issue_type,
+        priority: priority_from_labels(&labels),
+        assignee: json_array(record, "assignees")
+            .first()
+            .and_then(user)
+            .or_else(|| record.get("assignee").and_then(user)),
+        created_by: record.get("author").and_then(user),
+        created_at: json_str(record, "created_at").and_then(|value| parse_timestamp(&value)),
+        closed_at: json_str(record, "closed_at").and_then(|value| parse_timestamp(&value)),
+        close_reason: closed.then(|| "Closed on GitLab".to_string()),
+        due_at: json_str(record, "due_date").and_then(|value| parse_timestamp(&value)),
+        labels,
+        comments,
+        parent: record
+            .get("parent")
+            .and_then(|parent| link_ref(parent, project)),
+        blocks,
+        blocked_by,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::IssueType;
+
+    #[test]
+    fn test_parse_rest_and_project_exports() {
+        let rest = r#"[{"iid": 5, "title": "Outage", "description": "Down",
+            "state": "closed", "issue_type": "incident",
+            "labels": ["priority::P0", "backend"],
+            "assignees": [{"username": "alice"}], "author": {"username": "bob"},
+            "created_at": "2024-03-12T15:04:05.000Z", "closed_at": "2024-03-13T15:04:05.000Z",
+            "due_date": "2024-03-20",
+            "web_url": "https://gitlab.com/acme/infra/api/-/issues/5",
+            "links": [{"iid": 6, "link_type": "blocks"}, {"iid": 2, "link_type": "relates_to"},
+                      {"iid": 3, "link_type": "is_blocked_by", "web_url": "https://gitlab.com/acme/web/-/issues/3"}]}]"#;
+        let issues = parse(rest).unwrap();
+        let outage = &issues[0];
+        assert_eq!(outage.external_ref, "gl-acme/infra/api#5");
+        assert_eq!(outage.status, Status::Closed);
+        assert_eq!(outage.issue_type, Some(IssueType::Bug));
+        assert_eq!(outage.priority.map(|p| p.0), Some(0));
+        assert_eq!(outage.assignee.as_deref(), Some("alice"));
+        assert!(outage.due_at.is_some());
+        assert_eq!(outage.blocks, vec!["gl-acme/infra/api#6"]);
+        assert_eq!(outage.blocked_by, vec!["gl-acme/web#3"]);
+
+        let ndjson = concat!(
+            r#"{"iid": 7, "title": "Add export", "state": "opened", "label_links": [{"label": {"title": "feature"}}], "#,
+            r#""notes": [{"note": "changed the description", "system": true}, {"note": "LGTM", "author": {"name": "carol"}}]}"#,
+            "\n",
+            r#"{"iid": 8, "title": "Child", "state": "opened", "parent": {"iid": 7}}"#,
+        );
+        let issues = parse(ndjson).unwrap();
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].issue_type, Some(IssueType::Feature));
+        assert_eq!(issues[0].comments.len(), 1);
+        assert_eq!(issues[0].comments[0].author, "carol");
+        assert_eq!(issues[1].parent.as_deref(), Some("gl-7"));
+    }
+}
@@ -0,0 +1,787 @@
+//! Jira exports: CSV, XML (RSS) and REST `/search` JSON.
+//!
+//! The format is detected from the first character of the file. Statuses map
+//! through their status category when the export has one, otherwise through
+//! common status names; priorities map Highest..Lowest (and Blocker..Trivial)
+//! onto P0..P4. `Blocks` links, parents and sub-tasks become dependencies.
+//! The issue key (e.g. `PROJ-12`) is the external ref.
+
+use super::{
+    ExternalComment, ExternalIssue, decode_entities, html_to_text, json_array, json_records,
+    json_str, json_str_any, normalize_label, parse_timestamp, type_from_name,
+};
+use crate::error::{BeadsError, Result};
+use crate::model::{Priority, Status};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Parse a Jira export in any supported format.
+///
+/// # Errors
+///
+/// Returns an error if the file is malformed.
+pub fn parse(text: &str) -> Result<Vec<ExternalIssue>> {
+    let text = text.trim_start_matches('\u{feff}').trim_start();
+    let mut issues = match text.chars().next() {
+        Some('<') => parse_xml_export(text)?,
+        Some('{' | '[') => parse_json_export(text)?,
+        Some(_) => parse_csv_export(text)?,
+        None => Vec::new(),
+    };
+    // Normalize titles like "[PROJ-1] Summary" from RSS item titles
+    for issue in &mut issues {
+        let prefix = format!("[{}] ", issue.external_ref);
+        if let Some(title) = issue.title.strip_prefix(&prefix) {
+            issue.title = title.to_string();
+        }
+    }
+    Ok(issues)
+}
+
+/// br status for a Jira status name and (optional) status category key.
+fn map_status(name: &str, category: Option<&str>, resolved: bool) -> Status {
+    let name = name.trim().to_ascii_lowercase();
+    if name.contains("block") || name == "on hold" {
+        return Status::Blocked;
+    }
+    match category.map(str::to_ascii_lowercase).as_deref() {
+        Some("done") => return Status::Closed,
+        Some("indeterminate") => return Status::InProgress,
+        Some("new") => return Status::Open,
+        _ => {}
+    }
+    match name.as_str() {
+        "done" | "closed" | "resolved" | "won't do" | "won't fix" | "cancelled" | "canceled" => {
+            Status::Closed
+        }
+        "in progress" | "in review" | "review" | "in development" | "in testing" | "testing" => {
+            Status::InProgress
+        }
+        _ if resolved => Status::Closed,
+        _ => Status::Open,
+    }
+}
+
+fn map_priority(name: &str) -> Option<Priority> {
+    match name.trim().to_ascii_lowercase().as_str() {
+        "highest" | "blocker" => Some(Priority(0)),
+        "high" | "critical" => Some(Priority(1)),
+        "medium" | "major" => Some(Priority(2)),
+        "low" | "minor" => Some(Priority(3)),
+        "lowest" | "trivial" => Some(Priority(4)),
+        _ => None,
+    }
+}
+
+fn close_reason(status: &Status, resolution: Option<&str>) -> Option<String> {
+    if *status != Status::Closed {
+        return None;
+    }
+    Some(match resolution {
+        Some(resolution) => format!("Resolved in Jira: {resolution}"),
+        None => "Closed in Jira".to_string(),
+    })
+}
+
+/// Ignore Jira's placeholder values for empty fields.
+fn present(value: Option<String>) -> Option<String> {
+    value.filter(|value| {
+        let value = value.trim();
+        !value.is_empty() && !matches!(value, "Unassigned" | "Unresolved" | "None")
+    })
+}
+
+/// Fill in parents from sub-task lists and drop duplicate links.
+fn link_subtasks(issues: &mut [ExternalIssue], subtasks: Vec<(String, String)>) {
+    for (child, parent) in subtasks {
+        if let Some(issue) = issues.iter_mut().find(|issue| issue.external_ref == child) {
+            issue.parent.get_or_insert(parent);
+        }
+    }
+    for issue in issues {
+        issue.blocks.sort();
+        issue.blocks.dedup();
+        issue.blocked_by.sort();
+        issue.blocked_by.dedup();
+    }
+}
+
+// ===== CSV =====
+
+fn parse_csv_export(text: &str) -> Result<Vec<ExternalIssue>> {
+    let mut rows = parse_csv(text).into_iter();
+    let Some(header) = rows.next() else {
+        return Ok(Vec::new());
+    };
+    let header: Vec<String> = header
+        .iter()
+        .map(|name| name.trim().to_ascii_lowercase())
+        .collect();
+    if !header.iter().any(|name| name == "issue key") {
+        return Err(BeadsError::validation(
+            "file",
+            "Jira CSV export has no 'Issue key' column",
+        ));
+    }
+
+    let mut issues = Vec::new();
+    let mut subtasks = Vec::new();
+    let mut key_by_id: HashMap<String, String> = HashMap::new();
+    for row in rows {
+        // Columns like Labels, Comment and issue links repeat, so collect all values
+        let mut fields: HashMap<&str, Vec<String>> = HashMap::new();
+        for (name, value) in header.iter().zip(row) {
+            if !value.trim().is_empty() {
+                fields
+                    .entry(name.as_str())
+                    .or_default()
+                    .push(value.trim().to_string());
+            }
+        }
+        let first = |name: &str| fields.get(name).and_then(|values| values.first()).cloned();
+        let all = |name: &str| fields.get(name).cloned().unwrap_or_default();
+
+        let Some(key) = first("issue key") else {
+            continue;
+        };
+        if let Some(id) = first("issue id") {
+            key_by_id.insert(id, key.clone());
+        }
+        let resolution = present(first("resolution"));
+        let resolved = first("resolved").and_then(|value| parse_timestamp(&value));
+        let status = map_status(
+            &first("status").unwrap_or_default(),
+            first("status category").as_deref(),
+            resolution.is_some() || resolved.is_some(),
+        );
+        for child in all("sub-tasks")
+            .iter()
+            .flat_map(|value| value.split(','))
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+        {
+            subtasks.push((child.to_string(), key.clone()));
+        }
+
+        let comments = all("comment")
+            .iter()
+            .map(|value| {
+                // "12/Mar/24 3:04 PM;jdoe;Comment text"
+                let mut parts = value.splitn(3, ';');
+                match (parts.next(), parts.next(), parts.next()) {
+                    (Some(date), Some(author), Some(body)) if parse_timestamp(date).is_some() => {
+                        ExternalComment {
+                            author: author.to_string(),
+                            body: body.trim().to_string(),
+                            created_at: parse_timestamp(date),
+                        }
+                    }
+                    _ => ExternalComment {
+                        author: "jira".to_string(),
+                        body: value.clone(),
+                        created_at: None,
+                    },
+                }
+            })
+            .collect();
+
+        issues.push(ExternalIssue {
+            title: first("summary").unwrap_or_default(),
+            description: first("description"),
+            close_reason: close_reason(&status, resolution.as_deref()),
+            status,
+            issue_type: first("issue type").and_then(|name| type_from_name(&name)),
+            priority: first("priority").and_then(|name| map_priority(&name)),
+            assignee: present(first("assignee")),
+            created_by: present(first("reporter").or_else(|| first("creator"))),
+            created_at: first("created").and_then(|value| parse_timestamp(&value)),
+            closed_at: resolved,
+            due_at: first("due date").and_then(|value| parse_timestamp(&value)),
+            labels: all("labels")
+                .iter()
+                .filter_map(|label| normalize_label(label))
+                .collect(),
+            comments,
+            parent: first("parent")
+                .or_else(|| first("parent id"))
+                .or_else(|| first("custom field (epic link)")),
+            blocks: all("outward issue link (blocks)"),
+            blocked_by: all("inward issue link (blocks)"),
+            external_ref: key,
+        });
+    }
+
+    // Parents and sub-tasks may be given as numeric issue IDs
+    let to_key = |value: String| key_by_id.get(&value).cloned().unwrap_or(value);
+    for issue in &mut issues {
+        issue.parent = issue.parent.take().map(to_key);
+    }
+    let subtasks = subtasks
+        .into_iter()
+        .map(|(child, parent)| (to_key(child), parent))
+        .collect();
+    link_subtasks(&mut issues, subtasks);
+    Ok(issues)
+}
+
+/// Split CSV text into rows of fields (RFC 4180: quoted fields may contain
+/// commas, newlines and doubled quotes).
+fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' => in_quotes = true,
+            ',' => row.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows
+}
+
+// ===== XML =====
+
+/// Minimal XML element tree, enough for Jira's RSS export.
+#[derive(Debug, Default)]
+struct Element {
+    name: String,
+    attrs: Vec<(String, String)>,
+    children: Vec<Element>,
+    text: String,
+}
+
+impl Element {
+    fn child(&self, name: &str) -> Option<&Self> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Self> + 'a {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Trimmed text of the named child, if non-empty.
+    fn child_text(&self, name: &str) -> Option<String> {
+        self.child(name)
+            .map(|child| child.text.trim().to_string())
+            .filter(|text| !text.is_empty())
+    }
+
+    /// Descendants named `name`, depth first.
+    fn descendants<'a>(&'a self, name: &str, out: &mut Vec<&'a Self>) {
+        for child in &self.children {
+            if child.name == name {
+                out.push(child);
+            }
+            child.descendants(name, out);
+        }
+    }
+}
+
+/// Parse XML into an element tree rooted at a nameless document element.
+fn parse_xml(text: &str) -> Result<Element> {
+    let malformed = || BeadsError::validation("file", "malformed XML");
+    let mut stack = vec![Element::default()];
+    let mut rest = text;
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix("<!--") {
+            rest = after.find("-->").map_or("", |end| &after[end + 3..]);
+        } else if let Some(after) = rest.strip_prefix("<![CDATA[") {
+            let end = after.find("]]>").ok_or_else(malformed)?;
+            if let Some(top) = stack.last_mut() {
+                top.text.push_str(&after[..end]);
+            }
+            rest = &after[end + 3..];
+        } else if rest.starts_with("<?") || rest.starts_with("<!") {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+        } else if let Some(after) = rest.strip_prefix("</") {
+            let end = after.find('>').ok_or_else(malformed)?;
+            let name = after[..end].trim();
+            // Only the innermost open element may close here
+            if stack.len() < 2 || stack.last().is_none_or(|top| top.name != name) {
+                return Err(malformed());
+            }
+            let element = stack.pop().ok_or_else(malformed)?;
+            if let Some(parent) = stack.last_mut() {
+                parent.children.push(element);
+            }
+            rest = &after[end + 1..];
+        } else if rest.starts_with('<') {
+            let end = tag_end(rest).ok_or_else(malformed)?;
+            let tag = &rest[1..end];
+            let (tag, self_closing) = match tag.strip_suffix('/') {
+                Some(tag) => (tag, true),
+                None => (tag, false),
+            };
+            let element = parse_tag(tag);
+            if self_closing {
+                if let Some(top) = stack.last_mut() {
+                    top.children.push(element);
+                }
+            } else {
+                stack.push(element);
+            }
+            rest = &rest[end + 1..];
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            if let Some(top) = stack.last_mut() {
+                top.text.push_str(&decode_entities(&rest[..end]));
+            }
+            rest = &rest[end..];
+        }
+    }
+    // Every element must be closed by the end of the document
+    if stack.len() != 1 {
+        return Err(malformed());
+    }
+    stack.pop().ok_or_else(malformed)
+}
+
+/// Index of the `>` closing the tag at the start of `text`, skipping quoted
+/// attribute values.
+fn tag_end(text: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in text.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if c == q => quote = None,
+            (None, '>') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_tag(tag: &str) -> Element {
+    let tag = tag.trim();
+    let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+    let mut element = Element {
+        name: tag[..name_end].to_string(),
+        ..Element::default()
+    };
+    let mut rest = tag[name_end..].trim_start();
+    while let Some(eq) = rest.find('=') {
+        let key = rest[..eq].trim().to_string();
+        let value_part = rest[eq + 1..].trim_start();
+        let Some(quote) = value_part
+            .chars()
+            .next()
+            .filter(|c| matches!(c, '"' | '\''))
+        else {
+            break;
+        };
+        let Some(close) = value_part[1..].find(quote) else {
+            break;
+        };
+        element
+            .attrs
+            .push((key, decode_entities(&value_part[1..=close])));
+        rest = value_part[close + 2..].trim_start();
+    }
+    element
+}
+
+fn parse_xml_export(text: &str) -> Result<Vec<ExternalIssue>> {
+    let document = parse_xml(text)?;
+    let mut items = Vec::new();
+    document.descendants("item", &mut items);
+
+    let mut issues = Vec::new();
+    let mut subtasks = Vec::new();
+    for item in items {
+        let Some(key) = item.child_text("key") else {
+            continue;
+        };
+        for subtask in item
+            .child("subtasks")
+            .into_iter()
+            .flat_map(|list| list.children_named("subtask"))
+        {
+            subtasks.push((subtask.text.trim().to_string(), key.clone()));
+        }
+
+        let resolution = present(item.child_text("resolution"));
+        let resolved = item
+            .child_text("resolved")
+            .and_then(|value| parse_timestamp(&value));
+        let status = map_status(
+            &item.child_text("status").unwrap_or_default(),
+            item.child("statusCategory")
+                .and_then(|category| category.attr("key")),
+            resolution.is_some() || resolved.is_some(),
+        );
+        let person = |name: &str| {
+            let element = item.child(name)?;
+            present(
+                element
+                    .attr("username")
+                    .map(String::from)
+                    .or_else(|| Some(element.text.trim().to_string())),
+            )
+        };
+
+        let comments = item
+            .child("comments")
+            .into_iter()
+            .flat_map(|comments| comments.children_named("comment"))
+            .map(|comment| ExternalComment {
+                author: comment.attr("author").unwrap_or("jira").to_string(),
+                body: html_to_text(&comment.text),
+                created_at: comment.attr("created").and_then(parse_timestamp),
+            })
+            .filter(|comment| !comment.body.is_empty())
+            .collect();
+
+        let mut blocks = Vec::new();
+        let mut blocked_by = Vec::new();
+        for link_type in item
+            .child("issuelinks")
+            .into_iter()
+            .flat_map(|links| links.children_named("issuelinktype"))
+        {
+            let is_blocks = link_type
+                .child_text("name")
+                .is_some_and(|name| name.eq_ignore_ascii_case("blocks"));
+            if !is_blocks {
+                continue;
+            }
+            for (direction, target) in [
+                ("outwardlinks", &mut blocks),
+                ("inwardlinks", &mut blocked_by),
+            ] {
+                for links in link_type.children_named(direction) {
+                    target.extend(
+                        links
+                            .children_named("issuelink")
+                            .filter_map(|link| link.child_text("issuekey")),
+                    );
+                }
+            }
+        }
+
+        issues.push(ExternalIssue {
+            title: item
+                .child_text("summary")
+                .or_else(|| item.child_text("title"))
+                .unwrap_or_default(),
+            description: item
+                .child_text("description")
+                .map(|html| html_to_text(&html))
+                .filter(|text| !text.is_empty()),
+            close_reason: close_reason(&status, resolution.as_deref()),
+            status,
+            issue_type: item
+                .child_text("type")
+                .and_then(|name| type_from_name(&name)),
+            priority: item
+                .child_text("priority")
+                .and_then(|name| map_priority(&name)),
+            assignee: person("assignee"),
+            created_by: person("reporter"),
+            created_at: item
+                .child_text("created")
+                .and_then(|value| parse_timestamp(&value)),
+            closed_at: resolved,
+            due_at: item
+                .child_text("due")
+                .and_then(|value| parse_timestamp(&value)),
+            labels: item
+                .child("labels")
+                .into_iter()
+                .flat_map(|labels| labels.children_named("label"))
+                .filter_map(|label| normalize_label(&label.text))
+                .collect(),
+            comments,
+            parent: item.child_text("parent"),
+            blocks,
+            blocked_by,
+            external_ref: key,
+        });
+    }
+    link_subtasks(&mut issues, subtasks);
+    Ok(issues)
+}
+
+// ===== JSON =====
+
+fn parse_json_export(text: &str) -> Result<Vec<ExternalIssue>> {
+    let mut issues = Vec::new();
+    let mut subtasks = Vec::new();
+    for record in json_records(text, &["issues"])? {
+        let Some(key) = json_str(&record, "key") else {
+            continue;
+        };
+        let fields = record.get("fields").cloned().unwrap_or(Value::Null);
+        let name_of = |field: &str| fields.get(field).and_then(|value| json_str(value, "name"));
+        let person = |field: &str| {
+            fields
+                .get(field)
+                .and_then(|value| json_str_any(value, &["name", "emailAddress", "displayName"]))
+        };
+        let key_of = |value: &Value| json_str(value, "key");
+
+        for child in json_array(&fields, "subtasks").iter().filter_map(key_of) {
+            subtasks.push((child, key.clone()));
+        }
+
+        let resolution = name_of("resolution");
+        let resolved =
+            json_str(&fields, "resolutiondate").and_then(|value| parse_timestamp(&value));
+        let status_field = fields.get("status");
+        let status = map_status(
+            &name_of("status").unwrap_or_default(),
+            status_field
+                .and_then(|status| status.get("statusCategory"))
+                .and_then(|category| json_str(category, "key"))
+                .as_deref(),
+            resolution.is_some() || resolved.is_some(),
+        );
+
+        let comments = fields
+            .get("comment")
+            .map(|comment| json_array(comment, "comments"))
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|comment| {
+                Some(ExternalComment {
+                    author: comment
+                        .get("author")
+                        .and_then(|author| {
+                            json_str_any(author, &["name", "emailAddress", "displayName"])
+                        })
+                        .unwrap_or_else(|| "jira".to_string()),
+                    body: rich_text(comment.get("body")?)?,
+                    created_at: json_str(comment, "created")
+                        .and_then(|value| parse_timestamp(&value)),
+                })
+            })
+            .collect();
+
+        let mut blocks = Vec::new();
+        let mut blocked_by = Vec::new();
+        for link in json_array(&fields, "issuelinks") {
+            let is_blocks = link
+                .get("type")
+                .and_then(|link_type| json_str(link_type, "name"))
+                .is_some_and(|name| name.eq_ignore_ascii_case("blocks"));
+            if !is_blocks {
+                continue;
+            }
+            if let Some(target) = link.get("outwardIssue").and_then(key_of) {
+                blocks.push(target);
+            }
+            if let Some(target) = link.get("inwardIssue").and_then(key_of) {
+                blocked_by.push(target);
+            }
+        }
+
+        issues.push(ExternalIssue {
+            title: json_str(&fields, "summary").unwrap_or_default(),
+            description: fields.get("description").and_then(rich_text),
+            close_reason: close_reason(&status, resolution.as_deref()),
+            status,
+            issue_type: name_of("issuetype").and_then(|name| type_from_name(&name)),
+            priority: name_of("priority").and_then(|name| map_priority(&name)),
+            assignee: person("assignee"),
+            created_by: person("reporter").or_else(|| person("creator")),
+            created_at: json_str(&fields, "created").and_then(|value| parse_timestamp(&value)),
+            closed_at: resolved,
+            due_at: json_str(&fields, "duedate").and_then(|value| parse_timestamp(&value)),
+            labels: json_array(&fields, "labels")
+                .iter()
+                .filter_map(Value::as_str)
+                .filter_map(normalize_label)
+                .collect(),
+            comments,
+            parent: fields.get("parent").and_then(key_of),
+            blocks,
+            blocked_by,
+            external_ref: key,
+        });
+    }
+    link_subtasks(&mut issues, subtasks);
+    Ok(issues)
+}
+
+/// Text of a description or comment: a wiki-markup string (API v2) or an
+/// Atlassian Document Format tree (API v3).
+fn rich_text(value: &Value) -> Option<String> {
+    fn walk(node: &Value, out: &mut String) {
+        if let Some(text) = node.get("text").and_then(Value::as_str) {
+            out.push_str(text);
+        }
+        let node_type = node.get("type").and_then(Value::as_str).unwrap_or("");
+        if node_type == "hardBreak" {
+            out.push('\n');
+        }
+        for child in node
+            .get("content")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            walk(child, out);
+        }
+        if matches!(
+            node_type,
+            "paragraph" | "heading" | "listItem" | "codeBlock" | "blockquote"
+        ) && !out.ends_with('\n')
+        {
+            out.push('\n');
+        }
+    }
+
+    let text = match value {
+        Value::String(text) => text.trim().to_string(),
+        Value::Object(_) => {
+            let mut out = String::new();
+            walk(value, &mut out);
+            out.trim().to_string()
+        }
+        _ => String::new(),
+    };
+    (!text.is_empty()).then_some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::IssueType;
+
+    #[test]
+    fn test_parse_csv_export() {
+        let csv = "Summary,Issue key,Issue id,Issue Type,Status,Priority,Resolution,Assignee,Reporter,Created,Resolved,Description,Labels,Labels,Comment,Parent,Outward issue link (Blocks)\n\
+            Login broken,PROJ-2,10002,Bug,Done,High,Fixed,jdoe,asmith,12/Mar/24 3:04 PM,13/Mar/24 9:00 AM,\"Steps:\n1, 2, \"\"3\"\"\",backend,urgent fix,12/Mar/24 4:00 PM;jdoe;On it,10001,PROJ-3\n\
+            Auth epic,PROJ-1,10001,Epic,In Progress,Medium,,,asmith,11/Mar/24 3:04 PM,,,,,,,\n";
+        let issues = parse(csv).unwrap();
+        assert_eq!(issues.len(), 2);
+        let login = &issues[0];
+        assert_eq!(login.external_ref, "PROJ-2");
+        assert_eq!(login.status, Status::Closed);
+        assert_eq!(
+            login.close_reason.as_deref(),
+            Some("Resolved in Jira: Fixed")
+        );
+        assert_eq!(login.issue_type, Some(IssueType::Bug));
+        assert_eq!(login.priority, Some(Priority(1)));
+        assert_eq!(login.description.as_deref(), Some("Steps:\n1, 2, \"3\""));
+        assert_eq!(login.labels, vec!["backend", "urgent-fix"]);
+        assert_eq!(login.comments[0].author, "jdoe");
+        assert_eq!(login.comments[0].body, "On it");
+        assert_eq!(login.parent.as_deref(), Some("PROJ-1"));
+        assert_eq!(login.blocks, vec!["PROJ-3"]);
+        assert_eq!(issues[1].status, Status::InProgress);
+        assert_eq!(issues[1].assignee, None);
+    }
+
+    #[test]
+    fn test_parse_xml_export() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- RSS generated by JIRA -->
+<rss version="0.92"><channel><title>Jira</title>
+<item>
+  <title>[PROJ-5] Add SSO</title>
+  <key id="10005">PROJ-5</key>
+  <summary>Add SSO</summary>
+  <type id="3">Story</type>
+  <priority id="2">Highest</priority>
+  <status id="3">Review</status>
+  <statusCategory id="4" key="indeterminate" colorName="yellow"/>
+  <resolution id="-1">Unresolved</resolution>
+  <assignee username="jdoe">Jane Doe</assignee>
+  <reporter username="asmith">Al Smith</reporter>
+  <labels><label>auth</label></labels>
+  <created>Tue, 12 Mar 2024 15:04:05 +0000</created>
+  <description>&lt;p&gt;Use &lt;b&gt;SAML&lt;/b&gt; &amp;amp; OIDC&lt;/p&gt;</description>
+  <comments><comment id="1" author="jdoe" created="Wed, 13 Mar 2024 09:00:00 +0000"><![CDATA[<p>Started</p>]]></comment></comments>
+  <issuelinks><issuelinktype id="1"><name>Blocks</name>
+    <inwardlinks description="is blocked by"><issuelink><issuekey id="10004">PROJ-4</issuekey></issuelink></inwardlinks>
+  </issuelinktype></issuelinks>
+  <subtasks><subtask id="10006">PROJ-6</subtask></subtasks>
+</item>
+<item><title>[PROJ-6] SAML</title><key id="10006">PROJ-6</key><summary>SAML</summary><type>Sub-task</type><status>Open</status></item>
+</channel></rss>"#;
+        let issues = parse(xml).unwrap();
+        assert_eq!(issues.len(), 2);
+        let sso = &issues[0];
+        assert_eq!(sso.title, "Add SSO");
+        assert_eq!(sso.status, Status::InProgress);
+        assert_eq!(sso.priority, Some(Priority(0)));
+        assert_eq!(sso.issue_type, Some(IssueType::Feature));
+        assert_eq!(sso.assignee.as_deref(), Some("jdoe"));
+        assert_eq!(sso.description.as_deref(), Some("Use SAML & OIDC"));
+        assert_eq!(sso.comments[0].body, "Started");
+        assert!(sso.comments[0].created_at.is_some());
+        assert_eq!(sso.blocked_by, vec!["PROJ-4"]);
+        assert_eq!(issues[1].parent.as_deref(), Some("PROJ-5"));
+    }
+
+    #[test]
+    fn test_parse_xml_rejects_unbalanced_tags() {
+        for xml in [
+            "<rss><channel><item><key>PROJ-1</key></channel></rss>",
+            "<rss><channel></item></channel></rss>",
+            "<rss><channel><item><key>PROJ-1</key></item>",
+            "</rss>",
+        ] {
+            let err = parse_xml(xml).unwrap_err();
+            assert!(err.to_string().contains("malformed XML"), "{xml}: {err}");
+        }
+        assert!(parse_xml("<rss><channel/></rss>").is_ok());
+    }
+
+    #[test]
+    fn test_parse_json_export() {
+        let json = r#"{"issues": [{"key": "OPS-1", "fields": {
+            "summary": "Rotate keys", "issuetype": {"name": "Task"},
+            "status": {"name": "Won't Do", "statusCategory": {"key": "done"}},
+            "resolution": {"name": "Won't Do"}, "priority": {"name": "Lowest"},
+            "labels": ["security"], "created": "2024-03-12T15:04:05.000+0000",
+            "description": {"type": "doc", "content": [{"type": "paragraph", "content": [{"type": "text", "text": "Yearly"}]}]},
+            "parent": {"key": "OPS-0"},
+            "issuelinks": [{"type": {"name": "Blocks"}, "outwardIssue": {"key": "OPS-2"}}],
+            "comment": {"comments": [{"author": {"displayName": "Ann"}, "body": "Skip", "created": "2024-03-13T10:00:00.000+0000"}]}
+        }}]}"#;
+        let issues = parse(json).unwrap();
+        let keys = &issues[0];
+        assert_eq!(keys.status, Status::Closed);
+        assert_eq!(
+            keys.close_reason.as_deref(),
+            Some("Resolved in Jira: Won't Do")
+        );
+        assert_eq!(keys.priority, Some(Priority(4)));
+        assert_eq!(keys.description.as_deref(), Some("Yearly"));
+        assert_eq!(keys.parent.as_deref(), Some("OPS-0"));
+        assert_eq!(keys.blocks, vec!["OPS-2"]);
+        assert_eq!(keys.comments[0].author, "Ann");
+    }
+}
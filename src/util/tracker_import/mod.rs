@@ -0,0 +1,393 @@
+//! Parsers for issue tracker exports used by `br import --from`.
+//!
+//! Each tracker module turns an offline export into [`ExternalIssue`]s; the
+//! import command maps them onto br issues. Nothing here touches storage.
+//!
+//! | Tracker | Export | External ref |
+//! |---------|--------|--------------|
+//! | GitHub | `gh issue list --json ...` or REST `/issues` JSON | `gh-<owner>/<repo>#<number>` |
+//! | GitLab | REST `/issues` JSON or a project export's `issues.ndjson` | `gl-<project>#<iid>` |
+//! | Jira | CSV, XML (RSS) or REST `/search` JSON | the issue key |
+//!
+//! GitHub and GitLab numbers are only unique within a repository, so the
+//! repository path (taken from the issue's URL) is part of the ref. Exports
+//! without URLs fall back to `gh-<number>` and `gl-<iid>`.
+//!
+//! Links (parent, blocks) are expressed as external refs so they can point
+//! at issues outside the export that an earlier import already created.
+
+pub mod github;
+pub mod gitlab;
+pub mod jira;
+
+use crate::model::{IssueType, Priority, Status};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde_json::Value;
+
+/// An issue read from another tracker's export.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExternalIssue {
+    /// Stable reference used to find the issue again on re-import.
+    pub external_ref: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub status: Status,
+    pub issue_type: Option<IssueType>,
+    pub priority: Option<Priority>,
+    pub assignee: Option<String>,
+    pub created_by: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub close_reason: Option<String>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub labels: Vec<String>,
+    pub comments: Vec<ExternalComment>,
+    /// External ref of the parent issue (epic or parent of a sub-task).
+    pub parent: Option<String>,
+    /// External refs of issues that cannot start until this one is done.
+    pub blocks: Vec<String>,
+    /// External refs of issues this one waits on.
+    pub blocked_by: Vec<String>,
+}
+
+/// A comment read from another tracker's export.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExternalComment {
+    pub author: String,
+    pub body: String,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// Turn a tracker label into a valid br label: runs of characters other than
+/// alphanumerics, `-`, `_` and `:` become `-` (`good first issue` ->
+/// `good-first-issue`). Returns `None` if nothing is left.
+#[must_use]
+pub fn normalize_label(label: &str) -> Option<String> {
+    let mut out = String::new();
+    for c in label.trim().chars() {
+        if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | ':') {
+            out.push(c);
+        } else if !out.ends_with('-') {
+            out.push('-');
+        }
+    }
+    let out: String = out.trim_matches('-').chars().take(50).collect();
+    (!out.is_empty()).then_some(out)
+}
+
+/// Parse the timestamp formats trackers use in their exports.
+#[must_use]
+pub fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    if let Ok(dt) = DateTime::parse_from_rfc2822(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    // Jira REST: 2024-03-12T15:04:05.000+0000
+    if let Ok(dt) = DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z") {
+        return Some(dt.with_timezone(&Utc));
+    }
+    // GitLab project exports: 2024-03-12 15:04:05 UTC
+    let naive = value.trim_end_matches(" UTC");
+    for format in [
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%d %H:%M",
+        "%d/%b/%y %I:%M %p",
+    ] {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(naive, format) {
+            return Some(dt.and_utc());
+        }
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|dt| dt.and_utc())
+}
+
+/// Priority from a `P0`..`P4` style label, if there is one.
+#[must_use]
+pub fn priority_from_labels(labels: &[String]) -> Option<Priority> {
+    labels.iter().find_map(|label| {
+        let value = label
+            .rsplit([':', '-', ' '])
+            .next()
+            .unwrap_or(label)
+            .to_ascii_lowercase();
+        let digit = value.strip_prefix('p')?;
+        match digit.parse::<i32>() {
+            Ok(p) if (0..=4).contains(&p) => Some(Priority(p)),
+            _ => None,
+        }
+    })
+}
+
+/// Issue type from conventional labels (`bug`, `enhancement`, `docs`, ...).
+#[must_use]
+pub fn type_from_labels(labels: &[String]) -> Option<IssueType> {
+    labels.iter().find_map(|label| {
+        let name = label
+            .rsplit(':')
+            .next()
+            .unwrap_or(label)
+            .to_ascii_lowercase();
+        type_from_name(&name)
+    })
+}
+
+/// Issue type from a tracker's type name (`Bug`, `Story`, `Sub-task`, ...).
+#[must_use]
+pub fn type_from_name(name: &str) -> Option<IssueType> {
+    match name.trim().to_ascii_lowercase().as_str() {
+        "bug" | "defect" | "incident" => Some(IssueType::Bug),
+        "feature" | "enhancement" | "story" | "new feature" | "improvement" => {
+            Some(IssueType::Feature)
+        }
+        "epic" => Some(IssueType::Epic),
+        "task" | "sub-task" | "subtask" => Some(IssueType::Task),
+        "chore" | "maintenance" => Some(IssueType::Chore),
+        "docs" | "documentation" => Some(IssueType::Docs),
+        "question" => Some(IssueType::Question),
+        _ => None,
+    }
+}
+
+/// Reduce an HTML fragment (Jira descriptions and comments) to plain text.
+#[must_use]
+pub fn html_to_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('>') else {
+            text.push_str(&rest[start..]);
+            rest = "";
+            break;
+        };
+        let tag = rest[start + 1..start + end]
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        match tag.as_str() {
+            "br" | "p" | "div" | "tr" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                if !text.ends_with('\n') {
+                    text.push('\n');
+                }
+            }
+            "li" if !rest[start + 1..].starts_with('/') => text.push_str("\n- "),
+            _ => {}
+        }
+        rest = &rest[start + end + 1..];
+    }
+    text.push_str(rest);
+    let text = decode_entities(&text);
+    let lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+    let mut out = lines.join("\n");
+    while out.contains("\n\n\n") {
+        out = out.replace("\n\n\n", "\n\n");
+    }
+    out.trim().to_string()
+}
+
+/// Decode XML/HTML character entities.
+#[must_use]
+pub fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').filter(|&end| end <= 10).and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" | "#39" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => entity.strip_prefix('#').and_then(|code| {
+                    let code = match code.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => code.parse().ok()?,
+                    };
+                    char::from_u32(code)
+                }),
+            };
+            c.map(|c| (c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Repository path of an issue or repository URL: `owner/repo` for
+/// `https://github.com/owner/repo/issues/12` and for the REST API's
+/// `https://api.github.com/repos/owner/repo`, `group/sub/project` for
+/// `https://gitlab.com/group/sub/project/-/issues/5`.
+fn repo_path(url: &str) -> Option<String> {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let (_, path) = rest.split_once('/')?;
+    let path = path.split(['?', '#']).next().unwrap_or(path);
+    let path = path.strip_prefix("repos/").unwrap_or(path);
+    let repo = path
+        .split_once("/-/issues/")
+        .or_else(|| path.split_once("/issues/"))
+        .map_or(path, |(repo, _)| repo)
+        .trim_matches('/');
+    (!repo.is_empty()).then(|| repo.to_string())
+}
+
+/// Repository path of an issue record, from the first of `keys` holding a URL.
+fn record_repo(value: &Value, keys: &[&str]) -> Option<String> {
+    keys.iter()
+        .filter_map(|key| json_str(value, key))
+        .find_map(|url| repo_path(&url))
+}
+
+/// A string field of a JSON object, trimmed; `None` when missing or empty.
+fn json_str(value: &Value, key: &str) -> Option<String> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(ToString::to_string)
+}
+
+/// The first of `keys` present as a string field.
+fn json_str_any(value: &Value, keys: &[&str]) -> Option<String> {
+    keys.iter().find_map(|key| json_str(value, key))
+}
+
+/// The elements of a JSON array field (empty when missing).
+fn json_array<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+    value
+        .get(key)
+        .and_then(Value::as_array)
+        .map_or(&[][..], Vec::as_slice)
+}
+
+/// Labels from a list of strings or of `{ "name": ... }` objects, normalized.
+fn json_labels(value: &Value, key: &str) -> Vec<String> {
+    let mut labels: Vec<String> = Vec::new();
+    for label in json_array(value, key) {
+        let name = label
+            .as_str()
+            .map(ToString::to_string)
+            .or_else(|| json_str_any(label, &["name", "title"]));
+        if let Some(label) = name.as_deref().and_then(normalize_label) {
+            if !labels.contains(&label) {
+                labels.push(label);
+            }
+        }
+    }
+    labels
+}
+
+/// Read a JSON export that is an array, an object wrapping an array under
+/// one of `keys`, or newline-delimited objects.
+fn json_records(text: &str, keys: &[&str]) -> crate::error::Result<Vec<Value>> {
+    let trimmed = text.trim_start_matches('\u{feff}').trim();
+    if trimmed.is_empty() {
+        return Ok(Vec::new());
+    }
+    match serde_json::from_str::<Value>(trimmed) {
+        Ok(Value::Array(records)) => Ok(records),
+        Ok(value @ Value::Object(_)) => Ok(keys
+            .iter()
+            .find_map(|key| value.get(*key).and_then(Value::as_array).cloned())
+            .unwrap_or_else(|| vec![value])),
+        Ok(_) => Err(crate::error::BeadsError::validation(
+            "file",
+            "expected a JSON array or object of issues",
+        )),
+        Err(_) => trimmed
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| serde_json::from_str(line).map_err(Into::into))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_label_and_priority() {
+        assert_eq!(
+            normalize_label("good first issue").as_deref(),
+            Some("good-first-issue")
+        );
+        assert_eq!(
+            normalize_label("priority::high").as_deref(),
+            Some("priority::high")
+        );
+        assert_eq!(normalize_label(" ✨ "), None);
+        let labels = vec!["bug".to_string(), "priority:P1".to_string()];
+        assert_eq!(priority_from_labels(&labels), Some(Priority(1)));
+        assert_eq!(type_from_labels(&labels), Some(IssueType::Bug));
+    }
+
+    #[test]
+    fn test_parse_timestamp_formats() {
+        let expected = parse_timestamp("2024-03-12T15:04:05Z").unwrap();
+        assert_eq!(
+            parse_timestamp("Tue, 12 Mar 2024 15:04:05 +0000"),
+            Some(expected)
+        );
+        assert_eq!(
+            parse_timestamp("2024-03-12T15:04:05.000+0000"),
+            Some(expected)
+        );
+        assert_eq!(parse_timestamp("2024-03-12 15:04:05 UTC"), Some(expected));
+        assert!(parse_timestamp("12/Mar/24 3:04 PM").is_some());
+        assert_eq!(parse_timestamp("soon"), None);
+    }
+
+    #[test]
+    fn test_repo_path() {
+        assert_eq!(
+            repo_path("https://github.com/octo/app/issues/12").as_deref(),
+            Some("octo/app")
+        );
+        assert_eq!(
+            repo_path("https://api.github.com/repos/octo/app").as_deref(),
+            Some("octo/app")
+        );
+        assert_eq!(
+            repo_path("https://api.github.com/repos/octo/app/issues/12").as_deref(),
+            Some("octo/app")
+        );
+        assert_eq!(
+            repo_path("https://gitlab.example.com/group/sub/app/-/issues/5").as_deref(),
+            Some("group/sub/app")
+        );
+        assert_eq!(repo_path("https://github.com"), None);
+    }
+
+    #[test]
+    fn test_html_to_text() {
+        assert_eq!(
+            html_to_text("<p>Steps &amp; notes:</p><ul><li>one</li><li>two</li></ul>"),
+            "Steps & notes:\n\n- one\n- two"
+        );
+    }
+}
@@ -465,6 +465,8 @@ mod tests {
             labels: Vec::new(),
             dependencies: Vec::new(),
             comments: Vec::new(),
+            recurrence: None,
+            worklog: vec![],
         }
     }
 
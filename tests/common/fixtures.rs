@@ -52,6 +52,8 @@ pub fn issue(title: &str) -> Issue {
         is_template: false,
         dependencies: vec![],
         comments: vec![],
+        recurrence: None,
+        worklog: vec![],
     }
 }
 
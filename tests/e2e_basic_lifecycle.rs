@@ -63,6 +63,8 @@ fn make_issue(id: &str, title: &str, now: chrono::DateTime<Utc>) -> Issue {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        recurrence: None,
+        worklog: vec![],
     }
 }
 
@@ -3,7 +3,8 @@
 //! Tests cover:
 //! - Success paths: generate changelog with closed issues
 //! - Date filtering with --since
-//! - Grouping by issue type
+//! - Grouping by issue type and label, exclusions
+//! - Writing a section into a changelog file with --output
 //! - Error cases: before init, no closed issues
 //! - Edge cases: many issues, same-second closes, reopen-then-close
 
@@ -225,6 +226,99 @@ fn changelog_text_output() {
     info!("changelog_text_output passed");
 }
 
+#[test]
+fn changelog_group_by_label_with_exclusions() {
+    common::init_test_logging();
+    info!("Starting changelog_group_by_label_with_exclusions test");
+
+    let workspace = BrWorkspace::new();
+    run_br(&workspace, ["init"], "init");
+
+    let create1 = run_br(
+        &workspace,
+        ["create", "Faster sync", "--labels", "perf"],
+        "create1",
+    );
+    let id1 = parse_created_id(&create1.stdout);
+    run_br(&workspace, ["close", &id1], "close1");
+
+    let create2 = run_br(
+        &workspace,
+        ["create", "Internal cleanup", "--labels", "internal"],
+        "create2",
+    );
+    let id2 = parse_created_id(&create2.stdout);
+    run_br(&workspace, ["close", &id2], "close2");
+
+    let create3 = run_br(&workspace, ["create", "Unlabelled fix"], "create3");
+    let id3 = parse_created_id(&create3.stdout);
+    run_br(&workspace, ["close", &id3], "close3");
+
+    let changelog = run_br(
+        &workspace,
+        [
+            "changelog",
+            "--group-by",
+            "label",
+            "--exclude-label",
+            "internal",
+            "--json",
+        ],
+        "changelog",
+    );
+    assert!(
+        changelog.status.success(),
+        "changelog failed: {}",
+        changelog.stderr
+    );
+
+    let payload = extract_json_payload(&changelog.stdout);
+    let json: Value = serde_json::from_str(&payload).expect("parse json");
+    assert_eq!(json["total_closed"], 2, "internal issue should be excluded");
+
+    let groups = json["groups"].as_array().expect("groups");
+    let labels: Vec<&str> = groups
+        .iter()
+        .map(|g| g["label"].as_str().unwrap())
+        .collect();
+    assert_eq!(labels, vec!["perf", "Other"], "unlabelled issues go last");
+
+    info!("changelog_group_by_label_with_exclusions passed");
+}
+
+#[test]
+fn changelog_output_replaces_its_section() {
+    common::init_test_logging();
+    info!("Starting changelog_output_replaces_its_section test");
+
+    let workspace = BrWorkspace::new();
+    run_br(&workspace, ["init"], "init");
+
+    let create = run_br(&workspace, ["create", "Add export"], "create");
+    let id = parse_created_id(&create.stdout);
+    run_br(&workspace, ["close", &id], "close");
+
+    let args = ["changelog", "--title", "v1.0", "-o", "CHANGELOG.md"];
+    let first = run_br(&workspace, args, "changelog_first");
+    assert!(first.status.success(), "changelog failed: {}", first.stderr);
+    let path = workspace.root.join("CHANGELOG.md");
+    let written = std::fs::read_to_string(&path).expect("read changelog");
+    assert!(written.starts_with("# Changelog\n"), "got: {written}");
+    assert!(written.contains("<!-- br-changelog: v1.0 -->"));
+    assert!(written.contains("Add export"));
+
+    let second = run_br(&workspace, args, "changelog_second");
+    assert!(
+        second.status.success(),
+        "changelog failed: {}",
+        second.stderr
+    );
+    let rewritten = std::fs::read_to_string(&path).expect("read changelog");
+    assert_eq!(written, rewritten, "rerun should be idempotent");
+
+    info!("changelog_output_replaces_its_section passed");
+}
+
 #[test]
 fn changelog_robot_mode() {
     common::init_test_logging();
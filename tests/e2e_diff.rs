@@ -0,0 +1,141 @@
+//! E2E tests for point-in-time views: `br diff` and `--as-of`.
+//!
+//! Tests cover:
+//! - Field-level changes listed by `br diff` in a window
+//! - `br show --as-of` and `br list --as-of` rewinding later changes
+//! - Error cases: invalid timestamps
+
+mod common;
+
+use chrono::Utc;
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::thread::sleep;
+use std::time::Duration;
+use tracing::info;
+
+fn parse_created_id(stdout: &str) -> String {
+    let line = stdout.lines().next().unwrap_or("");
+    let id_part = line
+        .strip_prefix("✓ Created ")
+        .or_else(|| line.strip_prefix("Created "))
+        .and_then(|rest| rest.split(':').next())
+        .unwrap_or("");
+    id_part.trim().to_string()
+}
+
+/// Current time, with a pause on either side so it falls between events.
+fn checkpoint() -> String {
+    sleep(Duration::from_millis(50));
+    let now = Utc::now().to_rfc3339();
+    sleep(Duration::from_millis(50));
+    now
+}
+
+#[test]
+fn diff_and_as_of_rewind_later_changes() {
+    common::init_test_logging();
+    info!("Starting diff_and_as_of_rewind_later_changes test");
+
+    let workspace = BrWorkspace::new();
+    run_br(&workspace, ["init"], "init");
+    let before_create = checkpoint();
+
+    let create = run_br(&workspace, ["create", "Fix login bug"], "create");
+    let id = parse_created_id(&create.stdout);
+    let after_create = checkpoint();
+
+    let update = run_br(
+        &workspace,
+        [
+            "update",
+            &id,
+            "--title",
+            "Fix login redirect",
+            "--status",
+            "in_progress",
+        ],
+        "update",
+    );
+    assert!(update.status.success(), "update failed: {}", update.stderr);
+    let label = run_br(&workspace, ["label", "add", &id, "auth"], "label_add");
+    assert!(label.status.success(), "label failed: {}", label.stderr);
+
+    let diff = run_br(
+        &workspace,
+        ["diff", "--since", &after_create, "--json"],
+        "diff",
+    );
+    assert!(diff.status.success(), "diff failed: {}", diff.stderr);
+    let json: Value = serde_json::from_str(&extract_json_payload(&diff.stdout)).expect("json");
+    let changes = json["changes"].as_array().expect("changes");
+    let fields: Vec<&str> = changes
+        .iter()
+        .map(|change| change["field"].as_str().unwrap_or(""))
+        .collect();
+    assert!(!fields.contains(&"created"), "{fields:?}");
+    assert!(fields.contains(&"title"), "{fields:?}");
+    assert!(fields.contains(&"status"), "{fields:?}");
+    let title = changes
+        .iter()
+        .find(|change| change["field"] == "title")
+        .expect("title change");
+    assert_eq!(title["old_value"], "Fix login bug");
+    assert_eq!(title["new_value"], "Fix login redirect");
+    let labels = changes
+        .iter()
+        .find(|change| change["field"] == "labels")
+        .expect("label change");
+    assert_eq!(labels["new_value"], "auth");
+
+    let show = run_br(
+        &workspace,
+        ["show", &id, "--as-of", &after_create, "--json"],
+        "show_as_of",
+    );
+    assert!(show.status.success(), "show failed: {}", show.stderr);
+    let show_json: Vec<Value> =
+        serde_json::from_str(&extract_json_payload(&show.stdout)).expect("show json");
+    assert_eq!(show_json[0]["title"], "Fix login bug");
+    assert_eq!(show_json[0]["status"], "open");
+    assert!(
+        show_json[0]["labels"]
+            .as_array()
+            .is_none_or(|labels| labels.is_empty()),
+        "labels should be rewound: {}",
+        show_json[0]
+    );
+
+    let list = run_br(
+        &workspace,
+        ["list", "--as-of", &before_create, "--json"],
+        "list_as_of",
+    );
+    assert!(list.status.success(), "list failed: {}", list.stderr);
+    let list_json: Vec<Value> =
+        serde_json::from_str(&extract_json_payload(&list.stdout)).expect("list json");
+    assert!(
+        list_json.is_empty(),
+        "issue did not exist yet: {list_json:?}"
+    );
+
+    // The live database is untouched
+    let show = run_br(&workspace, ["show", &id, "--json"], "show_now");
+    let show_json: Vec<Value> =
+        serde_json::from_str(&extract_json_payload(&show.stdout)).expect("show json");
+    assert_eq!(show_json[0]["title"], "Fix login redirect");
+}
+
+#[test]
+fn diff_rejects_invalid_since() {
+    common::init_test_logging();
+    let workspace = BrWorkspace::new();
+    run_br(&workspace, ["init"], "init");
+
+    let diff = run_br(
+        &workspace,
+        ["diff", "--since", "not-a-date"],
+        "diff_invalid",
+    );
+    assert!(!diff.status.success(), "invalid --since should fail");
+}
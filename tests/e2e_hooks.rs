@@ -0,0 +1,59 @@
+//! E2E tests for lifecycle hooks.
+//!
+//! Tests cover:
+//! - A blocking hook that runs `br` itself fails fast on writes instead of
+//!   waiting for the write lock its own change is holding
+
+#![cfg(unix)]
+
+mod common;
+
+use common::cli::{BrWorkspace, run_br};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::time::Duration;
+
+#[test]
+fn blocking_hook_running_br_does_not_deadlock() {
+    let _log = common::test_log("blocking_hook_running_br_does_not_deadlock");
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    // The hook edits the issue it was fired for; its timeout is well under
+    // the default 30s lock timeout the inner `br` would otherwise wait out
+    let br = assert_cmd::cargo::cargo_bin!("br");
+    let mut config = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(workspace.root.join(".beads/config.yaml"))
+        .expect("open config");
+    writeln!(
+        config,
+        "\nhooks:\n  on_create:\n    reenter:\n      command: '{} update \"$BR_ISSUE_ID\" --priority 0'\n      on_failure: block\n      timeout: 20",
+        br.display()
+    )
+    .expect("write hook config");
+
+    let create = run_br(&workspace, ["create", "Hooked"], "create_hooked");
+    assert!(
+        !create.status.success(),
+        "the hook's write should fail: {}",
+        create.stdout
+    );
+    assert!(
+        create.duration < Duration::from_secs(15),
+        "the inner br waited for the lock: {:?}",
+        create.duration
+    );
+    assert!(
+        create.stderr.to_lowercase().contains("locked"),
+        "stderr: {}",
+        create.stderr
+    );
+
+    // The rejected change was rolled back
+    let list = run_br(&workspace, ["list", "--json"], "list_after");
+    assert!(list.status.success(), "list failed: {}", list.stderr);
+    assert!(!list.stdout.contains("Hooked"), "stdout: {}", list.stdout);
+}
@@ -647,7 +647,8 @@ fn is_allowed_sync_file(rel_path: &str) -> bool {
         .unwrap_or_default();
 
     // Check exact name matches
-    const ALLOWED_EXACT_NAMES: &[&str] = &[".manifest.json", "metadata.json"];
+    const ALLOWED_EXACT_NAMES: &[&str] =
+        &[".manifest.json", "metadata.json", "merge-conflicts.json"];
     if ALLOWED_EXACT_NAMES.iter().any(|&name| filename == name) {
         return true;
     }
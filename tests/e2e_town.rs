@@ -0,0 +1,222 @@
+//! E2E tests for cross-project (`--town`) views.
+//!
+//! Tests cover:
+//! - `ready --town` merging routed projects in policy order, with one limit
+//! - `list --town` tagging rows with their project, and rejecting CSV
+//! - `blocked --town` including blocked issues from routed projects
+//! - `external:<project>:<capability>` resolving against a routed project
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::fs;
+
+fn parse_created_id(stdout: &str) -> String {
+    let line = stdout.lines().next().unwrap_or("");
+    let normalized = line.strip_prefix("✓ ").unwrap_or(line);
+    let id_part = normalized
+        .strip_prefix("Created ")
+        .and_then(|rest| rest.split(':').next())
+        .unwrap_or("");
+    id_part.trim().to_string()
+}
+
+fn create(workspace: &BrWorkspace, args: &[&str], label: &str) -> String {
+    let mut full = vec!["create"];
+    full.extend_from_slice(args);
+    let run = run_br(workspace, full, label);
+    assert!(run.status.success(), "create failed: {}", run.stderr);
+    parse_created_id(&run.stdout)
+}
+
+fn json_rows(workspace: &BrWorkspace, args: &[&str], label: &str) -> Vec<Value> {
+    let run = run_br(workspace, args, label);
+    assert!(run.status.success(), "{label} failed: {}", run.stderr);
+    serde_json::from_str(&extract_json_payload(&run.stdout)).expect("json rows")
+}
+
+/// Project name `--town` shows for a workspace (its directory name).
+fn project_name(workspace: &BrWorkspace) -> String {
+    workspace
+        .root
+        .file_name()
+        .expect("workspace dir name")
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// A local project routing to a second project by absolute path.
+fn setup_town() -> (BrWorkspace, BrWorkspace) {
+    let local = BrWorkspace::new();
+    let routed = BrWorkspace::new();
+    for (workspace, label) in [(&local, "init_local"), (&routed, "init_routed")] {
+        let init = run_br(workspace, ["init"], label);
+        assert!(init.status.success(), "init failed: {}", init.stderr);
+    }
+    let route = format!(r#"{{"prefix":"fe-","path":"{}"}}"#, routed.root.display());
+    fs::write(local.root.join(".beads/routes.jsonl"), route).expect("write routes.jsonl");
+    (local, routed)
+}
+
+fn ids_and_projects(rows: &[Value]) -> Vec<(String, String)> {
+    rows.iter()
+        .map(|row| {
+            (
+                row["id"].as_str().unwrap_or_default().to_string(),
+                row["project"].as_str().unwrap_or_default().to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn ready_town_merges_projects_in_policy_order() {
+    let _log = common::test_log("ready_town_merges_projects_in_policy_order");
+    let (local, routed) = setup_town();
+    let local_name = project_name(&local);
+    let routed_name = project_name(&routed);
+
+    let local_p2 = create(&local, &["Local chore", "-p", "2"], "create_local_p2");
+    let routed_p0 = create(&routed, &["Routed outage", "-p", "0"], "create_routed_p0");
+    let routed_p3 = create(&routed, &["Routed backlog", "-p", "3"], "create_routed_p3");
+
+    // Without --town only the local project is visible
+    let plain = json_rows(&local, &["ready", "--json"], "ready_plain");
+    assert_eq!(plain.len(), 1);
+    assert_eq!(plain[0]["id"], local_p2.as_str());
+
+    let town = json_rows(
+        &local,
+        &["ready", "--town", "--sort", "priority", "--json"],
+        "ready_town",
+    );
+    assert_eq!(
+        ids_and_projects(&town),
+        vec![
+            (routed_p0.clone(), routed_name.clone()),
+            (local_p2, local_name),
+            (routed_p3, routed_name.clone()),
+        ]
+    );
+
+    // The limit applies to the merged list, not to each project
+    let limited = json_rows(
+        &local,
+        &[
+            "ready",
+            "--all-projects",
+            "--sort",
+            "priority",
+            "--limit",
+            "1",
+            "--json",
+        ],
+        "ready_town_limit",
+    );
+    assert_eq!(ids_and_projects(&limited), vec![(routed_p0, routed_name)]);
+}
+
+#[test]
+fn list_town_tags_rows_and_rejects_csv() {
+    let _log = common::test_log("list_town_tags_rows_and_rejects_csv");
+    let (local, routed) = setup_town();
+    let local_id = create(&local, &["Local task", "-p", "1"], "create_local");
+    let routed_id = create(&routed, &["Routed task", "-p", "2"], "create_routed");
+
+    let rows = json_rows(
+        &local,
+        &["list", "--town", "--sort", "priority", "--json"],
+        "list_town",
+    );
+    assert_eq!(
+        ids_and_projects(&rows),
+        vec![
+            (local_id.clone(), project_name(&local)),
+            (routed_id.clone(), project_name(&routed)),
+        ]
+    );
+
+    let text = run_br(&local, ["list", "--town"], "list_town_text");
+    assert!(text.status.success(), "list failed: {}", text.stderr);
+    assert!(
+        text.stdout
+            .contains(&format!("[{}] ", project_name(&routed)))
+            && text.stdout.contains(&routed_id),
+        "text rows should carry a project prefix: {}",
+        text.stdout
+    );
+
+    let csv = run_br(
+        &local,
+        ["list", "--town", "--format", "csv"],
+        "list_town_csv",
+    );
+    assert!(!csv.status.success(), "CSV with --town should fail");
+    assert!(csv.stderr.contains("CSV"), "stderr: {}", csv.stderr);
+}
+
+#[test]
+fn blocked_town_includes_routed_projects() {
+    let _log = common::test_log("blocked_town_includes_routed_projects");
+    let (local, routed) = setup_town();
+    let blocker = create(&routed, &["Schema change"], "create_blocker");
+    let waiting = create(&routed, &["Migrate data"], "create_waiting");
+    let dep = run_br(&routed, ["dep", "add", &waiting, &blocker], "dep_add");
+    assert!(dep.status.success(), "dep add failed: {}", dep.stderr);
+
+    let plain = json_rows(&local, &["blocked", "--json"], "blocked_plain");
+    assert!(plain.is_empty(), "local project has nothing blocked");
+
+    let town = json_rows(&local, &["blocked", "--town", "--json"], "blocked_town");
+    assert_eq!(
+        ids_and_projects(&town),
+        vec![(waiting.clone(), project_name(&routed))]
+    );
+
+    let ready = json_rows(&local, &["ready", "--town", "--json"], "ready_town");
+    let ready_ids: Vec<&str> = ready.iter().filter_map(|row| row["id"].as_str()).collect();
+    assert!(ready_ids.contains(&blocker.as_str()));
+    assert!(!ready_ids.contains(&waiting.as_str()));
+}
+
+#[test]
+fn town_resolves_external_blockers_against_routed_projects() {
+    let _log = common::test_log("town_resolves_external_blockers_against_routed_projects");
+    let (local, routed) = setup_town();
+    let routed_name = project_name(&routed);
+
+    let consumer = create(&local, &["Use auth"], "create_consumer");
+    let external = format!("external:{routed_name}:auth");
+    let dep = run_br(
+        &local,
+        ["dep", "add", &consumer, &external],
+        "dep_add_external",
+    );
+    assert!(dep.status.success(), "dep add failed: {}", dep.stderr);
+
+    let provider = create(&routed, &["Provide auth"], "create_provider");
+    let label = run_br(
+        &routed,
+        ["update", &provider, "--add-label", "provides:auth"],
+        "label_provider",
+    );
+    assert!(label.status.success(), "label failed: {}", label.stderr);
+
+    let town_ids = |label: &str| -> Vec<String> {
+        json_rows(&local, &["ready", "--town", "--json"], label)
+            .iter()
+            .filter_map(|row| row["id"].as_str().map(str::to_string))
+            .collect()
+    };
+    let blocked = json_rows(&local, &["blocked", "--town", "--json"], "blocked_before");
+    assert!(blocked.iter().any(|row| row["id"] == consumer.as_str()));
+    assert!(!town_ids("ready_before").contains(&consumer));
+
+    let close = run_br(&routed, ["close", &provider], "close_provider");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+
+    assert!(town_ids("ready_after").contains(&consumer));
+    let blocked = json_rows(&local, &["blocked", "--town", "--json"], "blocked_after");
+    assert!(!blocked.iter().any(|row| row["id"] == consumer.as_str()));
+}
@@ -0,0 +1,90 @@
+//! E2E tests for `br undo`.
+//!
+//! Tests cover:
+//! - Undoing a status change and a label, with a `--dry-run` preview
+//! - Redoing an undone change with `--redo`
+//! - Empty undo stack
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use tracing::info;
+
+fn parse_created_id(stdout: &str) -> String {
+    let line = stdout.lines().next().unwrap_or("");
+    let id_part = line
+        .strip_prefix("✓ Created ")
+        .or_else(|| line.strip_prefix("Created "))
+        .and_then(|rest| rest.split(':').next())
+        .unwrap_or("");
+    id_part.trim().to_string()
+}
+
+fn show_json(workspace: &BrWorkspace, id: &str, label: &str) -> Value {
+    let show = run_br(workspace, ["show", id, "--json"], label);
+    assert!(show.status.success(), "show failed: {}", show.stderr);
+    let json: Vec<Value> =
+        serde_json::from_str(&extract_json_payload(&show.stdout)).expect("show json");
+    json[0].clone()
+}
+
+#[test]
+fn undo_and_redo_restore_status_and_labels() {
+    common::init_test_logging();
+    info!("Starting undo_and_redo_restore_status_and_labels test");
+
+    let workspace = BrWorkspace::new();
+    run_br(&workspace, ["init"], "init");
+    let create = run_br(&workspace, ["create", "Fix login bug"], "create");
+    let id = parse_created_id(&create.stdout);
+
+    let label = run_br(&workspace, ["label", "add", &id, "auth"], "label_add");
+    assert!(label.status.success(), "label failed: {}", label.stderr);
+    let close = run_br(&workspace, ["close", &id], "close");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+
+    let preview = run_br(
+        &workspace,
+        ["undo", "--steps", "2", "--dry-run", "--json"],
+        "undo_dry_run",
+    );
+    assert!(preview.status.success(), "undo failed: {}", preview.stderr);
+    let json: Value = serde_json::from_str(&extract_json_payload(&preview.stdout)).expect("json");
+    assert_eq!(json["dry_run"], true);
+    assert_eq!(json["count"], 2);
+    assert_eq!(json["reverted"][0]["batch"]["op"], "update_issue");
+    assert_eq!(
+        show_json(&workspace, &id, "show_after_preview")["status"],
+        "closed"
+    );
+
+    let undo = run_br(&workspace, ["undo", "--steps", "2"], "undo");
+    assert!(undo.status.success(), "undo failed: {}", undo.stderr);
+    let issue = show_json(&workspace, &id, "show_after_undo");
+    assert_eq!(issue["status"], "open");
+    assert!(
+        issue["labels"]
+            .as_array()
+            .is_none_or(|labels| labels.is_empty()),
+        "label should be undone: {issue}"
+    );
+
+    let redo = run_br(&workspace, ["undo", "--redo"], "redo");
+    assert!(redo.status.success(), "redo failed: {}", redo.stderr);
+    let issue = show_json(&workspace, &id, "show_after_redo");
+    assert_eq!(issue["labels"][0], "auth");
+    assert_eq!(issue["status"], "open");
+}
+
+#[test]
+fn undo_with_nothing_recorded() {
+    common::init_test_logging();
+    let workspace = BrWorkspace::new();
+    run_br(&workspace, ["init"], "init");
+
+    let undo = run_br(&workspace, ["undo", "--json"], "undo_empty");
+    assert!(undo.status.success(), "undo failed: {}", undo.stderr);
+    let json: Value = serde_json::from_str(&extract_json_payload(&undo.stdout)).expect("json");
+    assert_eq!(json["count"], 0);
+}
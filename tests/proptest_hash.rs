@@ -64,6 +64,8 @@ fn make_issue(title: &str, description: Option<&str>) -> Issue {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        recurrence: None,
+        worklog: vec![],
     }
 }
 
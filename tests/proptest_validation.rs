@@ -64,6 +64,8 @@ fn make_valid_issue(title: &str) -> Issue {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        recurrence: None,
+        worklog: vec![],
     }
 }
 
@@ -43,6 +43,8 @@ fn create_issue(id: &str, title: &str, issue_type: IssueType) -> Issue {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        recurrence: None,
+        worklog: vec![],
     }
 }
 
@@ -43,6 +43,8 @@ fn make_issue(id: &str, title: &str, status: Status) -> Issue {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        recurrence: None,
+        worklog: vec![],
     }
 }
 
@@ -79,6 +79,8 @@ fn create_issue_all_fields_populated() {
         compacted_at_commit: None,
         original_size: None,
         sender: None,
+        recurrence: None,
+        worklog: vec![],
     };
 
     storage.create_issue(&issue, "tester").unwrap();
@@ -443,6 +445,8 @@ fn update_issue_clear_optional_fields() {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        recurrence: None,
+        worklog: vec![],
     };
 
     storage.create_issue(&issue, "tester").unwrap();
@@ -717,6 +721,8 @@ fn upsert_issue_stores_all_fields() {
         compacted_at_commit: None,
         original_size: None,
         sender: None,
+        recurrence: None,
+        worklog: vec![],
     };
 
     storage.upsert_issue_for_import(&issue).unwrap();
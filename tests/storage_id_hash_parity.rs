@@ -589,6 +589,8 @@ fn content_hash_trait_implementation() {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        recurrence: None,
+        worklog: vec![],
     };
 
     let hash_trait = issue.content_hash();